@@ -0,0 +1,632 @@
+include macros2.asm
+include number.asm
+
+.MODEL LARGE
+.386
+.STACK 200h
+
+.DATA
+_0                           dd    0
+_4                           dd    4
+_1                           dd    1
+_v                           dd    5 dup (?)
+_names                       db    3 dup ('$', 50 dup (?))
+_halves                      dd    2 dup (?)
+_i                           dd    ?
+_j                           dd    ?
+_t                           dd    ?
+_n                           dd    ?
+_5                           dd    5
+_10                          dd    10
+_3                           dd    3
+_string_0                    db    'ada', '$'
+_2                           dd    2
+_string_1                    db    ' lovelace', '$'
+_@fpu_cw                     dd    ?
+_@fpu_trunc_cw               dd    ?
+_@read_number                dd    ?
+_@division_by_zero           db    'error: Runtime error: division by zero', '$'
+_@overflow                   db    'error: Runtime error: integer overflow', '$'
+_@index_out_of_bounds        db    'error: Runtime error: index out of bounds', '$'
+_@true                       db    'true', '$'
+_@false                      db    'false', '$'
+_@t0                         dd    ?
+_@t1                         dd    ?
+_@t2                         dd    ?
+_@t3                         dd    ?
+_@t4                         dd    ?
+_@t5                         dd    ?
+_@t6                         dd    ?
+_@t7                         dd    ?
+_@t8                         dd    ?
+_@t9                         dd    ?
+_@t10                        dd    ?
+_@t11                        dd    ?
+_@t12                        dd    ?
+_@t13                        dd    ?
+_@t14                        dd    ?
+_@t15                        dd    ?
+_@t16                        dd    ?
+_@t17                        db    '$', 50 dup (?)
+_@t18                        db    '$', 50 dup (?)
+_@t19                        db    '$', 50 dup (?)
+_@t20                        db    '$', 50 dup (?)
+_@t21                        dd    ?
+_@t22                        dd    ?
+_@t23                        dd    ?
+_@t24                        dd    ?
+_@t25                        dd    ?
+_@t26                        dd    ?
+_@t27                        dd    ?
+_@t28                        dd    ?
+_@args_total_0               dd    ?
+_@frame_total LABEL BYTE
+_total@n                     dd    ?
+_total@v                     dd    4 dup (?)
+_total@i                     dd    ?
+_total@s                     dd    ?
+_@total@t0                   dd    ?
+_@total@t1                   dd    ?
+_@total@t2                   dd    ?
+_@total@t3                   dd    ?
+_@total@t4                   dd    ?
+_@total@t5                   dd    ?
+_@total@t6                   dd    ?
+.CODE
+
+Program:
+    MOV AX, @DATA
+    MOV DS, AX
+    MOV ES, AX
+
+    FSTCW   WORD PTR _@fpu_cw
+    MOV     AX, WORD PTR _@fpu_cw
+    OR      AX, 0C00H
+    MOV     WORD PTR _@fpu_trunc_cw, AX
+
+    MOV     EAX, _0
+    MOV     _i, EAX
+
+label_8:
+    MOV     EAX, _i
+    CMP     EAX, _5
+    JL    label_9
+
+    JMP    label_10
+
+label_9:
+    MOV     EAX, _i
+    IMUL    EAX, _3
+    MOV     _@t0, EAX
+
+    MOV     EAX, _10
+    SUB     EAX, _@t0
+    MOV     _@t1, EAX
+
+    MOV     EAX, _i
+    CMP     EAX, 5
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _@t1
+    MOV     _v[BX], EAX
+
+    MOV     EAX, _i
+    ADD     EAX, _1
+    MOV     _@t2, EAX
+
+    MOV     EAX, _@t2
+    MOV     _i, EAX
+
+    JMP    label_8
+
+label_10:
+    MOV     BX, 0
+    MOV     EAX, _v[BX]
+    MOV     _@t3, EAX
+
+    MOV     EAX, _@t3
+    ADD     EAX, _1
+    MOV     _@t4, EAX
+
+    MOV     BX, 0
+    MOV     EAX, _@t4
+    MOV     _v[BX], EAX
+
+    MOV     EAX, _0
+    MOV     _i, EAX
+
+label_11:
+    MOV     EAX, _i
+    CMP     EAX, _4
+    JL    label_12
+
+    JMP    label_13
+
+label_12:
+    MOV     EAX, _0
+    MOV     _j, EAX
+
+label_14:
+    MOV     EAX, _4
+    SUB     EAX, _i
+    MOV     _@t5, EAX
+
+    MOV     EAX, _j
+    CMP     EAX, _@t5
+    JL    label_15
+
+    JMP    label_16
+
+label_15:
+    MOV     EAX, _j
+    CMP     EAX, 5
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _v[BX]
+    MOV     _@t6, EAX
+
+    MOV     EAX, _j
+    ADD     EAX, _1
+    MOV     _@t7, EAX
+
+    MOV     EAX, _@t7
+    CMP     EAX, 5
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _v[BX]
+    MOV     _@t8, EAX
+
+    MOV     EAX, _@t6
+    CMP     EAX, _@t8
+    JG    label_17
+
+    JMP    label_18
+
+label_17:
+    MOV     EAX, _j
+    CMP     EAX, 5
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _v[BX]
+    MOV     _@t9, EAX
+
+    MOV     EAX, _@t9
+    MOV     _t, EAX
+
+    MOV     EAX, _j
+    ADD     EAX, _1
+    MOV     _@t10, EAX
+
+    MOV     EAX, _@t10
+    CMP     EAX, 5
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _v[BX]
+    MOV     _@t11, EAX
+
+    MOV     EAX, _j
+    CMP     EAX, 5
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _@t11
+    MOV     _v[BX], EAX
+
+    MOV     EAX, _j
+    ADD     EAX, _1
+    MOV     _@t12, EAX
+
+    MOV     EAX, _@t12
+    CMP     EAX, 5
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _t
+    MOV     _v[BX], EAX
+
+label_18:
+    MOV     EAX, _j
+    ADD     EAX, _1
+    MOV     _@t13, EAX
+
+    MOV     EAX, _@t13
+    MOV     _j, EAX
+
+    JMP    label_14
+
+label_16:
+    MOV     EAX, _i
+    ADD     EAX, _1
+    MOV     _@t14, EAX
+
+    MOV     EAX, _@t14
+    MOV     _i, EAX
+
+    JMP    label_11
+
+label_13:
+    MOV     EAX, _0
+    MOV     _i, EAX
+
+label_19:
+    MOV     EAX, _i
+    CMP     EAX, _5
+    JL    label_20
+
+    JMP    label_21
+
+label_20:
+    MOV     EAX, _i
+    CMP     EAX, 5
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _v[BX]
+    MOV     _@t15, EAX
+
+    MOV     EAX, _@t15
+    CALL    _@display_int
+    newLine
+
+    MOV     EAX, _i
+    ADD     EAX, _1
+    MOV     _@t16, EAX
+
+    MOV     EAX, _@t16
+    MOV     _i, EAX
+
+    JMP    label_19
+
+label_21:
+    MOV     BX, 0
+    LEA     DI, _names[BX]
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_0
+    CALL    _@append_string
+
+    MOV     BX, 0
+    MOV     DI, OFFSET _@t17
+    MOV     CX, 50
+    LEA     SI, _names[BX]
+    CALL    _@append_string
+
+    MOV     DI, OFFSET _@t18
+    MOV     CX, 50
+    MOV     SI, OFFSET _@t17
+    CALL    _@append_string
+    MOV     SI, OFFSET _string_1
+    CALL    _@append_string
+
+    MOV     BX, 102
+    LEA     DI, _names[BX]
+    MOV     CX, 50
+    MOV     SI, OFFSET _@t18
+    CALL    _@append_string
+
+    MOV     BX, 102
+    MOV     DI, OFFSET _@t19
+    MOV     CX, 50
+    LEA     SI, _names[BX]
+    CALL    _@append_string
+
+    DisplayString    _@t19
+    newLine
+
+    MOV     BX, 51
+    MOV     DI, OFFSET _@t20
+    MOV     CX, 50
+    LEA     SI, _names[BX]
+    CALL    _@append_string
+
+    DisplayString    _@t20
+    newLine
+
+    FILD    _3
+    FSTP    _@t21
+
+    MOV     BX, 4
+    FLD     _@t21
+    FSTP    _halves[BX]
+
+    MOV     BX, 4
+    FLD     _halves[BX]
+    FSTP    _@t22
+
+    FILD    _2
+    FSTP    _@t23
+
+    FLD     _@t22
+    FLD     _@t23
+    FDIV
+    FSTP    _@t24
+
+    MOV     BX, 0
+    FLD     _@t24
+    FSTP    _halves[BX]
+
+    MOV     BX, 0
+    FLD     _halves[BX]
+    FSTP    _@t25
+
+    DisplayFloat    _@t25, 2
+    newLine
+
+    GetFloat    _@read_number
+    FLD     _@read_number
+    FLDCW   WORD PTR _@fpu_trunc_cw
+    FISTP   _n
+    FLDCW   WORD PTR _@fpu_cw
+
+    newLine
+
+    GetFloat    _@read_number
+    FLD     _@read_number
+    FLDCW   WORD PTR _@fpu_trunc_cw
+    FISTP   _@t26
+    FLDCW   WORD PTR _@fpu_cw
+
+    newLine
+
+    MOV     EAX, _n
+    CMP     EAX, 5
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _@t26
+    MOV     _v[BX], EAX
+
+    MOV     EAX, _n
+    CMP     EAX, 5
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _v[BX]
+    MOV     _@t27, EAX
+
+    MOV     EAX, _@t27
+    CALL    _@display_int
+    newLine
+
+    MOV     EAX, _3
+    MOV     _@args_total_0, EAX
+
+    CALL    _@fn_total
+    MOV     _@t28, EAX
+
+    MOV     EAX, _@t28
+    CALL    _@display_int
+    newLine
+
+    MOV AX, 4C00H
+    INT 21H
+
+_@fn_total PROC NEAR
+    SUB     SP, 56
+    MOV     DI, SP
+    PUSH    ES
+    PUSH    SS
+    POP     ES
+    MOV     SI, OFFSET _@frame_total
+    MOV     CX, 56
+    CLD
+    REP     MOVSB
+    POP     ES
+
+    MOV     EAX, _@args_total_0
+    MOV     _total@n, EAX
+
+    MOV     EAX, _0
+    MOV     _total@i, EAX
+
+label_0:
+    MOV     EAX, _total@i
+    CMP     EAX, _4
+    JL    label_1
+
+    JMP    label_2
+
+label_1:
+    MOV     EAX, _total@n
+    IMUL    EAX, _total@i
+    MOV     _@total@t0, EAX
+
+    MOV     EAX, _total@i
+    CMP     EAX, 4
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _@total@t0
+    MOV     _total@v[BX], EAX
+
+    MOV     EAX, _total@i
+    ADD     EAX, _1
+    MOV     _@total@t1, EAX
+
+    MOV     EAX, _@total@t1
+    MOV     _total@i, EAX
+
+    JMP    label_0
+
+label_2:
+    MOV     EAX, _0
+    MOV     _total@s, EAX
+
+    MOV     EAX, _total@n
+    CMP     EAX, _1
+    JG    label_3
+
+    JMP    label_4
+
+label_3:
+    MOV     EAX, _total@n
+    SUB     EAX, _1
+    MOV     _@total@t2, EAX
+
+    MOV     EAX, _@total@t2
+    MOV     _@args_total_0, EAX
+
+    CALL    _@fn_total
+    MOV     _@total@t3, EAX
+
+    MOV     EAX, _@total@t3
+    MOV     _total@s, EAX
+
+label_4:
+    MOV     EAX, _0
+    MOV     _total@i, EAX
+
+label_5:
+    MOV     EAX, _total@i
+    CMP     EAX, _4
+    JL    label_6
+
+    JMP    label_7
+
+label_6:
+    MOV     EAX, _total@i
+    CMP     EAX, 4
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _total@v[BX]
+    MOV     _@total@t4, EAX
+
+    MOV     EAX, _total@s
+    ADD     EAX, _@total@t4
+    MOV     _@total@t5, EAX
+
+    MOV     EAX, _@total@t5
+    MOV     _total@s, EAX
+
+    MOV     EAX, _total@i
+    ADD     EAX, _1
+    MOV     _@total@t6, EAX
+
+    MOV     EAX, _@total@t6
+    MOV     _total@i, EAX
+
+    JMP    label_5
+
+label_7:
+    MOV     EAX, _total@s
+    JMP     _@return_total
+
+_@return_total:
+    MOV     SI, SP
+    PUSH    DS
+    MOV     DI, OFFSET _@frame_total
+    PUSH    SS
+    POP     DS
+    MOV     CX, 56
+    CLD
+    REP     MOVSB
+    POP     DS
+    ADD     SP, 56
+    RET
+_@fn_total ENDP
+
+
+; Runtime routines shared by every program
+
+; Prints the signed int in EAX
+_@display_int PROC NEAR
+    XOR     CX, CX
+    MOV     EBX, 10
+    CMP     EAX, 0
+    JGE     _@display_int_divide
+    PUSH    EAX
+    MOV     DL, '-'
+    MOV     AH, 02H
+    INT     21H
+    POP     EAX
+    NEG     EAX
+_@display_int_divide:
+    ; Digits are pushed from the least significant one and printed in reverse
+    XOR     EDX, EDX
+    DIV     EBX
+    PUSH    DX
+    INC     CX
+    CMP     EAX, 0
+    JNE     _@display_int_divide
+_@display_int_print:
+    POP     DX
+    ADD     DL, '0'
+    MOV     AH, 02H
+    INT     21H
+    LOOP    _@display_int_print
+    RET
+_@display_int ENDP
+
+; Prints true if EAX isn't zero and false if it is
+_@display_bool PROC NEAR
+    CMP     EAX, 0
+    JE      _@display_bool_false
+    DisplayString    _@true
+    RET
+_@display_bool_false:
+    DisplayString    _@false
+    RET
+_@display_bool ENDP
+
+; Appends the '$' terminated string at SI to the one ending at DI while CX, the space left, isn't
+; zero. Leaves DI on the '$' ending the result and CX with the space left so calls can be chained
+_@append_string PROC NEAR
+    CLD
+_@append_string_next:
+    CMP     BYTE PTR [SI], '$'
+    JE      _@append_string_end
+    JCXZ    _@append_string_end
+    MOVSB
+    DEC     CX
+    JMP     _@append_string_next
+_@append_string_end:
+    MOV     BYTE PTR [DI], '$'
+    RET
+_@append_string ENDP
+
+; Compares the '$' terminated strings at SI and DI, ZF is set if they are equal
+_@compare_strings PROC NEAR
+    MOV     AL, [SI]
+    CMP     AL, [DI]
+    JNE     _@compare_strings_end
+    CMP     AL, '$'
+    JE      _@compare_strings_end
+    INC     SI
+    INC     DI
+    JMP     _@compare_strings
+_@compare_strings_end:
+    RET
+_@compare_strings ENDP
+
+; Divides EAX by ECX leaving the quotient in EAX. Fails on a division by zero and on the only
+; quotient that doesn't fit, the smallest int divided by -1, where IDIV would raise an exception
+_@divide PROC NEAR
+    CMP     ECX, 0
+    JE      _@error_division_by_zero
+    CMP     ECX, -1
+    JNE     _@divide_signed
+    NEG     EAX
+    JO      _@error_overflow
+    RET
+_@divide_signed:
+    ; IDIV truncates towards zero
+    CDQ
+    IDIV    ECX
+    RET
+_@divide ENDP
+
+; Reports a division by zero and exits with an error
+_@error_division_by_zero:
+    DisplayString    _@division_by_zero
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an int operation whose result doesn't fit and exits with an error
+_@error_overflow:
+    DisplayString    _@overflow
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an access outside the bounds of an array and exits with an error
+_@error_index_out_of_bounds:
+    DisplayString    _@index_out_of_bounds
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+    END Program
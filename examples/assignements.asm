@@ -0,0 +1,169 @@
+include macros2.asm
+include number.asm
+
+.MODEL LARGE
+.386
+.STACK 200h
+
+.DATA
+_a                           dd    ?
+_b                           db    '$', 50 dup (?)
+_99999_99                    dd    99999.99
+_99_                         dd    99.
+__9999                       dd    .9999
+_string_0                    db    '@sdADaSjfla%dfg', '$'
+_string_1                    db    'asldk  fh sjf', '$'
+_@fpu_cw                     dd    ?
+_@fpu_trunc_cw               dd    ?
+_@read_number                dd    ?
+_@division_by_zero           db    'error: Runtime error: division by zero', '$'
+_@overflow                   db    'error: Runtime error: integer overflow', '$'
+_@index_out_of_bounds        db    'error: Runtime error: index out of bounds', '$'
+_@true                       db    'true', '$'
+_@false                      db    'false', '$'
+.CODE
+
+Program:
+    MOV AX, @DATA
+    MOV DS, AX
+    MOV ES, AX
+
+    FSTCW   WORD PTR _@fpu_cw
+    MOV     AX, WORD PTR _@fpu_cw
+    OR      AX, 0C00H
+    MOV     WORD PTR _@fpu_trunc_cw, AX
+
+    FLD     _99999_99
+    FSTP    _a
+
+    FLD     _99_
+    FSTP    _a
+
+    FLD     __9999
+    FSTP    _a
+
+    MOV     DI, OFFSET _b
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_0
+    CALL    _@append_string
+
+    MOV     DI, OFFSET _b
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_1
+    CALL    _@append_string
+
+    MOV AX, 4C00H
+    INT 21H
+
+
+; Runtime routines shared by every program
+
+; Prints the signed int in EAX
+_@display_int PROC NEAR
+    XOR     CX, CX
+    MOV     EBX, 10
+    CMP     EAX, 0
+    JGE     _@display_int_divide
+    PUSH    EAX
+    MOV     DL, '-'
+    MOV     AH, 02H
+    INT     21H
+    POP     EAX
+    NEG     EAX
+_@display_int_divide:
+    ; Digits are pushed from the least significant one and printed in reverse
+    XOR     EDX, EDX
+    DIV     EBX
+    PUSH    DX
+    INC     CX
+    CMP     EAX, 0
+    JNE     _@display_int_divide
+_@display_int_print:
+    POP     DX
+    ADD     DL, '0'
+    MOV     AH, 02H
+    INT     21H
+    LOOP    _@display_int_print
+    RET
+_@display_int ENDP
+
+; Prints true if EAX isn't zero and false if it is
+_@display_bool PROC NEAR
+    CMP     EAX, 0
+    JE      _@display_bool_false
+    DisplayString    _@true
+    RET
+_@display_bool_false:
+    DisplayString    _@false
+    RET
+_@display_bool ENDP
+
+; Appends the '$' terminated string at SI to the one ending at DI while CX, the space left, isn't
+; zero. Leaves DI on the '$' ending the result and CX with the space left so calls can be chained
+_@append_string PROC NEAR
+    CLD
+_@append_string_next:
+    CMP     BYTE PTR [SI], '$'
+    JE      _@append_string_end
+    JCXZ    _@append_string_end
+    MOVSB
+    DEC     CX
+    JMP     _@append_string_next
+_@append_string_end:
+    MOV     BYTE PTR [DI], '$'
+    RET
+_@append_string ENDP
+
+; Compares the '$' terminated strings at SI and DI, ZF is set if they are equal
+_@compare_strings PROC NEAR
+    MOV     AL, [SI]
+    CMP     AL, [DI]
+    JNE     _@compare_strings_end
+    CMP     AL, '$'
+    JE      _@compare_strings_end
+    INC     SI
+    INC     DI
+    JMP     _@compare_strings
+_@compare_strings_end:
+    RET
+_@compare_strings ENDP
+
+; Divides EAX by ECX leaving the quotient in EAX. Fails on a division by zero and on the only
+; quotient that doesn't fit, the smallest int divided by -1, where IDIV would raise an exception
+_@divide PROC NEAR
+    CMP     ECX, 0
+    JE      _@error_division_by_zero
+    CMP     ECX, -1
+    JNE     _@divide_signed
+    NEG     EAX
+    JO      _@error_overflow
+    RET
+_@divide_signed:
+    ; IDIV truncates towards zero
+    CDQ
+    IDIV    ECX
+    RET
+_@divide ENDP
+
+; Reports a division by zero and exits with an error
+_@error_division_by_zero:
+    DisplayString    _@division_by_zero
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an int operation whose result doesn't fit and exits with an error
+_@error_overflow:
+    DisplayString    _@overflow
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an access outside the bounds of an array and exits with an error
+_@error_index_out_of_bounds:
+    DisplayString    _@index_out_of_bounds
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+    END Program
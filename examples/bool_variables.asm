@@ -0,0 +1,595 @@
+include macros2.asm
+include number.asm
+
+.MODEL LARGE
+.386
+.STACK 200h
+
+.DATA
+_2                           dd    2
+_0                           dd    0
+_true                        dd    1
+_false                       dd    0
+_1                           dd    1
+_a                           dd    ?
+_b                           dd    ?
+_c                           dd    ?
+_d                           dd    ?
+_i                           dd    ?
+_evens                       dd    ?
+_ok                          dd    ?
+_done                        dd    ?
+_copy                        dd    ?
+_seen                        dd    4 dup (?)
+_3                           dd    3
+_5                           dd    5
+_string_0                    db    'ok is set', '$'
+_string_1                    db    'not done', '$'
+_4                           dd    4
+_@fpu_cw                     dd    ?
+_@fpu_trunc_cw               dd    ?
+_@read_number                dd    ?
+_@division_by_zero           db    'error: Runtime error: division by zero', '$'
+_@overflow                   db    'error: Runtime error: integer overflow', '$'
+_@index_out_of_bounds        db    'error: Runtime error: index out of bounds', '$'
+_@true                       db    'true', '$'
+_@false                      db    'false', '$'
+_@t0                         dd    ?
+_@t1                         dd    ?
+_@t2                         dd    ?
+_@t3                         dd    ?
+_@t4                         dd    ?
+_@t5                         dd    ?
+_@t6                         dd    ?
+_@t7                         dd    ?
+_@t8                         dd    ?
+_@t9                         dd    ?
+_@t10                        dd    ?
+_@t11                        dd    ?
+_@t12                        dd    ?
+_@t13                        dd    ?
+_@args_isEven_0              dd    ?
+_@args_count_0               dd    ?
+_@args_count_1               dd    ?
+_@frame_isEven LABEL BYTE
+_isEven@n                    dd    ?
+_@isEven@t0                  dd    ?
+_@isEven@t1                  dd    ?
+_@isEven@t2                  dd    ?
+_@isEven@t3                  dd    ?
+_@frame_count LABEL BYTE
+_count@flags                 dd    ?
+_count@value                 dd    ?
+.CODE
+
+Program:
+    MOV AX, @DATA
+    MOV DS, AX
+    MOV ES, AX
+
+    FSTCW   WORD PTR _@fpu_cw
+    MOV     AX, WORD PTR _@fpu_cw
+    OR      AX, 0C00H
+    MOV     WORD PTR _@fpu_trunc_cw, AX
+
+    MOV     EAX, _3
+    MOV     _a, EAX
+
+    MOV     EAX, _2
+    MOV     _b, EAX
+
+    MOV     EAX, _1
+    MOV     _c, EAX
+
+    MOV     EAX, _5
+    MOV     _d, EAX
+
+    MOV     EAX, _a
+    CMP     EAX, _b
+    JG    label_8
+
+    JMP    label_6
+
+label_8:
+    MOV     EAX, _c
+    CMP     EAX, _d
+    JL    label_5
+
+    JMP    label_6
+
+label_5:
+    MOV     EAX, _true
+    MOV     _@t0, EAX
+
+    JMP    label_7
+
+label_6:
+    MOV     EAX, _false
+    MOV     _@t0, EAX
+
+label_7:
+    MOV     EAX, _@t0
+    MOV     _ok, EAX
+
+    MOV     EAX, _ok
+    CALL    _@display_bool
+    newLine
+
+    MOV     EAX, _ok
+    CMP     EAX, _false
+    JE    label_9
+
+    JMP    label_10
+
+label_9:
+    MOV     EAX, _true
+    MOV     _@t1, EAX
+
+    JMP    label_11
+
+label_10:
+    MOV     EAX, _false
+    MOV     _@t1, EAX
+
+label_11:
+    MOV     EAX, _@t1
+    CALL    _@display_bool
+    newLine
+
+    MOV     EAX, _a
+    CMP     EAX, _b
+    JE    label_12
+
+label_15:
+    MOV     EAX, _c
+    CMP     EAX, _1
+    JE    label_12
+
+    JMP    label_13
+
+label_12:
+    MOV     EAX, _true
+    MOV     _@t2, EAX
+
+    JMP    label_14
+
+label_13:
+    MOV     EAX, _false
+    MOV     _@t2, EAX
+
+label_14:
+    MOV     EAX, _@t2
+    CALL    _@display_bool
+    newLine
+
+    MOV     EAX, _false
+    MOV     _done, EAX
+
+    MOV     EAX, _done
+    CALL    _@display_bool
+    newLine
+
+    MOV     EAX, _ok
+    MOV     _copy, EAX
+
+    MOV     EAX, _copy
+    CMP     EAX, _ok
+    JE    label_16
+
+    JMP    label_17
+
+label_16:
+    MOV     EAX, _true
+    MOV     _@t3, EAX
+
+    JMP    label_18
+
+label_17:
+    MOV     EAX, _false
+    MOV     _@t3, EAX
+
+label_18:
+    MOV     EAX, _@t3
+    CALL    _@display_bool
+    newLine
+
+    MOV     EAX, _copy
+    CMP     EAX, _true
+    JNE    label_19
+
+    JMP    label_20
+
+label_19:
+    MOV     EAX, _true
+    MOV     _@t4, EAX
+
+    JMP    label_21
+
+label_20:
+    MOV     EAX, _false
+    MOV     _@t4, EAX
+
+label_21:
+    MOV     EAX, _@t4
+    CALL    _@display_bool
+    newLine
+
+    MOV     EAX, _ok
+    CMP     EAX, _false
+    JNE    label_22
+
+    JMP    label_23
+
+label_22:
+    DisplayString    _string_0
+    newLine
+
+label_23:
+    MOV     EAX, _done
+    CMP     EAX, _false
+    JE    label_26
+
+    JMP    label_25
+
+label_26:
+    MOV     EAX, _copy
+    CMP     EAX, _false
+    JNE    label_24
+
+    JMP    label_25
+
+label_24:
+    DisplayString    _string_1
+    newLine
+
+label_25:
+    MOV     EAX, _0
+    MOV     _i, EAX
+
+    MOV     EAX, _0
+    MOV     _evens, EAX
+
+label_27:
+    MOV     EAX, _i
+    CMP     EAX, _4
+    JL    label_28
+
+    JMP    label_29
+
+label_28:
+    MOV     EAX, _i
+    MOV     _@args_isEven_0, EAX
+
+    CALL    _@fn_isEven
+    MOV     _@t5, EAX
+
+    MOV     EAX, _i
+    CMP     EAX, 4
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _@t5
+    MOV     _seen[BX], EAX
+
+    MOV     EAX, _i
+    CMP     EAX, 4
+    JAE     _@error_index_out_of_bounds
+    IMUL    BX, AX, 4
+    MOV     EAX, _seen[BX]
+    MOV     _@t6, EAX
+
+    MOV     EAX, _@t6
+    MOV     _@args_count_0, EAX
+
+    MOV     EAX, _true
+    MOV     _@args_count_1, EAX
+
+    CALL    _@fn_count
+    MOV     _@t7, EAX
+
+    MOV     EAX, _evens
+    ADD     EAX, _@t7
+    MOV     _@t8, EAX
+
+    MOV     EAX, _@t8
+    MOV     _evens, EAX
+
+    MOV     EAX, _i
+    ADD     EAX, _1
+    MOV     _@t9, EAX
+
+    MOV     EAX, _@t9
+    MOV     _i, EAX
+
+    JMP    label_27
+
+label_29:
+    MOV     EAX, _evens
+    CALL    _@display_int
+    newLine
+
+    MOV     BX, 4
+    MOV     EAX, _seen[BX]
+    MOV     _@t10, EAX
+
+    MOV     EAX, _@t10
+    CALL    _@display_bool
+    newLine
+
+    MOV     BX, 8
+    MOV     EAX, _seen[BX]
+    MOV     _@t11, EAX
+
+    MOV     EAX, _@t11
+    CALL    _@display_bool
+    newLine
+
+label_30:
+    MOV     EAX, _done
+    CMP     EAX, _false
+    JE    label_31
+
+    JMP    label_32
+
+label_31:
+    MOV     EAX, _d
+    SUB     EAX, _1
+    MOV     _@t12, EAX
+
+    MOV     EAX, _@t12
+    MOV     _d, EAX
+
+    MOV     EAX, _d
+    CMP     EAX, _2
+    JLE    label_33
+
+label_36:
+    MOV     EAX, _d
+    CMP     EAX, _0
+    JE    label_33
+
+    JMP    label_34
+
+label_33:
+    MOV     EAX, _true
+    MOV     _@t13, EAX
+
+    JMP    label_35
+
+label_34:
+    MOV     EAX, _false
+    MOV     _@t13, EAX
+
+label_35:
+    MOV     EAX, _@t13
+    MOV     _done, EAX
+
+    JMP    label_30
+
+label_32:
+    MOV     EAX, _d
+    CALL    _@display_int
+    newLine
+
+    MOV AX, 4C00H
+    INT 21H
+
+_@fn_isEven PROC NEAR
+    SUB     SP, 20
+    MOV     DI, SP
+    PUSH    ES
+    PUSH    SS
+    POP     ES
+    MOV     SI, OFFSET _@frame_isEven
+    MOV     CX, 20
+    CLD
+    REP     MOVSB
+    POP     ES
+
+    MOV     EAX, _@args_isEven_0
+    MOV     _isEven@n, EAX
+
+    MOV     EAX, _isEven@n
+    MOV     ECX, _2
+    CALL    _@divide
+    MOV     _@isEven@t1, EAX
+
+    MOV     EAX, _@isEven@t1
+    IMUL    EAX, _2
+    MOV     _@isEven@t2, EAX
+
+    MOV     EAX, _isEven@n
+    SUB     EAX, _@isEven@t2
+    MOV     _@isEven@t3, EAX
+
+    MOV     EAX, _@isEven@t3
+    CMP     EAX, _0
+    JE    label_0
+
+    JMP    label_1
+
+label_0:
+    MOV     EAX, _true
+    MOV     _@isEven@t0, EAX
+
+    JMP    label_2
+
+label_1:
+    MOV     EAX, _false
+    MOV     _@isEven@t0, EAX
+
+label_2:
+    MOV     EAX, _@isEven@t0
+    JMP     _@return_isEven
+
+_@return_isEven:
+    MOV     SI, SP
+    PUSH    DS
+    MOV     DI, OFFSET _@frame_isEven
+    PUSH    SS
+    POP     DS
+    MOV     CX, 20
+    CLD
+    REP     MOVSB
+    POP     DS
+    ADD     SP, 20
+    RET
+_@fn_isEven ENDP
+
+_@fn_count PROC NEAR
+    SUB     SP, 8
+    MOV     DI, SP
+    PUSH    ES
+    PUSH    SS
+    POP     ES
+    MOV     SI, OFFSET _@frame_count
+    MOV     CX, 8
+    CLD
+    REP     MOVSB
+    POP     ES
+
+    MOV     EAX, _@args_count_0
+    MOV     _count@flags, EAX
+
+    MOV     EAX, _@args_count_1
+    MOV     _count@value, EAX
+
+    MOV     EAX, _count@flags
+    CMP     EAX, _count@value
+    JE    label_3
+
+    JMP    label_4
+
+label_3:
+    MOV     EAX, _1
+    JMP     _@return_count
+
+label_4:
+    MOV     EAX, _0
+    JMP     _@return_count
+
+_@return_count:
+    MOV     SI, SP
+    PUSH    DS
+    MOV     DI, OFFSET _@frame_count
+    PUSH    SS
+    POP     DS
+    MOV     CX, 8
+    CLD
+    REP     MOVSB
+    POP     DS
+    ADD     SP, 8
+    RET
+_@fn_count ENDP
+
+
+; Runtime routines shared by every program
+
+; Prints the signed int in EAX
+_@display_int PROC NEAR
+    XOR     CX, CX
+    MOV     EBX, 10
+    CMP     EAX, 0
+    JGE     _@display_int_divide
+    PUSH    EAX
+    MOV     DL, '-'
+    MOV     AH, 02H
+    INT     21H
+    POP     EAX
+    NEG     EAX
+_@display_int_divide:
+    ; Digits are pushed from the least significant one and printed in reverse
+    XOR     EDX, EDX
+    DIV     EBX
+    PUSH    DX
+    INC     CX
+    CMP     EAX, 0
+    JNE     _@display_int_divide
+_@display_int_print:
+    POP     DX
+    ADD     DL, '0'
+    MOV     AH, 02H
+    INT     21H
+    LOOP    _@display_int_print
+    RET
+_@display_int ENDP
+
+; Prints true if EAX isn't zero and false if it is
+_@display_bool PROC NEAR
+    CMP     EAX, 0
+    JE      _@display_bool_false
+    DisplayString    _@true
+    RET
+_@display_bool_false:
+    DisplayString    _@false
+    RET
+_@display_bool ENDP
+
+; Appends the '$' terminated string at SI to the one ending at DI while CX, the space left, isn't
+; zero. Leaves DI on the '$' ending the result and CX with the space left so calls can be chained
+_@append_string PROC NEAR
+    CLD
+_@append_string_next:
+    CMP     BYTE PTR [SI], '$'
+    JE      _@append_string_end
+    JCXZ    _@append_string_end
+    MOVSB
+    DEC     CX
+    JMP     _@append_string_next
+_@append_string_end:
+    MOV     BYTE PTR [DI], '$'
+    RET
+_@append_string ENDP
+
+; Compares the '$' terminated strings at SI and DI, ZF is set if they are equal
+_@compare_strings PROC NEAR
+    MOV     AL, [SI]
+    CMP     AL, [DI]
+    JNE     _@compare_strings_end
+    CMP     AL, '$'
+    JE      _@compare_strings_end
+    INC     SI
+    INC     DI
+    JMP     _@compare_strings
+_@compare_strings_end:
+    RET
+_@compare_strings ENDP
+
+; Divides EAX by ECX leaving the quotient in EAX. Fails on a division by zero and on the only
+; quotient that doesn't fit, the smallest int divided by -1, where IDIV would raise an exception
+_@divide PROC NEAR
+    CMP     ECX, 0
+    JE      _@error_division_by_zero
+    CMP     ECX, -1
+    JNE     _@divide_signed
+    NEG     EAX
+    JO      _@error_overflow
+    RET
+_@divide_signed:
+    ; IDIV truncates towards zero
+    CDQ
+    IDIV    ECX
+    RET
+_@divide ENDP
+
+; Reports a division by zero and exits with an error
+_@error_division_by_zero:
+    DisplayString    _@division_by_zero
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an int operation whose result doesn't fit and exits with an error
+_@error_overflow:
+    DisplayString    _@overflow
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an access outside the bounds of an array and exits with an error
+_@error_index_out_of_bounds:
+    DisplayString    _@index_out_of_bounds
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+    END Program
@@ -0,0 +1,467 @@
+include macros2.asm
+include number.asm
+
+.MODEL LARGE
+.386
+.STACK 200h
+
+.DATA
+_a                           dd    ?
+_b                           dd    ?
+_c                           dd    ?
+_i                           dd    ?
+_j                           dd    ?
+_1                           dd    1
+_2                           dd    2
+_3                           dd    3
+_string_0                    db    'and or', '$'
+_string_1                    db    'wrong', '$'
+_string_2                    db    'and or else', '$'
+_string_3                    db    'or or', '$'
+_string_5                    db    'or and else', '$'
+_string_6                    db    'not and', '$'
+_true                        dd    1
+_false                       dd    0
+_string_8                    db    'not constants', '$'
+_0                           dd    0
+_string_9                    db    'isZero or not', '$'
+_5                           dd    5
+_10                          dd    10
+_4                           dd    4
+_100                         dd    100
+_@fpu_cw                     dd    ?
+_@fpu_trunc_cw               dd    ?
+_@read_number                dd    ?
+_@division_by_zero           db    'error: Runtime error: division by zero', '$'
+_@overflow                   db    'error: Runtime error: integer overflow', '$'
+_@index_out_of_bounds        db    'error: Runtime error: index out of bounds', '$'
+_@true                       db    'true', '$'
+_@false                      db    'false', '$'
+_@t0                         dd    ?
+_@t1                         dd    ?
+_@t2                         dd    ?
+_@t3                         dd    ?
+_@t4                         dd    ?
+_@t5                         dd    ?
+_@t6                         dd    ?
+_@t7                         dd    ?
+.CODE
+
+Program:
+    MOV AX, @DATA
+    MOV DS, AX
+    MOV ES, AX
+
+    FSTCW   WORD PTR _@fpu_cw
+    MOV     AX, WORD PTR _@fpu_cw
+    OR      AX, 0C00H
+    MOV     WORD PTR _@fpu_trunc_cw, AX
+
+    MOV     EAX, _1
+    MOV     _a, EAX
+
+    MOV     EAX, _2
+    MOV     _b, EAX
+
+    MOV     EAX, _3
+    MOV     _c, EAX
+
+    MOV     EAX, _a
+    CMP     EAX, _b
+    JL    label_2
+
+    JMP    label_1
+
+label_2:
+    MOV     EAX, _b
+    CMP     EAX, _c
+    JL    label_0
+
+label_3:
+    MOV     EAX, _c
+    CMP     EAX, _a
+    JL    label_0
+
+    JMP    label_1
+
+label_0:
+    DisplayString    _string_0
+    newLine
+
+label_1:
+    MOV     EAX, _a
+    CMP     EAX, _b
+    JG    label_7
+
+    JMP    label_6
+
+label_7:
+    MOV     EAX, _b
+    CMP     EAX, _c
+    JL    label_4
+
+label_8:
+    MOV     EAX, _c
+    CMP     EAX, _b
+    JL    label_4
+
+    JMP    label_6
+
+label_4:
+    DisplayString    _string_1
+    newLine
+
+    JMP    label_5
+
+label_6:
+    DisplayString    _string_2
+    newLine
+
+label_5:
+    MOV     EAX, _a
+    CMP     EAX, _b
+    JG    label_9
+
+label_11:
+    MOV     EAX, _b
+    CMP     EAX, _c
+    JG    label_9
+
+label_12:
+    MOV     EAX, _c
+    CMP     EAX, _a
+    JG    label_9
+
+    JMP    label_10
+
+label_9:
+    DisplayString    _string_3
+    newLine
+
+label_10:
+    MOV     EAX, _a
+    CMP     EAX, _b
+    JG    label_13
+
+label_16:
+    MOV     EAX, _b
+    CMP     EAX, _c
+    JG    label_17
+
+    JMP    label_15
+
+label_17:
+    MOV     EAX, _c
+    CMP     EAX, _a
+    JL    label_13
+
+    JMP    label_15
+
+label_13:
+    DisplayString    _string_1
+    newLine
+
+    JMP    label_14
+
+label_15:
+    DisplayString    _string_5
+    newLine
+
+label_14:
+    MOV     EAX, _a
+    CMP     EAX, _b
+    JLE    label_20
+
+    JMP    label_19
+
+label_20:
+    MOV     EAX, _b
+    CMP     EAX, _c
+    JLE    label_18
+
+    JMP    label_19
+
+label_18:
+    DisplayString    _string_6
+    newLine
+
+label_19:
+label_24:
+    JMP    label_23
+
+label_21:
+    DisplayString    _string_1
+    newLine
+
+    JMP    label_22
+
+label_23:
+    DisplayString    _string_8
+    newLine
+
+label_22:
+    MOV     EAX, _a
+    CMP     EAX, _0
+    JE    label_25
+
+label_27:
+    MOV     EAX, _b
+    CMP     EAX, _0
+    JNE    label_25
+
+    JMP    label_26
+
+label_25:
+    DisplayString    _string_9
+    newLine
+
+label_26:
+    MOV     EAX, _0
+    MOV     _i, EAX
+
+label_28:
+    MOV     EAX, _i
+    CMP     EAX, _3
+    JL    label_31
+
+    JMP    label_30
+
+label_31:
+    MOV     EAX, _i
+    CMP     EAX, _5
+    JNE    label_29
+
+    JMP    label_30
+
+label_29:
+    MOV     EAX, _0
+    MOV     _j, EAX
+
+label_32:
+    MOV     EAX, _j
+    CMP     EAX, _3
+    JL    label_33
+
+label_35:
+    MOV     EAX, _j
+    CMP     EAX, _10
+    JE    label_33
+
+    JMP    label_34
+
+label_33:
+    MOV     EAX, _i
+    CMP     EAX, _j
+    JE    label_36
+
+label_38:
+    MOV     EAX, _i
+    ADD     EAX, _j
+    MOV     _@t0, EAX
+
+    MOV     EAX, _@t0
+    CMP     EAX, _4
+    JE    label_39
+
+    JMP    label_37
+
+label_39:
+    MOV     EAX, _j
+    CMP     EAX, _3
+    JNE    label_36
+
+    JMP    label_37
+
+label_36:
+    MOV     EAX, _i
+    IMUL    EAX, _10
+    MOV     _@t1, EAX
+
+    MOV     EAX, _@t1
+    ADD     EAX, _j
+    MOV     _@t2, EAX
+
+    MOV     EAX, _@t2
+    CALL    _@display_int
+    newLine
+
+label_37:
+    MOV     EAX, _i
+    CMP     EAX, _j
+    JG    label_42
+
+    JMP    label_41
+
+label_42:
+    MOV     EAX, _j
+    CMP     EAX, _0
+    JE    label_40
+
+label_43:
+    MOV     EAX, _i
+    CMP     EAX, _0
+    JE    label_40
+
+    JMP    label_41
+
+label_40:
+    MOV     EAX, _i
+    CMP     EAX, _0
+    JNE    label_44
+
+    JMP    label_45
+
+label_44:
+    MOV     EAX, _i
+    IMUL    EAX, _10
+    MOV     _@t3, EAX
+
+    MOV     EAX, _100
+    ADD     EAX, _@t3
+    MOV     _@t4, EAX
+
+    MOV     EAX, _@t4
+    ADD     EAX, _j
+    MOV     _@t5, EAX
+
+    MOV     EAX, _@t5
+    CALL    _@display_int
+    newLine
+
+label_45:
+label_41:
+    MOV     EAX, _j
+    ADD     EAX, _1
+    MOV     _@t6, EAX
+
+    MOV     EAX, _@t6
+    MOV     _j, EAX
+
+    JMP    label_32
+
+label_34:
+    MOV     EAX, _i
+    ADD     EAX, _1
+    MOV     _@t7, EAX
+
+    MOV     EAX, _@t7
+    MOV     _i, EAX
+
+    JMP    label_28
+
+label_30:
+    MOV AX, 4C00H
+    INT 21H
+
+
+; Runtime routines shared by every program
+
+; Prints the signed int in EAX
+_@display_int PROC NEAR
+    XOR     CX, CX
+    MOV     EBX, 10
+    CMP     EAX, 0
+    JGE     _@display_int_divide
+    PUSH    EAX
+    MOV     DL, '-'
+    MOV     AH, 02H
+    INT     21H
+    POP     EAX
+    NEG     EAX
+_@display_int_divide:
+    ; Digits are pushed from the least significant one and printed in reverse
+    XOR     EDX, EDX
+    DIV     EBX
+    PUSH    DX
+    INC     CX
+    CMP     EAX, 0
+    JNE     _@display_int_divide
+_@display_int_print:
+    POP     DX
+    ADD     DL, '0'
+    MOV     AH, 02H
+    INT     21H
+    LOOP    _@display_int_print
+    RET
+_@display_int ENDP
+
+; Prints true if EAX isn't zero and false if it is
+_@display_bool PROC NEAR
+    CMP     EAX, 0
+    JE      _@display_bool_false
+    DisplayString    _@true
+    RET
+_@display_bool_false:
+    DisplayString    _@false
+    RET
+_@display_bool ENDP
+
+; Appends the '$' terminated string at SI to the one ending at DI while CX, the space left, isn't
+; zero. Leaves DI on the '$' ending the result and CX with the space left so calls can be chained
+_@append_string PROC NEAR
+    CLD
+_@append_string_next:
+    CMP     BYTE PTR [SI], '$'
+    JE      _@append_string_end
+    JCXZ    _@append_string_end
+    MOVSB
+    DEC     CX
+    JMP     _@append_string_next
+_@append_string_end:
+    MOV     BYTE PTR [DI], '$'
+    RET
+_@append_string ENDP
+
+; Compares the '$' terminated strings at SI and DI, ZF is set if they are equal
+_@compare_strings PROC NEAR
+    MOV     AL, [SI]
+    CMP     AL, [DI]
+    JNE     _@compare_strings_end
+    CMP     AL, '$'
+    JE      _@compare_strings_end
+    INC     SI
+    INC     DI
+    JMP     _@compare_strings
+_@compare_strings_end:
+    RET
+_@compare_strings ENDP
+
+; Divides EAX by ECX leaving the quotient in EAX. Fails on a division by zero and on the only
+; quotient that doesn't fit, the smallest int divided by -1, where IDIV would raise an exception
+_@divide PROC NEAR
+    CMP     ECX, 0
+    JE      _@error_division_by_zero
+    CMP     ECX, -1
+    JNE     _@divide_signed
+    NEG     EAX
+    JO      _@error_overflow
+    RET
+_@divide_signed:
+    ; IDIV truncates towards zero
+    CDQ
+    IDIV    ECX
+    RET
+_@divide ENDP
+
+; Reports a division by zero and exits with an error
+_@error_division_by_zero:
+    DisplayString    _@division_by_zero
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an int operation whose result doesn't fit and exits with an error
+_@error_overflow:
+    DisplayString    _@overflow
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an access outside the bounds of an array and exits with an error
+_@error_index_out_of_bounds:
+    DisplayString    _@index_out_of_bounds
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+    END Program
@@ -0,0 +1,878 @@
+    .intel_syntax noprefix
+
+    .section .data
+_a:
+    .quad 0
+_b:
+    .quad 0
+_c:
+    .quad 0
+_i:
+    .quad 0
+_j:
+    .quad 0
+__t0:
+    .quad 0
+__t1:
+    .quad 0
+__t2:
+    .quad 0
+__t3:
+    .quad 0
+__t4:
+    .quad 0
+__t5:
+    .quad 0
+__t6:
+    .quad 0
+__t7:
+    .quad 0
+
+    .section .rodata
+_string_0:
+    .byte 97, 110, 100, 32, 111, 114
+_string_1:
+    .byte 119, 114, 111, 110, 103
+_string_2:
+    .byte 97, 110, 100, 32, 111, 114, 32, 101, 108, 115, 101
+_string_3:
+    .byte 111, 114, 32, 111, 114
+_string_5:
+    .byte 111, 114, 32, 97, 110, 100, 32, 101, 108, 115, 101
+_string_6:
+    .byte 110, 111, 116, 32, 97, 110, 100
+_string_8:
+    .byte 110, 111, 116, 32, 99, 111, 110, 115, 116, 97, 110, 116, 115
+_string_9:
+    .byte 105, 115, 90, 101, 114, 111, 32, 111, 114, 32, 110, 111, 116
+
+    .section .text
+    .globl _start
+_start:
+    mov rax, 1
+    mov QWORD PTR [rip + _a], rax
+    mov rax, 2
+    mov QWORD PTR [rip + _b], rax
+    mov rax, 3
+    mov QWORD PTR [rip + _c], rax
+    mov rax, QWORD PTR [rip + _a]
+    mov rcx, QWORD PTR [rip + _b]
+    cmp rax, rcx
+    jl .L2
+    jmp .L1
+.L2:
+    mov rax, QWORD PTR [rip + _b]
+    mov rcx, QWORD PTR [rip + _c]
+    cmp rax, rcx
+    jl .L0
+.L3:
+    mov rax, QWORD PTR [rip + _c]
+    mov rcx, QWORD PTR [rip + _a]
+    cmp rax, rcx
+    jl .L0
+    jmp .L1
+.L0:
+    lea rax, [rip + _string_0]
+    mov rdx, 6
+    mov rdi, rax
+    mov rsi, rdx
+    call __lm_print_string
+    call __lm_print_newline
+.L1:
+    mov rax, QWORD PTR [rip + _a]
+    mov rcx, QWORD PTR [rip + _b]
+    cmp rax, rcx
+    jg .L7
+    jmp .L6
+.L7:
+    mov rax, QWORD PTR [rip + _b]
+    mov rcx, QWORD PTR [rip + _c]
+    cmp rax, rcx
+    jl .L4
+.L8:
+    mov rax, QWORD PTR [rip + _c]
+    mov rcx, QWORD PTR [rip + _b]
+    cmp rax, rcx
+    jl .L4
+    jmp .L6
+.L4:
+    lea rax, [rip + _string_1]
+    mov rdx, 5
+    mov rdi, rax
+    mov rsi, rdx
+    call __lm_print_string
+    call __lm_print_newline
+    jmp .L5
+.L6:
+    lea rax, [rip + _string_2]
+    mov rdx, 11
+    mov rdi, rax
+    mov rsi, rdx
+    call __lm_print_string
+    call __lm_print_newline
+.L5:
+    mov rax, QWORD PTR [rip + _a]
+    mov rcx, QWORD PTR [rip + _b]
+    cmp rax, rcx
+    jg .L9
+.L11:
+    mov rax, QWORD PTR [rip + _b]
+    mov rcx, QWORD PTR [rip + _c]
+    cmp rax, rcx
+    jg .L9
+.L12:
+    mov rax, QWORD PTR [rip + _c]
+    mov rcx, QWORD PTR [rip + _a]
+    cmp rax, rcx
+    jg .L9
+    jmp .L10
+.L9:
+    lea rax, [rip + _string_3]
+    mov rdx, 5
+    mov rdi, rax
+    mov rsi, rdx
+    call __lm_print_string
+    call __lm_print_newline
+.L10:
+    mov rax, QWORD PTR [rip + _a]
+    mov rcx, QWORD PTR [rip + _b]
+    cmp rax, rcx
+    jg .L13
+.L16:
+    mov rax, QWORD PTR [rip + _b]
+    mov rcx, QWORD PTR [rip + _c]
+    cmp rax, rcx
+    jg .L17
+    jmp .L15
+.L17:
+    mov rax, QWORD PTR [rip + _c]
+    mov rcx, QWORD PTR [rip + _a]
+    cmp rax, rcx
+    jl .L13
+    jmp .L15
+.L13:
+    lea rax, [rip + _string_1]
+    mov rdx, 5
+    mov rdi, rax
+    mov rsi, rdx
+    call __lm_print_string
+    call __lm_print_newline
+    jmp .L14
+.L15:
+    lea rax, [rip + _string_5]
+    mov rdx, 11
+    mov rdi, rax
+    mov rsi, rdx
+    call __lm_print_string
+    call __lm_print_newline
+.L14:
+    mov rax, QWORD PTR [rip + _a]
+    mov rcx, QWORD PTR [rip + _b]
+    cmp rax, rcx
+    jle .L20
+    jmp .L19
+.L20:
+    mov rax, QWORD PTR [rip + _b]
+    mov rcx, QWORD PTR [rip + _c]
+    cmp rax, rcx
+    jle .L18
+    jmp .L19
+.L18:
+    lea rax, [rip + _string_6]
+    mov rdx, 7
+    mov rdi, rax
+    mov rsi, rdx
+    call __lm_print_string
+    call __lm_print_newline
+.L19:
+.L24:
+    jmp .L23
+.L21:
+    lea rax, [rip + _string_1]
+    mov rdx, 5
+    mov rdi, rax
+    mov rsi, rdx
+    call __lm_print_string
+    call __lm_print_newline
+    jmp .L22
+.L23:
+    lea rax, [rip + _string_8]
+    mov rdx, 13
+    mov rdi, rax
+    mov rsi, rdx
+    call __lm_print_string
+    call __lm_print_newline
+.L22:
+    mov rax, QWORD PTR [rip + _a]
+    mov rcx, 0
+    cmp rax, rcx
+    je .L25
+.L27:
+    mov rax, QWORD PTR [rip + _b]
+    mov rcx, 0
+    cmp rax, rcx
+    jne .L25
+    jmp .L26
+.L25:
+    lea rax, [rip + _string_9]
+    mov rdx, 13
+    mov rdi, rax
+    mov rsi, rdx
+    call __lm_print_string
+    call __lm_print_newline
+.L26:
+    mov rax, 0
+    mov QWORD PTR [rip + _i], rax
+.L28:
+    mov rax, QWORD PTR [rip + _i]
+    mov rcx, 3
+    cmp rax, rcx
+    jl .L31
+    jmp .L30
+.L31:
+    mov rax, QWORD PTR [rip + _i]
+    mov rcx, 5
+    cmp rax, rcx
+    jne .L29
+    jmp .L30
+.L29:
+    mov rax, 0
+    mov QWORD PTR [rip + _j], rax
+.L32:
+    mov rax, QWORD PTR [rip + _j]
+    mov rcx, 3
+    cmp rax, rcx
+    jl .L33
+.L35:
+    mov rax, QWORD PTR [rip + _j]
+    mov rcx, 10
+    cmp rax, rcx
+    je .L33
+    jmp .L34
+.L33:
+    mov rax, QWORD PTR [rip + _i]
+    mov rcx, QWORD PTR [rip + _j]
+    cmp rax, rcx
+    je .L36
+.L38:
+    mov rax, QWORD PTR [rip + _i]
+    mov rcx, QWORD PTR [rip + _j]
+    add rax, rcx
+    mov QWORD PTR [rip + __t0], rax
+    mov rax, QWORD PTR [rip + __t0]
+    mov rcx, 4
+    cmp rax, rcx
+    je .L39
+    jmp .L37
+.L39:
+    mov rax, QWORD PTR [rip + _j]
+    mov rcx, 3
+    cmp rax, rcx
+    jne .L36
+    jmp .L37
+.L36:
+    mov rax, QWORD PTR [rip + _i]
+    mov rcx, 10
+    imul rax, rcx
+    mov QWORD PTR [rip + __t1], rax
+    mov rax, QWORD PTR [rip + __t1]
+    mov rcx, QWORD PTR [rip + _j]
+    add rax, rcx
+    mov QWORD PTR [rip + __t2], rax
+    mov rax, QWORD PTR [rip + __t2]
+    mov rdi, rax
+    call __lm_print_int
+    call __lm_print_newline
+.L37:
+    mov rax, QWORD PTR [rip + _i]
+    mov rcx, QWORD PTR [rip + _j]
+    cmp rax, rcx
+    jg .L42
+    jmp .L41
+.L42:
+    mov rax, QWORD PTR [rip + _j]
+    mov rcx, 0
+    cmp rax, rcx
+    je .L40
+.L43:
+    mov rax, QWORD PTR [rip + _i]
+    mov rcx, 0
+    cmp rax, rcx
+    je .L40
+    jmp .L41
+.L40:
+    mov rax, QWORD PTR [rip + _i]
+    mov rcx, 0
+    cmp rax, rcx
+    jne .L44
+    jmp .L45
+.L44:
+    mov rax, QWORD PTR [rip + _i]
+    mov rcx, 10
+    imul rax, rcx
+    mov QWORD PTR [rip + __t3], rax
+    mov rax, 100
+    mov rcx, QWORD PTR [rip + __t3]
+    add rax, rcx
+    mov QWORD PTR [rip + __t4], rax
+    mov rax, QWORD PTR [rip + __t4]
+    mov rcx, QWORD PTR [rip + _j]
+    add rax, rcx
+    mov QWORD PTR [rip + __t5], rax
+    mov rax, QWORD PTR [rip + __t5]
+    mov rdi, rax
+    call __lm_print_int
+    call __lm_print_newline
+.L45:
+.L41:
+    mov rax, QWORD PTR [rip + _j]
+    mov rcx, 1
+    add rax, rcx
+    mov QWORD PTR [rip + __t6], rax
+    mov rax, QWORD PTR [rip + __t6]
+    mov QWORD PTR [rip + _j], rax
+    jmp .L32
+.L34:
+    mov rax, QWORD PTR [rip + _i]
+    mov rcx, 1
+    add rax, rcx
+    mov QWORD PTR [rip + __t7], rax
+    mov rax, QWORD PTR [rip + __t7]
+    mov QWORD PTR [rip + _i], rax
+    jmp .L28
+.L30:
+    mov eax, 60
+    xor edi, edi
+    syscall
+
+# LM runtime for x86-64 Linux
+#
+# Every routine talks to the kernel directly through syscalls so the program can be linked without
+# libc. Routines may clobber rax, rcx, rdx, rsi, rdi, r8-r11 and xmm0-xmm2
+
+    .section .rodata
+__lm_newline:
+    .byte 10
+__lm_minus:
+    .byte 45
+__lm_true:
+    .ascii "true"
+__lm_false:
+    .ascii "false"
+__lm_hundred:
+    .double 100.0
+__lm_ten:
+    .double 10.0
+__lm_error_prefix:
+    .ascii "error: Runtime error: "
+    .set __lm_error_prefix_len, . - __lm_error_prefix
+__lm_eof_message:
+    .ascii "reached end of input"
+    .set __lm_eof_message_len, . - __lm_eof_message
+__lm_invalid_int_message:
+    .ascii "expected an int"
+    .set __lm_invalid_int_message_len, . - __lm_invalid_int_message
+__lm_invalid_float_message:
+    .ascii "expected a float"
+    .set __lm_invalid_float_message_len, . - __lm_invalid_float_message
+__lm_division_by_zero_message:
+    .ascii "division by zero"
+    .set __lm_division_by_zero_message_len, . - __lm_division_by_zero_message
+__lm_overflow_message:
+    .ascii "integer overflow"
+    .set __lm_overflow_message_len, . - __lm_overflow_message
+__lm_index_out_of_bounds_message:
+    .ascii "index out of bounds"
+    .set __lm_index_out_of_bounds_message_len, . - __lm_index_out_of_bounds_message
+
+    .section .text
+
+# Writes rdx bytes starting at rsi to the file descriptor in rdi
+__lm_write:
+    test rdx, rdx
+    jz .Lwrite_done
+    mov eax, 1
+    syscall
+    test rax, rax
+    jle .Lwrite_done
+    add rsi, rax
+    sub rdx, rax
+    jmp __lm_write
+.Lwrite_done:
+    ret
+
+# Prints rsi bytes starting at rdi to stdout
+__lm_print_string:
+    mov rdx, rsi
+    mov rsi, rdi
+    mov edi, 1
+    jmp __lm_write
+
+__lm_print_newline:
+    lea rdi, [rip + __lm_newline]
+    mov esi, 1
+    jmp __lm_print_string
+
+# Prints the signed integer in rdi
+__lm_print_int:
+    sub rsp, 32
+    lea rsi, [rsp + 32]
+    mov rax, rdi
+    mov r8, rdi
+    test rax, rax
+    jns .Lprint_int_digits
+    neg rax
+.Lprint_int_digits:
+    xor edx, edx
+    mov ecx, 10
+    div rcx
+    add dl, 48
+    dec rsi
+    mov BYTE PTR [rsi], dl
+    test rax, rax
+    jnz .Lprint_int_digits
+    test r8, r8
+    jns .Lprint_int_write
+    dec rsi
+    mov BYTE PTR [rsi], 45
+.Lprint_int_write:
+    mov rdi, rsi
+    lea rsi, [rsp + 32]
+    sub rsi, rdi
+    call __lm_print_string
+    add rsp, 32
+    ret
+
+# Prints `true` if rdi isn't zero and `false` if it is
+__lm_print_bool:
+    test rdi, rdi
+    jz .Lprint_bool_false
+    lea rdi, [rip + __lm_true]
+    mov esi, 4
+    jmp __lm_print_string
+.Lprint_bool_false:
+    lea rdi, [rip + __lm_false]
+    mov esi, 5
+    jmp __lm_print_string
+
+# Prints the float in xmm0 rounded to two decimals
+__lm_print_float:
+    sub rsp, 8
+    cvtss2sd xmm0, xmm0
+    movq rax, xmm0
+    test rax, rax
+    jns .Lprint_float_abs
+    btr rax, 63
+    mov QWORD PTR [rsp], rax
+    lea rdi, [rip + __lm_minus]
+    mov esi, 1
+    call __lm_print_string
+    movsd xmm0, QWORD PTR [rsp]
+.Lprint_float_abs:
+    mulsd xmm0, QWORD PTR [rip + __lm_hundred]
+    cvtsd2si rax, xmm0
+    xor edx, edx
+    mov ecx, 100
+    div rcx
+    mov QWORD PTR [rsp], rdx
+    mov rdi, rax
+    call __lm_print_int
+    mov rax, QWORD PTR [rsp]
+    xor edx, edx
+    mov ecx, 10
+    div rcx
+    add al, 48
+    add dl, 48
+    mov BYTE PTR [rsp], 46
+    mov BYTE PTR [rsp + 1], al
+    mov BYTE PTR [rsp + 2], dl
+    mov rdi, rsp
+    mov esi, 3
+    call __lm_print_string
+    add rsp, 8
+    ret
+
+# Reads a line from stdin into the buffer at rdi of capacity rsi dropping the line terminator
+# Returns the length in rax or -1 if the input ended before anything was read
+__lm_read_line:
+    push rbx
+    push r12
+    push r13
+    push r14
+    sub rsp, 8
+    mov rbx, rdi
+    mov r12, rsi
+    xor r13d, r13d
+    xor r14d, r14d
+.Lread_line_next:
+    xor eax, eax
+    xor edi, edi
+    mov rsi, rsp
+    mov edx, 1
+    syscall
+    cmp rax, 1
+    jne .Lread_line_eof
+    inc r14
+    movzx eax, BYTE PTR [rsp]
+    cmp al, 10
+    je .Lread_line_strip
+    cmp r13, r12
+    jae .Lread_line_next
+    mov BYTE PTR [rbx + r13], al
+    inc r13
+    jmp .Lread_line_next
+.Lread_line_eof:
+    test r14, r14
+    jnz .Lread_line_strip
+    mov rax, -1
+    jmp .Lread_line_return
+.Lread_line_strip:
+    test r13, r13
+    jz .Lread_line_length
+    cmp BYTE PTR [rbx + r13 - 1], 13
+    jne .Lread_line_length
+    dec r13
+    jmp .Lread_line_strip
+.Lread_line_length:
+    mov rax, r13
+.Lread_line_return:
+    add rsp, 8
+    pop r14
+    pop r13
+    pop r12
+    pop rbx
+    ret
+
+# Reads a line into the buffer at rdi of capacity rsi and returns its length in rax
+__lm_read_string:
+    call __lm_read_line
+    cmp rax, -1
+    je __lm_error_eof
+    ret
+
+# Moves rsi forward and rcx backwards past any ASCII whitespace
+__lm_trim:
+    cmp rsi, rcx
+    je .Ltrim_end
+    movzx eax, BYTE PTR [rsi]
+    cmp al, 32
+    je .Ltrim_skip_start
+    sub al, 9
+    cmp al, 4
+    ja .Ltrim_end
+.Ltrim_skip_start:
+    inc rsi
+    jmp __lm_trim
+.Ltrim_end:
+    cmp rsi, rcx
+    je .Ltrim_done
+    movzx eax, BYTE PTR [rcx - 1]
+    cmp al, 32
+    je .Ltrim_skip_end
+    sub al, 9
+    cmp al, 4
+    ja .Ltrim_done
+.Ltrim_skip_end:
+    dec rcx
+    jmp .Ltrim_end
+.Ltrim_done:
+    ret
+
+# Reads a line and parses it as a signed integer returned in rax
+__lm_read_int:
+    sub rsp, 264
+    mov rdi, rsp
+    mov esi, 256
+    call __lm_read_line
+    cmp rax, -1
+    je __lm_error_eof
+    mov rsi, rsp
+    lea rcx, [rsp + rax]
+    call __lm_trim
+    xor r8d, r8d
+    cmp rsi, rcx
+    je __lm_error_invalid_int
+    movzx edx, BYTE PTR [rsi]
+    cmp dl, 45
+    jne .Lread_int_plus
+    mov r8d, 1
+    inc rsi
+    jmp .Lread_int_first_digit
+.Lread_int_plus:
+    cmp dl, 43
+    jne .Lread_int_first_digit
+    inc rsi
+.Lread_int_first_digit:
+    cmp rsi, rcx
+    je __lm_error_invalid_int
+    xor eax, eax
+.Lread_int_digit:
+    movzx edx, BYTE PTR [rsi]
+    sub edx, 48
+    cmp edx, 9
+    ja __lm_error_invalid_int
+    imul rax, rax, 10
+    jo __lm_error_invalid_int
+    add rax, rdx
+    jo __lm_error_invalid_int
+    inc rsi
+    cmp rsi, rcx
+    jne .Lread_int_digit
+    test r8, r8
+    jz .Lread_int_done
+    neg rax
+.Lread_int_done:
+    add rsp, 264
+    ret
+
+# Reads a line and parses it as a float returned in xmm0
+__lm_read_float:
+    sub rsp, 264
+    mov rdi, rsp
+    mov esi, 256
+    call __lm_read_line
+    cmp rax, -1
+    je __lm_error_eof
+    mov rsi, rsp
+    lea rcx, [rsp + rax]
+    call __lm_trim
+    # r8 = negative, r9 = mantissa digits, r10 = decimal exponent, rax = mantissa
+    xor r8d, r8d
+    xor r9d, r9d
+    xor r10d, r10d
+    xor eax, eax
+    cmp rsi, rcx
+    je __lm_error_invalid_float
+    movzx edx, BYTE PTR [rsi]
+    cmp dl, 45
+    jne .Lread_float_plus
+    mov r8d, 1
+    inc rsi
+    jmp .Lread_float_integer
+.Lread_float_plus:
+    cmp dl, 43
+    jne .Lread_float_integer
+    inc rsi
+.Lread_float_integer:
+    cmp rsi, rcx
+    je .Lread_float_scale
+    movzx edx, BYTE PTR [rsi]
+    cmp dl, 46
+    je .Lread_float_point
+    sub edx, 48
+    cmp edx, 9
+    ja .Lread_float_exponent
+    inc r9
+    inc rsi
+    # Digits that don't fit in the mantissa only move the exponent
+    mov r11, 100000000000000000
+    cmp rax, r11
+    jae .Lread_float_integer_dropped
+    imul rax, rax, 10
+    add rax, rdx
+    jmp .Lread_float_integer
+.Lread_float_integer_dropped:
+    inc r10
+    jmp .Lread_float_integer
+.Lread_float_point:
+    inc rsi
+.Lread_float_fraction:
+    cmp rsi, rcx
+    je .Lread_float_scale
+    movzx edx, BYTE PTR [rsi]
+    sub edx, 48
+    cmp edx, 9
+    ja .Lread_float_exponent
+    inc r9
+    inc rsi
+    mov r11, 100000000000000000
+    cmp rax, r11
+    jae .Lread_float_fraction
+    imul rax, rax, 10
+    add rax, rdx
+    dec r10
+    jmp .Lread_float_fraction
+.Lread_float_exponent:
+    test r9, r9
+    jz __lm_error_invalid_float
+    movzx edx, BYTE PTR [rsi]
+    or dl, 32
+    cmp dl, 101
+    jne __lm_error_invalid_float
+    inc rsi
+    xor edi, edi
+    cmp rsi, rcx
+    je __lm_error_invalid_float
+    movzx edx, BYTE PTR [rsi]
+    cmp dl, 45
+    jne .Lread_float_exponent_plus
+    mov edi, 1
+    inc rsi
+    jmp .Lread_float_exponent_first_digit
+.Lread_float_exponent_plus:
+    cmp dl, 43
+    jne .Lread_float_exponent_first_digit
+    inc rsi
+.Lread_float_exponent_first_digit:
+    cmp rsi, rcx
+    je __lm_error_invalid_float
+    xor r11d, r11d
+.Lread_float_exponent_digit:
+    movzx edx, BYTE PTR [rsi]
+    sub edx, 48
+    cmp edx, 9
+    ja __lm_error_invalid_float
+    # Anything past this already overflows or underflows a float
+    cmp r11, 100000
+    jae .Lread_float_exponent_next
+    imul r11, r11, 10
+    add r11, rdx
+.Lread_float_exponent_next:
+    inc rsi
+    cmp rsi, rcx
+    jne .Lread_float_exponent_digit
+    test rdi, rdi
+    jz .Lread_float_exponent_add
+    neg r11
+.Lread_float_exponent_add:
+    add r10, r11
+.Lread_float_scale:
+    test r9, r9
+    jz __lm_error_invalid_float
+    cvtsi2sd xmm0, rax
+    movsd xmm1, QWORD PTR [rip + __lm_ten]
+.Lread_float_scale_up:
+    cmp r10, 0
+    jle .Lread_float_scale_down
+    mulsd xmm0, xmm1
+    dec r10
+    jmp .Lread_float_scale_up
+.Lread_float_scale_down:
+    cmp r10, 0
+    jge .Lread_float_sign
+    divsd xmm0, xmm1
+    inc r10
+    jmp .Lread_float_scale_down
+.Lread_float_sign:
+    cvtsd2ss xmm0, xmm0
+    test r8, r8
+    jz .Lread_float_done
+    movd eax, xmm0
+    xor eax, 0x80000000
+    movd xmm0, eax
+.Lread_float_done:
+    add rsp, 264
+    ret
+
+# Appends the string at rsi of length rdx to the buffer at rdi holding rcx bytes, dropping
+# whatever doesn't fit in r8 bytes. Returns the new length in rax
+__lm_append_string:
+    mov rax, rcx
+.Lappend_string_byte:
+    test rdx, rdx
+    jz .Lappend_string_done
+    cmp rax, r8
+    jae .Lappend_string_done
+    movzx r9d, BYTE PTR [rsi]
+    mov BYTE PTR [rdi + rax], r9b
+    inc rsi
+    inc rax
+    dec rdx
+    jmp .Lappend_string_byte
+.Lappend_string_done:
+    ret
+
+# Divides rax by rcx leaving the quotient in rax. Fails on a division by zero and on the only
+# quotient that doesn't fit, the smallest int divided by -1, where idiv would raise SIGFPE
+__lm_divide:
+    test rcx, rcx
+    jz __lm_error_division_by_zero
+    cmp rcx, -1
+    jne .Ldivide_signed
+    neg rax
+    jo __lm_error_overflow
+    ret
+.Ldivide_signed:
+    cqo
+    idiv rcx
+    ret
+
+# Compares the string at rdi of length rsi with the string at rdx of length rcx
+# Returns -1, 0 or 1 in rax
+__lm_compare_strings:
+    mov r8, rsi
+    cmp r8, rcx
+    cmova r8, rcx
+    xor r9d, r9d
+.Lcompare_strings_byte:
+    cmp r9, r8
+    je .Lcompare_strings_length
+    movzx eax, BYTE PTR [rdi + r9]
+    movzx r10d, BYTE PTR [rdx + r9]
+    cmp eax, r10d
+    jb .Lcompare_strings_less
+    ja .Lcompare_strings_greater
+    inc r9
+    jmp .Lcompare_strings_byte
+.Lcompare_strings_length:
+    cmp rsi, rcx
+    jb .Lcompare_strings_less
+    ja .Lcompare_strings_greater
+    xor eax, eax
+    ret
+.Lcompare_strings_less:
+    mov rax, -1
+    ret
+.Lcompare_strings_greater:
+    mov eax, 1
+    ret
+
+__lm_error_eof:
+    lea rdi, [rip + __lm_eof_message]
+    mov esi, __lm_eof_message_len
+    jmp __lm_runtime_error
+
+__lm_error_invalid_int:
+    lea rdi, [rip + __lm_invalid_int_message]
+    mov esi, __lm_invalid_int_message_len
+    jmp __lm_runtime_error
+
+__lm_error_invalid_float:
+    lea rdi, [rip + __lm_invalid_float_message]
+    mov esi, __lm_invalid_float_message_len
+    jmp __lm_runtime_error
+
+__lm_error_division_by_zero:
+    lea rdi, [rip + __lm_division_by_zero_message]
+    mov esi, __lm_division_by_zero_message_len
+    jmp __lm_runtime_error
+
+__lm_error_overflow:
+    lea rdi, [rip + __lm_overflow_message]
+    mov esi, __lm_overflow_message_len
+    jmp __lm_runtime_error
+
+__lm_error_index_out_of_bounds:
+    lea rdi, [rip + __lm_index_out_of_bounds_message]
+    mov esi, __lm_index_out_of_bounds_message_len
+    jmp __lm_runtime_error
+
+# Prints the message at rdi of length rsi to stderr and exits with status 1
+__lm_runtime_error:
+    mov r12, rdi
+    mov r13, rsi
+    mov edi, 2
+    lea rsi, [rip + __lm_error_prefix]
+    mov edx, __lm_error_prefix_len
+    call __lm_write
+    mov edi, 2
+    mov rsi, r12
+    mov rdx, r13
+    call __lm_write
+    mov edi, 2
+    lea rsi, [rip + __lm_newline]
+    mov edx, 1
+    call __lm_write
+    mov eax, 60
+    mov edi, 1
+    syscall
@@ -0,0 +1,553 @@
+include macros2.asm
+include number.asm
+
+.MODEL LARGE
+.386
+.STACK 200h
+
+.DATA
+_true                        dd    1
+_false                       dd    0
+_1                           dd    1
+_0                           dd    0
+_a                           dd    ?
+_b                           dd    ?
+_passed                      dd    ?
+_3                           dd    3
+_7                           dd    7
+_string_0                    db    'a < b', '$'
+_string_1                    db    'a and b are positive', '$'
+_string_2                    db    'not a == b', '$'
+_string_3                    db    'a is zero', '$'
+_4                           dd    4
+_string_4                    db    'nested', '$'
+_@fpu_cw                     dd    ?
+_@fpu_trunc_cw               dd    ?
+_@read_number                dd    ?
+_@division_by_zero           db    'error: Runtime error: division by zero', '$'
+_@overflow                   db    'error: Runtime error: integer overflow', '$'
+_@index_out_of_bounds        db    'error: Runtime error: index out of bounds', '$'
+_@true                       db    'true', '$'
+_@false                      db    'false', '$'
+_@t0                         dd    ?
+_@t1                         dd    ?
+_@t2                         dd    ?
+_@t3                         dd    ?
+_@t4                         dd    ?
+_@t5                         dd    ?
+_@t6                         dd    ?
+_@t7                         dd    ?
+_@t8                         dd    ?
+_@t9                         dd    ?
+_@t10                        dd    ?
+_@t11                        dd    ?
+_@t12                        dd    ?
+_@t13                        dd    ?
+_@t14                        dd    ?
+_@t15                        dd    ?
+_@t16                        dd    ?
+_@args_check_0               db    '$', 50 dup (?)
+_@args_check_1               dd    ?
+_@args_both_0                dd    ?
+_@args_both_1                dd    ?
+_@frame_check LABEL BYTE
+_check@label                 db    '$', 50 dup (?)
+_check@ok                    dd    ?
+_@frame_both LABEL BYTE
+_both@first                  dd    ?
+_both@second                 dd    ?
+_@both@t0                    dd    ?
+.CODE
+
+Program:
+    MOV AX, @DATA
+    MOV DS, AX
+    MOV ES, AX
+
+    FSTCW   WORD PTR _@fpu_cw
+    MOV     AX, WORD PTR _@fpu_cw
+    OR      AX, 0C00H
+    MOV     WORD PTR _@fpu_trunc_cw, AX
+
+    MOV     EAX, _3
+    MOV     _a, EAX
+
+    MOV     EAX, _7
+    MOV     _b, EAX
+
+    MOV     EAX, _a
+    CMP     EAX, _b
+    JL    label_6
+
+    JMP    label_7
+
+label_6:
+    MOV     EAX, _true
+    MOV     _@t0, EAX
+
+    JMP    label_8
+
+label_7:
+    MOV     EAX, _false
+    MOV     _@t0, EAX
+
+label_8:
+    MOV     DI, OFFSET _@args_check_0
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_0
+    CALL    _@append_string
+
+    MOV     EAX, _@t0
+    MOV     _@args_check_1, EAX
+
+    CALL    _@fn_check
+    MOV     _@t1, EAX
+
+    MOV     EAX, _@t1
+    MOV     _passed, EAX
+
+    MOV     EAX, _a
+    CMP     EAX, _0
+    JG    label_12
+
+    JMP    label_10
+
+label_12:
+    MOV     EAX, _b
+    CMP     EAX, _0
+    JG    label_9
+
+    JMP    label_10
+
+label_9:
+    MOV     EAX, _true
+    MOV     _@t2, EAX
+
+    JMP    label_11
+
+label_10:
+    MOV     EAX, _false
+    MOV     _@t2, EAX
+
+label_11:
+    MOV     DI, OFFSET _@args_check_0
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_1
+    CALL    _@append_string
+
+    MOV     EAX, _@t2
+    MOV     _@args_check_1, EAX
+
+    CALL    _@fn_check
+    MOV     _@t3, EAX
+
+    MOV     EAX, _passed
+    ADD     EAX, _@t3
+    MOV     _@t4, EAX
+
+    MOV     EAX, _@t4
+    MOV     _passed, EAX
+
+    MOV     EAX, _a
+    CMP     EAX, _b
+    JNE    label_13
+
+    JMP    label_14
+
+label_13:
+    MOV     EAX, _true
+    MOV     _@t5, EAX
+
+    JMP    label_15
+
+label_14:
+    MOV     EAX, _false
+    MOV     _@t5, EAX
+
+label_15:
+    MOV     DI, OFFSET _@args_check_0
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_2
+    CALL    _@append_string
+
+    MOV     EAX, _@t5
+    MOV     _@args_check_1, EAX
+
+    CALL    _@fn_check
+    MOV     _@t6, EAX
+
+    MOV     EAX, _passed
+    ADD     EAX, _@t6
+    MOV     _@t7, EAX
+
+    MOV     EAX, _@t7
+    MOV     _passed, EAX
+
+    MOV     EAX, _a
+    CMP     EAX, _0
+    JE    label_16
+
+    JMP    label_17
+
+label_16:
+    MOV     EAX, _true
+    MOV     _@t8, EAX
+
+    JMP    label_18
+
+label_17:
+    MOV     EAX, _false
+    MOV     _@t8, EAX
+
+label_18:
+    MOV     DI, OFFSET _@args_check_0
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_3
+    CALL    _@append_string
+
+    MOV     EAX, _@t8
+    MOV     _@args_check_1, EAX
+
+    CALL    _@fn_check
+    MOV     _@t9, EAX
+
+    MOV     EAX, _passed
+    ADD     EAX, _@t9
+    MOV     _@t10, EAX
+
+    MOV     EAX, _@t10
+    MOV     _passed, EAX
+
+    MOV     EAX, _a
+    CMP     EAX, _b
+    JL    label_19
+
+    JMP    label_20
+
+label_19:
+    MOV     EAX, _true
+    MOV     _@t11, EAX
+
+    JMP    label_21
+
+label_20:
+    MOV     EAX, _false
+    MOV     _@t11, EAX
+
+label_21:
+    MOV     EAX, _b
+    SUB     EAX, _a
+    MOV     _@t13, EAX
+
+    MOV     EAX, _@t13
+    CMP     EAX, _4
+    JE    label_22
+
+    JMP    label_23
+
+label_22:
+    MOV     EAX, _true
+    MOV     _@t12, EAX
+
+    JMP    label_24
+
+label_23:
+    MOV     EAX, _false
+    MOV     _@t12, EAX
+
+label_24:
+    MOV     EAX, _@t11
+    MOV     _@args_both_0, EAX
+
+    MOV     EAX, _@t12
+    MOV     _@args_both_1, EAX
+
+    CALL    _@fn_both
+
+    MOV     EAX, _a
+    CMP     EAX, _b
+    JNE    label_28
+
+    JMP    label_29
+
+label_28:
+    MOV     EAX, _true
+    MOV     _@t15, EAX
+
+    JMP    label_30
+
+label_29:
+    MOV     EAX, _false
+    MOV     _@t15, EAX
+
+label_30:
+    MOV     DI, OFFSET _@args_check_0
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_4
+    CALL    _@append_string
+
+    MOV     EAX, _@t15
+    MOV     _@args_check_1, EAX
+
+    CALL    _@fn_check
+    MOV     _@t16, EAX
+
+    MOV     EAX, _@t16
+    CMP     EAX, _1
+    JE    label_25
+
+    JMP    label_26
+
+label_25:
+    MOV     EAX, _true
+    MOV     _@t14, EAX
+
+    JMP    label_27
+
+label_26:
+    MOV     EAX, _false
+    MOV     _@t14, EAX
+
+label_27:
+    MOV     EAX, _@t14
+    MOV     _@args_both_0, EAX
+
+    MOV     EAX, _false
+    MOV     _@args_both_1, EAX
+
+    CALL    _@fn_both
+
+    MOV     EAX, _passed
+    CALL    _@display_int
+    newLine
+
+    MOV AX, 4C00H
+    INT 21H
+
+_@fn_check PROC NEAR
+    SUB     SP, 56
+    MOV     DI, SP
+    PUSH    ES
+    PUSH    SS
+    POP     ES
+    MOV     SI, OFFSET _@frame_check
+    MOV     CX, 55
+    CLD
+    REP     MOVSB
+    POP     ES
+
+    MOV     DI, OFFSET _check@label
+    MOV     CX, 50
+    MOV     SI, OFFSET _@args_check_0
+    CALL    _@append_string
+
+    MOV     EAX, _@args_check_1
+    MOV     _check@ok, EAX
+
+    DisplayString    _check@label
+    newLine
+
+    MOV     EAX, _check@ok
+    CALL    _@display_bool
+    newLine
+
+    MOV     EAX, _check@ok
+    CMP     EAX, _false
+    JNE    label_0
+
+    JMP    label_1
+
+label_0:
+    MOV     EAX, _1
+    JMP     _@return_check
+
+label_1:
+    MOV     EAX, _0
+    JMP     _@return_check
+
+_@return_check:
+    MOV     SI, SP
+    PUSH    DS
+    MOV     DI, OFFSET _@frame_check
+    PUSH    SS
+    POP     DS
+    MOV     CX, 55
+    CLD
+    REP     MOVSB
+    POP     DS
+    ADD     SP, 56
+    RET
+_@fn_check ENDP
+
+_@fn_both PROC NEAR
+    SUB     SP, 12
+    MOV     DI, SP
+    PUSH    ES
+    PUSH    SS
+    POP     ES
+    MOV     SI, OFFSET _@frame_both
+    MOV     CX, 12
+    CLD
+    REP     MOVSB
+    POP     ES
+
+    MOV     EAX, _@args_both_0
+    MOV     _both@first, EAX
+
+    MOV     EAX, _@args_both_1
+    MOV     _both@second, EAX
+
+    MOV     EAX, _both@first
+    CMP     EAX, _false
+    JNE    label_5
+
+    JMP    label_3
+
+label_5:
+    MOV     EAX, _both@second
+    CMP     EAX, _false
+    JNE    label_2
+
+    JMP    label_3
+
+label_2:
+    MOV     EAX, _true
+    MOV     _@both@t0, EAX
+
+    JMP    label_4
+
+label_3:
+    MOV     EAX, _false
+    MOV     _@both@t0, EAX
+
+label_4:
+    MOV     EAX, _@both@t0
+    CALL    _@display_bool
+    newLine
+
+_@return_both:
+    MOV     SI, SP
+    PUSH    DS
+    MOV     DI, OFFSET _@frame_both
+    PUSH    SS
+    POP     DS
+    MOV     CX, 12
+    CLD
+    REP     MOVSB
+    POP     DS
+    ADD     SP, 12
+    RET
+_@fn_both ENDP
+
+
+; Runtime routines shared by every program
+
+; Prints the signed int in EAX
+_@display_int PROC NEAR
+    XOR     CX, CX
+    MOV     EBX, 10
+    CMP     EAX, 0
+    JGE     _@display_int_divide
+    PUSH    EAX
+    MOV     DL, '-'
+    MOV     AH, 02H
+    INT     21H
+    POP     EAX
+    NEG     EAX
+_@display_int_divide:
+    ; Digits are pushed from the least significant one and printed in reverse
+    XOR     EDX, EDX
+    DIV     EBX
+    PUSH    DX
+    INC     CX
+    CMP     EAX, 0
+    JNE     _@display_int_divide
+_@display_int_print:
+    POP     DX
+    ADD     DL, '0'
+    MOV     AH, 02H
+    INT     21H
+    LOOP    _@display_int_print
+    RET
+_@display_int ENDP
+
+; Prints true if EAX isn't zero and false if it is
+_@display_bool PROC NEAR
+    CMP     EAX, 0
+    JE      _@display_bool_false
+    DisplayString    _@true
+    RET
+_@display_bool_false:
+    DisplayString    _@false
+    RET
+_@display_bool ENDP
+
+; Appends the '$' terminated string at SI to the one ending at DI while CX, the space left, isn't
+; zero. Leaves DI on the '$' ending the result and CX with the space left so calls can be chained
+_@append_string PROC NEAR
+    CLD
+_@append_string_next:
+    CMP     BYTE PTR [SI], '$'
+    JE      _@append_string_end
+    JCXZ    _@append_string_end
+    MOVSB
+    DEC     CX
+    JMP     _@append_string_next
+_@append_string_end:
+    MOV     BYTE PTR [DI], '$'
+    RET
+_@append_string ENDP
+
+; Compares the '$' terminated strings at SI and DI, ZF is set if they are equal
+_@compare_strings PROC NEAR
+    MOV     AL, [SI]
+    CMP     AL, [DI]
+    JNE     _@compare_strings_end
+    CMP     AL, '$'
+    JE      _@compare_strings_end
+    INC     SI
+    INC     DI
+    JMP     _@compare_strings
+_@compare_strings_end:
+    RET
+_@compare_strings ENDP
+
+; Divides EAX by ECX leaving the quotient in EAX. Fails on a division by zero and on the only
+; quotient that doesn't fit, the smallest int divided by -1, where IDIV would raise an exception
+_@divide PROC NEAR
+    CMP     ECX, 0
+    JE      _@error_division_by_zero
+    CMP     ECX, -1
+    JNE     _@divide_signed
+    NEG     EAX
+    JO      _@error_overflow
+    RET
+_@divide_signed:
+    ; IDIV truncates towards zero
+    CDQ
+    IDIV    ECX
+    RET
+_@divide ENDP
+
+; Reports a division by zero and exits with an error
+_@error_division_by_zero:
+    DisplayString    _@division_by_zero
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an int operation whose result doesn't fit and exits with an error
+_@error_overflow:
+    DisplayString    _@overflow
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an access outside the bounds of an array and exits with an error
+_@error_index_out_of_bounds:
+    DisplayString    _@index_out_of_bounds
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+    END Program
@@ -0,0 +1,429 @@
+include macros2.asm
+include number.asm
+
+.MODEL LARGE
+.386
+.STACK 200h
+
+.DATA
+_a                           dd    ?
+_d                           dd    ?
+_x                           dd    ?
+_ok                          dd    ?
+_10000                       dd    10000
+_100                         dd    100
+_1                           dd    1
+_2024                        dd    2024
+_01                          dd    01
+_05                          dd    05
+_2                           dd    2
+_3                           dd    3
+_4                           dd    4
+_5                           dd    5
+_0                           dd    0
+_7                           dd    7
+_1_5                         dd    1.5
+_1_0                         dd    1.0
+_true                        dd    1
+_false                       dd    0
+_string_0                    db    'only the taken branch is kept', '$'
+_string_1                    db    'dropped', '$'
+_@fpu_cw                     dd    ?
+_@fpu_trunc_cw               dd    ?
+_@read_number                dd    ?
+_@division_by_zero           db    'error: Runtime error: division by zero', '$'
+_@overflow                   db    'error: Runtime error: integer overflow', '$'
+_@index_out_of_bounds        db    'error: Runtime error: index out of bounds', '$'
+_@true                       db    'true', '$'
+_@false                      db    'false', '$'
+_@t0                         dd    ?
+_@t1                         dd    ?
+_@t2                         dd    ?
+_@t3                         dd    ?
+_@t4                         dd    ?
+_@t5                         dd    ?
+_@t6                         dd    ?
+_@t7                         dd    ?
+_@t8                         dd    ?
+_@t9                         dd    ?
+_@t10                        dd    ?
+_@t11                        dd    ?
+_@t12                        dd    ?
+_@t13                        dd    ?
+_@t14                        dd    ?
+_@t15                        dd    ?
+_@t16                        dd    ?
+_@t17                        dd    ?
+_@t18                        dd    ?
+_@t19                        dd    ?
+_@t20                        dd    ?
+_@t21                        dd    ?
+_@t22                        dd    ?
+_@t23                        dd    ?
+_@t24                        dd    ?
+_@t25                        dd    ?
+_@t26                        dd    ?
+_@t27                        dd    ?
+_@t28                        dd    ?
+.CODE
+
+Program:
+    MOV AX, @DATA
+    MOV DS, AX
+    MOV ES, AX
+
+    FSTCW   WORD PTR _@fpu_cw
+    MOV     AX, WORD PTR _@fpu_cw
+    OR      AX, 0C00H
+    MOV     WORD PTR _@fpu_trunc_cw, AX
+
+    MOV     EAX, _2024
+    IMUL    EAX, _10000
+    MOV     _@t0, EAX
+
+    MOV     EAX, _01
+    IMUL    EAX, _100
+    MOV     _@t1, EAX
+
+    MOV     EAX, _@t0
+    ADD     EAX, _@t1
+    MOV     _@t2, EAX
+
+    MOV     EAX, _05
+    IMUL    EAX, _1
+    MOV     _@t3, EAX
+
+    MOV     EAX, _@t2
+    ADD     EAX, _@t3
+    MOV     _@t4, EAX
+
+    MOV     EAX, _@t4
+    MOV     _d, EAX
+
+    MOV     EAX, _d
+    CALL    _@display_int
+    newLine
+
+    MOV     EAX, _2
+    IMUL    EAX, _3
+    MOV     _@t5, EAX
+
+    MOV     EAX, _5
+    NEG     EAX
+    MOV     _@t6, EAX
+
+    MOV     EAX, _4
+    IMUL    EAX, _@t6
+    MOV     _@t7, EAX
+
+    MOV     EAX, _@t5
+    ADD     EAX, _@t7
+    MOV     _@t8, EAX
+
+    MOV     EAX, _@t8
+    CALL    _@display_int
+    newLine
+
+    MOV     EAX, _7
+    NEG     EAX
+    MOV     _@t9, EAX
+
+    MOV     EAX, _0
+    SUB     EAX, _@t9
+    MOV     _@t10, EAX
+
+    MOV     EAX, _@t10
+    CALL    _@display_int
+    newLine
+
+    MOV     EAX, _7
+    MOV     ECX, _2
+    CALL    _@divide
+    MOV     _@t11, EAX
+
+    FILD    _@t11
+    FSTP    _@t12
+
+    FLD     _@t12
+    FLD     _1_5
+    FMUL
+    FSTP    _@t13
+
+    DisplayFloat    _@t13, 2
+    newLine
+
+    MOV     EAX, _1
+    ADD     EAX, _2
+    MOV     _@t15, EAX
+
+    MOV     EAX, _@t15
+    CMP     EAX, _2
+    JG    label_3
+
+    JMP    label_1
+
+label_3:
+    FILD    _1
+    FSTP    _@t16
+
+    FLD     _@t16
+    FCOMP   _1_0
+    FSTSW   AX
+    SAHF
+    JNE    label_0
+
+    JMP    label_1
+
+label_0:
+    MOV     EAX, _true
+    MOV     _@t14, EAX
+
+    JMP    label_2
+
+label_1:
+    MOV     EAX, _false
+    MOV     _@t14, EAX
+
+label_2:
+    MOV     EAX, _@t14
+    CALL    _@display_bool
+    newLine
+
+    MOV     EAX, _4
+    MOV     _a, EAX
+
+    MOV     EAX, _a
+    IMUL    EAX, _1
+    MOV     _@t17, EAX
+
+    MOV     EAX, _@t17
+    ADD     EAX, _0
+    MOV     _@t18, EAX
+
+    MOV     EAX, _@t18
+    CALL    _@display_int
+    newLine
+
+    MOV     EAX, _1
+    IMUL    EAX, _a
+    MOV     _@t19, EAX
+
+    MOV     EAX, _@t19
+    SUB     EAX, _0
+    MOV     _@t20, EAX
+
+    MOV     EAX, _@t20
+    CALL    _@display_int
+    newLine
+
+    MOV     EAX, _a
+    MOV     ECX, _1
+    CALL    _@divide
+    MOV     _@t21, EAX
+
+    MOV     EAX, _@t21
+    ADD     EAX, _0
+    MOV     _@t22, EAX
+
+    FILD    _@t22
+    FSTP    _@t23
+
+    FLD     _@t23
+    FSTP    _x
+
+    DisplayFloat    _x, 2
+    newLine
+
+    FILD    _0
+    FSTP    _@t24
+
+    FILD    _a
+    FSTP    _@t25
+
+    FLD     _@t25
+    FLD     _1_0
+    FMUL
+    FSTP    _@t26
+
+    FLD     _@t24
+    FLD     _@t26
+    FADD
+    FSTP    _@t27
+
+    DisplayFloat    _@t27, 2
+    newLine
+
+label_4:
+    DisplayString    _string_0
+    newLine
+
+    JMP    label_5
+
+label_6:
+    DisplayString    _string_1
+    newLine
+
+label_5:
+    MOV     EAX, _2
+    CMP     EAX, _1
+    JL    label_7
+
+    JMP    label_8
+
+label_7:
+    DisplayString    _string_1
+    newLine
+
+label_8:
+label_9:
+    JMP    label_11
+
+label_10:
+    DisplayString    _string_1
+    newLine
+
+    JMP    label_9
+
+label_11:
+    JMP    label_12
+
+label_15:
+    MOV     EAX, _a
+    CMP     EAX, _2
+    JG    label_12
+
+    JMP    label_13
+
+label_12:
+    MOV     EAX, _true
+    MOV     _@t28, EAX
+
+    JMP    label_14
+
+label_13:
+    MOV     EAX, _false
+    MOV     _@t28, EAX
+
+label_14:
+    MOV     EAX, _@t28
+    MOV     _ok, EAX
+
+    MOV     EAX, _ok
+    CALL    _@display_bool
+    newLine
+
+    MOV AX, 4C00H
+    INT 21H
+
+
+; Runtime routines shared by every program
+
+; Prints the signed int in EAX
+_@display_int PROC NEAR
+    XOR     CX, CX
+    MOV     EBX, 10
+    CMP     EAX, 0
+    JGE     _@display_int_divide
+    PUSH    EAX
+    MOV     DL, '-'
+    MOV     AH, 02H
+    INT     21H
+    POP     EAX
+    NEG     EAX
+_@display_int_divide:
+    ; Digits are pushed from the least significant one and printed in reverse
+    XOR     EDX, EDX
+    DIV     EBX
+    PUSH    DX
+    INC     CX
+    CMP     EAX, 0
+    JNE     _@display_int_divide
+_@display_int_print:
+    POP     DX
+    ADD     DL, '0'
+    MOV     AH, 02H
+    INT     21H
+    LOOP    _@display_int_print
+    RET
+_@display_int ENDP
+
+; Prints true if EAX isn't zero and false if it is
+_@display_bool PROC NEAR
+    CMP     EAX, 0
+    JE      _@display_bool_false
+    DisplayString    _@true
+    RET
+_@display_bool_false:
+    DisplayString    _@false
+    RET
+_@display_bool ENDP
+
+; Appends the '$' terminated string at SI to the one ending at DI while CX, the space left, isn't
+; zero. Leaves DI on the '$' ending the result and CX with the space left so calls can be chained
+_@append_string PROC NEAR
+    CLD
+_@append_string_next:
+    CMP     BYTE PTR [SI], '$'
+    JE      _@append_string_end
+    JCXZ    _@append_string_end
+    MOVSB
+    DEC     CX
+    JMP     _@append_string_next
+_@append_string_end:
+    MOV     BYTE PTR [DI], '$'
+    RET
+_@append_string ENDP
+
+; Compares the '$' terminated strings at SI and DI, ZF is set if they are equal
+_@compare_strings PROC NEAR
+    MOV     AL, [SI]
+    CMP     AL, [DI]
+    JNE     _@compare_strings_end
+    CMP     AL, '$'
+    JE      _@compare_strings_end
+    INC     SI
+    INC     DI
+    JMP     _@compare_strings
+_@compare_strings_end:
+    RET
+_@compare_strings ENDP
+
+; Divides EAX by ECX leaving the quotient in EAX. Fails on a division by zero and on the only
+; quotient that doesn't fit, the smallest int divided by -1, where IDIV would raise an exception
+_@divide PROC NEAR
+    CMP     ECX, 0
+    JE      _@error_division_by_zero
+    CMP     ECX, -1
+    JNE     _@divide_signed
+    NEG     EAX
+    JO      _@error_overflow
+    RET
+_@divide_signed:
+    ; IDIV truncates towards zero
+    CDQ
+    IDIV    ECX
+    RET
+_@divide ENDP
+
+; Reports a division by zero and exits with an error
+_@error_division_by_zero:
+    DisplayString    _@division_by_zero
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an int operation whose result doesn't fit and exits with an error
+_@error_overflow:
+    DisplayString    _@overflow
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an access outside the bounds of an array and exits with an error
+_@error_index_out_of_bounds:
+    DisplayString    _@index_out_of_bounds
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+    END Program
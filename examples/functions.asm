@@ -0,0 +1,519 @@
+include macros2.asm
+include number.asm
+
+.MODEL LARGE
+.386
+.STACK 200h
+
+.DATA
+_1                           dd    1
+_2                           dd    2
+_string_0                    db    'hello, ', '$'
+_n                           dd    ?
+_5                           dd    5
+_10                          dd    10
+_3                           dd    3
+_string_1                    db    'world', '$'
+_string_2                    db    'factorial of 6', '$'
+_6                           dd    6
+_@fpu_cw                     dd    ?
+_@fpu_trunc_cw               dd    ?
+_@read_number                dd    ?
+_@division_by_zero           db    'error: Runtime error: division by zero', '$'
+_@overflow                   db    'error: Runtime error: integer overflow', '$'
+_@index_out_of_bounds        db    'error: Runtime error: index out of bounds', '$'
+_@true                       db    'true', '$'
+_@false                      db    'false', '$'
+_@t0                         dd    ?
+_@t1                         dd    ?
+_@t2                         dd    ?
+_@t3                         dd    ?
+_@t4                         db    '$', 50 dup (?)
+_@t5                         dd    ?
+_@args_factorial_0           dd    ?
+_@args_fibonacci_0           dd    ?
+_@args_half_0                dd    ?
+_@args_greeting_0            db    '$', 50 dup (?)
+_@args_show_0                db    '$', 50 dup (?)
+_@args_show_1                dd    ?
+_@return                     db    '$', 50 dup (?)
+_@frame_factorial LABEL BYTE
+_factorial@n                 dd    ?
+_@factorial@t0               dd    ?
+_@factorial@t1               dd    ?
+_@factorial@t2               dd    ?
+_@frame_fibonacci LABEL BYTE
+_fibonacci@n                 dd    ?
+_fibonacci@a                 dd    ?
+_fibonacci@b                 dd    ?
+_@fibonacci@t0               dd    ?
+_@fibonacci@t1               dd    ?
+_@fibonacci@t2               dd    ?
+_@fibonacci@t3               dd    ?
+_@fibonacci@t4               dd    ?
+_@frame_half LABEL BYTE
+_half@x                      dd    ?
+_@half@t0                    dd    ?
+_@half@t1                    dd    ?
+_@frame_greeting LABEL BYTE
+_greeting@name               db    '$', 50 dup (?)
+_@greeting@t0                db    '$', 50 dup (?)
+_@frame_show LABEL BYTE
+_show@label                  db    '$', 50 dup (?)
+_show@value                  dd    ?
+.CODE
+
+Program:
+    MOV AX, @DATA
+    MOV DS, AX
+    MOV ES, AX
+
+    FSTCW   WORD PTR _@fpu_cw
+    MOV     AX, WORD PTR _@fpu_cw
+    OR      AX, 0C00H
+    MOV     WORD PTR _@fpu_trunc_cw, AX
+
+    MOV     EAX, _5
+    MOV     _n, EAX
+
+    MOV     EAX, _n
+    MOV     _@args_factorial_0, EAX
+
+    CALL    _@fn_factorial
+    MOV     _@t0, EAX
+
+    MOV     EAX, _@t0
+    CALL    _@display_int
+    newLine
+
+    MOV     EAX, _10
+    MOV     _@args_fibonacci_0, EAX
+
+    CALL    _@fn_fibonacci
+    MOV     _@t1, EAX
+
+    MOV     EAX, _@t1
+    CALL    _@display_int
+    newLine
+
+    FILD    _3
+    FSTP    _@t2
+
+    FLD     _@t2
+    FSTP    _@args_half_0
+
+    CALL    _@fn_half
+    FSTP    _@t3
+
+    DisplayFloat    _@t3, 2
+    newLine
+
+    MOV     DI, OFFSET _@args_greeting_0
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_1
+    CALL    _@append_string
+
+    CALL    _@fn_greeting
+    MOV     DI, OFFSET _@t4
+    MOV     CX, 50
+    MOV     SI, OFFSET _@return
+    CALL    _@append_string
+
+
+    DisplayString    _@t4
+    newLine
+
+    MOV     EAX, _6
+    MOV     _@args_factorial_0, EAX
+
+    CALL    _@fn_factorial
+    MOV     _@t5, EAX
+
+    MOV     DI, OFFSET _@args_show_0
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_2
+    CALL    _@append_string
+
+    MOV     EAX, _@t5
+    MOV     _@args_show_1, EAX
+
+    CALL    _@fn_show
+
+    MOV     EAX, _n
+    CALL    _@display_int
+    newLine
+
+    MOV AX, 4C00H
+    INT 21H
+
+_@fn_factorial PROC NEAR
+    SUB     SP, 16
+    MOV     DI, SP
+    PUSH    ES
+    PUSH    SS
+    POP     ES
+    MOV     SI, OFFSET _@frame_factorial
+    MOV     CX, 16
+    CLD
+    REP     MOVSB
+    POP     ES
+
+    MOV     EAX, _@args_factorial_0
+    MOV     _factorial@n, EAX
+
+    MOV     EAX, _factorial@n
+    CMP     EAX, _1
+    JLE    label_0
+
+    JMP    label_1
+
+label_0:
+    MOV     EAX, _1
+    JMP     _@return_factorial
+
+label_1:
+    MOV     EAX, _factorial@n
+    SUB     EAX, _1
+    MOV     _@factorial@t0, EAX
+
+    MOV     EAX, _@factorial@t0
+    MOV     _@args_factorial_0, EAX
+
+    CALL    _@fn_factorial
+    MOV     _@factorial@t1, EAX
+
+    MOV     EAX, _factorial@n
+    IMUL    EAX, _@factorial@t1
+    MOV     _@factorial@t2, EAX
+
+    MOV     EAX, _@factorial@t2
+    JMP     _@return_factorial
+
+_@return_factorial:
+    MOV     SI, SP
+    PUSH    DS
+    MOV     DI, OFFSET _@frame_factorial
+    PUSH    SS
+    POP     DS
+    MOV     CX, 16
+    CLD
+    REP     MOVSB
+    POP     DS
+    ADD     SP, 16
+    RET
+_@fn_factorial ENDP
+
+_@fn_fibonacci PROC NEAR
+    SUB     SP, 32
+    MOV     DI, SP
+    PUSH    ES
+    PUSH    SS
+    POP     ES
+    MOV     SI, OFFSET _@frame_fibonacci
+    MOV     CX, 32
+    CLD
+    REP     MOVSB
+    POP     ES
+
+    MOV     EAX, _@args_fibonacci_0
+    MOV     _fibonacci@n, EAX
+
+    MOV     EAX, _fibonacci@n
+    CMP     EAX, _2
+    JL    label_2
+
+    JMP    label_4
+
+label_2:
+    MOV     EAX, _fibonacci@n
+    JMP     _@return_fibonacci
+
+    JMP    label_3
+
+label_4:
+    MOV     EAX, _fibonacci@n
+    SUB     EAX, _1
+    MOV     _@fibonacci@t0, EAX
+
+    MOV     EAX, _@fibonacci@t0
+    MOV     _@args_fibonacci_0, EAX
+
+    CALL    _@fn_fibonacci
+    MOV     _@fibonacci@t1, EAX
+
+    MOV     EAX, _@fibonacci@t1
+    MOV     _fibonacci@a, EAX
+
+    MOV     EAX, _fibonacci@n
+    SUB     EAX, _2
+    MOV     _@fibonacci@t2, EAX
+
+    MOV     EAX, _@fibonacci@t2
+    MOV     _@args_fibonacci_0, EAX
+
+    CALL    _@fn_fibonacci
+    MOV     _@fibonacci@t3, EAX
+
+    MOV     EAX, _@fibonacci@t3
+    MOV     _fibonacci@b, EAX
+
+    MOV     EAX, _fibonacci@a
+    ADD     EAX, _fibonacci@b
+    MOV     _@fibonacci@t4, EAX
+
+    MOV     EAX, _@fibonacci@t4
+    JMP     _@return_fibonacci
+
+label_3:
+_@return_fibonacci:
+    MOV     SI, SP
+    PUSH    DS
+    MOV     DI, OFFSET _@frame_fibonacci
+    PUSH    SS
+    POP     DS
+    MOV     CX, 32
+    CLD
+    REP     MOVSB
+    POP     DS
+    ADD     SP, 32
+    RET
+_@fn_fibonacci ENDP
+
+_@fn_half PROC NEAR
+    SUB     SP, 12
+    MOV     DI, SP
+    PUSH    ES
+    PUSH    SS
+    POP     ES
+    MOV     SI, OFFSET _@frame_half
+    MOV     CX, 12
+    CLD
+    REP     MOVSB
+    POP     ES
+
+    FLD     _@args_half_0
+    FSTP    _half@x
+
+    FILD    _2
+    FSTP    _@half@t0
+
+    FLD     _half@x
+    FLD     _@half@t0
+    FDIV
+    FSTP    _@half@t1
+
+    FLD     _@half@t1
+    JMP     _@return_half
+
+_@return_half:
+    MOV     SI, SP
+    PUSH    DS
+    MOV     DI, OFFSET _@frame_half
+    PUSH    SS
+    POP     DS
+    MOV     CX, 12
+    CLD
+    REP     MOVSB
+    POP     DS
+    ADD     SP, 12
+    RET
+_@fn_half ENDP
+
+_@fn_greeting PROC NEAR
+    SUB     SP, 102
+    MOV     DI, SP
+    PUSH    ES
+    PUSH    SS
+    POP     ES
+    MOV     SI, OFFSET _@frame_greeting
+    MOV     CX, 102
+    CLD
+    REP     MOVSB
+    POP     ES
+
+    MOV     DI, OFFSET _greeting@name
+    MOV     CX, 50
+    MOV     SI, OFFSET _@args_greeting_0
+    CALL    _@append_string
+
+    MOV     DI, OFFSET _@greeting@t0
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_0
+    CALL    _@append_string
+    MOV     SI, OFFSET _greeting@name
+    CALL    _@append_string
+
+    MOV     DI, OFFSET _@return
+    MOV     CX, 50
+    MOV     SI, OFFSET _@greeting@t0
+    CALL    _@append_string
+
+    JMP     _@return_greeting
+
+_@return_greeting:
+    MOV     SI, SP
+    PUSH    DS
+    MOV     DI, OFFSET _@frame_greeting
+    PUSH    SS
+    POP     DS
+    MOV     CX, 102
+    CLD
+    REP     MOVSB
+    POP     DS
+    ADD     SP, 102
+    RET
+_@fn_greeting ENDP
+
+_@fn_show PROC NEAR
+    SUB     SP, 56
+    MOV     DI, SP
+    PUSH    ES
+    PUSH    SS
+    POP     ES
+    MOV     SI, OFFSET _@frame_show
+    MOV     CX, 55
+    CLD
+    REP     MOVSB
+    POP     ES
+
+    MOV     DI, OFFSET _show@label
+    MOV     CX, 50
+    MOV     SI, OFFSET _@args_show_0
+    CALL    _@append_string
+
+    MOV     EAX, _@args_show_1
+    MOV     _show@value, EAX
+
+    DisplayString    _show@label
+    newLine
+
+    MOV     EAX, _show@value
+    CALL    _@display_int
+    newLine
+
+_@return_show:
+    MOV     SI, SP
+    PUSH    DS
+    MOV     DI, OFFSET _@frame_show
+    PUSH    SS
+    POP     DS
+    MOV     CX, 55
+    CLD
+    REP     MOVSB
+    POP     DS
+    ADD     SP, 56
+    RET
+_@fn_show ENDP
+
+
+; Runtime routines shared by every program
+
+; Prints the signed int in EAX
+_@display_int PROC NEAR
+    XOR     CX, CX
+    MOV     EBX, 10
+    CMP     EAX, 0
+    JGE     _@display_int_divide
+    PUSH    EAX
+    MOV     DL, '-'
+    MOV     AH, 02H
+    INT     21H
+    POP     EAX
+    NEG     EAX
+_@display_int_divide:
+    ; Digits are pushed from the least significant one and printed in reverse
+    XOR     EDX, EDX
+    DIV     EBX
+    PUSH    DX
+    INC     CX
+    CMP     EAX, 0
+    JNE     _@display_int_divide
+_@display_int_print:
+    POP     DX
+    ADD     DL, '0'
+    MOV     AH, 02H
+    INT     21H
+    LOOP    _@display_int_print
+    RET
+_@display_int ENDP
+
+; Prints true if EAX isn't zero and false if it is
+_@display_bool PROC NEAR
+    CMP     EAX, 0
+    JE      _@display_bool_false
+    DisplayString    _@true
+    RET
+_@display_bool_false:
+    DisplayString    _@false
+    RET
+_@display_bool ENDP
+
+; Appends the '$' terminated string at SI to the one ending at DI while CX, the space left, isn't
+; zero. Leaves DI on the '$' ending the result and CX with the space left so calls can be chained
+_@append_string PROC NEAR
+    CLD
+_@append_string_next:
+    CMP     BYTE PTR [SI], '$'
+    JE      _@append_string_end
+    JCXZ    _@append_string_end
+    MOVSB
+    DEC     CX
+    JMP     _@append_string_next
+_@append_string_end:
+    MOV     BYTE PTR [DI], '$'
+    RET
+_@append_string ENDP
+
+; Compares the '$' terminated strings at SI and DI, ZF is set if they are equal
+_@compare_strings PROC NEAR
+    MOV     AL, [SI]
+    CMP     AL, [DI]
+    JNE     _@compare_strings_end
+    CMP     AL, '$'
+    JE      _@compare_strings_end
+    INC     SI
+    INC     DI
+    JMP     _@compare_strings
+_@compare_strings_end:
+    RET
+_@compare_strings ENDP
+
+; Divides EAX by ECX leaving the quotient in EAX. Fails on a division by zero and on the only
+; quotient that doesn't fit, the smallest int divided by -1, where IDIV would raise an exception
+_@divide PROC NEAR
+    CMP     ECX, 0
+    JE      _@error_division_by_zero
+    CMP     ECX, -1
+    JNE     _@divide_signed
+    NEG     EAX
+    JO      _@error_overflow
+    RET
+_@divide_signed:
+    ; IDIV truncates towards zero
+    CDQ
+    IDIV    ECX
+    RET
+_@divide ENDP
+
+; Reports a division by zero and exits with an error
+_@error_division_by_zero:
+    DisplayString    _@division_by_zero
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an int operation whose result doesn't fit and exits with an error
+_@error_overflow:
+    DisplayString    _@overflow
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an access outside the bounds of an array and exits with an error
+_@error_index_out_of_bounds:
+    DisplayString    _@index_out_of_bounds
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+    END Program
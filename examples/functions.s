@@ -0,0 +1,920 @@
+    .intel_syntax noprefix
+
+    .section .data
+_n:
+    .quad 0
+__t0:
+    .quad 0
+__t1:
+    .quad 0
+__t2:
+    .float 0.0
+__t3:
+    .float 0.0
+__t4:
+    .quad 0
+__t4.len:
+    .quad 0
+__t4.buf:
+    .zero 50
+__t5:
+    .quad 0
+__args_factorial.0:
+    .quad 0
+__frame_factorial:
+_factorial..n:
+    .quad 0
+__factorial.t0:
+    .quad 0
+__factorial.t1:
+    .quad 0
+__factorial.t2:
+    .quad 0
+__args_fibonacci.0:
+    .quad 0
+__frame_fibonacci:
+_fibonacci..n:
+    .quad 0
+_fibonacci..a:
+    .quad 0
+_fibonacci..b:
+    .quad 0
+__fibonacci.t0:
+    .quad 0
+__fibonacci.t1:
+    .quad 0
+__fibonacci.t2:
+    .quad 0
+__fibonacci.t3:
+    .quad 0
+__fibonacci.t4:
+    .quad 0
+__args_half.0:
+    .float 0.0
+__frame_half:
+_half..x:
+    .float 0.0
+__half.t0:
+    .float 0.0
+__half.t1:
+    .float 0.0
+__args_greeting.0:
+    .quad 0
+__args_greeting.0.len:
+    .quad 0
+__args_greeting.0.buf:
+    .zero 50
+__frame_greeting:
+_greeting..name:
+    .quad 0
+_greeting..name.len:
+    .quad 0
+_greeting..name.buf:
+    .zero 50
+__greeting.t0:
+    .quad 0
+__greeting.t0.len:
+    .quad 0
+__greeting.t0.buf:
+    .zero 50
+__args_show.0:
+    .quad 0
+__args_show.0.len:
+    .quad 0
+__args_show.0.buf:
+    .zero 50
+__args_show.1:
+    .quad 0
+__frame_show:
+_show..label:
+    .quad 0
+_show..label.len:
+    .quad 0
+_show..label.buf:
+    .zero 50
+_show..value:
+    .quad 0
+__lm_return:
+    .quad 0
+__lm_return.len:
+    .quad 0
+__lm_return.buf:
+    .zero 50
+
+    .section .rodata
+_string_0:
+    .byte 104, 101, 108, 108, 111, 44, 32
+_string_1:
+    .byte 119, 111, 114, 108, 100
+_string_2:
+    .byte 102, 97, 99, 116, 111, 114, 105, 97, 108, 32, 111, 102, 32, 54
+
+    .section .text
+    .globl _start
+_start:
+    mov rax, 5
+    mov QWORD PTR [rip + _n], rax
+    mov rax, QWORD PTR [rip + _n]
+    mov QWORD PTR [rip + __args_factorial.0], rax
+    call __fn_factorial
+    mov QWORD PTR [rip + __t0], rax
+    mov rax, QWORD PTR [rip + __t0]
+    mov rdi, rax
+    call __lm_print_int
+    call __lm_print_newline
+    mov rax, 10
+    mov QWORD PTR [rip + __args_fibonacci.0], rax
+    call __fn_fibonacci
+    mov QWORD PTR [rip + __t1], rax
+    mov rax, QWORD PTR [rip + __t1]
+    mov rdi, rax
+    call __lm_print_int
+    call __lm_print_newline
+    mov rax, 3
+    cvtsi2ss xmm0, rax
+    movss DWORD PTR [rip + __t2], xmm0
+    movss xmm0, DWORD PTR [rip + __t2]
+    movss DWORD PTR [rip + __args_half.0], xmm0
+    call __fn_half
+    movss DWORD PTR [rip + __t3], xmm0
+    movss xmm0, DWORD PTR [rip + __t3]
+    call __lm_print_float
+    call __lm_print_newline
+    lea rax, [rip + _string_1]
+    mov rdx, 5
+    mov QWORD PTR [rip + __args_greeting.0], rax
+    mov QWORD PTR [rip + __args_greeting.0.len], rdx
+    call __fn_greeting
+    xor ecx, ecx
+    mov rsi, rax
+    lea rdi, [rip + __t4.buf]
+    mov r8, 50
+    call __lm_append_string
+    mov rdx, rax
+    lea rax, [rip + __t4.buf]
+    mov QWORD PTR [rip + __t4], rax
+    mov QWORD PTR [rip + __t4.len], rdx
+    mov rax, QWORD PTR [rip + __t4]
+    mov rdx, QWORD PTR [rip + __t4.len]
+    mov rdi, rax
+    mov rsi, rdx
+    call __lm_print_string
+    call __lm_print_newline
+    mov rax, 6
+    mov QWORD PTR [rip + __args_factorial.0], rax
+    call __fn_factorial
+    mov QWORD PTR [rip + __t5], rax
+    lea rax, [rip + _string_2]
+    mov rdx, 14
+    mov QWORD PTR [rip + __args_show.0], rax
+    mov QWORD PTR [rip + __args_show.0.len], rdx
+    mov rax, QWORD PTR [rip + __t5]
+    mov QWORD PTR [rip + __args_show.1], rax
+    call __fn_show
+    mov rax, QWORD PTR [rip + _n]
+    mov rdi, rax
+    call __lm_print_int
+    call __lm_print_newline
+    mov eax, 60
+    xor edi, edi
+    syscall
+
+__fn_factorial:
+    sub rsp, 32
+    lea rsi, [rip + __frame_factorial]
+    mov rdi, rsp
+    mov ecx, 32
+    rep movsb
+    mov rax, QWORD PTR [rip + __args_factorial.0]
+    mov QWORD PTR [rip + _factorial..n], rax
+    mov rax, QWORD PTR [rip + _factorial..n]
+    mov rcx, 1
+    cmp rax, rcx
+    jle .L0
+    jmp .L1
+.L0:
+    mov rax, 1
+    jmp __fn_factorial.return
+.L1:
+    mov rax, QWORD PTR [rip + _factorial..n]
+    mov rcx, 1
+    sub rax, rcx
+    mov QWORD PTR [rip + __factorial.t0], rax
+    mov rax, QWORD PTR [rip + __factorial.t0]
+    mov QWORD PTR [rip + __args_factorial.0], rax
+    call __fn_factorial
+    mov QWORD PTR [rip + __factorial.t1], rax
+    mov rax, QWORD PTR [rip + _factorial..n]
+    mov rcx, QWORD PTR [rip + __factorial.t1]
+    imul rax, rcx
+    mov QWORD PTR [rip + __factorial.t2], rax
+    mov rax, QWORD PTR [rip + __factorial.t2]
+    jmp __fn_factorial.return
+__fn_factorial.return:
+    mov rsi, rsp
+    lea rdi, [rip + __frame_factorial]
+    mov ecx, 32
+    rep movsb
+    add rsp, 32
+    ret
+
+__fn_fibonacci:
+    sub rsp, 64
+    lea rsi, [rip + __frame_fibonacci]
+    mov rdi, rsp
+    mov ecx, 64
+    rep movsb
+    mov rax, QWORD PTR [rip + __args_fibonacci.0]
+    mov QWORD PTR [rip + _fibonacci..n], rax
+    mov rax, QWORD PTR [rip + _fibonacci..n]
+    mov rcx, 2
+    cmp rax, rcx
+    jl .L2
+    jmp .L4
+.L2:
+    mov rax, QWORD PTR [rip + _fibonacci..n]
+    jmp __fn_fibonacci.return
+    jmp .L3
+.L4:
+    mov rax, QWORD PTR [rip + _fibonacci..n]
+    mov rcx, 1
+    sub rax, rcx
+    mov QWORD PTR [rip + __fibonacci.t0], rax
+    mov rax, QWORD PTR [rip + __fibonacci.t0]
+    mov QWORD PTR [rip + __args_fibonacci.0], rax
+    call __fn_fibonacci
+    mov QWORD PTR [rip + __fibonacci.t1], rax
+    mov rax, QWORD PTR [rip + __fibonacci.t1]
+    mov QWORD PTR [rip + _fibonacci..a], rax
+    mov rax, QWORD PTR [rip + _fibonacci..n]
+    mov rcx, 2
+    sub rax, rcx
+    mov QWORD PTR [rip + __fibonacci.t2], rax
+    mov rax, QWORD PTR [rip + __fibonacci.t2]
+    mov QWORD PTR [rip + __args_fibonacci.0], rax
+    call __fn_fibonacci
+    mov QWORD PTR [rip + __fibonacci.t3], rax
+    mov rax, QWORD PTR [rip + __fibonacci.t3]
+    mov QWORD PTR [rip + _fibonacci..b], rax
+    mov rax, QWORD PTR [rip + _fibonacci..a]
+    mov rcx, QWORD PTR [rip + _fibonacci..b]
+    add rax, rcx
+    mov QWORD PTR [rip + __fibonacci.t4], rax
+    mov rax, QWORD PTR [rip + __fibonacci.t4]
+    jmp __fn_fibonacci.return
+.L3:
+__fn_fibonacci.return:
+    mov rsi, rsp
+    lea rdi, [rip + __frame_fibonacci]
+    mov ecx, 64
+    rep movsb
+    add rsp, 64
+    ret
+
+__fn_half:
+    sub rsp, 16
+    lea rsi, [rip + __frame_half]
+    mov rdi, rsp
+    mov ecx, 12
+    rep movsb
+    movss xmm0, DWORD PTR [rip + __args_half.0]
+    movss DWORD PTR [rip + _half..x], xmm0
+    mov rax, 2
+    cvtsi2ss xmm0, rax
+    movss DWORD PTR [rip + __half.t0], xmm0
+    movss xmm0, DWORD PTR [rip + _half..x]
+    movss xmm1, DWORD PTR [rip + __half.t0]
+    divss xmm0, xmm1
+    movss DWORD PTR [rip + __half.t1], xmm0
+    movss xmm0, DWORD PTR [rip + __half.t1]
+    jmp __fn_half.return
+__fn_half.return:
+    mov rsi, rsp
+    lea rdi, [rip + __frame_half]
+    mov ecx, 12
+    rep movsb
+    add rsp, 16
+    ret
+
+__fn_greeting:
+    sub rsp, 144
+    lea rsi, [rip + __frame_greeting]
+    mov rdi, rsp
+    mov ecx, 132
+    rep movsb
+    mov rax, QWORD PTR [rip + __args_greeting.0]
+    mov rdx, QWORD PTR [rip + __args_greeting.0.len]
+    xor ecx, ecx
+    mov rsi, rax
+    lea rdi, [rip + _greeting..name.buf]
+    mov r8, 50
+    call __lm_append_string
+    mov rdx, rax
+    lea rax, [rip + _greeting..name.buf]
+    mov QWORD PTR [rip + _greeting..name], rax
+    mov QWORD PTR [rip + _greeting..name.len], rdx
+    lea rax, [rip + _string_0]
+    mov rdx, 7
+    xor ecx, ecx
+    mov rsi, rax
+    lea rdi, [rip + __greeting.t0.buf]
+    mov r8, 50
+    call __lm_append_string
+    mov QWORD PTR [rip + __greeting.t0.len], rax
+    mov rax, QWORD PTR [rip + _greeting..name]
+    mov rdx, QWORD PTR [rip + _greeting..name.len]
+    mov rcx, QWORD PTR [rip + __greeting.t0.len]
+    mov rsi, rax
+    lea rdi, [rip + __greeting.t0.buf]
+    mov r8, 50
+    call __lm_append_string
+    mov rdx, rax
+    lea rax, [rip + __greeting.t0.buf]
+    mov QWORD PTR [rip + __greeting.t0], rax
+    mov QWORD PTR [rip + __greeting.t0.len], rdx
+    mov rax, QWORD PTR [rip + __greeting.t0]
+    mov rdx, QWORD PTR [rip + __greeting.t0.len]
+    xor ecx, ecx
+    mov rsi, rax
+    lea rdi, [rip + __lm_return.buf]
+    mov r8, 50
+    call __lm_append_string
+    mov rdx, rax
+    lea rax, [rip + __lm_return.buf]
+    jmp __fn_greeting.return
+__fn_greeting.return:
+    mov rsi, rsp
+    lea rdi, [rip + __frame_greeting]
+    mov ecx, 132
+    rep movsb
+    add rsp, 144
+    ret
+
+__fn_show:
+    sub rsp, 80
+    lea rsi, [rip + __frame_show]
+    mov rdi, rsp
+    mov ecx, 74
+    rep movsb
+    mov rax, QWORD PTR [rip + __args_show.0]
+    mov rdx, QWORD PTR [rip + __args_show.0.len]
+    xor ecx, ecx
+    mov rsi, rax
+    lea rdi, [rip + _show..label.buf]
+    mov r8, 50
+    call __lm_append_string
+    mov rdx, rax
+    lea rax, [rip + _show..label.buf]
+    mov QWORD PTR [rip + _show..label], rax
+    mov QWORD PTR [rip + _show..label.len], rdx
+    mov rax, QWORD PTR [rip + __args_show.1]
+    mov QWORD PTR [rip + _show..value], rax
+    mov rax, QWORD PTR [rip + _show..label]
+    mov rdx, QWORD PTR [rip + _show..label.len]
+    mov rdi, rax
+    mov rsi, rdx
+    call __lm_print_string
+    call __lm_print_newline
+    mov rax, QWORD PTR [rip + _show..value]
+    mov rdi, rax
+    call __lm_print_int
+    call __lm_print_newline
+__fn_show.return:
+    mov rsi, rsp
+    lea rdi, [rip + __frame_show]
+    mov ecx, 74
+    rep movsb
+    add rsp, 80
+    ret
+
+# LM runtime for x86-64 Linux
+#
+# Every routine talks to the kernel directly through syscalls so the program can be linked without
+# libc. Routines may clobber rax, rcx, rdx, rsi, rdi, r8-r11 and xmm0-xmm2
+
+    .section .rodata
+__lm_newline:
+    .byte 10
+__lm_minus:
+    .byte 45
+__lm_true:
+    .ascii "true"
+__lm_false:
+    .ascii "false"
+__lm_hundred:
+    .double 100.0
+__lm_ten:
+    .double 10.0
+__lm_error_prefix:
+    .ascii "error: Runtime error: "
+    .set __lm_error_prefix_len, . - __lm_error_prefix
+__lm_eof_message:
+    .ascii "reached end of input"
+    .set __lm_eof_message_len, . - __lm_eof_message
+__lm_invalid_int_message:
+    .ascii "expected an int"
+    .set __lm_invalid_int_message_len, . - __lm_invalid_int_message
+__lm_invalid_float_message:
+    .ascii "expected a float"
+    .set __lm_invalid_float_message_len, . - __lm_invalid_float_message
+__lm_division_by_zero_message:
+    .ascii "division by zero"
+    .set __lm_division_by_zero_message_len, . - __lm_division_by_zero_message
+__lm_overflow_message:
+    .ascii "integer overflow"
+    .set __lm_overflow_message_len, . - __lm_overflow_message
+__lm_index_out_of_bounds_message:
+    .ascii "index out of bounds"
+    .set __lm_index_out_of_bounds_message_len, . - __lm_index_out_of_bounds_message
+
+    .section .text
+
+# Writes rdx bytes starting at rsi to the file descriptor in rdi
+__lm_write:
+    test rdx, rdx
+    jz .Lwrite_done
+    mov eax, 1
+    syscall
+    test rax, rax
+    jle .Lwrite_done
+    add rsi, rax
+    sub rdx, rax
+    jmp __lm_write
+.Lwrite_done:
+    ret
+
+# Prints rsi bytes starting at rdi to stdout
+__lm_print_string:
+    mov rdx, rsi
+    mov rsi, rdi
+    mov edi, 1
+    jmp __lm_write
+
+__lm_print_newline:
+    lea rdi, [rip + __lm_newline]
+    mov esi, 1
+    jmp __lm_print_string
+
+# Prints the signed integer in rdi
+__lm_print_int:
+    sub rsp, 32
+    lea rsi, [rsp + 32]
+    mov rax, rdi
+    mov r8, rdi
+    test rax, rax
+    jns .Lprint_int_digits
+    neg rax
+.Lprint_int_digits:
+    xor edx, edx
+    mov ecx, 10
+    div rcx
+    add dl, 48
+    dec rsi
+    mov BYTE PTR [rsi], dl
+    test rax, rax
+    jnz .Lprint_int_digits
+    test r8, r8
+    jns .Lprint_int_write
+    dec rsi
+    mov BYTE PTR [rsi], 45
+.Lprint_int_write:
+    mov rdi, rsi
+    lea rsi, [rsp + 32]
+    sub rsi, rdi
+    call __lm_print_string
+    add rsp, 32
+    ret
+
+# Prints `true` if rdi isn't zero and `false` if it is
+__lm_print_bool:
+    test rdi, rdi
+    jz .Lprint_bool_false
+    lea rdi, [rip + __lm_true]
+    mov esi, 4
+    jmp __lm_print_string
+.Lprint_bool_false:
+    lea rdi, [rip + __lm_false]
+    mov esi, 5
+    jmp __lm_print_string
+
+# Prints the float in xmm0 rounded to two decimals
+__lm_print_float:
+    sub rsp, 8
+    cvtss2sd xmm0, xmm0
+    movq rax, xmm0
+    test rax, rax
+    jns .Lprint_float_abs
+    btr rax, 63
+    mov QWORD PTR [rsp], rax
+    lea rdi, [rip + __lm_minus]
+    mov esi, 1
+    call __lm_print_string
+    movsd xmm0, QWORD PTR [rsp]
+.Lprint_float_abs:
+    mulsd xmm0, QWORD PTR [rip + __lm_hundred]
+    cvtsd2si rax, xmm0
+    xor edx, edx
+    mov ecx, 100
+    div rcx
+    mov QWORD PTR [rsp], rdx
+    mov rdi, rax
+    call __lm_print_int
+    mov rax, QWORD PTR [rsp]
+    xor edx, edx
+    mov ecx, 10
+    div rcx
+    add al, 48
+    add dl, 48
+    mov BYTE PTR [rsp], 46
+    mov BYTE PTR [rsp + 1], al
+    mov BYTE PTR [rsp + 2], dl
+    mov rdi, rsp
+    mov esi, 3
+    call __lm_print_string
+    add rsp, 8
+    ret
+
+# Reads a line from stdin into the buffer at rdi of capacity rsi dropping the line terminator
+# Returns the length in rax or -1 if the input ended before anything was read
+__lm_read_line:
+    push rbx
+    push r12
+    push r13
+    push r14
+    sub rsp, 8
+    mov rbx, rdi
+    mov r12, rsi
+    xor r13d, r13d
+    xor r14d, r14d
+.Lread_line_next:
+    xor eax, eax
+    xor edi, edi
+    mov rsi, rsp
+    mov edx, 1
+    syscall
+    cmp rax, 1
+    jne .Lread_line_eof
+    inc r14
+    movzx eax, BYTE PTR [rsp]
+    cmp al, 10
+    je .Lread_line_strip
+    cmp r13, r12
+    jae .Lread_line_next
+    mov BYTE PTR [rbx + r13], al
+    inc r13
+    jmp .Lread_line_next
+.Lread_line_eof:
+    test r14, r14
+    jnz .Lread_line_strip
+    mov rax, -1
+    jmp .Lread_line_return
+.Lread_line_strip:
+    test r13, r13
+    jz .Lread_line_length
+    cmp BYTE PTR [rbx + r13 - 1], 13
+    jne .Lread_line_length
+    dec r13
+    jmp .Lread_line_strip
+.Lread_line_length:
+    mov rax, r13
+.Lread_line_return:
+    add rsp, 8
+    pop r14
+    pop r13
+    pop r12
+    pop rbx
+    ret
+
+# Reads a line into the buffer at rdi of capacity rsi and returns its length in rax
+__lm_read_string:
+    call __lm_read_line
+    cmp rax, -1
+    je __lm_error_eof
+    ret
+
+# Moves rsi forward and rcx backwards past any ASCII whitespace
+__lm_trim:
+    cmp rsi, rcx
+    je .Ltrim_end
+    movzx eax, BYTE PTR [rsi]
+    cmp al, 32
+    je .Ltrim_skip_start
+    sub al, 9
+    cmp al, 4
+    ja .Ltrim_end
+.Ltrim_skip_start:
+    inc rsi
+    jmp __lm_trim
+.Ltrim_end:
+    cmp rsi, rcx
+    je .Ltrim_done
+    movzx eax, BYTE PTR [rcx - 1]
+    cmp al, 32
+    je .Ltrim_skip_end
+    sub al, 9
+    cmp al, 4
+    ja .Ltrim_done
+.Ltrim_skip_end:
+    dec rcx
+    jmp .Ltrim_end
+.Ltrim_done:
+    ret
+
+# Reads a line and parses it as a signed integer returned in rax
+__lm_read_int:
+    sub rsp, 264
+    mov rdi, rsp
+    mov esi, 256
+    call __lm_read_line
+    cmp rax, -1
+    je __lm_error_eof
+    mov rsi, rsp
+    lea rcx, [rsp + rax]
+    call __lm_trim
+    xor r8d, r8d
+    cmp rsi, rcx
+    je __lm_error_invalid_int
+    movzx edx, BYTE PTR [rsi]
+    cmp dl, 45
+    jne .Lread_int_plus
+    mov r8d, 1
+    inc rsi
+    jmp .Lread_int_first_digit
+.Lread_int_plus:
+    cmp dl, 43
+    jne .Lread_int_first_digit
+    inc rsi
+.Lread_int_first_digit:
+    cmp rsi, rcx
+    je __lm_error_invalid_int
+    xor eax, eax
+.Lread_int_digit:
+    movzx edx, BYTE PTR [rsi]
+    sub edx, 48
+    cmp edx, 9
+    ja __lm_error_invalid_int
+    imul rax, rax, 10
+    jo __lm_error_invalid_int
+    add rax, rdx
+    jo __lm_error_invalid_int
+    inc rsi
+    cmp rsi, rcx
+    jne .Lread_int_digit
+    test r8, r8
+    jz .Lread_int_done
+    neg rax
+.Lread_int_done:
+    add rsp, 264
+    ret
+
+# Reads a line and parses it as a float returned in xmm0
+__lm_read_float:
+    sub rsp, 264
+    mov rdi, rsp
+    mov esi, 256
+    call __lm_read_line
+    cmp rax, -1
+    je __lm_error_eof
+    mov rsi, rsp
+    lea rcx, [rsp + rax]
+    call __lm_trim
+    # r8 = negative, r9 = mantissa digits, r10 = decimal exponent, rax = mantissa
+    xor r8d, r8d
+    xor r9d, r9d
+    xor r10d, r10d
+    xor eax, eax
+    cmp rsi, rcx
+    je __lm_error_invalid_float
+    movzx edx, BYTE PTR [rsi]
+    cmp dl, 45
+    jne .Lread_float_plus
+    mov r8d, 1
+    inc rsi
+    jmp .Lread_float_integer
+.Lread_float_plus:
+    cmp dl, 43
+    jne .Lread_float_integer
+    inc rsi
+.Lread_float_integer:
+    cmp rsi, rcx
+    je .Lread_float_scale
+    movzx edx, BYTE PTR [rsi]
+    cmp dl, 46
+    je .Lread_float_point
+    sub edx, 48
+    cmp edx, 9
+    ja .Lread_float_exponent
+    inc r9
+    inc rsi
+    # Digits that don't fit in the mantissa only move the exponent
+    mov r11, 100000000000000000
+    cmp rax, r11
+    jae .Lread_float_integer_dropped
+    imul rax, rax, 10
+    add rax, rdx
+    jmp .Lread_float_integer
+.Lread_float_integer_dropped:
+    inc r10
+    jmp .Lread_float_integer
+.Lread_float_point:
+    inc rsi
+.Lread_float_fraction:
+    cmp rsi, rcx
+    je .Lread_float_scale
+    movzx edx, BYTE PTR [rsi]
+    sub edx, 48
+    cmp edx, 9
+    ja .Lread_float_exponent
+    inc r9
+    inc rsi
+    mov r11, 100000000000000000
+    cmp rax, r11
+    jae .Lread_float_fraction
+    imul rax, rax, 10
+    add rax, rdx
+    dec r10
+    jmp .Lread_float_fraction
+.Lread_float_exponent:
+    test r9, r9
+    jz __lm_error_invalid_float
+    movzx edx, BYTE PTR [rsi]
+    or dl, 32
+    cmp dl, 101
+    jne __lm_error_invalid_float
+    inc rsi
+    xor edi, edi
+    cmp rsi, rcx
+    je __lm_error_invalid_float
+    movzx edx, BYTE PTR [rsi]
+    cmp dl, 45
+    jne .Lread_float_exponent_plus
+    mov edi, 1
+    inc rsi
+    jmp .Lread_float_exponent_first_digit
+.Lread_float_exponent_plus:
+    cmp dl, 43
+    jne .Lread_float_exponent_first_digit
+    inc rsi
+.Lread_float_exponent_first_digit:
+    cmp rsi, rcx
+    je __lm_error_invalid_float
+    xor r11d, r11d
+.Lread_float_exponent_digit:
+    movzx edx, BYTE PTR [rsi]
+    sub edx, 48
+    cmp edx, 9
+    ja __lm_error_invalid_float
+    # Anything past this already overflows or underflows a float
+    cmp r11, 100000
+    jae .Lread_float_exponent_next
+    imul r11, r11, 10
+    add r11, rdx
+.Lread_float_exponent_next:
+    inc rsi
+    cmp rsi, rcx
+    jne .Lread_float_exponent_digit
+    test rdi, rdi
+    jz .Lread_float_exponent_add
+    neg r11
+.Lread_float_exponent_add:
+    add r10, r11
+.Lread_float_scale:
+    test r9, r9
+    jz __lm_error_invalid_float
+    cvtsi2sd xmm0, rax
+    movsd xmm1, QWORD PTR [rip + __lm_ten]
+.Lread_float_scale_up:
+    cmp r10, 0
+    jle .Lread_float_scale_down
+    mulsd xmm0, xmm1
+    dec r10
+    jmp .Lread_float_scale_up
+.Lread_float_scale_down:
+    cmp r10, 0
+    jge .Lread_float_sign
+    divsd xmm0, xmm1
+    inc r10
+    jmp .Lread_float_scale_down
+.Lread_float_sign:
+    cvtsd2ss xmm0, xmm0
+    test r8, r8
+    jz .Lread_float_done
+    movd eax, xmm0
+    xor eax, 0x80000000
+    movd xmm0, eax
+.Lread_float_done:
+    add rsp, 264
+    ret
+
+# Appends the string at rsi of length rdx to the buffer at rdi holding rcx bytes, dropping
+# whatever doesn't fit in r8 bytes. Returns the new length in rax
+__lm_append_string:
+    mov rax, rcx
+.Lappend_string_byte:
+    test rdx, rdx
+    jz .Lappend_string_done
+    cmp rax, r8
+    jae .Lappend_string_done
+    movzx r9d, BYTE PTR [rsi]
+    mov BYTE PTR [rdi + rax], r9b
+    inc rsi
+    inc rax
+    dec rdx
+    jmp .Lappend_string_byte
+.Lappend_string_done:
+    ret
+
+# Divides rax by rcx leaving the quotient in rax. Fails on a division by zero and on the only
+# quotient that doesn't fit, the smallest int divided by -1, where idiv would raise SIGFPE
+__lm_divide:
+    test rcx, rcx
+    jz __lm_error_division_by_zero
+    cmp rcx, -1
+    jne .Ldivide_signed
+    neg rax
+    jo __lm_error_overflow
+    ret
+.Ldivide_signed:
+    cqo
+    idiv rcx
+    ret
+
+# Compares the string at rdi of length rsi with the string at rdx of length rcx
+# Returns -1, 0 or 1 in rax
+__lm_compare_strings:
+    mov r8, rsi
+    cmp r8, rcx
+    cmova r8, rcx
+    xor r9d, r9d
+.Lcompare_strings_byte:
+    cmp r9, r8
+    je .Lcompare_strings_length
+    movzx eax, BYTE PTR [rdi + r9]
+    movzx r10d, BYTE PTR [rdx + r9]
+    cmp eax, r10d
+    jb .Lcompare_strings_less
+    ja .Lcompare_strings_greater
+    inc r9
+    jmp .Lcompare_strings_byte
+.Lcompare_strings_length:
+    cmp rsi, rcx
+    jb .Lcompare_strings_less
+    ja .Lcompare_strings_greater
+    xor eax, eax
+    ret
+.Lcompare_strings_less:
+    mov rax, -1
+    ret
+.Lcompare_strings_greater:
+    mov eax, 1
+    ret
+
+__lm_error_eof:
+    lea rdi, [rip + __lm_eof_message]
+    mov esi, __lm_eof_message_len
+    jmp __lm_runtime_error
+
+__lm_error_invalid_int:
+    lea rdi, [rip + __lm_invalid_int_message]
+    mov esi, __lm_invalid_int_message_len
+    jmp __lm_runtime_error
+
+__lm_error_invalid_float:
+    lea rdi, [rip + __lm_invalid_float_message]
+    mov esi, __lm_invalid_float_message_len
+    jmp __lm_runtime_error
+
+__lm_error_division_by_zero:
+    lea rdi, [rip + __lm_division_by_zero_message]
+    mov esi, __lm_division_by_zero_message_len
+    jmp __lm_runtime_error
+
+__lm_error_overflow:
+    lea rdi, [rip + __lm_overflow_message]
+    mov esi, __lm_overflow_message_len
+    jmp __lm_runtime_error
+
+__lm_error_index_out_of_bounds:
+    lea rdi, [rip + __lm_index_out_of_bounds_message]
+    mov esi, __lm_index_out_of_bounds_message_len
+    jmp __lm_runtime_error
+
+# Prints the message at rdi of length rsi to stderr and exits with status 1
+__lm_runtime_error:
+    mov r12, rdi
+    mov r13, rsi
+    mov edi, 2
+    lea rsi, [rip + __lm_error_prefix]
+    mov edx, __lm_error_prefix_len
+    call __lm_write
+    mov edi, 2
+    mov rsi, r12
+    mov rdx, r13
+    call __lm_write
+    mov edi, 2
+    lea rsi, [rip + __lm_newline]
+    mov edx, 1
+    call __lm_write
+    mov eax, 60
+    mov edi, 1
+    syscall
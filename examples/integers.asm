@@ -0,0 +1,276 @@
+include macros2.asm
+include number.asm
+
+.MODEL LARGE
+.386
+.STACK 200h
+
+.DATA
+_a                           dd    ?
+_b                           dd    ?
+_x                           dd    ?
+_7                           dd    7
+_2                           dd    2
+_1                           dd    1
+_2_0                         dd    2.0
+_0_5                         dd    0.5
+_1_5                         dd    1.5
+_6_5                         dd    6.5
+_string_0                    db    'int compared to float', '$'
+_@fpu_cw                     dd    ?
+_@fpu_trunc_cw               dd    ?
+_@read_number                dd    ?
+_@division_by_zero           db    'error: Runtime error: division by zero', '$'
+_@overflow                   db    'error: Runtime error: integer overflow', '$'
+_@index_out_of_bounds        db    'error: Runtime error: index out of bounds', '$'
+_@true                       db    'true', '$'
+_@false                      db    'false', '$'
+_@t0                         dd    ?
+_@t1                         dd    ?
+_@t2                         dd    ?
+_@t3                         dd    ?
+_@t4                         dd    ?
+_@t5                         dd    ?
+_@t6                         dd    ?
+_@t7                         dd    ?
+_@t8                         dd    ?
+_@t9                         dd    ?
+_@t10                        dd    ?
+_@t11                        dd    ?
+_@t12                        dd    ?
+_@t13                        dd    ?
+.CODE
+
+Program:
+    MOV AX, @DATA
+    MOV DS, AX
+    MOV ES, AX
+
+    FSTCW   WORD PTR _@fpu_cw
+    MOV     AX, WORD PTR _@fpu_cw
+    OR      AX, 0C00H
+    MOV     WORD PTR _@fpu_trunc_cw, AX
+
+    MOV     EAX, _7
+    MOV     _a, EAX
+
+    MOV     EAX, _2
+    NEG     EAX
+    MOV     _@t0, EAX
+
+    MOV     EAX, _@t0
+    MOV     _b, EAX
+
+    MOV     EAX, _a
+    MOV     ECX, _2
+    CALL    _@divide
+    MOV     _@t1, EAX
+
+    MOV     EAX, _@t1
+    CALL    _@display_int
+    newLine
+
+    MOV     EAX, _a
+    MOV     ECX, _b
+    CALL    _@divide
+    MOV     _@t2, EAX
+
+    MOV     EAX, _@t2
+    CALL    _@display_int
+    newLine
+
+    MOV     EAX, _7
+    NEG     EAX
+    MOV     _@t3, EAX
+
+    MOV     EAX, _@t3
+    MOV     ECX, _2
+    CALL    _@divide
+    MOV     _@t4, EAX
+
+    MOV     EAX, _@t4
+    CALL    _@display_int
+    newLine
+
+    MOV     EAX, _a
+    IMUL    EAX, _b
+    MOV     _@t5, EAX
+
+    MOV     EAX, _@t5
+    SUB     EAX, _1
+    MOV     _@t6, EAX
+
+    MOV     EAX, _@t6
+    CALL    _@display_int
+    newLine
+
+    FILD    _a
+    FSTP    _@t7
+
+    FLD     _@t7
+    FLD     _2_0
+    FDIV
+    FSTP    _@t8
+
+    FLD     _@t8
+    FSTP    _x
+
+    DisplayFloat    _x, 2
+    newLine
+
+    FILD    _a
+    FSTP    _@t9
+
+    FLD     _@t9
+    FSTP    _x
+
+    FLD     _x
+    FLD     _0_5
+    FADD
+    FSTP    _@t10
+
+    DisplayFloat    _@t10, 2
+    newLine
+
+    FILD    _a
+    FSTP    _@t11
+
+    FLD     _@t11
+    FLD     _1_5
+    FMUL
+    FSTP    _@t12
+
+    DisplayFloat    _@t12, 2
+    newLine
+
+    FILD    _a
+    FSTP    _@t13
+
+    FLD     _@t13
+    FCOMP   _6_5
+    FSTSW   AX
+    SAHF
+    JA    label_0
+
+    JMP    label_1
+
+label_0:
+    DisplayString    _string_0
+    newLine
+
+label_1:
+    MOV AX, 4C00H
+    INT 21H
+
+
+; Runtime routines shared by every program
+
+; Prints the signed int in EAX
+_@display_int PROC NEAR
+    XOR     CX, CX
+    MOV     EBX, 10
+    CMP     EAX, 0
+    JGE     _@display_int_divide
+    PUSH    EAX
+    MOV     DL, '-'
+    MOV     AH, 02H
+    INT     21H
+    POP     EAX
+    NEG     EAX
+_@display_int_divide:
+    ; Digits are pushed from the least significant one and printed in reverse
+    XOR     EDX, EDX
+    DIV     EBX
+    PUSH    DX
+    INC     CX
+    CMP     EAX, 0
+    JNE     _@display_int_divide
+_@display_int_print:
+    POP     DX
+    ADD     DL, '0'
+    MOV     AH, 02H
+    INT     21H
+    LOOP    _@display_int_print
+    RET
+_@display_int ENDP
+
+; Prints true if EAX isn't zero and false if it is
+_@display_bool PROC NEAR
+    CMP     EAX, 0
+    JE      _@display_bool_false
+    DisplayString    _@true
+    RET
+_@display_bool_false:
+    DisplayString    _@false
+    RET
+_@display_bool ENDP
+
+; Appends the '$' terminated string at SI to the one ending at DI while CX, the space left, isn't
+; zero. Leaves DI on the '$' ending the result and CX with the space left so calls can be chained
+_@append_string PROC NEAR
+    CLD
+_@append_string_next:
+    CMP     BYTE PTR [SI], '$'
+    JE      _@append_string_end
+    JCXZ    _@append_string_end
+    MOVSB
+    DEC     CX
+    JMP     _@append_string_next
+_@append_string_end:
+    MOV     BYTE PTR [DI], '$'
+    RET
+_@append_string ENDP
+
+; Compares the '$' terminated strings at SI and DI, ZF is set if they are equal
+_@compare_strings PROC NEAR
+    MOV     AL, [SI]
+    CMP     AL, [DI]
+    JNE     _@compare_strings_end
+    CMP     AL, '$'
+    JE      _@compare_strings_end
+    INC     SI
+    INC     DI
+    JMP     _@compare_strings
+_@compare_strings_end:
+    RET
+_@compare_strings ENDP
+
+; Divides EAX by ECX leaving the quotient in EAX. Fails on a division by zero and on the only
+; quotient that doesn't fit, the smallest int divided by -1, where IDIV would raise an exception
+_@divide PROC NEAR
+    CMP     ECX, 0
+    JE      _@error_division_by_zero
+    CMP     ECX, -1
+    JNE     _@divide_signed
+    NEG     EAX
+    JO      _@error_overflow
+    RET
+_@divide_signed:
+    ; IDIV truncates towards zero
+    CDQ
+    IDIV    ECX
+    RET
+_@divide ENDP
+
+; Reports a division by zero and exits with an error
+_@error_division_by_zero:
+    DisplayString    _@division_by_zero
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an int operation whose result doesn't fit and exits with an error
+_@error_overflow:
+    DisplayString    _@overflow
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an access outside the bounds of an array and exits with an error
+_@error_index_out_of_bounds:
+    DisplayString    _@index_out_of_bounds
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+    END Program
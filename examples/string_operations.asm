@@ -0,0 +1,268 @@
+include macros2.asm
+include number.asm
+
+.MODEL LARGE
+.386
+.STACK 200h
+
+.DATA
+_greeting                    db    '$', 50 dup (?)
+_name                        db    '$', 50 dup (?)
+_message                     db    '$', 50 dup (?)
+_padding                     db    '$', 50 dup (?)
+_i                           dd    ?
+_string_0                    db    'hello', '$'
+_string_1                    db    'world', '$'
+_string_2                    db    ', ', '$'
+_string_3                    db    'hello, world', '$'
+_string_4                    db    'equal', '$'
+_string_5                    db    'not equal', '$'
+_string_6                    db    'bye', '$'
+_0                           dd    0
+_6                           dd    6
+_string_7                    db    '0123456789', '$'
+_1                           dd    1
+_@fpu_cw                     dd    ?
+_@fpu_trunc_cw               dd    ?
+_@read_number                dd    ?
+_@division_by_zero           db    'error: Runtime error: division by zero', '$'
+_@overflow                   db    'error: Runtime error: integer overflow', '$'
+_@index_out_of_bounds        db    'error: Runtime error: index out of bounds', '$'
+_@true                       db    'true', '$'
+_@false                      db    'false', '$'
+_@t0                         db    '$', 50 dup (?)
+_@t1                         db    '$', 50 dup (?)
+_@t2                         db    '$', 50 dup (?)
+_@t3                         dd    ?
+.CODE
+
+Program:
+    MOV AX, @DATA
+    MOV DS, AX
+    MOV ES, AX
+
+    FSTCW   WORD PTR _@fpu_cw
+    MOV     AX, WORD PTR _@fpu_cw
+    OR      AX, 0C00H
+    MOV     WORD PTR _@fpu_trunc_cw, AX
+
+    MOV     DI, OFFSET _greeting
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_0
+    CALL    _@append_string
+
+    MOV     DI, OFFSET _name
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_1
+    CALL    _@append_string
+
+    MOV     DI, OFFSET _@t0
+    MOV     CX, 50
+    MOV     SI, OFFSET _greeting
+    CALL    _@append_string
+    MOV     SI, OFFSET _string_2
+    CALL    _@append_string
+
+    MOV     DI, OFFSET _@t1
+    MOV     CX, 50
+    MOV     SI, OFFSET _@t0
+    CALL    _@append_string
+    MOV     SI, OFFSET _name
+    CALL    _@append_string
+
+    MOV     DI, OFFSET _message
+    MOV     CX, 50
+    MOV     SI, OFFSET _@t1
+    CALL    _@append_string
+
+    DisplayString    _message
+    newLine
+
+    MOV     SI, OFFSET _message
+    MOV     DI, OFFSET _string_3
+    CALL    _@compare_strings
+    JE    label_0
+
+    JMP    label_1
+
+label_0:
+    DisplayString    _string_4
+    newLine
+
+label_1:
+    MOV     SI, OFFSET _greeting
+    MOV     DI, OFFSET _name
+    CALL    _@compare_strings
+    JNE    label_2
+
+    JMP    label_3
+
+label_2:
+    DisplayString    _string_5
+    newLine
+
+label_3:
+    MOV     DI, OFFSET _name
+    MOV     CX, 50
+    MOV     SI, OFFSET _greeting
+    CALL    _@append_string
+
+    MOV     DI, OFFSET _greeting
+    MOV     CX, 50
+    MOV     SI, OFFSET _string_6
+    CALL    _@append_string
+
+    DisplayString    _name
+    newLine
+
+    MOV     EAX, _0
+    MOV     _i, EAX
+
+label_4:
+    MOV     EAX, _i
+    CMP     EAX, _6
+    JL    label_5
+
+    JMP    label_6
+
+label_5:
+    MOV     DI, OFFSET _@t2
+    MOV     CX, 50
+    MOV     SI, OFFSET _padding
+    CALL    _@append_string
+    MOV     SI, OFFSET _string_7
+    CALL    _@append_string
+
+    MOV     DI, OFFSET _padding
+    MOV     CX, 50
+    MOV     SI, OFFSET _@t2
+    CALL    _@append_string
+
+    MOV     EAX, _i
+    ADD     EAX, _1
+    MOV     _@t3, EAX
+
+    MOV     EAX, _@t3
+    MOV     _i, EAX
+
+    JMP    label_4
+
+label_6:
+    DisplayString    _padding
+    newLine
+
+    MOV AX, 4C00H
+    INT 21H
+
+
+; Runtime routines shared by every program
+
+; Prints the signed int in EAX
+_@display_int PROC NEAR
+    XOR     CX, CX
+    MOV     EBX, 10
+    CMP     EAX, 0
+    JGE     _@display_int_divide
+    PUSH    EAX
+    MOV     DL, '-'
+    MOV     AH, 02H
+    INT     21H
+    POP     EAX
+    NEG     EAX
+_@display_int_divide:
+    ; Digits are pushed from the least significant one and printed in reverse
+    XOR     EDX, EDX
+    DIV     EBX
+    PUSH    DX
+    INC     CX
+    CMP     EAX, 0
+    JNE     _@display_int_divide
+_@display_int_print:
+    POP     DX
+    ADD     DL, '0'
+    MOV     AH, 02H
+    INT     21H
+    LOOP    _@display_int_print
+    RET
+_@display_int ENDP
+
+; Prints true if EAX isn't zero and false if it is
+_@display_bool PROC NEAR
+    CMP     EAX, 0
+    JE      _@display_bool_false
+    DisplayString    _@true
+    RET
+_@display_bool_false:
+    DisplayString    _@false
+    RET
+_@display_bool ENDP
+
+; Appends the '$' terminated string at SI to the one ending at DI while CX, the space left, isn't
+; zero. Leaves DI on the '$' ending the result and CX with the space left so calls can be chained
+_@append_string PROC NEAR
+    CLD
+_@append_string_next:
+    CMP     BYTE PTR [SI], '$'
+    JE      _@append_string_end
+    JCXZ    _@append_string_end
+    MOVSB
+    DEC     CX
+    JMP     _@append_string_next
+_@append_string_end:
+    MOV     BYTE PTR [DI], '$'
+    RET
+_@append_string ENDP
+
+; Compares the '$' terminated strings at SI and DI, ZF is set if they are equal
+_@compare_strings PROC NEAR
+    MOV     AL, [SI]
+    CMP     AL, [DI]
+    JNE     _@compare_strings_end
+    CMP     AL, '$'
+    JE      _@compare_strings_end
+    INC     SI
+    INC     DI
+    JMP     _@compare_strings
+_@compare_strings_end:
+    RET
+_@compare_strings ENDP
+
+; Divides EAX by ECX leaving the quotient in EAX. Fails on a division by zero and on the only
+; quotient that doesn't fit, the smallest int divided by -1, where IDIV would raise an exception
+_@divide PROC NEAR
+    CMP     ECX, 0
+    JE      _@error_division_by_zero
+    CMP     ECX, -1
+    JNE     _@divide_signed
+    NEG     EAX
+    JO      _@error_overflow
+    RET
+_@divide_signed:
+    ; IDIV truncates towards zero
+    CDQ
+    IDIV    ECX
+    RET
+_@divide ENDP
+
+; Reports a division by zero and exits with an error
+_@error_division_by_zero:
+    DisplayString    _@division_by_zero
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an int operation whose result doesn't fit and exits with an error
+_@error_overflow:
+    DisplayString    _@overflow
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+; Reports an access outside the bounds of an array and exits with an error
+_@error_index_out_of_bounds:
+    DisplayString    _@index_out_of_bounds
+    newLine
+    MOV AX, 4C01H
+    INT 21H
+
+    END Program
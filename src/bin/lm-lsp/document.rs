@@ -0,0 +1,356 @@
+use lm_compiler::{
+    Analysis, analyze,
+    compiler::{
+        context::{Reference, ReferenceKind, SymbolTableElementType},
+        error::Severity,
+    },
+    grammar::TokenKind,
+};
+use lsp_types::{
+    CompletionItem, CompletionItemKind, DiagnosticRelatedInformation, DiagnosticSeverity, Location,
+    NumberOrString, Position, Range, SemanticToken, SemanticTokenType, Uri,
+};
+use std::collections::HashSet;
+
+/// Words the lexer reads as keywords, offered as completions
+const KEYWORDS: [TokenKind; 20] = [
+    TokenKind::TokenInt,
+    TokenKind::TokenFloat,
+    TokenKind::TokenString,
+    TokenKind::TokenBool,
+    TokenKind::TokenInit,
+    TokenKind::TokenWhile,
+    TokenKind::TokenTrue,
+    TokenKind::TokenFalse,
+    TokenKind::TokenIf,
+    TokenKind::TokenElse,
+    TokenKind::TokenAnd,
+    TokenKind::TokenOr,
+    TokenKind::TokenNot,
+    TokenKind::TokenRead,
+    TokenKind::TokenWrite,
+    TokenKind::TokenIsZero,
+    TokenKind::TokenConvDate,
+    TokenKind::TokenFunction,
+    TokenKind::TokenProcedure,
+    TokenKind::TokenReturn,
+];
+
+/// Token types the server highlights, the index in this list is what semantic tokens carry
+pub const TOKEN_TYPES: [SemanticTokenType; 7] = [
+    SemanticTokenType::KEYWORD,
+    SemanticTokenType::TYPE,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::NUMBER,
+    SemanticTokenType::STRING,
+    SemanticTokenType::OPERATOR,
+];
+
+/// An open `.lm` file along with what the compiler found in its current text
+pub struct Document {
+    uri: Uri,
+    text: String,
+    /// Byte offset where each line starts
+    line_starts: Vec<usize>,
+    analysis: Analysis,
+}
+
+impl Document {
+    pub fn new(uri: Uri, text: String) -> Self {
+        let analysis = analyze(&text, uri.path().as_str());
+        let line_starts = line_starts(&text).collect();
+        Self {
+            uri,
+            text,
+            line_starts,
+            analysis,
+        }
+    }
+
+    pub fn diagnostics(&self) -> Vec<lsp_types::Diagnostic> {
+        self.analysis
+            .diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let severity = match diagnostic.severity {
+                    Severity::Error => DiagnosticSeverity::ERROR,
+                    Severity::Warning => DiagnosticSeverity::WARNING,
+                };
+                let mut message = diagnostic.error.to_string();
+                for help in &diagnostic.help {
+                    message.push_str("\nhelp: ");
+                    message.push_str(help);
+                }
+                let related_information = diagnostic
+                    .labels
+                    .iter()
+                    .map(|label| DiagnosticRelatedInformation {
+                        location: self.location(&label.span),
+                        message: label.message.clone(),
+                    })
+                    .collect::<Vec<_>>();
+                lsp_types::Diagnostic {
+                    range: self.range(&diagnostic.span),
+                    severity: Some(severity),
+                    code: Some(NumberOrString::String(diagnostic.error.code().into())),
+                    source: Some(String::from("lm-compiler")),
+                    message,
+                    related_information: (!related_information.is_empty())
+                        .then_some(related_information),
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    /// Where the variable or function under `position` is declared
+    pub fn definition(&self, position: Position) -> Option<Location> {
+        let reference = self.reference_at(position)?;
+        let span = match reference.kind {
+            ReferenceKind::Variable => {
+                self.analysis
+                    .symbol_table
+                    .get_symbol_from_name(&reference.key)?
+                    .span?
+                    .range
+            }
+            ReferenceKind::Function => self
+                .analysis
+                .symbol_table
+                .get_function(&reference.key)?
+                .span
+                .range
+                .clone(),
+        };
+        Some(self.location(&span))
+    }
+
+    /// Type of the variable or signature of the function under `position`
+    pub fn hover(&self, position: Position) -> Option<(String, Range)> {
+        let reference = self.reference_at(position)?;
+        let text = match reference.kind {
+            ReferenceKind::Variable => {
+                let symbol = self
+                    .analysis
+                    .symbol_table
+                    .get_symbol_from_name(&reference.key)?;
+                format!(
+                    "{}: {}",
+                    &self.text[reference.span.clone()],
+                    symbol.data_type
+                )
+            }
+            ReferenceKind::Function => self.signature(&reference.key)?,
+        };
+        Some((text, self.range(&reference.span)))
+    }
+
+    /// Keywords and every variable and function declared in the file
+    pub fn completions(&self) -> Vec<CompletionItem> {
+        let keywords = KEYWORDS.iter().map(|keyword| CompletionItem {
+            label: keyword.to_string().trim_matches('"').to_string(),
+            kind: Some(CompletionItemKind::KEYWORD),
+            ..Default::default()
+        });
+
+        let mut seen = HashSet::new();
+        let variables = self.analysis.symbol_table.iter().filter_map(|symbol| {
+            let span = symbol.span.as_ref()?;
+            if !matches!(
+                symbol.data_type,
+                SymbolTableElementType::DataType(_) | SymbolTableElementType::Array(..)
+            ) {
+                return None;
+            }
+            Some(CompletionItem {
+                label: self.text.get(span.range.clone())?.to_string(),
+                kind: Some(CompletionItemKind::VARIABLE),
+                detail: Some(symbol.data_type.to_string()),
+                ..Default::default()
+            })
+        });
+        // Duplicated functions get `@` and a number appended to their name
+        let functions = self
+            .analysis
+            .symbol_table
+            .functions()
+            .filter(|function| !function.name.contains('@'))
+            .map(|function| CompletionItem {
+                label: function.name.clone(),
+                kind: Some(CompletionItemKind::FUNCTION),
+                detail: self.signature(&function.name),
+                ..Default::default()
+            });
+
+        keywords
+            .chain(variables)
+            .chain(functions)
+            .filter(|item| seen.insert(item.label.clone()))
+            .collect()
+    }
+
+    /// Every token read by the parser, encoded relative to the previous one
+    pub fn semantic_tokens(&self) -> Vec<SemanticToken> {
+        let mut tokens = Vec::new();
+        let mut previous = Position::new(0, 0);
+        for (span, kind) in &self.analysis.tokens {
+            let is_function = || {
+                self.analysis.references.iter().any(|reference| {
+                    reference.kind == ReferenceKind::Function && reference.span == *span
+                })
+            };
+            let token_type = match kind {
+                TokenKind::TokenId if is_function() => SemanticTokenType::FUNCTION,
+                kind => match token_type(*kind) {
+                    Some(token_type) => token_type,
+                    None => continue,
+                },
+            };
+            let range = self.range(span);
+            // Tokens can't span lines, none of the language does
+            if range.start.line != range.end.line {
+                continue;
+            }
+            let delta_line = range.start.line - previous.line;
+            let delta_start = if delta_line == 0 {
+                range.start.character - previous.character
+            } else {
+                range.start.character
+            };
+            tokens.push(SemanticToken {
+                delta_line,
+                delta_start,
+                length: range.end.character - range.start.character,
+                token_type: TOKEN_TYPES
+                    .iter()
+                    .position(|known| *known == token_type)
+                    .unwrap_or_default() as u32,
+                token_modifiers_bitset: 0,
+            });
+            previous = range.start;
+        }
+        tokens
+    }
+
+    /// `function name(parameter: type, ...): type`, `procedure` for the ones without a return
+    /// type
+    fn signature(&self, name: &str) -> Option<String> {
+        let symbol_table = &self.analysis.symbol_table;
+        let function = symbol_table.get_function(name)?;
+        let parameters = function
+            .parameters
+            .iter()
+            .filter_map(|key| {
+                let symbol = symbol_table.get_symbol_from_name(key)?;
+                let name = self.text.get(symbol.span?.range)?;
+                Some(format!("{name}: {}", symbol.data_type))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(match &function.return_type {
+            Some(return_type) => format!("function {name}({parameters}): {return_type}"),
+            None => format!("procedure {name}({parameters})"),
+        })
+    }
+
+    fn reference_at(&self, position: Position) -> Option<&Reference> {
+        let offset = self.offset(position);
+        self.analysis
+            .references
+            .iter()
+            .find(|reference| reference.span.contains(&offset) || reference.span.end == offset)
+    }
+
+    fn location(&self, span: &std::ops::Range<usize>) -> Location {
+        Location::new(self.uri.clone(), self.range(span))
+    }
+
+    fn range(&self, span: &std::ops::Range<usize>) -> Range {
+        Range::new(self.position(span.start), self.position(span.end))
+    }
+
+    /// LSP position of the byte `offset`, columns count UTF-16 code units
+    fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.text.len());
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line];
+        let character = self
+            .text
+            .get(line_start..offset)
+            .map_or(0, |text| text.encode_utf16().count());
+        Position::new(line as u32, character as u32)
+    }
+
+    /// Byte offset of an LSP position, positions past the end of a line point at its end
+    fn offset(&self, position: Position) -> usize {
+        let Some(&line_start) = self.line_starts.get(position.line as usize) else {
+            return self.text.len();
+        };
+        let line = self.text[line_start..].lines().next().unwrap_or_default();
+        let mut units = 0;
+        for (index, char) in line.char_indices() {
+            if units >= position.character as usize {
+                return line_start + index;
+            }
+            units += char.len_utf16();
+        }
+        line_start + line.len()
+    }
+}
+
+/// Byte offset where each line of `text` starts
+fn line_starts(text: &str) -> impl Iterator<Item = usize> + '_ {
+    std::iter::once(0).chain(text.match_indices('\n').map(|(index, _)| index + 1))
+}
+
+/// How a token is highlighted, `None` for punctuation
+fn token_type(kind: TokenKind) -> Option<SemanticTokenType> {
+    Some(match kind {
+        TokenKind::TokenInt
+        | TokenKind::TokenFloat
+        | TokenKind::TokenString
+        | TokenKind::TokenBool => SemanticTokenType::TYPE,
+        TokenKind::TokenIntLiteral | TokenKind::TokenFloatLiteral | TokenKind::TokenDate => {
+            SemanticTokenType::NUMBER
+        }
+        TokenKind::TokenStringLiteral => SemanticTokenType::STRING,
+        TokenKind::TokenId => SemanticTokenType::VARIABLE,
+        TokenKind::TokenAssign
+        | TokenKind::TokenSum
+        | TokenKind::TokenMul
+        | TokenKind::TokenSub
+        | TokenKind::TokenDiv
+        | TokenKind::TokenEqual
+        | TokenKind::TokenNotEqual
+        | TokenKind::TokenLess
+        | TokenKind::TokenLessEqual
+        | TokenKind::TokenGreater
+        | TokenKind::TokenGreaterEqual => SemanticTokenType::OPERATOR,
+        TokenKind::STOP
+        | TokenKind::TokenParOpen
+        | TokenKind::TokenParClose
+        | TokenKind::TokenCBOpen
+        | TokenKind::TokenCBClose
+        | TokenKind::TokenSBOpen
+        | TokenKind::TokenSBClose
+        | TokenKind::TokenColon
+        | TokenKind::TokenComma => return None,
+        TokenKind::TokenInit
+        | TokenKind::TokenWhile
+        | TokenKind::TokenTrue
+        | TokenKind::TokenFalse
+        | TokenKind::TokenIf
+        | TokenKind::TokenElse
+        | TokenKind::TokenAnd
+        | TokenKind::TokenOr
+        | TokenKind::TokenNot
+        | TokenKind::TokenRead
+        | TokenKind::TokenWrite
+        | TokenKind::TokenIsZero
+        | TokenKind::TokenConvDate
+        | TokenKind::TokenFunction
+        | TokenKind::TokenProcedure
+        | TokenKind::TokenReturn => SemanticTokenType::KEYWORD,
+    })
+}
@@ -0,0 +1,217 @@
+//! Language server for `.lm` files speaking LSP over stdin and stdout
+
+mod document;
+#[cfg(test)]
+mod tests;
+
+use document::{Document, TOKEN_TYPES};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    CompletionOptions, GotoDefinitionResponse, Hover, HoverContents, HoverProviderCapability,
+    InitializeParams, MarkupContent, MarkupKind, OneOf, PublishDiagnosticsParams, SemanticTokens,
+    SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions,
+    SemanticTokensServerCapabilities, ServerCapabilities, TextDocumentSyncCapability,
+    TextDocumentSyncKind, Uri,
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+        Notification as LspNotification, PublishDiagnostics,
+    },
+    request::{
+        Completion, GotoDefinition, HoverRequest, Request as LspRequest, SemanticTokensFullRequest,
+    },
+};
+use serde::Serialize;
+use std::{collections::HashMap, error::Error};
+
+type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
+
+fn main() -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let params = connection.initialize(serde_json::to_value(capabilities())?)?;
+    let _: InitializeParams = serde_json::from_value(params)?;
+
+    Server::new(&connection).run()?;
+    // The writer thread only stops once every sender is gone
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
+
+/// What the server can do, sent to the client when it connects
+fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        // Programs are small, analyzing the whole text again on each change is fast enough
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        definition_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions::default()),
+        semantic_tokens_provider: Some(SemanticTokensServerCapabilities::SemanticTokensOptions(
+            SemanticTokensOptions {
+                legend: SemanticTokensLegend {
+                    token_types: TOKEN_TYPES.to_vec(),
+                    token_modifiers: Vec::new(),
+                },
+                full: Some(SemanticTokensFullOptions::Bool(true)),
+                ..Default::default()
+            },
+        )),
+        ..Default::default()
+    }
+}
+
+struct Server<'a> {
+    connection: &'a Connection,
+    documents: HashMap<Uri, Document>,
+}
+
+impl<'a> Server<'a> {
+    fn new(connection: &'a Connection) -> Self {
+        Self {
+            connection,
+            documents: HashMap::new(),
+        }
+    }
+
+    /// Handles messages until the client asks the server to shut down, only losing the
+    /// connection to the client stops it earlier
+    fn run(&mut self) -> Result<()> {
+        for message in &self.connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> Result<()> {
+        match request.method.as_str() {
+            GotoDefinition::METHOD => self.respond::<GotoDefinition, _>(request, |params| {
+                let position = params.text_document_position_params;
+                self.documents
+                    .get(&position.text_document.uri)
+                    .and_then(|document| document.definition(position.position))
+                    .map(GotoDefinitionResponse::Scalar)
+            }),
+            HoverRequest::METHOD => self.respond::<HoverRequest, _>(request, |params| {
+                let position = params.text_document_position_params;
+                self.documents
+                    .get(&position.text_document.uri)
+                    .and_then(|document| document.hover(position.position))
+                    .map(|(text, range)| Hover {
+                        contents: HoverContents::Markup(MarkupContent {
+                            kind: MarkupKind::Markdown,
+                            value: format!("```\n{text}\n```"),
+                        }),
+                        range: Some(range),
+                    })
+            }),
+            Completion::METHOD => self.respond::<Completion, _>(request, |params| {
+                self.documents
+                    .get(&params.text_document_position.text_document.uri)
+                    .map(Document::completions)
+                    .unwrap_or_default()
+            }),
+            SemanticTokensFullRequest::METHOD => {
+                self.respond::<SemanticTokensFullRequest, _>(request, |params| {
+                    self.documents
+                        .get(&params.text_document.uri)
+                        .map(|document| SemanticTokens {
+                            result_id: None,
+                            data: document.semantic_tokens(),
+                        })
+                })
+            }
+            method => {
+                let response = Response::new_err(
+                    request.id,
+                    ErrorCode::MethodNotFound as i32,
+                    format!("unsupported request {method}"),
+                );
+                Ok(self.connection.sender.send(response.into())?)
+            }
+        }
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Result<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let Some(params) = params::<DidOpenTextDocument>(notification) else {
+                    return Ok(());
+                };
+                let document = params.text_document;
+                self.update(document.uri, document.text)
+            }
+            DidChangeTextDocument::METHOD => {
+                let Some(params) = params::<DidChangeTextDocument>(notification) else {
+                    return Ok(());
+                };
+                // With full sync the last change has the whole text
+                match params.content_changes.into_iter().last() {
+                    Some(change) => self.update(params.text_document.uri, change.text),
+                    None => Ok(()),
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let Some(params) = params::<DidCloseTextDocument>(notification) else {
+                    return Ok(());
+                };
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                self.publish_diagnostics(uri, Vec::new())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Analyzes the new text of the document and publishes what was found in it
+    fn update(&mut self, uri: Uri, text: String) -> Result<()> {
+        let document = Document::new(uri.clone(), text);
+        let diagnostics = document.diagnostics();
+        self.documents.insert(uri.clone(), document);
+        self.publish_diagnostics(uri, diagnostics)
+    }
+
+    fn publish_diagnostics(&self, uri: Uri, diagnostics: Vec<lsp_types::Diagnostic>) -> Result<()> {
+        let params = PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version: None,
+        };
+        let notification = Notification::new(PublishDiagnostics::METHOD.into(), params);
+        Ok(self.connection.sender.send(notification.into())?)
+    }
+
+    /// Answers `request` with what `handler` returns for its params, or with an error if they
+    /// aren't the ones the method takes
+    fn respond<R: LspRequest, T: Serialize>(
+        &self,
+        request: Request,
+        handler: impl FnOnce(R::Params) -> T,
+    ) -> Result<()> {
+        let response = match serde_json::from_value(request.params) {
+            Ok(params) => Response::new_ok(request.id, handler(params)),
+            Err(error) => Response::new_err(
+                request.id,
+                ErrorCode::InvalidParams as i32,
+                format!("invalid params for {}: {error}", R::METHOD),
+            ),
+        };
+        Ok(self.connection.sender.send(response.into())?)
+    }
+}
+
+/// Params of `notification`, notifications can't be answered so ones with params the method
+/// doesn't take are logged and dropped
+fn params<N: LspNotification>(notification: Notification) -> Option<N::Params> {
+    serde_json::from_value(notification.params)
+        .inspect_err(|error| eprintln!("dropped {}: invalid params: {error}", N::METHOD))
+        .ok()
+}
@@ -0,0 +1,207 @@
+use super::{Server, document::Document};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::{
+    CompletionItemKind, DiagnosticSeverity, NumberOrString, Position, Range, Uri,
+    notification::{DidOpenTextDocument, Exit, Notification as _, PublishDiagnostics},
+    request::{HoverRequest, Request as _, Shutdown},
+};
+use serde_json::{Value, json};
+use std::{thread, time::Duration};
+
+const PROGRAM: &str = "\
+function twice(n : int) : int {
+    return n * 2
+}
+
+main() {
+    init { a : int }
+    a := twice(3)
+    b := a
+    write(a)
+}
+";
+
+fn uri() -> Uri {
+    "file:///program.lm".parse().unwrap()
+}
+
+fn document() -> Document {
+    Document::new(uri(), PROGRAM.to_string())
+}
+
+#[test]
+fn diagnostics() {
+    let diagnostics = document().diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::ERROR));
+    assert_eq!(
+        diagnostic.code,
+        Some(NumberOrString::String(String::from("E0005")))
+    );
+    assert_eq!(
+        diagnostic.range,
+        Range::new(Position::new(7, 4), Position::new(7, 5))
+    );
+}
+
+#[test]
+fn definition() {
+    let document = document();
+    let variable = document.definition(Position::new(8, 10)).unwrap();
+    assert_eq!(variable.uri, uri());
+    assert_eq!(
+        variable.range,
+        Range::new(Position::new(5, 11), Position::new(5, 12))
+    );
+    let function = document.definition(Position::new(6, 10)).unwrap();
+    assert_eq!(
+        function.range,
+        Range::new(Position::new(0, 9), Position::new(0, 14))
+    );
+    assert_eq!(document.definition(Position::new(3, 0)), None);
+}
+
+#[test]
+fn hover() {
+    let document = document();
+    let (text, range) = document.hover(Position::new(6, 4)).unwrap();
+    assert_eq!(text, "a: VAR_INT");
+    assert_eq!(range, Range::new(Position::new(6, 4), Position::new(6, 5)));
+    let (text, _) = document.hover(Position::new(6, 12)).unwrap();
+    assert_eq!(text, "function twice(n: VAR_INT): INT");
+}
+
+#[test]
+fn completions() {
+    let completions = document().completions();
+    let kind = |label: &str| {
+        completions
+            .iter()
+            .find(|item| item.label == label)
+            .and_then(|item| item.kind)
+    };
+    assert_eq!(kind("while"), Some(CompletionItemKind::KEYWORD));
+    assert_eq!(kind("a"), Some(CompletionItemKind::VARIABLE));
+    assert_eq!(kind("twice"), Some(CompletionItemKind::FUNCTION));
+    assert_eq!(kind("b"), None);
+}
+
+#[test]
+fn semantic_tokens() {
+    let tokens = document()
+        .semantic_tokens()
+        .iter()
+        .map(|token| {
+            (
+                token.delta_line,
+                token.delta_start,
+                token.length,
+                token.token_type,
+            )
+        })
+        .collect::<Vec<_>>();
+    // `function` is a keyword, `twice` a function, `n` a variable and `int` a type
+    assert_eq!(
+        tokens[..4],
+        [(0, 0, 8, 0), (0, 9, 5, 3), (0, 6, 1, 2), (0, 4, 3, 1)]
+    );
+}
+
+/// Runs a server on an in-memory connection for `client`, then shuts it down
+fn with_server(client: impl FnOnce(&Connection)) {
+    let (server, connection) = Connection::memory();
+    thread::scope(|scope| {
+        let running = scope.spawn(|| Server::new(&server).run());
+        // Moved in so a failing client drops its end and the server stops instead of hanging
+        let connection = connection;
+        client(&connection);
+        let shutdown = RequestId::from(0);
+        send_request(&connection, shutdown.clone(), Shutdown::METHOD, Value::Null);
+        assert_eq!(receive_response(&connection).id, shutdown);
+        send_notification(&connection, Exit::METHOD, Value::Null);
+        running.join().unwrap().unwrap();
+    });
+}
+
+fn send_request(connection: &Connection, id: RequestId, method: &str, params: Value) {
+    let request = Request::new(id, method.to_string(), params);
+    connection.sender.send(request.into()).unwrap();
+}
+
+fn send_notification(connection: &Connection, method: &str, params: Value) {
+    let notification = Notification::new(method.to_string(), params);
+    connection.sender.send(notification.into()).unwrap();
+}
+
+fn receive(connection: &Connection) -> Message {
+    connection
+        .receiver
+        .recv_timeout(Duration::from_secs(10))
+        .unwrap()
+}
+
+fn receive_response(connection: &Connection) -> Response {
+    match receive(connection) {
+        Message::Response(response) => response,
+        message => panic!("expected a response, got {message:?}"),
+    }
+}
+
+fn open(connection: &Connection) {
+    let params = json!({
+        "textDocument": {
+            "uri": uri(),
+            "languageId": "lm",
+            "version": 1,
+            "text": PROGRAM,
+        }
+    });
+    send_notification(connection, DidOpenTextDocument::METHOD, params);
+}
+
+#[test]
+fn server_publishes_diagnostics() {
+    with_server(|connection| {
+        open(connection);
+        let Message::Notification(notification) = receive(connection) else {
+            panic!("expected the diagnostics");
+        };
+        assert_eq!(notification.method, PublishDiagnostics::METHOD);
+        assert_eq!(notification.params["diagnostics"][0]["code"], "E0005");
+    });
+}
+
+#[test]
+fn server_survives_malformed_params() {
+    with_server(|connection| {
+        // Nothing can be answered to a notification, the server drops it
+        send_notification(connection, DidOpenTextDocument::METHOD, json!({"text": 1}));
+        send_request(
+            connection,
+            RequestId::from(1),
+            HoverRequest::METHOD,
+            json!({"position": "start"}),
+        );
+        let response = receive_response(connection);
+        assert_eq!(response.id, RequestId::from(1));
+        assert_eq!(
+            response.response_result.map_err(|error| error.code),
+            Err(ErrorCode::InvalidParams as i32)
+        );
+
+        open(connection);
+        assert!(matches!(receive(connection), Message::Notification(_)));
+        let params = json!({
+            "textDocument": { "uri": uri() },
+            "position": { "line": 6, "character": 4 },
+        });
+        send_request(connection, RequestId::from(2), HoverRequest::METHOD, params);
+        let response = receive_response(connection);
+        assert_eq!(response.id, RequestId::from(2));
+        assert_eq!(
+            response.response_result.unwrap()["contents"]["value"],
+            "```\na: VAR_INT\n```"
+        );
+    });
+}
@@ -0,0 +1,777 @@
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    ops::Range,
+};
+
+use crate::compiler::{
+    asm::CodegenBackend,
+    ast::ExpressionType,
+    context::{MAX_STRING_LENGTH, SymbolTable, SymbolTableElement, SymbolTableElementType},
+    error::{CompilerError, Diagnostic},
+    ir::{BinaryOp, Comparison, Instruction, IrFunction, IrProgram, Operand},
+};
+
+/// Generates x86-64 GAS assembly in Intel syntax for Linux
+///
+/// The program talks to the kernel through the runtime in `runtime.s` so the output can be
+/// assembled and linked without libc:
+///
+/// ```sh
+/// as program.s -o program.o && ld program.o -o program
+/// ```
+pub struct GasGenerator;
+
+impl CodegenBackend for GasGenerator {
+    fn extension(&self) -> &'static str {
+        "s"
+    }
+
+    fn generate(
+        &self,
+        program: &IrProgram,
+        symbol_table: &SymbolTable,
+        out: &mut dyn Write,
+    ) -> Result<(), Box<Diagnostic>> {
+        let mut emitter = GasEmitter::new(symbol_table, out);
+        emitter
+            .generate_asm(program)
+            .map_err(|error| Box::new(Diagnostic::new(emitter.span.clone(), error, true)))
+    }
+}
+
+/// Every variable and temporary lives in memory. Instructions load the left operand in
+/// `rax`/`xmm0` and the right one in `rcx`/`xmm1`, strings use a pointer and a length in
+/// `rax`,`rdx` and `rcx`,`r8`. The elements of an array are laid out one after the other and
+/// accessed through their address in `rbx`, which the runtime doesn't clobber
+///
+/// Functions follow the frame model of [`CodegenBackend`], values are returned in
+/// `rax`/`xmm0`/`rax,rdx` and strings are copied to `__lm_return`
+struct GasEmitter<'a> {
+    float_labels: HashMap<String, String>,
+    /// Function whose code is being generated
+    function: Option<String>,
+    symbol_table: &'a SymbolTable,
+    file: &'a mut dyn Write,
+    /// Source of the instruction or function being generated, where an error points
+    span: Range<usize>,
+}
+
+/// Registers an operand is loaded into
+#[derive(Clone, Copy)]
+enum Side {
+    Left,
+    Right,
+}
+
+impl<'a> GasEmitter<'a> {
+    fn new(symbol_table: &'a SymbolTable, file: &'a mut dyn Write) -> Self {
+        Self {
+            float_labels: HashMap::new(),
+            function: None,
+            span: 0..0,
+            symbol_table,
+            file,
+        }
+    }
+
+    fn generate_asm(&mut self, program: &IrProgram) -> Result<(), CompilerError> {
+        writeln!(self.file, "    .intel_syntax noprefix")?;
+        // .data and .rodata
+        self.generate_data(program)?;
+        // _start
+        self.generate_code_prologue()?;
+        // Program
+        for (instruction, span) in program.instructions.iter().zip(&program.spans) {
+            self.span = span.clone();
+            self.generate_instruction(instruction)?;
+        }
+        // exit(0)
+        self.generate_code_epilogue()?;
+        // Functions
+        for function in &program.functions {
+            self.generate_function(function)?;
+        }
+        // Runtime routines
+        write!(self.file, "{}", include_str!("runtime.s"))?;
+        Ok(())
+    }
+
+    fn generate_data(&mut self, program: &IrProgram) -> Result<(), CompilerError> {
+        writeln!(self.file)?;
+        writeln!(self.file, "    .section .data")?;
+        for symbol in self.symbol_table.iter() {
+            if self.symbol_table.is_local(&symbol.original) {
+                continue;
+            }
+            match &symbol.data_type {
+                SymbolTableElementType::DataType(data_type) => {
+                    self.generate_storage(&Self::label(&symbol.name), &data_type.clone().into())?
+                }
+                SymbolTableElementType::Array(data_type, length) => self.generate_array_storage(
+                    &Self::label(&symbol.name),
+                    &data_type.clone().into(),
+                    *length,
+                )?,
+                _ => {}
+            }
+        }
+        for (temp, r#type) in program.temps.iter().enumerate() {
+            self.generate_storage(&self.temp_name(temp), r#type)?;
+        }
+        for function in &program.functions {
+            self.generate_frame(function)?;
+        }
+        if program
+            .functions
+            .iter()
+            .any(|function| function.return_type == Some(ExpressionType::String))
+        {
+            // Returned strings are copied here before the frame holding them is restored
+            self.generate_storage("__lm_return", &ExpressionType::String)?;
+        }
+
+        writeln!(self.file)?;
+        writeln!(self.file, "    .section .rodata")?;
+        for symbol in self.symbol_table.iter() {
+            match &symbol.data_type {
+                SymbolTableElementType::Float => {
+                    let value = Self::parse_literal::<f32>(symbol)?;
+                    // Floats are emitted by their bit pattern so the value is exactly the one
+                    // parsed by the compiler
+                    let label = format!("_float_{}", self.float_labels.len());
+                    writeln!(self.file, "{label}:")?;
+                    writeln!(self.file, "    .long 0x{:08x} # {value}", value.to_bits())?;
+                    self.float_labels.insert(symbol.original.clone(), label);
+                }
+                SymbolTableElementType::String => {
+                    let value = symbol.value.as_deref().unwrap_or_default();
+                    writeln!(self.file, "{}:", symbol.name)?;
+                    // Bytes avoid the assembler interpreting escape sequences in the literal
+                    if !value.is_empty() {
+                        let bytes = value
+                            .bytes()
+                            .map(|byte| byte.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        writeln!(self.file, "    .byte {bytes}")?;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn generate_storage(&mut self, name: &str, r#type: &ExpressionType) -> Result<(), io::Error> {
+        writeln!(self.file, "{name}:")?;
+        match r#type {
+            // Bools are stored as 1 or 0
+            ExpressionType::Int | ExpressionType::Bool => writeln!(self.file, "    .quad 0"),
+            ExpressionType::Float => writeln!(self.file, "    .float 0.0"),
+            ExpressionType::String => {
+                // Pointer to the current value, its length and the buffer holding it
+                writeln!(self.file, "    .quad 0")?;
+                writeln!(self.file, "{name}.len:")?;
+                writeln!(self.file, "    .quad 0")?;
+                writeln!(self.file, "{name}.buf:")?;
+                writeln!(self.file, "    .zero {MAX_STRING_LENGTH}")
+            }
+        }
+    }
+
+    /// Zeroed storage for `length` elements, each one laid out as in [`Self::generate_storage`]
+    fn generate_array_storage(
+        &mut self,
+        name: &str,
+        r#type: &ExpressionType,
+        length: usize,
+    ) -> Result<(), io::Error> {
+        writeln!(self.file, "{name}:")?;
+        writeln!(
+            self.file,
+            "    .zero {}",
+            Self::storage_size(r#type) * length
+        )
+    }
+
+    /// Storage of the parameters, variables and temporaries of `function` and the staging area of
+    /// its arguments
+    fn generate_frame(&mut self, function: &IrFunction) -> Result<(), CompilerError> {
+        self.span = function.span.clone();
+        let name = &function.name;
+        for (index, (_, r#type)) in function.parameters.iter().enumerate() {
+            self.generate_storage(&format!("__args_{name}.{index}"), r#type)?;
+        }
+        writeln!(self.file, "__frame_{name}:")?;
+        for (variable, r#type) in function.parameters.iter().chain(&function.locals) {
+            let label = Self::label(&self.get_symbol(variable)?.name);
+            match self.symbol_table.get_array_length(variable) {
+                Some(length) => self.generate_array_storage(&label, r#type, length)?,
+                None => self.generate_storage(&label, r#type)?,
+            }
+        }
+        self.function = Some(name.clone());
+        for (temp, r#type) in function.temps.iter().enumerate() {
+            self.generate_storage(&self.temp_name(temp), r#type)?;
+        }
+        self.function = None;
+        Ok(())
+    }
+
+    fn generate_code_prologue(&mut self) -> Result<(), io::Error> {
+        writeln!(self.file)?;
+        writeln!(self.file, "    .section .text")?;
+        writeln!(self.file, "    .globl _start")?;
+        writeln!(self.file, "_start:")
+    }
+
+    fn generate_code_epilogue(&mut self) -> Result<(), io::Error> {
+        writeln!(self.file, "    mov eax, 60")?;
+        writeln!(self.file, "    xor edi, edi")?;
+        writeln!(self.file, "    syscall")
+    }
+
+    fn generate_function(&mut self, function: &IrFunction) -> Result<(), CompilerError> {
+        self.span = function.span.clone();
+        let name = &function.name;
+        let frame_size = self.frame_size(function);
+        // Keeps the stack aligned to 16 bytes
+        let reserved = frame_size.next_multiple_of(16);
+        writeln!(self.file)?;
+        writeln!(self.file, "__fn_{name}:")?;
+        if frame_size > 0 {
+            writeln!(self.file, "    sub rsp, {reserved}")?;
+            writeln!(self.file, "    lea rsi, [rip + __frame_{name}]")?;
+            writeln!(self.file, "    mov rdi, rsp")?;
+            writeln!(self.file, "    mov ecx, {frame_size}")?;
+            writeln!(self.file, "    rep movsb")?;
+        }
+        for (index, (parameter, r#type)) in function.parameters.iter().enumerate() {
+            let argument = format!("__args_{name}.{index}");
+            let parameter = Self::label(&self.get_symbol(parameter)?.name);
+            self.load_label(&argument, r#type, Side::Left)?;
+            if *r#type == ExpressionType::String {
+                self.generate_copy_string(&parameter)?;
+            }
+            self.store_label(&parameter, r#type)?;
+        }
+
+        self.function = Some(name.clone());
+        for (instruction, span) in function.instructions.iter().zip(&function.spans) {
+            self.span = span.clone();
+            self.generate_instruction(instruction)?;
+        }
+        self.function = None;
+
+        // The returned value is in rax/xmm0/rax,rdx which the copy leaves untouched
+        writeln!(self.file, "__fn_{name}.return:")?;
+        if frame_size > 0 {
+            writeln!(self.file, "    mov rsi, rsp")?;
+            writeln!(self.file, "    lea rdi, [rip + __frame_{name}]")?;
+            writeln!(self.file, "    mov ecx, {frame_size}")?;
+            writeln!(self.file, "    rep movsb")?;
+            writeln!(self.file, "    add rsp, {reserved}")?;
+        }
+        writeln!(self.file, "    ret")?;
+        Ok(())
+    }
+
+    fn frame_size(&self, function: &IrFunction) -> usize {
+        let variables =
+            function
+                .parameters
+                .iter()
+                .chain(&function.locals)
+                .map(|(variable, r#type)| {
+                    let length = self.symbol_table.get_array_length(variable).unwrap_or(1);
+                    Self::storage_size(r#type) * length
+                });
+        let temps = function.temps.iter().map(Self::storage_size);
+        variables.chain(temps).sum()
+    }
+
+    /// Bytes emitted by [`Self::generate_storage`]
+    const fn storage_size(r#type: &ExpressionType) -> usize {
+        match r#type {
+            ExpressionType::Int | ExpressionType::Bool => 8,
+            ExpressionType::Float => 4,
+            ExpressionType::String => 16 + MAX_STRING_LENGTH,
+        }
+    }
+
+    fn generate_instruction(&mut self, instruction: &Instruction) -> Result<(), CompilerError> {
+        match instruction {
+            Instruction::Binary {
+                op,
+                r#type,
+                dest,
+                left,
+                right,
+            } => {
+                if *r#type == ExpressionType::String && *op == BinaryOp::Add {
+                    // Both strings are appended to the buffer of `dest`
+                    let name = self.name(dest)?;
+                    self.load(left, r#type, Side::Left)?;
+                    writeln!(self.file, "    xor ecx, ecx")?;
+                    self.generate_append_string(&name)?;
+                    writeln!(self.file, "    mov QWORD PTR [rip + {name}.len], rax")?;
+                    self.load(right, r#type, Side::Left)?;
+                    writeln!(self.file, "    mov rcx, QWORD PTR [rip + {name}.len]")?;
+                    self.generate_append_string(&name)?;
+                    writeln!(self.file, "    mov rdx, rax")?;
+                    writeln!(self.file, "    lea rax, [rip + {name}.buf]")?;
+                    self.store(dest, r#type)?;
+                    return Ok(());
+                }
+                self.load(left, r#type, Side::Left)?;
+                self.load(right, r#type, Side::Right)?;
+                match r#type {
+                    ExpressionType::Int => match op {
+                        BinaryOp::Add => writeln!(self.file, "    add rax, rcx")?,
+                        BinaryOp::Sub => writeln!(self.file, "    sub rax, rcx")?,
+                        BinaryOp::Mul => writeln!(self.file, "    imul rax, rcx")?,
+                        BinaryOp::Div => writeln!(self.file, "    call __lm_divide")?,
+                    },
+                    ExpressionType::Float => {
+                        let instruction = match op {
+                            BinaryOp::Add => "addss",
+                            BinaryOp::Sub => "subss",
+                            BinaryOp::Mul => "mulss",
+                            BinaryOp::Div => "divss",
+                        };
+                        writeln!(self.file, "    {instruction} xmm0, xmm1")?;
+                    }
+                    ExpressionType::String | ExpressionType::Bool => {
+                        return Err(CompilerError::Internal(format!(
+                            "Invalid operands for {op}: {type} and {type}"
+                        )));
+                    }
+                }
+                self.store(dest, r#type)?;
+            }
+            Instruction::Negate { r#type, dest, src } => {
+                self.load(src, r#type, Side::Left)?;
+                match r#type {
+                    ExpressionType::Int => writeln!(self.file, "    neg rax")?,
+                    ExpressionType::Float => {
+                        writeln!(self.file, "    movd eax, xmm0")?;
+                        writeln!(self.file, "    xor eax, 0x80000000")?;
+                        writeln!(self.file, "    movd xmm0, eax")?;
+                    }
+                    ExpressionType::String | ExpressionType::Bool => {
+                        return Err(CompilerError::Internal(format!("Tried to negate a {type}")));
+                    }
+                }
+                self.store(dest, r#type)?;
+            }
+            Instruction::Convert {
+                from,
+                to,
+                dest,
+                src,
+            } => {
+                self.load(src, from, Side::Left)?;
+                match (from, to) {
+                    (ExpressionType::Int, ExpressionType::Float) => {
+                        writeln!(self.file, "    cvtsi2ss xmm0, rax")?;
+                    }
+                    (ExpressionType::Float, ExpressionType::Int) => {
+                        writeln!(self.file, "    cvttss2si rax, xmm0")?;
+                    }
+                    (from, to) => {
+                        return Err(CompilerError::Internal(format!(
+                            "Invalid conversion from {from} to {to}"
+                        )));
+                    }
+                }
+                self.store(dest, to)?;
+            }
+            Instruction::Copy { r#type, dest, src } => {
+                self.load(src, r#type, Side::Left)?;
+                // Strings are copied to the buffer of `dest` so later writes to `src` don't
+                // change it
+                if *r#type == ExpressionType::String {
+                    let name = self.name(dest)?;
+                    self.generate_copy_string(&name)?;
+                }
+                self.store(dest, r#type)?;
+            }
+            Instruction::Load {
+                r#type,
+                dest,
+                array,
+                index,
+                length,
+            } => {
+                self.generate_element_address(array, index, r#type, *length)?;
+                match r#type {
+                    ExpressionType::Int | ExpressionType::Bool => {
+                        writeln!(self.file, "    mov rax, QWORD PTR [rbx]")?
+                    }
+                    ExpressionType::Float => {
+                        writeln!(self.file, "    movss xmm0, DWORD PTR [rbx]")?
+                    }
+                    ExpressionType::String => {
+                        writeln!(self.file, "    mov rax, QWORD PTR [rbx]")?;
+                        writeln!(self.file, "    mov rdx, QWORD PTR [rbx + 8]")?;
+                    }
+                }
+                self.store(dest, r#type)?;
+            }
+            Instruction::Store {
+                r#type,
+                array,
+                index,
+                src,
+                length,
+            } => {
+                self.generate_element_address(array, index, r#type, *length)?;
+                self.load(src, r#type, Side::Left)?;
+                match r#type {
+                    ExpressionType::Int | ExpressionType::Bool => {
+                        writeln!(self.file, "    mov QWORD PTR [rbx], rax")?
+                    }
+                    ExpressionType::Float => {
+                        writeln!(self.file, "    movss DWORD PTR [rbx], xmm0")?
+                    }
+                    ExpressionType::String => {
+                        // Copied to the buffer of the element, right after its pointer and length
+                        writeln!(self.file, "    mov rsi, rax")?;
+                        writeln!(self.file, "    lea rdi, [rbx + 16]")?;
+                        writeln!(self.file, "    xor ecx, ecx")?;
+                        writeln!(self.file, "    mov r8, {MAX_STRING_LENGTH}")?;
+                        writeln!(self.file, "    call __lm_append_string")?;
+                        writeln!(self.file, "    mov QWORD PTR [rbx + 8], rax")?;
+                        writeln!(self.file, "    lea rax, [rbx + 16]")?;
+                        writeln!(self.file, "    mov QWORD PTR [rbx], rax")?;
+                    }
+                }
+            }
+            Instruction::Read { r#type, dest } => match r#type {
+                ExpressionType::Int => {
+                    writeln!(self.file, "    call __lm_read_int")?;
+                    self.store(dest, r#type)?;
+                }
+                ExpressionType::Float => {
+                    writeln!(self.file, "    call __lm_read_float")?;
+                    self.store(dest, r#type)?;
+                }
+                ExpressionType::String => {
+                    let name = self.name(dest)?;
+                    writeln!(self.file, "    lea rdi, [rip + {name}.buf]")?;
+                    writeln!(self.file, "    mov esi, {MAX_STRING_LENGTH}")?;
+                    writeln!(self.file, "    call __lm_read_string")?;
+                    writeln!(self.file, "    mov rdx, rax")?;
+                    writeln!(self.file, "    lea rax, [rip + {name}.buf]")?;
+                    self.store(dest, r#type)?;
+                }
+                ExpressionType::Bool => {
+                    return Err(CompilerError::Internal("Tried to read a bool".into()));
+                }
+            },
+            Instruction::Write { r#type, src } => {
+                self.load(src, r#type, Side::Left)?;
+                match r#type {
+                    ExpressionType::Int => {
+                        writeln!(self.file, "    mov rdi, rax")?;
+                        writeln!(self.file, "    call __lm_print_int")?;
+                    }
+                    ExpressionType::Bool => {
+                        writeln!(self.file, "    mov rdi, rax")?;
+                        writeln!(self.file, "    call __lm_print_bool")?;
+                    }
+                    ExpressionType::Float => {
+                        writeln!(self.file, "    call __lm_print_float")?;
+                    }
+                    ExpressionType::String => {
+                        writeln!(self.file, "    mov rdi, rax")?;
+                        writeln!(self.file, "    mov rsi, rdx")?;
+                        writeln!(self.file, "    call __lm_print_string")?;
+                    }
+                }
+                writeln!(self.file, "    call __lm_print_newline")?;
+            }
+            Instruction::Label(label) => writeln!(self.file, ".L{label}:")?,
+            Instruction::Jump(label) => writeln!(self.file, "    jmp .L{label}")?,
+            Instruction::JumpIf {
+                comparison,
+                r#type,
+                left,
+                right,
+                target,
+            } => {
+                self.load(left, r#type, Side::Left)?;
+                self.load(right, r#type, Side::Right)?;
+                self.generate_jump_if(comparison, r#type, *target)?;
+            }
+            Instruction::Call {
+                function,
+                arguments,
+                result,
+            } => {
+                for (index, (argument, r#type)) in arguments.iter().enumerate() {
+                    self.load(argument, r#type, Side::Left)?;
+                    self.store_label(&format!("__args_{function}.{index}"), r#type)?;
+                }
+                writeln!(self.file, "    call __fn_{function}")?;
+                if let Some((dest, r#type)) = result {
+                    if *r#type == ExpressionType::String {
+                        let name = self.name(dest)?;
+                        self.generate_copy_string(&name)?;
+                    }
+                    self.store(dest, r#type)?;
+                }
+            }
+            Instruction::Return { r#type, src } => {
+                let function = self.function.clone().ok_or(CompilerError::Internal(
+                    "Return outside of a function".into(),
+                ))?;
+                self.load(src, r#type, Side::Left)?;
+                if *r#type == ExpressionType::String {
+                    self.generate_copy_string("__lm_return")?;
+                }
+                writeln!(self.file, "    jmp __fn_{function}.return")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Leaves the address of the element of `array` at `index` in `rbx`
+    fn generate_element_address(
+        &mut self,
+        array: &str,
+        index: &Operand,
+        r#type: &ExpressionType,
+        length: usize,
+    ) -> Result<(), CompilerError> {
+        let label = Self::label(&self.get_symbol(array)?.name);
+        let size = Self::storage_size(r#type);
+        if let Operand::Constant(value, _) = index {
+            let index = Self::parse_literal::<usize>(&self.get_symbol(value)?)?;
+            writeln!(self.file, "    lea rbx, [rip + {label} + {}]", index * size)?;
+            return Ok(());
+        }
+        self.load(index, &ExpressionType::Int, Side::Right)?;
+        writeln!(self.file, "    cmp rcx, {length}")?;
+        writeln!(self.file, "    jae __lm_error_index_out_of_bounds")?;
+        writeln!(self.file, "    imul rcx, rcx, {size}")?;
+        writeln!(self.file, "    lea rbx, [rip + {label}]")?;
+        writeln!(self.file, "    add rbx, rcx")?;
+        Ok(())
+    }
+
+    /// Copies the string in `rax`,`rdx` to the buffer of `name`, leaving the copy in `rax`,`rdx`
+    fn generate_copy_string(&mut self, name: &str) -> Result<(), io::Error> {
+        writeln!(self.file, "    xor ecx, ecx")?;
+        self.generate_append_string(name)?;
+        writeln!(self.file, "    mov rdx, rax")?;
+        writeln!(self.file, "    lea rax, [rip + {name}.buf]")
+    }
+
+    /// Appends the string in `rax`,`rdx` to the buffer of `name` after its first `rcx` bytes,
+    /// leaving the new length in `rax`
+    fn generate_append_string(&mut self, name: &str) -> Result<(), io::Error> {
+        writeln!(self.file, "    mov rsi, rax")?;
+        writeln!(self.file, "    lea rdi, [rip + {name}.buf]")?;
+        writeln!(self.file, "    mov r8, {MAX_STRING_LENGTH}")?;
+        writeln!(self.file, "    call __lm_append_string")
+    }
+
+    fn generate_jump_if(
+        &mut self,
+        comparison: &Comparison,
+        r#type: &ExpressionType,
+        target: usize,
+    ) -> Result<(), io::Error> {
+        match r#type {
+            ExpressionType::Int | ExpressionType::Bool => {
+                writeln!(self.file, "    cmp rax, rcx")?;
+                writeln!(self.file, "    {} .L{target}", Self::jmp_signed(comparison))
+            }
+            ExpressionType::String => {
+                writeln!(self.file, "    mov rdi, rax")?;
+                writeln!(self.file, "    mov rsi, rdx")?;
+                writeln!(self.file, "    mov rdx, rcx")?;
+                writeln!(self.file, "    mov rcx, r8")?;
+                writeln!(self.file, "    call __lm_compare_strings")?;
+                writeln!(self.file, "    cmp rax, 0")?;
+                writeln!(self.file, "    {} .L{target}", Self::jmp_signed(comparison))
+            }
+            // Unordered operands (NaN) set the parity flag and only `!=` holds for them. `<` and
+            // `<=` swap the operands so an unordered result falls through
+            ExpressionType::Float => match comparison {
+                Comparison::Gt => {
+                    writeln!(self.file, "    ucomiss xmm0, xmm1")?;
+                    writeln!(self.file, "    ja .L{target}")
+                }
+                Comparison::Gte => {
+                    writeln!(self.file, "    ucomiss xmm0, xmm1")?;
+                    writeln!(self.file, "    jae .L{target}")
+                }
+                Comparison::Lt => {
+                    writeln!(self.file, "    ucomiss xmm1, xmm0")?;
+                    writeln!(self.file, "    ja .L{target}")
+                }
+                Comparison::Lte => {
+                    writeln!(self.file, "    ucomiss xmm1, xmm0")?;
+                    writeln!(self.file, "    jae .L{target}")
+                }
+                Comparison::Eq => {
+                    writeln!(self.file, "    ucomiss xmm0, xmm1")?;
+                    writeln!(self.file, "    jp 1f")?;
+                    writeln!(self.file, "    je .L{target}")?;
+                    writeln!(self.file, "1:")
+                }
+                Comparison::Ne => {
+                    writeln!(self.file, "    ucomiss xmm0, xmm1")?;
+                    writeln!(self.file, "    jp .L{target}")?;
+                    writeln!(self.file, "    jne .L{target}")
+                }
+            },
+        }
+    }
+
+    fn load(
+        &mut self,
+        operand: &Operand,
+        r#type: &ExpressionType,
+        side: Side,
+    ) -> Result<(), CompilerError> {
+        let (int, float, length) = match side {
+            Side::Left => ("rax", "xmm0", "rdx"),
+            Side::Right => ("rcx", "xmm1", "r8"),
+        };
+        if let Operand::Constant(value, _) = operand {
+            let symbol = self.get_symbol(value)?;
+            match r#type {
+                ExpressionType::Int | ExpressionType::Bool => {
+                    // Parsed so literals with leading zeros aren't read as octal by the assembler
+                    let value = Self::parse_literal::<i64>(&symbol)?;
+                    writeln!(self.file, "    mov {int}, {value}")?;
+                }
+                ExpressionType::Float => {
+                    let label =
+                        self.float_labels
+                            .get(value)
+                            .ok_or(CompilerError::Internal(format!(
+                                "Float constant {value} was not emitted"
+                            )))?;
+                    writeln!(self.file, "    movss {float}, DWORD PTR [rip + {label}]")?;
+                }
+                ExpressionType::String => {
+                    writeln!(self.file, "    lea {int}, [rip + {}]", symbol.name)?;
+                    writeln!(self.file, "    mov {length}, {}", value.len())?;
+                }
+            }
+            return Ok(());
+        }
+
+        let name = self.name(operand)?;
+        self.load_label(&name, r#type, side)
+    }
+
+    fn load_label(
+        &mut self,
+        name: &str,
+        r#type: &ExpressionType,
+        side: Side,
+    ) -> Result<(), CompilerError> {
+        let (int, float, length) = match side {
+            Side::Left => ("rax", "xmm0", "rdx"),
+            Side::Right => ("rcx", "xmm1", "r8"),
+        };
+        match r#type {
+            ExpressionType::Int | ExpressionType::Bool => {
+                writeln!(self.file, "    mov {int}, QWORD PTR [rip + {name}]")?
+            }
+            ExpressionType::Float => {
+                writeln!(self.file, "    movss {float}, DWORD PTR [rip + {name}]")?
+            }
+            ExpressionType::String => {
+                writeln!(self.file, "    mov {int}, QWORD PTR [rip + {name}]")?;
+                writeln!(self.file, "    mov {length}, QWORD PTR [rip + {name}.len]")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Stores the value in `rax`/`xmm0`/`rax,rdx` to `dest`
+    fn store(&mut self, dest: &Operand, r#type: &ExpressionType) -> Result<(), CompilerError> {
+        let name = self.name(dest)?;
+        self.store_label(&name, r#type)
+    }
+
+    fn store_label(&mut self, name: &str, r#type: &ExpressionType) -> Result<(), CompilerError> {
+        match r#type {
+            ExpressionType::Int | ExpressionType::Bool => {
+                writeln!(self.file, "    mov QWORD PTR [rip + {name}], rax")?
+            }
+            ExpressionType::Float => {
+                writeln!(self.file, "    movss DWORD PTR [rip + {name}], xmm0")?
+            }
+            ExpressionType::String => {
+                writeln!(self.file, "    mov QWORD PTR [rip + {name}], rax")?;
+                writeln!(self.file, "    mov QWORD PTR [rip + {name}.len], rdx")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Label of a variable or temporary
+    fn name(&self, operand: &Operand) -> Result<String, CompilerError> {
+        match operand {
+            Operand::Variable(name) => Ok(Self::label(&self.get_symbol(name)?.name)),
+            Operand::Temp(temp) => Ok(self.temp_name(*temp)),
+            Operand::Constant(value, _) => Err(CompilerError::Internal(format!(
+                "Constant {value} has no storage"
+            ))),
+        }
+    }
+
+    fn temp_name(&self, temp: usize) -> String {
+        // Variables are named `_` followed by their name so they never start with two underscores
+        match &self.function {
+            Some(function) => format!("__{function}.t{temp}"),
+            None => format!("__t{temp}"),
+        }
+    }
+
+    /// Label of a symbol, `@` separating a function from its variables isn't valid in GAS so it
+    /// becomes `..` which can't clash with the `.len` and `.buf` of a string
+    fn label(name: &str) -> String {
+        name.replace('@', "..")
+    }
+
+    fn get_symbol(&self, name: &str) -> Result<SymbolTableElement, CompilerError> {
+        self.symbol_table
+            .get_symbol_from_name(name)
+            .ok_or(CompilerError::Internal(format!(
+                "Symbol {name} is not in the symbol table"
+            )))
+    }
+
+    fn parse_literal<T: std::str::FromStr>(
+        symbol: &SymbolTableElement,
+    ) -> Result<T, CompilerError> {
+        symbol
+            .value
+            .as_deref()
+            .and_then(|value| value.parse().ok())
+            .ok_or(CompilerError::Internal(format!(
+                "Constant {} has an invalid value",
+                symbol.original
+            )))
+    }
+
+    const fn jmp_signed(comparison: &Comparison) -> &'static str {
+        match comparison {
+            Comparison::Gt => "jg",
+            Comparison::Gte => "jge",
+            Comparison::Eq => "je",
+            Comparison::Ne => "jne",
+            Comparison::Lt => "jl",
+            Comparison::Lte => "jle",
+        }
+    }
+}
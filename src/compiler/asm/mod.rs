@@ -1,2 +1,54 @@
+mod gas;
 mod tasm;
+pub use gas::GasGenerator;
 pub use tasm::TasmGenerator;
+
+use crate::compiler::{context::SymbolTable, error::Diagnostic, ir::IrProgram};
+use clap::ValueEnum;
+use std::io::Write;
+
+/// Turns the IR of a program into its source for some target
+///
+/// Implement this to add a target without touching the AST or the compiler context
+///
+/// The backends share a frame model. The parameters, variables and temporaries of a function are
+/// laid out together in its frame. A call saves the frame on the stack before overwriting it and
+/// restores it when returning, so recursive calls don't clobber the values of the caller.
+/// Arguments are passed through a staging area outside the frame. A returned string is left in
+/// storage the next call overwrites, so the caller copies it out, and the callee copies it there
+/// because the buffer of a local is restored with the frame. Indexes that aren't constants are
+/// checked against the bounds of the array with an unsigned comparison, which catches negative
+/// ones too
+pub trait CodegenBackend {
+    /// Extension of the generated file
+    fn extension(&self) -> &'static str;
+
+    /// Writes `program` to `out`, errors point at the source of the instruction that caused them
+    fn generate(
+        &self,
+        program: &IrProgram,
+        symbol_table: &SymbolTable,
+        out: &mut dyn Write,
+    ) -> Result<(), Box<Diagnostic>>;
+}
+
+/// Assembly flavour emitted by the code generator
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Target {
+    /// 16-bit DOS assembly for TASM
+    #[default]
+    Tasm,
+    /// x86-64 GAS assembly for Linux
+    #[value(name = "x86_64-linux")]
+    X86_64Linux,
+}
+
+impl Target {
+    /// Backend that generates code for this target
+    pub fn backend(&self) -> Box<dyn CodegenBackend> {
+        match self {
+            Self::Tasm => Box::new(TasmGenerator),
+            Self::X86_64Linux => Box::new(GasGenerator),
+        }
+    }
+}
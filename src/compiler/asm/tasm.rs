@@ -1,96 +1,210 @@
 use std::{
-    fs::File,
     io::{self, Write},
-    rc::Rc,
+    ops::Range,
 };
 
 use crate::{
     compiler::{
-        ast::{AstAction, ExpressionType, Node, NodeValue},
-        context::{SymbolTable, SymbolTableElement, SymbolTableElementType},
-        error::CompilerError,
+        asm::CodegenBackend,
+        ast::ExpressionType,
+        context::{MAX_STRING_LENGTH, SymbolTable, SymbolTableElement, SymbolTableElementType},
+        error::{CompilerError, Diagnostic},
+        ir::{BinaryOp, Comparison, Instruction, IrFunction, IrProgram, Operand},
     },
     grammar::types::DataType,
 };
 
-pub struct TasmGenerator<'a> {
-    label_if_false_count: usize,
-    label_if_body_count: usize,
-    label_if_else_body_count: usize,
-    label_while_cond_count: usize,
-    current_end_label: String,
-    current_begin_label: String,
+/// Generates 16-bit DOS assembly for TASM, ints use the integer unit (`EAX`, `IDIV`) and only floats
+/// use the x87 FPU
+///
+/// Ints are 32 bits wide here instead of the 64 bits of the interpreter and the other targets.
+/// Literals that don't fit are rejected, while arithmetic wraps at 32 bits
+///
+/// Functions follow the frame model of [`CodegenBackend`], ints are returned in `EAX`, floats in
+/// `ST(0)` and strings in `_@return`
+///
+/// The elements of an array are laid out one after the other like the variables of its type and
+/// accessed with the offset of the element in `BX`
+pub struct TasmGenerator;
+
+impl CodegenBackend for TasmGenerator {
+    fn extension(&self) -> &'static str {
+        "asm"
+    }
+
+    fn generate(
+        &self,
+        program: &IrProgram,
+        symbol_table: &SymbolTable,
+        out: &mut dyn Write,
+    ) -> Result<(), Box<Diagnostic>> {
+        // The temporaries only exist in the generated program
+        let mut symbol_table = symbol_table.clone();
+        let mut emitter = TasmEmitter::new(&mut symbol_table, out);
+        emitter
+            .generate_asm(program)
+            .map_err(|error| Box::new(Diagnostic::new(emitter.span.clone(), error, true)))
+    }
+}
+
+struct TasmEmitter<'a> {
+    /// Function whose code is being generated
+    function: Option<String>,
     symbol_table: &'a mut SymbolTable,
-    file: &'a mut File,
+    file: &'a mut dyn Write,
+    /// Source of the instruction or function being generated, where an error points
+    span: Range<usize>,
 }
 
-impl<'a> TasmGenerator<'a> {
-    pub fn new(symbol_table: &'a mut SymbolTable, file: &'a mut File) -> Self {
+impl<'a> TasmEmitter<'a> {
+    fn new(symbol_table: &'a mut SymbolTable, file: &'a mut dyn Write) -> Self {
         Self {
+            function: None,
+            span: 0..0,
             file,
             symbol_table,
-            current_end_label: String::new(),
-            current_begin_label: String::new(),
-            label_if_false_count: 0,
-            label_if_body_count: 0,
-            label_if_else_body_count: 0,
-            label_while_cond_count: 0,
         }
     }
 
-    pub fn generate_asm(mut self, root: Rc<Node>) -> Result<(), CompilerError> {
+    fn generate_asm(&mut self, program: &IrProgram) -> Result<(), CompilerError> {
         // Header
         self.generate_asm_header()?;
-        // Add internal variables to symbol table
-        self.add_internal_symbols();
+        // Add internal variables and temporaries to symbol table
+        self.add_internal_symbols(program);
         // .DATA
         self.symbol_table.to_data(self.file)?;
+        for function in &program.functions {
+            self.generate_frame(function)?;
+        }
         // .PROGRAM header
         self.generate_code_prologue()?;
         // Program
-        self.generate_asm_from_tree(&root)?;
+        for (instruction, span) in program.instructions.iter().zip(&program.spans) {
+            self.span = span.clone();
+            self.generate_instruction(instruction)?;
+        }
+        self.generate_code_exit()?;
+        // Functions
+        for function in &program.functions {
+            self.generate_function(function)?;
+        }
         // END Program
         self.generate_code_epilogue()?;
         Ok(())
     }
 
-    fn add_internal_symbols(&mut self) {
-        let neg_one_symbol = SymbolTableElement {
-            name: String::from("_@1"),
-            value: Some(String::from("-1.0")),
-            original: String::from("_@1"),
-            data_type: SymbolTableElementType::Float,
-            length: None,
-        };
-        let l_comp_symbol = SymbolTableElement {
-            name: String::from("_@l_cond"),
+    fn add_internal_symbols(&mut self, program: &IrProgram) {
+        let float_symbol = |name: &str| SymbolTableElement {
+            name: name.into(),
             value: None,
-            original: String::from("_@l_cond"),
+            original: name.into(),
             data_type: DataType::FloatType("".into()).into(),
             length: None,
+            span: None,
         };
-        let r_comp_symbol = SymbolTableElement {
-            name: String::from("_@r_cond"),
-            value: None,
-            original: String::from("_@r_cond"),
-            data_type: DataType::FloatType("".into()).into(),
+        // Control words of the FPU rounding to nearest and truncating
+        let fpu_cw_symbol = float_symbol("_@fpu_cw");
+        let fpu_trunc_cw_symbol = float_symbol("_@fpu_trunc_cw");
+        // Numbers are read as floats and then converted
+        let read_number_symbol = float_symbol("_@read_number");
+        let error_symbol = |name: &str, message: &str| SymbolTableElement {
+            name: name.into(),
+            value: Some(format!("error: Runtime error: {message}")),
+            original: name.into(),
+            data_type: SymbolTableElementType::String,
             length: None,
+            span: None,
         };
-        let write_number_symbol = SymbolTableElement {
-            name: String::from("_@write_number"),
-            value: None,
-            original: String::from("_@write_number"),
-            data_type: DataType::FloatType("".into()).into(),
+        let division_by_zero_symbol = error_symbol("_@division_by_zero", "division by zero");
+        let overflow_symbol = error_symbol("_@overflow", "integer overflow");
+        let index_out_of_bounds_symbol =
+            error_symbol("_@index_out_of_bounds", "index out of bounds");
+        // Text written for bools
+        let string_symbol = |name: &str, value: &str| SymbolTableElement {
+            name: name.into(),
+            value: Some(value.into()),
+            original: name.into(),
+            data_type: SymbolTableElementType::String,
             length: None,
+            span: None,
         };
+        let true_symbol = string_symbol("_@true", "true");
+        let false_symbol = string_symbol("_@false", "false");
         for symbol in [
-            neg_one_symbol,
-            l_comp_symbol,
-            r_comp_symbol,
-            write_number_symbol,
+            fpu_cw_symbol,
+            fpu_trunc_cw_symbol,
+            read_number_symbol,
+            division_by_zero_symbol,
+            overflow_symbol,
+            index_out_of_bounds_symbol,
+            true_symbol,
+            false_symbol,
         ] {
             self.symbol_table.insert(symbol);
         }
+
+        for (temp, r#type) in program.temps.iter().enumerate() {
+            self.symbol_table
+                .insert(Self::storage_symbol(self.temp_name(temp), r#type));
+        }
+        for function in &program.functions {
+            for (index, (_, r#type)) in function.parameters.iter().enumerate() {
+                self.symbol_table.insert(Self::storage_symbol(
+                    Self::argument_name(&function.name, index),
+                    r#type,
+                ));
+            }
+        }
+        if program
+            .functions
+            .iter()
+            .any(|function| function.return_type == Some(ExpressionType::String))
+        {
+            // Returned strings are copied here before the frame holding them is restored
+            self.symbol_table.insert(Self::storage_symbol(
+                String::from("_@return"),
+                &ExpressionType::String,
+            ));
+        }
+    }
+
+    /// Symbol of a variable only known to the generated program
+    fn storage_symbol(name: String, r#type: &ExpressionType) -> SymbolTableElement {
+        let data_type = match r#type {
+            ExpressionType::Float => DataType::FloatType("".into()),
+            ExpressionType::Int => DataType::IntType("".into()),
+            ExpressionType::String => DataType::StringType("".into()),
+            ExpressionType::Bool => DataType::BoolType("".into()),
+        };
+        SymbolTableElement {
+            name: name.clone(),
+            value: None,
+            original: name,
+            data_type: data_type.into(),
+            length: None,
+            span: None,
+        }
+    }
+
+    /// Parameters, variables and temporaries of `function` laid out one after the other
+    fn generate_frame(&mut self, function: &IrFunction) -> Result<(), CompilerError> {
+        self.span = function.span.clone();
+        writeln!(self.file, "_@frame_{} LABEL BYTE", function.name)?;
+        for (variable, _) in function.parameters.iter().chain(&function.locals) {
+            let symbol =
+                self.symbol_table
+                    .get_symbol_from_name(variable)
+                    .ok_or(CompilerError::Internal(format!(
+                        "Symbol {variable} is not in the symbol table"
+                    )))?;
+            symbol.to_data(self.file)?;
+        }
+        self.function = Some(function.name.clone());
+        for (temp, r#type) in function.temps.iter().enumerate() {
+            Self::storage_symbol(self.temp_name(temp), r#type).to_data(self.file)?;
+        }
+        self.function = None;
+        Ok(())
     }
 
     fn generate_asm_header(&mut self) -> Result<(), io::Error> {
@@ -113,596 +227,516 @@ impl<'a> TasmGenerator<'a> {
         writeln!(file, "    MOV AX, @DATA")?;
         writeln!(file, "    MOV DS, AX")?;
         writeln!(file, "    MOV ES, AX")?;
+        writeln!(file)?;
+
+        // Float to int conversions switch to the truncating control word
+        writeln!(file, "    FSTCW   WORD PTR _@fpu_cw")?;
+        writeln!(file, "    MOV     AX, WORD PTR _@fpu_cw")?;
+        writeln!(file, "    OR      AX, 0C00H")?;
+        writeln!(file, "    MOV     WORD PTR _@fpu_trunc_cw, AX")?;
         writeln!(file)
     }
 
-    fn generate_code_epilogue(&mut self) -> Result<(), io::Error> {
+    fn generate_code_exit(&mut self) -> Result<(), io::Error> {
         let file = &mut self.file;
         writeln!(file, "    MOV AX, 4C00H")?;
-        writeln!(file, "    INT 21H")?;
-        writeln!(file, "    END Program")
+        writeln!(file, "    INT 21H")
     }
 
-    fn generate_asm_from_tree(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
-        match &node.value {
-            NodeValue::Value(val) => self.generate_node_value_value(val)?,
-            NodeValue::True => {}
-            NodeValue::False => {}
-            NodeValue::Action(action) => match action {
-                AstAction::S => {
-                    self.generate_asm_from_tree(node.left_child.as_ref().unwrap())?;
-                    self.generate_asm_from_tree(node.right_child.as_ref().unwrap())?;
-                }
-                AstAction::Assign => self.generate_action_assign(node)?,
-                AstAction::GT
-                | AstAction::GTE
-                | AstAction::EQ
-                | AstAction::NE
-                | AstAction::LT
-                | AstAction::LTE => self.generate_action_cmp(node)?,
-                AstAction::Plus => {
-                    self.generate_asm_from_tree(node.left_child.as_ref().unwrap())?;
-                    self.generate_asm_from_tree(node.right_child.as_ref().unwrap())?;
-                    writeln!(self.file, "    FADD")?;
-                }
-                AstAction::Div => {
-                    self.generate_asm_from_tree(node.left_child.as_ref().unwrap())?;
-                    self.generate_asm_from_tree(node.right_child.as_ref().unwrap())?;
-                    writeln!(self.file, "    FDIV")?;
-                }
-                AstAction::Sub => {
-                    self.generate_asm_from_tree(node.left_child.as_ref().unwrap())?;
-                    self.generate_asm_from_tree(node.right_child.as_ref().unwrap())?;
-                    writeln!(self.file, "    FSUB")?;
-                }
-                AstAction::Mult => {
-                    self.generate_asm_from_tree(node.left_child.as_ref().unwrap())?;
-                    self.generate_asm_from_tree(node.right_child.as_ref().unwrap())?;
-                    writeln!(self.file, "    FMUL")?;
-                }
-
-                AstAction::If => self.generate_action_if(node)?,
-                AstAction::Else => {
-                    return Err(CompilerError::Internal(
-                        "Tried to generate code for else node from generate_asm_from_tree".into(),
-                    ));
-                }
-                AstAction::And => self.generate_action_and(node)?,
-                AstAction::Or => self.generate_action_or(node)?,
-                AstAction::While => self.generate_action_while(node)?,
-                AstAction::Read => self.generate_action_read(node)?,
-                AstAction::Write => self.generate_action_write(node)?,
-                AstAction::Negative => self.generate_action_negative(node)?,
-                AstAction::Noop => {}
-            },
-        }
-        Ok(())
+    fn generate_code_epilogue(&mut self) -> Result<(), io::Error> {
+        let file = &mut self.file;
+        writeln!(file)?;
+        write!(file, "{}", include_str!("runtime.asm"))?;
+        writeln!(file)?;
+        writeln!(file, "    END Program")
     }
 
-    fn generate_node_value_value(&mut self, val: &str) -> Result<(), CompilerError> {
-        let val = self
-            .symbol_table
-            .get_symbol_from_name(val)
-            .ok_or(CompilerError::Internal(
-                "Symbol not found when generating ASM for NodeValue::Value".into(),
-            ))?;
-        if let SymbolTableElementType::String = val.data_type {
-            return Ok(());
+    fn generate_function(&mut self, function: &IrFunction) -> Result<(), CompilerError> {
+        self.span = function.span.clone();
+        let name = &function.name;
+        let frame_size = self.frame_size(function);
+        // The stack is kept aligned to words
+        let reserved = frame_size.next_multiple_of(2);
+        writeln!(self.file)?;
+        writeln!(self.file, "_@fn_{name} PROC NEAR")?;
+        if frame_size > 0 {
+            // The frame is copied from DS to the stack in SS
+            writeln!(self.file, "    SUB     SP, {reserved}")?;
+            writeln!(self.file, "    MOV     DI, SP")?;
+            writeln!(self.file, "    PUSH    ES")?;
+            writeln!(self.file, "    PUSH    SS")?;
+            writeln!(self.file, "    POP     ES")?;
+            writeln!(self.file, "    MOV     SI, OFFSET _@frame_{name}")?;
+            writeln!(self.file, "    MOV     CX, {frame_size}")?;
+            writeln!(self.file, "    CLD")?;
+            writeln!(self.file, "    REP     MOVSB")?;
+            writeln!(self.file, "    POP     ES")?;
+            writeln!(self.file)?;
         }
-        if let SymbolTableElementType::DataType(DataType::StringType(_)) = val.data_type {
-            return Ok(());
+        for (index, (parameter, r#type)) in function.parameters.iter().enumerate() {
+            let parameter = self.name(&Operand::Variable(parameter.clone()))?;
+            self.generate_copy(&parameter, &Self::argument_name(name, index), r#type)?;
         }
-        writeln!(self.file, "    FLD     {}", val.name)?;
-        Ok(())
-    }
 
-    fn generate_action_assign(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
-        let (left_child, right_child) =
-            Self::get_left_and_right_child_or_error(node, "Invalid assignment node")?;
-
-        self.generate_asm_from_tree(&right_child)?;
-        let NodeValue::Value(lhs) = &left_child.value else {
-            return Err(CompilerError::Internal(
-                "Left hand side of an assignment is not an id".into(),
-            ));
-        };
-
-        let lhs_symbol =
-            self.symbol_table
-                .get_symbol_from_name(lhs)
-                .ok_or(CompilerError::Internal(
-                    "Left hand side of an assignment is not in the symbol table".into(),
-                ))?;
-
-        writeln!(self.file, "    FST    {}", lhs_symbol.name)?;
-        writeln!(self.file, "    FFREE")?;
-        writeln!(self.file)?;
+        self.function = Some(name.clone());
+        for (instruction, span) in function.instructions.iter().zip(&function.spans) {
+            self.span = span.clone();
+            self.generate_instruction(instruction)?;
+        }
+        self.function = None;
+
+        // The returned value is in EAX/ST(0)/_@return which the copy leaves untouched
+        writeln!(self.file, "_@return_{name}:")?;
+        if frame_size > 0 {
+            writeln!(self.file, "    MOV     SI, SP")?;
+            writeln!(self.file, "    PUSH    DS")?;
+            writeln!(self.file, "    MOV     DI, OFFSET _@frame_{name}")?;
+            writeln!(self.file, "    PUSH    SS")?;
+            writeln!(self.file, "    POP     DS")?;
+            writeln!(self.file, "    MOV     CX, {frame_size}")?;
+            writeln!(self.file, "    CLD")?;
+            writeln!(self.file, "    REP     MOVSB")?;
+            writeln!(self.file, "    POP     DS")?;
+            writeln!(self.file, "    ADD     SP, {reserved}")?;
+        }
+        writeln!(self.file, "    RET")?;
+        writeln!(self.file, "_@fn_{name} ENDP")?;
         Ok(())
     }
 
-    fn generate_action_cmp(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
-        let (left_child, right_child) =
-            Self::get_left_and_right_child_or_error(node, "Invalid comparison node")?;
-
-        self.generate_asm_from_tree(&left_child)?;
-        writeln!(self.file, "    FST     _@l_cond")?;
-        writeln!(self.file)?;
-        self.generate_asm_from_tree(&right_child)?;
-        writeln!(self.file, "    FST     _@r_cond")?;
-        writeln!(self.file)?;
-        writeln!(self.file, "    FLD     _@l_cond")?;
-        writeln!(self.file, "    FCOMP   _@r_cond")?;
-        writeln!(self.file, "    FSTSW   AX")?;
-        writeln!(self.file, "    SAHF")?;
-        writeln!(self.file, "    FFREE")?;
-        writeln!(self.file)?;
-        Ok(())
+    /// Bytes taken by the frame written by [`Self::generate_frame`]
+    fn frame_size(&self, function: &IrFunction) -> usize {
+        let variables =
+            function
+                .parameters
+                .iter()
+                .chain(&function.locals)
+                .map(|(variable, r#type)| {
+                    let length = self.symbol_table.get_array_length(variable).unwrap_or(1);
+                    Self::storage_size(r#type) * length
+                });
+        let temps = function.temps.iter().map(Self::storage_size);
+        variables.chain(temps).sum()
     }
 
-    fn generate_action_if(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
-        let (left_child, right_child) =
-            Self::get_left_and_right_child_or_error(node, "Invalid If node")?;
-
-        let label_if_body = format!("if_body_{}", self.label_if_body_count);
-        let label_if_false = format!("if_false_{}", self.label_if_false_count);
-        self.label_if_body_count += 1;
-        self.label_if_false_count += 1;
-        self.current_end_label = label_if_false.clone();
-        self.current_begin_label = label_if_body.clone();
-
-        self.generate_asm_from_tree(&left_child)?;
-
-        if let NodeValue::Action(AstAction::Else) = right_child.value {
-            return self.generate_action_else(&right_child, &left_child);
+    /// Bytes taken by a variable of `type` in .DATA
+    const fn storage_size(r#type: &ExpressionType) -> usize {
+        match r#type {
+            ExpressionType::Int | ExpressionType::Float | ExpressionType::Bool => 4,
+            // Characters and the '$' ending them
+            ExpressionType::String => MAX_STRING_LENGTH + 1,
         }
+    }
 
-        // Create jump to label if false depending on operator
-        match &left_child.value {
-            NodeValue::Value(_val) => {
-                return Err(CompilerError::Internal(
-                    "Invalid If node lhs is a value".into(),
-                ));
+    fn generate_instruction(&mut self, instruction: &Instruction) -> Result<(), CompilerError> {
+        match instruction {
+            Instruction::Binary {
+                op,
+                r#type: ExpressionType::Int,
+                dest,
+                left,
+                right,
+            } => {
+                writeln!(self.file, "    MOV     EAX, {}", self.name(left)?)?;
+                match op {
+                    BinaryOp::Add => writeln!(self.file, "    ADD     EAX, {}", self.name(right)?)?,
+                    BinaryOp::Sub => writeln!(self.file, "    SUB     EAX, {}", self.name(right)?)?,
+                    BinaryOp::Mul => writeln!(self.file, "    IMUL    EAX, {}", self.name(right)?)?,
+                    BinaryOp::Div => {
+                        writeln!(self.file, "    MOV     ECX, {}", self.name(right)?)?;
+                        writeln!(self.file, "    CALL    _@divide")?;
+                    }
+                }
+                writeln!(self.file, "    MOV     {}, EAX", self.name(dest)?)?;
+                writeln!(self.file)?;
             }
-            NodeValue::True => { /* */ }
-            NodeValue::False => {
-                writeln!(self.file, "    JMP    {label_if_false}")?;
+            Instruction::Binary {
+                op: BinaryOp::Add,
+                r#type: ExpressionType::String,
+                dest,
+                left,
+                right,
+            } => {
+                // Both strings are appended to the buffer of `dest`
+                writeln!(self.file, "    MOV     DI, OFFSET {}", self.name(dest)?)?;
+                writeln!(self.file, "    MOV     CX, {MAX_STRING_LENGTH}")?;
+                writeln!(self.file, "    MOV     SI, OFFSET {}", self.name(left)?)?;
+                writeln!(self.file, "    CALL    _@append_string")?;
+                writeln!(self.file, "    MOV     SI, OFFSET {}", self.name(right)?)?;
+                writeln!(self.file, "    CALL    _@append_string")?;
                 writeln!(self.file)?;
             }
-            NodeValue::Action(action) => match action {
-                AstAction::GT
-                | AstAction::GTE
-                | AstAction::EQ
-                | AstAction::NE
-                | AstAction::LT
-                | AstAction::LTE => {
-                    let jmp = Self::jmp_to_opposite_asm_jmp(action).ok_or(
-                        CompilerError::Internal("Tried to jump to invalid action".into()),
-                    )?;
-                    writeln!(self.file, "    {jmp}    {label_if_false}")?;
-                    writeln!(self.file)?;
-                }
-                AstAction::And => {}
-                AstAction::Or => {}
-                action => {
-                    return Err(CompilerError::Internal(format!(
-                        "Invalid action: {action} in If node"
-                    )));
-                }
-            },
-        };
-        // Generate label to jump if any of the OR statements are true
-        writeln!(self.file, "{label_if_body}:")?;
-        // Generate If body
-        self.generate_asm_from_tree(&right_child)?;
-        // Label to jump if statement is false
-        writeln!(self.file, "{label_if_false}:")?;
-        Ok(())
-    }
-
-    fn generate_action_and(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
-        let (left_child, right_child) =
-            Self::get_left_and_right_child_or_error(node, "Invalid AND node")?;
-
-        let label_jmp_to_end = self.current_end_label.clone();
-        let label_jmp_to_begin = self.current_begin_label.clone();
-        // Traverse the left subtree generating the comparison
-        self.generate_asm_from_tree(&left_child)?;
-        // If the generated left side is false then jump to the end of the if
-        match &left_child.value {
-            NodeValue::Value(_val) => {
-                return Err(CompilerError::Internal(
-                    "Invalid And node lhs is a value".into(),
-                ));
+            Instruction::Binary {
+                op,
+                r#type: r#type @ (ExpressionType::String | ExpressionType::Bool),
+                ..
+            } => {
+                return Err(CompilerError::Internal(format!(
+                    "Invalid operands for {op}: {type} and {type}"
+                )));
+            }
+            Instruction::Binary {
+                op,
+                dest,
+                left,
+                right,
+                ..
+            } => {
+                writeln!(self.file, "    FLD     {}", self.name(left)?)?;
+                writeln!(self.file, "    FLD     {}", self.name(right)?)?;
+                let instruction = match op {
+                    BinaryOp::Add => "FADD",
+                    BinaryOp::Sub => "FSUB",
+                    BinaryOp::Mul => "FMUL",
+                    BinaryOp::Div => "FDIV",
+                };
+                writeln!(self.file, "    {instruction}")?;
+                writeln!(self.file, "    FSTP    {}", self.name(dest)?)?;
+                writeln!(self.file)?;
             }
-            NodeValue::True => { /* */ }
-            NodeValue::False => {
-                writeln!(self.file, "    JMP    {label_jmp_to_end}")?;
+            Instruction::Negate {
+                r#type: ExpressionType::Int,
+                dest,
+                src,
+            } => {
+                writeln!(self.file, "    MOV     EAX, {}", self.name(src)?)?;
+                writeln!(self.file, "    NEG     EAX")?;
+                writeln!(self.file, "    MOV     {}, EAX", self.name(dest)?)?;
                 writeln!(self.file)?;
             }
-            NodeValue::Action(action) => match action {
-                AstAction::GT
-                | AstAction::GTE
-                | AstAction::EQ
-                | AstAction::NE
-                | AstAction::LT
-                | AstAction::LTE => {
-                    let jmp = Self::jmp_to_opposite_asm_jmp(action).ok_or(
-                        CompilerError::Internal("Tried to jump to invalid action".into()),
-                    )?;
-                    writeln!(self.file, "    {jmp}    {label_jmp_to_end}")?;
-                    writeln!(self.file)?;
-                }
-                AstAction::Or => {
-                    // TODO maybe we need this
-                    // writeln!(self.file, "    JMP    {label_if_false}")?;
-                }
-                AstAction::And => {}
-                action => {
-                    return Err(CompilerError::Internal(format!(
-                        "Invalid action: {action} in And node"
-                    )));
-                }
-            },
-        }
-
-        self.generate_asm_from_tree(&right_child)?;
-        // If the generated right side is false then jump to the end of the if
-        match &right_child.value {
-            NodeValue::Value(_val) => {
-                return Err(CompilerError::Internal(
-                    "Invalid And node lhs is a value".into(),
-                ));
+            Instruction::Negate {
+                r#type: r#type @ (ExpressionType::String | ExpressionType::Bool),
+                ..
+            } => {
+                return Err(CompilerError::Internal(format!("Tried to negate a {type}")));
             }
-            NodeValue::True => { /* */ }
-            NodeValue::False => {
-                writeln!(self.file, "    JMP    {label_jmp_to_end}")?;
+            Instruction::Negate { dest, src, .. } => {
+                writeln!(self.file, "    FLD     {}", self.name(src)?)?;
+                writeln!(self.file, "    FCHS")?;
+                writeln!(self.file, "    FSTP    {}", self.name(dest)?)?;
                 writeln!(self.file)?;
             }
-            NodeValue::Action(action) => match action {
-                AstAction::GT
-                | AstAction::GTE
-                | AstAction::EQ
-                | AstAction::NE
-                | AstAction::LT
-                | AstAction::LTE => {
-                    let jmp = Self::jmp_to_opposite_asm_jmp(action).ok_or(
-                        CompilerError::Internal("Tried to jump to invalid action".into()),
-                    )?;
-                    writeln!(self.file, "    {jmp}    {label_jmp_to_end}")?;
-                    writeln!(self.file)?;
-                }
-                AstAction::Or => {}
-                AstAction::And => {}
-                action => {
-                    return Err(CompilerError::Internal(format!(
-                        "Invalid action: {action} in And node"
-                    )));
-                }
-            },
-        }
-        // All of the conditions are true jump to begin of block
-        writeln!(self.file, "    JMP    {label_jmp_to_begin}")?;
-        Ok(())
-    }
-
-    fn generate_action_or(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
-        let (left_child, right_child) =
-            Self::get_left_and_right_child_or_error(node, "Invalid AND node")?;
-
-        let label_begin_body = &self.current_begin_label.clone();
-        let label_end_body = &self.current_end_label.clone();
-        // Traverse the left subtree generating the comparison
-        self.generate_asm_from_tree(&left_child)?;
-        // If either the left side or the right side are true we jump to the if body
-        match &left_child.value {
-            NodeValue::Value(_val) => {
-                return Err(CompilerError::Internal(
-                    "Invalid Or node lhs is a value".into(),
-                ));
-            }
-            NodeValue::True => {
-                writeln!(self.file, "    JMP    {label_begin_body}")?;
+            Instruction::Convert {
+                from: ExpressionType::Int,
+                to: ExpressionType::Float,
+                dest,
+                src,
+            } => {
+                writeln!(self.file, "    FILD    {}", self.name(src)?)?;
+                writeln!(self.file, "    FSTP    {}", self.name(dest)?)?;
                 writeln!(self.file)?;
             }
-            NodeValue::False => { /* */ }
-            NodeValue::Action(action) => match action {
-                AstAction::GT
-                | AstAction::GTE
-                | AstAction::EQ
-                | AstAction::NE
-                | AstAction::LT
-                | AstAction::LTE => {
-                    let jmp = Self::jmp_to_asm_jmp(action).ok_or(CompilerError::Internal(
-                        "Tried to jump to invalid action".into(),
-                    ))?;
-                    writeln!(self.file, "    {jmp}    {label_begin_body}")?;
-                    writeln!(self.file)?;
-                }
-                AstAction::And | AstAction::Or => {}
-                action => {
-                    return Err(CompilerError::Internal(format!(
-                        "Invalid action: {action} in Or node"
-                    )));
-                }
-            },
-        }
-
-        self.generate_asm_from_tree(&right_child)?;
-        match &right_child.value {
-            NodeValue::Value(_val) => {
-                return Err(CompilerError::Internal(
-                    "Invalid Or node lhs is a value".into(),
-                ));
+            Instruction::Convert {
+                from: ExpressionType::Float,
+                to: ExpressionType::Int,
+                dest,
+                src,
+            } => {
+                writeln!(self.file, "    FLD     {}", self.name(src)?)?;
+                self.generate_float_to_int(&self.name(dest)?)?;
             }
-            NodeValue::True => {
-                writeln!(self.file, "    JMP    {label_begin_body}")?;
+            Instruction::Convert { from, to, .. } => {
+                return Err(CompilerError::Internal(format!(
+                    "Invalid conversion from {from} to {to}"
+                )));
+            }
+            Instruction::Copy { r#type, dest, src } => {
+                self.generate_copy(&self.name(dest)?, &self.name(src)?, r#type)?;
+            }
+            Instruction::Load {
+                r#type,
+                dest,
+                array,
+                index,
+                length,
+            } => {
+                let element = self.generate_element_offset(array, index, r#type, *length)?;
+                let dest = self.name(dest)?;
+                match r#type {
+                    ExpressionType::Int | ExpressionType::Bool => {
+                        writeln!(self.file, "    MOV     EAX, {element}")?;
+                        writeln!(self.file, "    MOV     {dest}, EAX")?;
+                    }
+                    ExpressionType::Float => {
+                        writeln!(self.file, "    FLD     {element}")?;
+                        writeln!(self.file, "    FSTP    {dest}")?;
+                    }
+                    ExpressionType::String => {
+                        writeln!(self.file, "    MOV     DI, OFFSET {dest}")?;
+                        writeln!(self.file, "    MOV     CX, {MAX_STRING_LENGTH}")?;
+                        writeln!(self.file, "    LEA     SI, {element}")?;
+                        writeln!(self.file, "    CALL    _@append_string")?;
+                    }
+                }
                 writeln!(self.file)?;
             }
-            NodeValue::False => { /* */ }
-            NodeValue::Action(action) => match action {
-                AstAction::GT
-                | AstAction::GTE
-                | AstAction::EQ
-                | AstAction::NE
-                | AstAction::LT
-                | AstAction::LTE => {
-                    let jmp = Self::jmp_to_opposite_asm_jmp(action).ok_or(
-                        CompilerError::Internal("Tried to jump to invalid action".into()),
-                    )?;
-                    writeln!(self.file, "    {jmp}    {label_begin_body}")?;
-                    writeln!(self.file)?;
+            Instruction::Store {
+                r#type,
+                array,
+                index,
+                src,
+                length,
+            } => {
+                let element = self.generate_element_offset(array, index, r#type, *length)?;
+                let src = self.name(src)?;
+                match r#type {
+                    ExpressionType::Int | ExpressionType::Bool => {
+                        writeln!(self.file, "    MOV     EAX, {src}")?;
+                        writeln!(self.file, "    MOV     {element}, EAX")?;
+                    }
+                    ExpressionType::Float => {
+                        writeln!(self.file, "    FLD     {src}")?;
+                        writeln!(self.file, "    FSTP    {element}")?;
+                    }
+                    ExpressionType::String => {
+                        writeln!(self.file, "    LEA     DI, {element}")?;
+                        writeln!(self.file, "    MOV     CX, {MAX_STRING_LENGTH}")?;
+                        writeln!(self.file, "    MOV     SI, OFFSET {src}")?;
+                        writeln!(self.file, "    CALL    _@append_string")?;
+                    }
                 }
-                AstAction::And | AstAction::Or => {}
-                action => {
-                    return Err(CompilerError::Internal(format!(
-                        "Invalid action: {action} in Or node"
-                    )));
+                writeln!(self.file)?;
+            }
+            Instruction::Read { r#type, dest } => {
+                let name = self.name(dest)?;
+                match r#type {
+                    ExpressionType::Int => {
+                        writeln!(self.file, "    GetFloat    _@read_number")?;
+                        writeln!(self.file, "    FLD     _@read_number")?;
+                        self.generate_float_to_int(&name)?;
+                    }
+                    ExpressionType::Float => {
+                        writeln!(self.file, "    GetFloat    {name}")?;
+                    }
+                    ExpressionType::String => {
+                        writeln!(self.file, "    GetString    {name}")?;
+                    }
+                    ExpressionType::Bool => {
+                        return Err(CompilerError::Internal("Tried to read a bool".into()));
+                    }
                 }
-            },
-        }
-        // None of the conditions are met jump to the end of the statement
-        writeln!(self.file, "    JMP    {label_end_body}")?;
-        Ok(())
-    }
-
-    fn generate_action_negative(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
-        let left_child = Self::get_left_child_or_error(node, "No left child on Negative node")?;
-        let NodeValue::Value(lhs) = &left_child.value else {
-            return Err(CompilerError::Internal(
-                "Left child of Negative node is not a value ".into(),
-            ));
-        };
-        let symbol = self
-            .symbol_table
-            .get_symbol_from_name(lhs)
-            .ok_or(CompilerError::Internal(
-                "Left side of Negative node is not in the symbol table".into(),
-            ))?;
-
-        writeln!(self.file, "    FLD    _@1")?;
-        writeln!(self.file, "    FMUL   {}", symbol.name)?;
-        writeln!(self.file)?;
-        Ok(())
-    }
-
-    fn generate_action_write(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
-        let left_child = Self::get_left_child_or_error(node, "No left child on Write node")?;
-        self.generate_asm_from_tree(&left_child)?;
-        let write_type = left_child.r#type.as_ref().ok_or(CompilerError::Internal(
-            "Left child of write expression has no type".into(),
-        ))?;
-
-        match write_type {
-            ExpressionType::Float | ExpressionType::Int => {
-                writeln!(self.file, "    FST    _@write_number")?;
-                writeln!(self.file, "    DisplayFloat    _@write_number, 2")?;
+                writeln!(self.file, "    newLine")?;
+                writeln!(self.file)?;
             }
-            ExpressionType::String => {
-                let NodeValue::Value(val) = &left_child.value else {
-                    return Err(CompilerError::Internal(
-                        "Left child of Write node of String type is not a Value".into(),
-                    ));
-                };
-                let name =
-                    self.symbol_table
-                        .get_symbol_from_name(val)
-                        .ok_or(CompilerError::Internal(
-                            "Left child symbol is not in the symbol table in write node".into(),
-                        ))?;
-                writeln!(self.file, "    DisplayString    {}", name.name)?;
+            Instruction::Write { r#type, src } => {
+                let name = self.name(src)?;
+                match r#type {
+                    ExpressionType::Int => {
+                        writeln!(self.file, "    MOV     EAX, {name}")?;
+                        writeln!(self.file, "    CALL    _@display_int")?;
+                    }
+                    ExpressionType::Bool => {
+                        writeln!(self.file, "    MOV     EAX, {name}")?;
+                        writeln!(self.file, "    CALL    _@display_bool")?;
+                    }
+                    ExpressionType::Float => {
+                        writeln!(self.file, "    DisplayFloat    {name}, 2")?;
+                    }
+                    ExpressionType::String => {
+                        writeln!(self.file, "    DisplayString    {name}")?;
+                    }
+                }
+                writeln!(self.file, "    newLine")?;
+                writeln!(self.file)?;
             }
-        }
-        writeln!(self.file, "    newLine")?;
-        writeln!(self.file)?;
-        Ok(())
-    }
-
-    fn generate_action_while(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
-        let (left_child, right_child) =
-            Self::get_left_and_right_child_or_error(node, "Invalid While node")?;
-        let while_cond_label = format!("while_cond_{}", self.label_while_cond_count);
-        let while_end_label = format!("while_end_{}", self.label_while_cond_count);
-        self.label_while_cond_count += 1;
-        self.current_begin_label = while_cond_label.clone();
-        self.current_end_label = while_end_label.clone();
-
-        // Set the label to the beggining of the loop
-        writeln!(self.file, "{while_cond_label}:")?;
-        self.generate_asm_from_tree(&left_child)?;
-        // When the condition is false jump to the end of while
-        match &left_child.value {
-            NodeValue::Value(_val) => {
-                return Err(CompilerError::Internal(
-                    "Invalid While node lhs is a value".into(),
-                ));
+            Instruction::Label(label) => writeln!(self.file, "label_{label}:")?,
+            Instruction::Jump(label) => {
+                writeln!(self.file, "    JMP    label_{label}")?;
+                writeln!(self.file)?;
             }
-            NodeValue::False => {
-                writeln!(self.file, "    JMP    {while_end_label}")?;
+            Instruction::JumpIf {
+                comparison,
+                r#type,
+                left,
+                right,
+                target,
+            } => {
+                let jmp = match r#type {
+                    ExpressionType::Int | ExpressionType::Bool => {
+                        writeln!(self.file, "    MOV     EAX, {}", self.name(left)?)?;
+                        writeln!(self.file, "    CMP     EAX, {}", self.name(right)?)?;
+                        Self::jmp_signed(comparison)
+                    }
+                    ExpressionType::Float => {
+                        writeln!(self.file, "    FLD     {}", self.name(left)?)?;
+                        writeln!(self.file, "    FCOMP   {}", self.name(right)?)?;
+                        writeln!(self.file, "    FSTSW   AX")?;
+                        writeln!(self.file, "    SAHF")?;
+                        Self::jmp_unsigned(comparison)
+                    }
+                    ExpressionType::String => {
+                        writeln!(self.file, "    MOV     SI, OFFSET {}", self.name(left)?)?;
+                        writeln!(self.file, "    MOV     DI, OFFSET {}", self.name(right)?)?;
+                        writeln!(self.file, "    CALL    _@compare_strings")?;
+                        match comparison {
+                            Comparison::Eq => "JE",
+                            Comparison::Ne => "JNE",
+                            comparison => {
+                                return Err(CompilerError::Internal(format!(
+                                    "Invalid comparison between strings: {comparison}"
+                                )));
+                            }
+                        }
+                    }
+                };
+                writeln!(self.file, "    {jmp}    label_{target}")?;
                 writeln!(self.file)?;
             }
-            NodeValue::True => { /* */ }
-            NodeValue::Action(action) => match action {
-                AstAction::GT
-                | AstAction::GTE
-                | AstAction::EQ
-                | AstAction::NE
-                | AstAction::LT
-                | AstAction::LTE => {
-                    let jmp = Self::jmp_to_opposite_asm_jmp(action).ok_or(
-                        CompilerError::Internal("Tried to jump to invalid action".into()),
-                    )?;
-                    writeln!(self.file, "    {jmp}    {while_end_label}")?;
-                    writeln!(self.file)?;
+            Instruction::Call {
+                function,
+                arguments,
+                result,
+            } => {
+                for (index, (argument, r#type)) in arguments.iter().enumerate() {
+                    let argument = self.name(argument)?;
+                    self.generate_copy(&Self::argument_name(function, index), &argument, r#type)?;
                 }
-                AstAction::And | AstAction::Or => {}
-                action => {
-                    return Err(CompilerError::Internal(format!(
-                        "Invalid action: {action} in While node"
-                    )));
+                writeln!(self.file, "    CALL    _@fn_{function}")?;
+                match result {
+                    Some((dest, ExpressionType::Int | ExpressionType::Bool)) => {
+                        writeln!(self.file, "    MOV     {}, EAX", self.name(dest)?)?;
+                    }
+                    Some((dest, ExpressionType::Float)) => {
+                        writeln!(self.file, "    FSTP    {}", self.name(dest)?)?;
+                    }
+                    Some((dest, ExpressionType::String)) => {
+                        self.generate_copy(&self.name(dest)?, "_@return", &ExpressionType::String)?;
+                    }
+                    None => {}
                 }
-            },
-        };
-        // Generate body of the while
-        self.generate_asm_from_tree(&right_child)?;
-        // Jump to begging of while
-        writeln!(self.file, "    JMP    {while_cond_label}")?;
-        writeln!(self.file)?;
-        // End of while label
-        writeln!(self.file, "{while_end_label}:")?;
-        Ok(())
-    }
-
-    fn generate_action_else(
-        &mut self,
-        node: &Rc<Node>,
-        condition_node: &Rc<Node>,
-    ) -> Result<(), CompilerError> {
-        let begin_else_label = format!("else_{}", self.label_if_else_body_count);
-        let end_if_else_label = format!("end_if_else{}", self.label_if_else_body_count);
-        self.current_begin_label = begin_else_label.clone();
-        self.current_end_label = end_if_else_label.clone();
-        self.label_if_else_body_count += 1;
-
-        match &condition_node.value {
-            NodeValue::Value(_val) => {
-                return Err(CompilerError::Internal(
-                    "Invalid If Else node lhs is a value".into(),
-                ));
-            }
-            NodeValue::False => {
-                writeln!(self.file, "    JMP    {begin_else_label}")?;
                 writeln!(self.file)?;
             }
-            NodeValue::True => { /* */ }
-            NodeValue::Action(action) => match action {
-                AstAction::GT
-                | AstAction::GTE
-                | AstAction::EQ
-                | AstAction::NE
-                | AstAction::LT
-                | AstAction::LTE => {
-                    let jmp = Self::jmp_to_opposite_asm_jmp(action).ok_or(
-                        CompilerError::Internal("Tried to jump to invalid action".into()),
-                    )?;
-                    writeln!(self.file, "    {jmp}    {begin_else_label}")?;
-                    writeln!(self.file)?;
-                }
-                AstAction::And | AstAction::Or => {}
-                action => {
-                    return Err(CompilerError::Internal(format!(
-                        "Invalid action: {action} in If Else node"
-                    )));
+            Instruction::Return { r#type, src } => {
+                let function = self.function.clone().ok_or(CompilerError::Internal(
+                    "Return outside of a function".into(),
+                ))?;
+                let src = self.name(src)?;
+                match r#type {
+                    ExpressionType::Int | ExpressionType::Bool => {
+                        writeln!(self.file, "    MOV     EAX, {src}")?
+                    }
+                    ExpressionType::Float => writeln!(self.file, "    FLD     {src}")?,
+                    ExpressionType::String => self.generate_copy("_@return", &src, r#type)?,
                 }
-            },
+                writeln!(self.file, "    JMP     _@return_{function}")?;
+                writeln!(self.file)?;
+            }
         }
-        self.generate_asm_from_tree(node.left_child.as_ref().unwrap())?;
-        writeln!(self.file, "    JMP    {end_if_else_label}")?;
-        writeln!(self.file)?;
-        writeln!(self.file, "{}:", begin_else_label)?;
-        self.generate_asm_from_tree(node.right_child.as_ref().unwrap())?;
-        writeln!(self.file, "{}:", end_if_else_label)?;
         Ok(())
     }
 
-    fn generate_action_read(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
-        let left_child = Self::get_left_child_or_error(node, "No left child on Read node")?;
-        let NodeValue::Value(val) = &left_child.value else {
-            return Err(CompilerError::Internal(
-                "Invalid Read node left child is not a value".into(),
-            ));
-        };
-        let symbol = self
-            .symbol_table
-            .get_symbol_from_name(val)
-            .ok_or(CompilerError::Internal(
-                "Left child symbol is not in the symbol table in read node".into(),
-            ))?;
+    /// Leaves the offset of the element of `array` at `index` in `BX` returning the operand
+    /// addressing it
+    fn generate_element_offset(
+        &mut self,
+        array: &str,
+        index: &Operand,
+        r#type: &ExpressionType,
+        length: usize,
+    ) -> Result<String, CompilerError> {
+        let array = self.name(&Operand::Variable(array.into()))?;
+        let size = Self::storage_size(r#type);
+        if let Operand::Constant(value, _) = index {
+            let index = value.parse::<usize>().map_err(|_| {
+                CompilerError::Internal(format!("Constant index {value} is invalid"))
+            })?;
+            writeln!(self.file, "    MOV     BX, {}", index * size)?;
+        } else {
+            writeln!(self.file, "    MOV     EAX, {}", self.name(index)?)?;
+            writeln!(self.file, "    CMP     EAX, {length}")?;
+            writeln!(self.file, "    JAE     _@error_index_out_of_bounds")?;
+            writeln!(self.file, "    IMUL    BX, AX, {size}")?;
+        }
+        Ok(format!("{array}[BX]"))
+    }
 
-        let SymbolTableElementType::DataType(symbol_type) = symbol.data_type else {
-            return Err(CompilerError::Internal(
-                "Left child symbol is not a variable".into(),
-            ));
-        };
-        match symbol_type {
-            DataType::FloatType(_) => {
-                writeln!(self.file, "    GetFloat    {}", symbol.name)?;
+    /// Copies the value of `src` to `dest`, strings are truncated to the length of `dest`
+    fn generate_copy(
+        &mut self,
+        dest: &str,
+        src: &str,
+        r#type: &ExpressionType,
+    ) -> Result<(), io::Error> {
+        match r#type {
+            ExpressionType::Int | ExpressionType::Bool => {
+                writeln!(self.file, "    MOV     EAX, {src}")?;
+                writeln!(self.file, "    MOV     {dest}, EAX")?;
             }
-            DataType::IntType(_) => {
-                writeln!(self.file, "    GetFloat    {}", symbol.name)?;
+            ExpressionType::Float => {
+                writeln!(self.file, "    FLD     {src}")?;
+                writeln!(self.file, "    FSTP    {dest}")?;
             }
-            DataType::StringType(_) => {
-                writeln!(self.file, "    GetString    {}", symbol.name)?;
+            ExpressionType::String => {
+                writeln!(self.file, "    MOV     DI, OFFSET {dest}")?;
+                writeln!(self.file, "    MOV     CX, {MAX_STRING_LENGTH}")?;
+                writeln!(self.file, "    MOV     SI, OFFSET {src}")?;
+                writeln!(self.file, "    CALL    _@append_string")?;
             }
         }
-        writeln!(self.file, "    newLine")?;
-        writeln!(self.file)?;
-        Ok(())
+        writeln!(self.file)
+    }
+
+    /// Pops the top of the FPU stack into the int `dest` truncating it
+    fn generate_float_to_int(&mut self, dest: &str) -> Result<(), io::Error> {
+        writeln!(self.file, "    FLDCW   WORD PTR _@fpu_trunc_cw")?;
+        writeln!(self.file, "    FISTP   {dest}")?;
+        writeln!(self.file, "    FLDCW   WORD PTR _@fpu_cw")?;
+        writeln!(self.file)
     }
 
-    const fn jmp_to_opposite_asm_jmp(jmp: &AstAction) -> Option<&'a str> {
-        Some(match jmp {
-            AstAction::GT => "JNAE",
-            AstAction::GTE => "JNA",
-            AstAction::EQ => "JNE",
-            AstAction::NE => "JE",
-            AstAction::LT => "JAE",
-            AstAction::LTE => "JA",
-            _ => return None,
-        })
+    /// Name of the operand in .DATA
+    fn name(&self, operand: &Operand) -> Result<String, CompilerError> {
+        match operand {
+            Operand::Constant(value, ExpressionType::Int) if value.parse::<i32>().is_err() => {
+                Err(CompilerError::IntegerOutOfRange(format!(
+                    "{value} doesn't fit in the 32-bit ints of TASM"
+                )))
+            }
+            Operand::Variable(name) | Operand::Constant(name, _) => self
+                .symbol_table
+                .get_symbol_from_name(name)
+                .map(|symbol| symbol.name)
+                .ok_or(CompilerError::Internal(format!(
+                    "Symbol {name} is not in the symbol table"
+                ))),
+            Operand::Temp(temp) => Ok(self.temp_name(*temp)),
+        }
     }
 
-    const fn jmp_to_asm_jmp(jmp: &AstAction) -> Option<&'a str> {
-        Some(match jmp {
-            AstAction::GT => "JA",
-            AstAction::GTE => "JAE",
-            AstAction::EQ => "EQ",
-            AstAction::NE => "JNE",
-            AstAction::LT => "JNAE",
-            AstAction::LTE => "JNA",
-            _ => return None,
-        })
+    fn temp_name(&self, temp: usize) -> String {
+        match &self.function {
+            Some(function) => format!("_@{function}@t{temp}"),
+            None => format!("_@t{temp}"),
+        }
     }
 
-    fn get_left_and_right_child_or_error(
-        node: &Rc<Node>,
-        err: &str,
-    ) -> Result<(Rc<Node>, Rc<Node>), CompilerError> {
-        let left_child = Self::get_left_child_or_error(node, err)?;
-        let right_child = Self::get_right_child_or_error(node, err)?;
-        Ok((left_child, right_child))
+    /// Variable the caller copies an argument of `function` to
+    fn argument_name(function: &str, index: usize) -> String {
+        format!("_@args_{function}_{index}")
     }
 
-    fn get_left_child_or_error(node: &Rc<Node>, err: &str) -> Result<Rc<Node>, CompilerError> {
-        node.left_child
-            .as_ref()
-            .cloned()
-            .ok_or(CompilerError::Internal(err.into()))
+    /// Jump taken when the int comparison holds
+    const fn jmp_signed(comparison: &Comparison) -> &'static str {
+        match comparison {
+            Comparison::Gt => "JG",
+            Comparison::Gte => "JGE",
+            Comparison::Eq => "JE",
+            Comparison::Ne => "JNE",
+            Comparison::Lt => "JL",
+            Comparison::Lte => "JLE",
+        }
     }
 
-    fn get_right_child_or_error(node: &Rc<Node>, err: &str) -> Result<Rc<Node>, CompilerError> {
-        node.right_child
-            .as_ref()
-            .cloned()
-            .ok_or(CompilerError::Internal(err.into()))
+    /// Jump taken when the float comparison holds, `FSTSW` and `SAHF` leave the flags of an
+    /// unsigned comparison
+    const fn jmp_unsigned(comparison: &Comparison) -> &'static str {
+        match comparison {
+            Comparison::Gt => "JA",
+            Comparison::Gte => "JAE",
+            Comparison::Eq => "JE",
+            Comparison::Ne => "JNE",
+            Comparison::Lt => "JB",
+            Comparison::Lte => "JBE",
+        }
     }
 }
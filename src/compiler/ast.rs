@@ -1,8 +1,8 @@
 use crate::{
     compiler::{
-        asm::TasmGenerator,
         context::{SymbolTable, SymbolTableElementType},
-        error::CompilerError,
+        error::Diagnostic,
+        ir::IrProgram,
     },
     grammar::types::{ComparisonOp, DataType},
 };
@@ -10,9 +10,9 @@ use std::{
     array,
     cell::Cell,
     fmt::{Debug, Display},
-    fs::File,
     io::{self, Write},
     mem,
+    ops::Range,
     rc::Rc,
 };
 
@@ -26,6 +26,12 @@ pub struct Ast {
     pub if_body_stack: Vec<Rc<Node>>,
     pub conjunction_stack: Vec<Rc<Node>>,
     pub statement_stack: Vec<Rc<Node>>,
+    /// Arguments of the calls being parsed, the innermost call is the last one
+    pub call_arguments_stack: Vec<Vec<Rc<Node>>>,
+    /// Functions declared before the main body in source order
+    pub function_stack: Vec<Rc<Node>>,
+    /// Index expressions of the array accesses being parsed, the innermost one is the last one
+    pub index_stack: Vec<Rc<Node>>,
 }
 
 impl Debug for Ast {
@@ -58,6 +64,8 @@ pub enum AstPtr {
     Read,
     Write,
     ConvDate,
+    Function,
+    FunctionCall,
 }
 
 pub enum AstNodeRef {
@@ -83,6 +91,36 @@ pub struct Node {
     pub left_child: Option<Rc<Node>>,
     pub right_child: Option<Rc<Node>>,
     pub r#type: Option<ExpressionType>,
+    /// Source the node was parsed from, nodes the compiler adds take the span of the code
+    /// that made it add them
+    pub span: Span,
+}
+
+/// Location of a piece of the source
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Span {
+    /// Absolute byte range
+    pub range: Range<usize>,
+    /// Line where the range starts, counting from 1
+    pub line: usize,
+    /// Byte of the line where the range starts, counting from 1
+    pub column: usize,
+}
+
+impl Span {
+    pub fn new(range: Range<usize>, line: usize, column: usize) -> Self {
+        Self {
+            range,
+            line,
+            column,
+        }
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
 }
 
 impl Debug for Node {
@@ -92,13 +130,77 @@ impl Debug for Node {
 }
 
 impl Node {
-    pub fn new_leaf(value: NodeValue, node_type: Option<ExpressionType>) -> Self {
+    pub fn new_leaf(value: NodeValue, node_type: Option<ExpressionType>, span: Span) -> Self {
         Self {
             value,
             parent: Cell::new(None),
             left_child: None,
             right_child: None,
             r#type: node_type,
+            span,
+        }
+    }
+
+    /// Creates an action node outside of the parser, used by the passes that rewrite the tree
+    pub fn new_action(
+        action: AstAction,
+        left: Rc<Node>,
+        right: Rc<Node>,
+        node_type: Option<ExpressionType>,
+        span: Span,
+    ) -> Rc<Node> {
+        let node = Rc::new(Node {
+            value: NodeValue::Action(action),
+            parent: Cell::new(None),
+            left_child: Some(left.clone()),
+            right_child: Some(right.clone()),
+            r#type: node_type,
+            span,
+        });
+        left.parent.replace(Some(node.clone()));
+        right.parent.replace(Some(node.clone()));
+        node
+    }
+
+    /// Wraps an int expression in an explicit conversion to float
+    pub fn int_to_float(node: Rc<Node>) -> Rc<Node> {
+        let span = node.span.clone();
+        let noop = Rc::new(Node::new_leaf(
+            NodeValue::Action(AstAction::Noop),
+            None,
+            span.clone(),
+        ));
+        Self::new_action(
+            AstAction::IntToFloat,
+            node,
+            noop,
+            Some(ExpressionType::Float),
+            span,
+        )
+    }
+
+    /// Indented listing of the node and everything under it, one node per line with its type
+    /// and where it starts
+    pub fn tree(&self) -> String {
+        let mut output = String::new();
+        self.tree_recursive(&mut output, 0);
+        output
+    }
+
+    fn tree_recursive(&self, output: &mut String, depth: usize) {
+        let node_type = self
+            .r#type
+            .as_ref()
+            .map(|t| format!(" | {t}"))
+            .unwrap_or_default();
+        output.push_str(&format!(
+            "{}{}{node_type} {}\n",
+            "  ".repeat(depth),
+            self.value,
+            self.span
+        ));
+        for child in [&self.left_child, &self.right_child].into_iter().flatten() {
+            child.tree_recursive(output, depth + 1);
         }
     }
 }
@@ -127,6 +229,7 @@ pub enum ExpressionType {
     Float,
     Int,
     String,
+    Bool,
 }
 
 impl Display for ExpressionType {
@@ -135,6 +238,7 @@ impl Display for ExpressionType {
             Self::Float => write!(f, "FLOAT"),
             Self::Int => write!(f, "INT"),
             Self::String => write!(f, "STRING"),
+            Self::Bool => write!(f, "BOOL"),
         }
     }
 }
@@ -145,6 +249,7 @@ impl From<DataType> for ExpressionType {
             DataType::FloatType(_) => ExpressionType::Float,
             DataType::IntType(_) => ExpressionType::Int,
             DataType::StringType(_) => ExpressionType::String,
+            DataType::BoolType(_) => ExpressionType::Bool,
         }
     }
 }
@@ -152,10 +257,11 @@ impl From<DataType> for ExpressionType {
 impl From<SymbolTableElementType> for ExpressionType {
     fn from(value: SymbolTableElementType) -> Self {
         match value {
-            SymbolTableElementType::DataType(t) => t.into(),
+            SymbolTableElementType::DataType(t) | SymbolTableElementType::Array(t, _) => t.into(),
             SymbolTableElementType::Float => Self::Float,
             SymbolTableElementType::Int => Self::Int,
             SymbolTableElementType::String => Self::String,
+            SymbolTableElementType::Bool => Self::Bool,
         }
     }
 }
@@ -182,6 +288,12 @@ pub enum AstAction {
     Write,
     S,
     Negative,
+    IntToFloat,
+    Function,
+    Call,
+    Argument,
+    Return,
+    Index,
     Noop,
 }
 
@@ -208,6 +320,12 @@ impl Display for AstAction {
             Self::Write => write!(f, "WRITE"),
             Self::S => write!(f, "S"),
             Self::Negative => write!(f, "NEG"),
+            Self::IntToFloat => write!(f, "TO_FLOAT"),
+            Self::Function => write!(f, "FUNCTION"),
+            Self::Call => write!(f, "CALL"),
+            Self::Argument => write!(f, "ARG"),
+            Self::Return => write!(f, "RETURN"),
+            Self::Index => write!(f, "INDEX"),
             Self::Noop => write!(f, "NOOP"),
         }
     }
@@ -230,7 +348,11 @@ impl Default for Ast {
     fn default() -> Self {
         Self {
             tree: array::from_fn(|_| {
-                Rc::new(Node::new_leaf(NodeValue::Value("".to_string()), None))
+                Rc::new(Node::new_leaf(
+                    NodeValue::Value("".to_string()),
+                    None,
+                    Span::default(),
+                ))
             }),
             expression_stack: Vec::new(),
             term_stack: Vec::new(),
@@ -240,6 +362,9 @@ impl Default for Ast {
             if_body_stack: Vec::new(),
             conjunction_stack: Vec::new(),
             statement_stack: Vec::new(),
+            call_arguments_stack: Vec::new(),
+            function_stack: Vec::new(),
+            index_stack: Vec::new(),
         }
     }
 }
@@ -265,6 +390,7 @@ impl Ast {
         right_child_ptr: AstNodeRef,
         dest_ptr: AstPtr,
         r#type: Option<ExpressionType>,
+        span: Span,
     ) -> Rc<Node> {
         let left_child = match left_child_ptr {
             AstNodeRef::Ptr(ptr) => self.tree.get(ptr as usize).cloned(),
@@ -281,6 +407,7 @@ impl Ast {
             left_child: left_child.clone(),
             right_child: right_child.clone(),
             r#type,
+            span,
         });
 
         if let Some(left) = left_child {
@@ -299,8 +426,9 @@ impl Ast {
         value: String,
         dest_ptr: AstPtr,
         node_type: Option<ExpressionType>,
+        span: Span,
     ) -> Rc<Node> {
-        let leaf = Rc::new(Node::new_leaf(NodeValue::Value(value), node_type));
+        let leaf = Rc::new(Node::new_leaf(NodeValue::Value(value), node_type, span));
         self.tree[dest_ptr as usize] = leaf.clone();
         leaf
     }
@@ -309,30 +437,27 @@ impl Ast {
         self.tree[from as usize].clone()
     }
 
+    /// Writes the tree under `from` as a Graphviz graph, an error points at the node being written
     pub fn graph_ast(
         &self,
         from: AstPtr,
         graph_label: &str,
-        file: &mut File,
-    ) -> Result<(), io::Error> {
-        writeln!(file, "graph \"\"")?;
-        writeln!(file, "{{")?;
-        writeln!(file, "    fontname=\"Arial\"")?;
-        writeln!(file, "    node [fontname=\"Arial\"]")?;
-        writeln!(file, "    edge [fontname=\"Arial\"]")?;
-        writeln!(file, "    label=\"{}\"", graph_label.trim())?;
-        writeln!(file)?;
-        Ast::graph_recursive_traverse(&self.tree[from as usize], 0, file)?;
-        writeln!(file, "}}")
+        file: &mut dyn Write,
+    ) -> Result<(), Box<Diagnostic>> {
+        let root = &self.tree[from as usize];
+        Self::graph_header(graph_label, file).map_err(|error| Self::graph_error(root, error))?;
+        Ast::graph_recursive_traverse(root, 0, file)?;
+        writeln!(file, "}}").map_err(|error| Self::graph_error(root, error))
     }
 
     fn graph_recursive_traverse(
         node: &Rc<Node>,
         mut node_count: usize,
-        file: &mut File,
-    ) -> Result<usize, io::Error> {
+        file: &mut dyn Write,
+    ) -> Result<usize, Box<Diagnostic>> {
+        let error = |error| Self::graph_error(node, error);
         let node_name = format!("n{node_count:0>3}");
-        writeln!(file, "    {node_name:0>3} ;")?;
+        writeln!(file, "    {node_name:0>3} ;").map_err(error)?;
         writeln!(
             file,
             "    {node_name:0>3} [label=\"{}{}\"] ;",
@@ -341,27 +466,37 @@ impl Ast {
                 .as_ref()
                 .map(|t| format!(" | {t}"))
                 .unwrap_or_default()
-        )?;
+        )
+        .map_err(error)?;
         if let Some(left_child) = &node.left_child {
             node_count += 1;
-            writeln!(file, "    {node_name} -- n{node_count:0>3} ;")?;
+            writeln!(file, "    {node_name} -- n{node_count:0>3} ;").map_err(error)?;
             node_count = Ast::graph_recursive_traverse(left_child, node_count, file)?;
         }
 
         if let Some(right_child) = &node.right_child {
             node_count += 1;
-            writeln!(file, "    {node_name} -- n{node_count:0>3} ;")?;
+            writeln!(file, "    {node_name} -- n{node_count:0>3} ;").map_err(error)?;
             node_count = Ast::graph_recursive_traverse(right_child, node_count, file)?;
         }
         Ok(node_count)
     }
 
-    pub fn generate_asm(
-        &self,
-        file: &mut File,
-        symbol_table: &mut SymbolTable,
-    ) -> Result<(), CompilerError> {
-        let node = self.get_node_from_ptr(AstPtr::Program);
-        TasmGenerator::new(symbol_table, file).generate_asm(node)
+    fn graph_header(graph_label: &str, file: &mut dyn Write) -> io::Result<()> {
+        writeln!(file, "graph \"\"")?;
+        writeln!(file, "{{")?;
+        writeln!(file, "    fontname=\"Arial\"")?;
+        writeln!(file, "    node [fontname=\"Arial\"]")?;
+        writeln!(file, "    edge [fontname=\"Arial\"]")?;
+        writeln!(file, "    label=\"{}\"", graph_label.trim())?;
+        writeln!(file)
+    }
+
+    fn graph_error(node: &Node, error: io::Error) -> Box<Diagnostic> {
+        Box::new(Diagnostic::new(node.span.range.clone(), error.into(), true))
+    }
+
+    pub fn generate_ir(&self, symbol_table: &SymbolTable) -> Result<IrProgram, Box<Diagnostic>> {
+        IrProgram::lower(&self.get_node_from_ptr(AstPtr::Program), symbol_table)
     }
 }
@@ -1,79 +1,58 @@
 use crate::{
     compiler::{
-        ast::{Ast, AstPtr},
-        error::CompilerError,
+        asm::CodegenBackend,
+        ast::{Ast, AstPtr, ExpressionType, Span},
+        error::{CompilerError, Diagnostic, Severity, log_warning},
+        ir::IrProgram,
+        optimizer,
     },
     grammar::{
+        TokenKind,
         rules_builder::Symbol,
         types::{DataType, TokenFloatLiteral, TokenIntLiteral},
     },
 };
 use std::{
     cell::RefCell,
+    collections::HashMap,
     fmt::Display,
-    fs::{File, OpenOptions, read_to_string},
-    io::{self, Read, Seek, Write},
-    path::{Path, PathBuf},
+    io::{self, Write},
+    mem,
+    ops::Range,
     rc::Rc,
 };
 
+/// Number of characters a string variable can hold, longer values are truncated
+pub const MAX_STRING_LENGTH: usize = 50;
+
 #[derive(Clone)]
 pub struct Compiler {
     pub inner: Rc<RefCell<CompilerContext>>,
 }
 
 impl Compiler {
-    pub fn new(path: PathBuf) -> Result<Self, CompilerError> {
-        Ok(Self {
-            inner: Rc::new(RefCell::new(CompilerContext::new(path)?)),
-        })
+    pub fn new(file_name: String, source_code: String) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(CompilerContext::new(file_name, source_code))),
+        }
     }
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct SymbolTable {
     table: Vec<SymbolTableElement>,
+    functions: Vec<FunctionSignature>,
     str_count: usize,
 }
 
 impl SymbolTable {
-    pub fn to_data(&self, file: &mut File) -> Result<(), io::Error> {
+    /// Writes the .DATA segment, the variables of functions are left out because they live in
+    /// the frame of the function
+    pub fn to_data(&self, file: &mut dyn Write) -> Result<(), io::Error> {
         writeln!(file, ".DATA")?;
         for symbol in &self.table {
-            match symbol.data_type {
-                SymbolTableElementType::String => {
-                    writeln!(
-                        file,
-                        "{:<25}    db    '{}', '$'",
-                        symbol.name,
-                        symbol.value.as_ref().unwrap()
-                    )?;
-                }
-                SymbolTableElementType::Int => {
-                    writeln!(
-                        file,
-                        "{:<25}    dd    {}.0",
-                        symbol.name,
-                        symbol.value.as_ref().unwrap()
-                    )?;
-                }
-                SymbolTableElementType::Float => {
-                    writeln!(
-                        file,
-                        "{:<25}    dd    {}",
-                        symbol.name,
-                        symbol.value.as_ref().unwrap()
-                    )?;
-                }
-                SymbolTableElementType::DataType(DataType::FloatType(_)) => {
-                    writeln!(file, "{:<25}    dd    ?", symbol.name)?;
-                }
-                SymbolTableElementType::DataType(DataType::IntType(_)) => {
-                    writeln!(file, "{:<25}    dd    ?", symbol.name)?;
-                }
-                SymbolTableElementType::DataType(DataType::StringType(_)) => {
-                    writeln!(file, "{:<25}    db    20 dup(' '), '$'", symbol.name)?;
-                }
+            if !self.is_local(&symbol.original) {
+                symbol.to_data(file)?;
             }
         }
         Ok(())
@@ -89,7 +68,7 @@ impl SymbolTable {
         }
     }
 
-    fn iter(&self) -> impl Iterator<Item = &SymbolTableElement> {
+    pub fn iter(&self) -> impl Iterator<Item = &SymbolTableElement> {
         self.table.iter()
     }
 
@@ -100,7 +79,52 @@ impl SymbolTable {
             .cloned()
     }
 
-    pub fn insert_string_literal(&mut self, s: String) {
+    pub fn insert_function(&mut self, function: FunctionSignature) {
+        self.functions.push(function);
+    }
+
+    pub fn functions(&self) -> impl Iterator<Item = &FunctionSignature> {
+        self.functions.iter()
+    }
+
+    pub fn get_function(&self, name: &str) -> Option<&FunctionSignature> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+
+    fn get_function_mut(&mut self, name: &str) -> Option<&mut FunctionSignature> {
+        self.functions
+            .iter_mut()
+            .find(|function| function.name == name)
+    }
+
+    /// Whether `key` is a parameter or a variable declared inside a function
+    pub fn is_local(&self, key: &str) -> bool {
+        self.functions.iter().any(|function| {
+            function.parameters.iter().any(|parameter| parameter == key)
+                || function.locals.iter().any(|local| local == key)
+        })
+    }
+
+    /// Type of the variable stored under `key`, the type of the elements for arrays
+    pub fn get_variable_type(&self, key: &str) -> Option<ExpressionType> {
+        self.get_symbol_from_name(key)
+            .and_then(|symbol| match symbol.data_type {
+                SymbolTableElementType::DataType(data_type)
+                | SymbolTableElementType::Array(data_type, _) => Some(data_type.into()),
+                _ => None,
+            })
+    }
+
+    /// Number of elements of the array stored under `key`, `None` if it isn't an array
+    pub fn get_array_length(&self, key: &str) -> Option<usize> {
+        self.get_symbol_from_name(key)
+            .and_then(|symbol| match symbol.data_type {
+                SymbolTableElementType::Array(_, length) => Some(length),
+                _ => None,
+            })
+    }
+
+    pub fn insert_string_literal(&mut self, s: String, span: Span) {
         let name = format!("_string_{}", self.str_count);
         self.str_count += 1;
         let symbol = SymbolTableElement {
@@ -109,134 +133,278 @@ impl SymbolTable {
             data_type: SymbolTableElementType::String,
             length: Some(s.len()),
             value: Some(s),
+            span: Some(span),
         };
         self.insert(symbol);
     }
 }
 
+impl Display for SymbolTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for symbol in self.iter() {
+            writeln!(f, "{symbol}")?
+        }
+        for function in self.functions() {
+            writeln!(f, "{function}")?
+        }
+        Ok(())
+    }
+}
+
+/// Signature of a function or procedure declared by the program
+#[derive(Clone, Debug)]
+pub struct FunctionSignature {
+    pub name: String,
+    /// Keys of the parameters in the symbol table in the order they are declared
+    pub parameters: Vec<String>,
+    /// Keys of the variables declared in the body
+    pub locals: Vec<String>,
+    /// `None` for procedures
+    pub return_type: Option<ExpressionType>,
+    /// Where the name of the function is written in its declaration
+    pub span: Span,
+}
+
+impl Display for FunctionSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.return_type {
+            Some(_) => "FUNCTION",
+            None => "PROCEDURE",
+        };
+        let return_type = self
+            .return_type
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| String::from("-"));
+        write!(
+            f,
+            "{}|{kind}|{return_type}|{}",
+            self.name,
+            self.parameters.len()
+        )
+    }
+}
+
+/// Where the lexer started skipping tokens after a syntax error and whether it can stop
+#[derive(Clone, Copy, Debug)]
+pub struct Recovery {
+    pub start: usize,
+    /// Cleared when the statement broken by the error can't be completed, the rest of the input
+    /// is skipped then
+    pub resumable: bool,
+}
+
 pub struct CompilerContext {
     pub res_stack: Vec<Symbol>,
-    source_code_path: PathBuf,
+    file_name: String,
     source_code: String,
     symbol_table: SymbolTable,
-    parser_file: File,
-    lexer_file: File,
-    symbol_table_file: File,
-    graph_file: File,
-    asm_file: File,
+    lexer_output: String,
+    parser_output: String,
+    diagnostics: Vec<Diagnostic>,
+    /// Set while the lexer is skipping tokens after a syntax error
+    pub recovering: Option<Recovery>,
+    /// Whether any code was skipped after a syntax error, the variables could be used there
+    recovered: bool,
+    /// Positions where the lexer completed a broken statement with tokens missing from the source
+    insertions: Vec<usize>,
+    /// Function whose signature or body is being parsed
+    current_function: Option<String>,
+    /// Scopes enclosing the code being parsed, the innermost one last
+    scopes: Vec<Scope>,
+    /// Number of blocks opened so far, used to give their variables unique keys
+    blocks: usize,
+    /// Variables declared in `init` blocks in the order they are declared
+    usages: Vec<VariableUsage>,
+    /// Tokens read by the parser in source order
+    tokens: Vec<(Range<usize>, TokenKind)>,
+    /// Declarations and uses of variables and functions in source order
+    references: Vec<Reference>,
     pub ast: Ast,
 }
 
+/// A name written in the source that stands for a variable or a function
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reference {
+    /// Span of the name
+    pub span: Range<usize>,
+    /// Key of the variable in the symbol table or name of the function
+    pub key: String,
+    pub kind: ReferenceKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReferenceKind {
+    Variable,
+    Function,
+}
+
+/// How a variable declared in an `init` block is used by the code parsed so far
+struct VariableUsage {
+    key: String,
+    /// Name of the variable in the source
+    name: String,
+    /// Span of the name in the declaration
+    declaration: Range<usize>,
+    assigned: bool,
+    read: bool,
+}
+
+/// Variables declared directly in the program, a function or a block
+#[derive(Default)]
+struct Scope {
+    /// Appended to the keys of the variables of a block, `None` for the program and functions
+    block: Option<usize>,
+    /// Whether the scope holds the parameters of a function, the enclosing ones aren't visible
+    /// from it
+    function: bool,
+    /// Keys of the variables by their name in the source
+    variables: HashMap<String, String>,
+}
+
 impl CompilerContext {
-    pub fn new(path: PathBuf) -> Result<Self, CompilerError> {
-        let source_code = CompilerContext::read_source_to_string(&path)?;
-        let parser_file = CompilerContext::open_parser_file(&path)?;
-        let lexer_file = CompilerContext::open_lexer_file(&path)?;
-        let symbol_table_file = CompilerContext::open_symbol_table_file(&path)?;
-        let graph_file = CompilerContext::open_graph_file(&path)?;
-        let asm_file = CompilerContext::open_asm_file(&path)?;
-
-        Ok(Self {
+    /// Creates a context for the given source, `file_name` is only used to report diagnostics
+    pub fn new(file_name: String, source_code: String) -> Self {
+        Self {
             res_stack: Vec::new(),
-            source_code_path: path,
+            file_name,
             source_code,
             symbol_table: SymbolTable::default(),
-            parser_file,
-            lexer_file,
-            symbol_table_file,
-            graph_file,
-            asm_file,
+            lexer_output: String::new(),
+            parser_output: String::new(),
+            diagnostics: Vec::new(),
+            recovering: None,
+            recovered: false,
+            insertions: Vec::new(),
+            current_function: None,
+            scopes: vec![Scope::default()],
+            blocks: 0,
+            usages: Vec::new(),
+            tokens: Vec::new(),
+            references: Vec::new(),
             ast: Ast::new(),
-        })
+        }
     }
 
-    fn read_source_to_string(path: &PathBuf) -> Result<String, CompilerError> {
-        Ok(read_to_string(path)?)
+    pub fn path(&self) -> String {
+        self.file_name.clone()
     }
 
-    fn open_lexer_file(path: &Path) -> Result<File, CompilerError> {
-        Ok(OpenOptions::new()
-            .create(true)
-            .truncate(true)
-            .write(true)
-            .open(path.with_extension("lexer"))?)
+    pub fn source(&self) -> &String {
+        &self.source_code
     }
 
-    fn open_parser_file(path: &Path) -> Result<File, CompilerError> {
-        Ok(OpenOptions::new()
-            .create(true)
-            .truncate(true)
-            .read(true)
-            .write(true)
-            .open(path.with_extension("parser"))?)
+    /// Records a problem, unless it is about a statement completed by the lexer whose errors would
+    /// only be about the tokens it made up
+    pub fn push_diagnostic(&mut self, diagnostic: Diagnostic) {
+        let span = &diagnostic.span;
+        let completed = self
+            .insertions
+            .iter()
+            .any(|&at| (span.start < at && at <= span.end) || (span.start == at && span.end == at));
+        if !completed {
+            self.diagnostics.push(diagnostic);
+        }
     }
 
-    fn open_symbol_table_file(path: &Path) -> Result<File, CompilerError> {
-        Ok(OpenOptions::new()
-            .create(true)
-            .truncate(true)
-            .write(true)
-            .open(path.with_extension("symbol_table"))?)
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
     }
 
-    fn open_graph_file(path: &Path) -> Result<File, CompilerError> {
-        Ok(OpenOptions::new()
-            .create(true)
-            .truncate(true)
-            .write(true)
-            .open(path.with_extension("dot"))?)
+    pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        mem::take(&mut self.diagnostics)
     }
 
-    fn open_asm_file(path: &Path) -> Result<File, CompilerError> {
-        Ok(OpenOptions::new()
-            .create(true)
-            .truncate(true)
-            .write(true)
-            .open(path.with_extension("asm"))?)
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(Diagnostic::is_error)
     }
 
-    pub fn path(&self) -> String {
-        self.source_code_path.to_string_lossy().into()
+    /// Turns every warning into an error
+    pub fn deny_warnings(&mut self) {
+        for diagnostic in &mut self.diagnostics {
+            if !diagnostic.is_error() {
+                diagnostic.severity = Severity::Error;
+                diagnostic.help.push(String::from(
+                    "warnings are treated as errors because of `-W error`",
+                ));
+            }
+        }
     }
 
-    pub fn source(&self) -> &String {
-        &self.source_code
+    pub fn write_to_lexer_output(&mut self, line: &str) {
+        self.lexer_output.push_str(line);
+        self.lexer_output.push('\n');
+    }
+
+    pub fn write_to_parser_output(&mut self, line: &str) {
+        self.parser_output.push_str(line);
+        self.parser_output.push('\n');
     }
 
-    pub fn dump_symbol_table_to_file(&mut self) -> Result<(), CompilerError> {
-        for symbol in self.symbol_table.iter() {
-            writeln!(self.symbol_table_file, "{symbol}")?
+    /// Starts skipping tokens after the syntax error at `start`
+    pub fn start_recovery(&mut self, start: usize) {
+        self.recovering = Some(Recovery {
+            start,
+            resumable: true,
+        });
+        self.recovered = true;
+    }
+
+    /// Records that the lexer made up a token at `at` to complete a broken statement
+    pub fn insert_token(&mut self, at: usize) {
+        self.insertions.push(at);
+    }
+
+    /// Records a token the parser accepted
+    pub fn push_token(&mut self, span: Range<usize>, kind: TokenKind) {
+        self.tokens.push((span, kind));
+    }
+
+    pub fn tokens(&self) -> &[(Range<usize>, TokenKind)] {
+        &self.tokens
+    }
+
+    /// Span of the first identifier at or after `start`, the name a rule beginning at `start`
+    /// is about
+    pub fn identifier_span(&self, start: usize) -> Option<Range<usize>> {
+        let first = self.tokens.partition_point(|(span, _)| span.start < start);
+        self.tokens[first..]
+            .iter()
+            .find(|(_, kind)| *kind == TokenKind::TokenId)
+            .map(|(span, _)| span.clone())
+    }
+
+    /// Records that the name at `span` stands for `key`, recording the same name twice has no
+    /// effect
+    pub fn push_reference(&mut self, span: Range<usize>, key: String, kind: ReferenceKind) {
+        let reference = Reference { span, key, kind };
+        if !self.references.contains(&reference) {
+            self.references.push(reference);
         }
-        Ok(())
     }
 
-    pub fn write_to_lexer_file(&mut self, line: &str) {
-        if let Err(e) = writeln!(self.lexer_file, "{line}") {
-            eprintln!("IO error: {e}");
-            std::process::exit(1)
-        };
+    pub fn references(&self) -> &[Reference] {
+        &self.references
     }
 
-    pub fn write_to_parser_file(&mut self, line: &str) {
-        if let Err(e) = writeln!(self.parser_file, "{line}") {
-            eprintln!("IO error: {e}");
-            std::process::exit(1)
-        };
+    pub fn lexer_output(&self) -> &str {
+        &self.lexer_output
     }
 
-    pub fn read_parser_file_to_string(&mut self) -> Result<String, CompilerError> {
-        let mut buf = String::new();
-        self.parser_file.rewind()?;
-        self.parser_file.read_to_string(&mut buf)?;
-        Ok(buf)
+    pub fn parser_output(&self) -> &str {
+        &self.parser_output
+    }
+
+    pub fn symbol_table(&self) -> &SymbolTable {
+        &self.symbol_table
     }
 
     pub fn push_to_symbol_table(&mut self, symbol: SymbolTableElement) {
         self.symbol_table.insert(symbol)
     }
 
-    pub fn push_string_literal_to_symbol_table(&mut self, s: String) {
-        self.symbol_table.insert_string_literal(s);
+    pub fn push_string_literal_to_symbol_table(&mut self, s: String, span: Span) {
+        self.symbol_table.insert_string_literal(s, span);
     }
 
     pub fn symbol_exists(&self, symbol: &SymbolTableElement) -> bool {
@@ -250,17 +418,259 @@ impl CompilerContext {
             .map(|x| x.data_type.clone())
     }
 
-    pub fn create_ast_graph(&mut self, from: AstPtr) -> Result<(), CompilerError> {
-        Ok(self.ast.graph_ast(
-            from,
-            &self.source_code_path.to_string_lossy(),
-            &mut self.graph_file,
-        )?)
+    /// Starts parsing the function `name` declared at `span`, the variables declared until
+    /// [`Self::exit_function`] belong to it
+    pub fn enter_function(&mut self, name: String, span: Span) {
+        self.symbol_table.insert_function(FunctionSignature {
+            name: name.clone(),
+            parameters: Vec::new(),
+            locals: Vec::new(),
+            return_type: None,
+            span,
+        });
+        self.current_function = Some(name);
+        self.scopes.push(Scope {
+            function: true,
+            ..Default::default()
+        });
+    }
+
+    pub fn exit_function(&mut self) {
+        self.current_function = None;
+        while let Some(scope) = self.scopes.pop() {
+            if scope.function {
+                break;
+            }
+        }
+    }
+
+    /// Starts a block, the variables declared until [`Self::close_scope`] shadow the ones with
+    /// the same name in the enclosing scopes
+    pub fn open_scope(&mut self) {
+        self.blocks += 1;
+        self.scopes.push(Scope {
+            block: Some(self.blocks),
+            ..Default::default()
+        });
+    }
+
+    pub fn close_scope(&mut self) {
+        if self
+            .scopes
+            .last()
+            .is_some_and(|scope| scope.block.is_some())
+        {
+            self.scopes.pop();
+        }
+    }
+
+    /// Signature of the function being parsed
+    pub fn current_function(&self) -> Option<&FunctionSignature> {
+        self.symbol_table
+            .get_function(self.current_function.as_deref()?)
+    }
+
+    pub fn set_return_type(&mut self, return_type: ExpressionType) {
+        if let Some(name) = &self.current_function
+            && let Some(function) = self.symbol_table.get_function_mut(name)
+        {
+            function.return_type = Some(return_type);
+        }
+    }
+
+    pub fn get_function(&self, name: &str) -> Option<&FunctionSignature> {
+        self.symbol_table.get_function(name)
     }
 
-    pub fn generate_asm(&mut self) -> Result<(), CompilerError> {
+    /// Key of the variable `id` in the symbol table, the one declared in the innermost scope
+    /// enclosing the code being parsed. Functions only see their own variables
+    ///
+    /// Undeclared variables get the key they would have if they were declared in the current
+    /// scope, which isn't in the symbol table
+    pub fn variable_key(&self, id: &str) -> String {
+        for scope in self.scopes.iter().rev() {
+            if let Some(key) = scope.variables.get(id) {
+                return key.clone();
+            }
+            if scope.function {
+                break;
+            }
+        }
+        self.new_variable_key(id)
+    }
+
+    /// Where the variable `id` visible from the current scope was declared
+    pub fn declaration_span(&self, id: &str) -> Option<Span> {
+        self.symbol_table
+            .get_symbol_from_name(&self.variable_key(id))?
+            .span
+    }
+
+    /// Unique key for a variable declared in the current scope. Variables of a function are
+    /// prefixed with its name and the ones of a block get the number of the block appended,
+    /// identifiers can't contain `@` so keys never clash
+    fn new_variable_key(&self, id: &str) -> String {
+        let key = match &self.current_function {
+            Some(function) => format!("{function}@{id}"),
+            None => id.to_string(),
+        };
+        match self.scopes.last().and_then(|scope| scope.block) {
+            Some(block) => format!("{key}@{block}"),
+            None => key,
+        }
+    }
+
+    /// Adds the variable `id` declared at `span` to the current scope, returns `false` if it
+    /// was already declared in it
+    pub fn declare_variable(&mut self, id: &str, data_type: DataType, span: Span) -> bool {
+        let declared = self.declare(id, data_type.into(), false, span.clone());
+        if declared {
+            self.track_usage(id, span.range);
+        }
+        declared
+    }
+
+    /// Adds the array `id` of `length` elements declared at `span` to the current scope, returns
+    /// `false` if it was already declared in it
+    pub fn declare_array(
+        &mut self,
+        id: &str,
+        data_type: DataType,
+        length: usize,
+        span: Span,
+    ) -> bool {
+        let data_type = SymbolTableElementType::Array(data_type, length);
+        let declared = self.declare(id, data_type, false, span.clone());
+        if declared {
+            self.track_usage(id, span.range);
+        }
+        declared
+    }
+
+    /// Adds the parameter `id` of the function being parsed declared at `span` to the symbol
+    /// table, returns `false` if it was already declared
+    pub fn declare_parameter(&mut self, id: &str, data_type: DataType, span: Span) -> bool {
+        self.declare(id, data_type.into(), true, span)
+    }
+
+    fn declare(
+        &mut self,
+        id: &str,
+        data_type: SymbolTableElementType,
+        parameter: bool,
+        span: Span,
+    ) -> bool {
+        if self
+            .scopes
+            .last()
+            .is_some_and(|scope| scope.variables.contains_key(id))
+        {
+            return false;
+        }
+        let key = self.new_variable_key(id);
+        self.push_reference(span.range.clone(), key.clone(), ReferenceKind::Variable);
+        self.push_to_symbol_table(SymbolTableElement {
+            name: format!("_{key}"),
+            original: key.clone(),
+            data_type,
+            value: None,
+            length: Some(id.len()),
+            span: Some(span),
+        });
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.variables.insert(id.to_string(), key.clone());
+        }
+
+        if let Some(name) = &self.current_function
+            && let Some(function) = self.symbol_table.get_function_mut(name)
+        {
+            if parameter {
+                function.parameters.push(key);
+            } else {
+                function.locals.push(key);
+            }
+        }
+        true
+    }
+
+    /// Replaces the program with its optimized version, see [`optimizer::optimize`]
+    pub fn optimize(&mut self) {
+        let program = self.ast.get_node_from_ptr(AstPtr::Program);
+        let optimized = optimizer::optimize(&program, &mut self.symbol_table);
         self.ast
-            .generate_asm(&mut self.asm_file, &mut self.symbol_table)
+            .assign_node_to_ptr(optimized.into(), AstPtr::Program);
+    }
+
+    fn track_usage(&mut self, id: &str, declaration: Range<usize>) {
+        self.usages.push(VariableUsage {
+            key: self.variable_key(id),
+            name: id.to_string(),
+            declaration,
+            assigned: false,
+            read: false,
+        });
+    }
+
+    /// Records that the variable stored under `key` is given a value
+    pub fn assign_variable(&mut self, key: &str) {
+        if let Some(usage) = self.usages.iter_mut().find(|usage| usage.key == key) {
+            usage.assigned = true;
+        }
+    }
+
+    /// Records that the variable stored under `key` is read at `span`, warning if no code
+    /// before it in the source gives it a value. Once code was skipped after a syntax error it
+    /// can't be told
+    pub fn read_variable(&mut self, key: &str, span: Range<usize>) {
+        let Some(usage) = self.usages.iter_mut().find(|usage| usage.key == key) else {
+            return;
+        };
+        let first_read = !usage.read;
+        usage.read = true;
+        if first_read && !usage.assigned && !self.recovered {
+            let warning = CompilerError::UnassignedRead(usage.name.clone());
+            log_warning(span, warning, self);
+        }
+    }
+
+    /// Warns about the variables declared in `init` blocks that are never read
+    pub fn warn_unused_variables(&mut self) {
+        for usage in mem::take(&mut self.usages) {
+            // Code skipped while recovering from a syntax error could read or assign it
+            if usage.read || self.recovered {
+                continue;
+            }
+            let warning = if usage.assigned {
+                format!("{} is assigned but never read", usage.name)
+            } else {
+                usage.name
+            };
+            log_warning(
+                usage.declaration,
+                CompilerError::UnusedVariable(warning),
+                self,
+            );
+        }
+    }
+
+    pub fn create_ast_graph(&mut self, from: AstPtr) -> Result<String, Box<Diagnostic>> {
+        let mut graph = Vec::new();
+        self.ast.graph_ast(from, &self.file_name, &mut graph)?;
+        Ok(String::from_utf8_lossy(&graph).into_owned())
+    }
+
+    pub fn generate_ir(&self) -> Result<IrProgram, Box<Diagnostic>> {
+        self.ast.generate_ir(&self.symbol_table)
+    }
+
+    pub fn generate_asm(
+        &self,
+        program: &IrProgram,
+        backend: &dyn CodegenBackend,
+    ) -> Result<String, Box<Diagnostic>> {
+        let mut asm = Vec::new();
+        backend.generate(program, &self.symbol_table, &mut asm)?;
+        Ok(String::from_utf8_lossy(&asm).into_owned())
     }
 }
 
@@ -271,24 +681,93 @@ pub struct SymbolTableElement {
     pub data_type: SymbolTableElementType,
     pub value: Option<String>,
     pub length: Option<usize>,
+    /// Where the symbol first appears in the source, `None` for symbols made by the compiler
+    pub span: Option<Span>,
 }
 
 #[derive(Clone, Default)]
 pub enum SymbolTableElementType {
     DataType(DataType),
+    /// Array with the type and number of its elements
+    Array(DataType, usize),
     #[default]
     Int,
     Float,
     String,
+    Bool,
+}
+
+impl SymbolTableElement {
+    /// Literal `true` or `false`, stored as 1 or 0 like bool variables
+    pub fn bool_literal(value: bool) -> Self {
+        let original = value.to_string();
+        Self {
+            name: format!("_{original}"),
+            original,
+            data_type: SymbolTableElementType::Bool,
+            value: Some(u8::from(value).to_string()),
+            length: None,
+            span: None,
+        }
+    }
+
+    /// Records that the symbol appears in the source at `span`
+    pub fn with_span(self, span: Span) -> Self {
+        Self {
+            span: Some(span),
+            ..self
+        }
+    }
+
+    /// Writes the definition of the symbol in .DATA
+    pub fn to_data(&self, file: &mut dyn Write) -> Result<(), io::Error> {
+        let name = &self.name;
+        match &self.data_type {
+            SymbolTableElementType::String => {
+                writeln!(
+                    file,
+                    "{name:<25}    db    '{}', '$'",
+                    self.value.as_ref().unwrap()
+                )
+            }
+            SymbolTableElementType::Int
+            | SymbolTableElementType::Float
+            | SymbolTableElementType::Bool => {
+                writeln!(file, "{name:<25}    dd    {}", self.value.as_ref().unwrap())
+            }
+            SymbolTableElementType::DataType(DataType::FloatType(_))
+            | SymbolTableElementType::DataType(DataType::IntType(_))
+            | SymbolTableElementType::DataType(DataType::BoolType(_)) => {
+                writeln!(file, "{name:<25}    dd    ?")
+            }
+            SymbolTableElementType::DataType(DataType::StringType(_)) => {
+                writeln!(file, "{name:<25}    db    '$', {MAX_STRING_LENGTH} dup (?)")
+            }
+            SymbolTableElementType::Array(
+                DataType::FloatType(_) | DataType::IntType(_) | DataType::BoolType(_),
+                length,
+            ) => {
+                writeln!(file, "{name:<25}    dd    {length} dup (?)")
+            }
+            SymbolTableElementType::Array(DataType::StringType(_), length) => {
+                writeln!(
+                    file,
+                    "{name:<25}    db    {length} dup ('$', {MAX_STRING_LENGTH} dup (?))"
+                )
+            }
+        }
+    }
 }
 
 impl Display for SymbolTableElementType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::DataType(t) => write!(f, "{t}"),
+            Self::Array(t, length) => write!(f, "{t}[{length}]"),
             Self::Int => write!(f, "int"),
             Self::Float => write!(f, "float"),
             Self::String => write!(f, "string"),
+            Self::Bool => write!(f, "bool"),
         }
     }
 }
@@ -304,6 +783,7 @@ impl Display for SymbolTableElement {
         let name = &self.name;
         let data_type = match &self.data_type {
             SymbolTableElementType::DataType(t) => t.to_string(),
+            SymbolTableElementType::Array(t, length) => format!("{t}[{length}]"),
             _ => String::from("-"),
         };
         let value = self
@@ -340,6 +820,7 @@ impl From<TokenIntLiteral> for SymbolTableElement {
             data_type: SymbolTableElementType::Int,
             value: Some(value.original),
             length: None,
+            span: None,
         }
     }
 }
@@ -357,6 +838,7 @@ impl From<TokenFloatLiteral> for SymbolTableElement {
             data_type: SymbolTableElementType::Float,
             value: Some(value.original),
             length: None,
+            span: None,
         }
     }
 }
@@ -0,0 +1,162 @@
+use crate::{
+    compiler::{
+        asm::{CodegenBackend, Target},
+        ast::{AstPtr, Node},
+        context::{Compiler, Reference, SymbolTable},
+        error::Diagnostic,
+        ir::IrProgram,
+    },
+    grammar::{RulesParser, TokenKind},
+};
+use rustemo::Parser;
+use std::{ops::Range, rc::Rc};
+
+/// Options that control a single compilation
+pub struct CompileOptions {
+    /// Name used to refer to the source in diagnostics and as the label of the AST graph
+    pub file_name: String,
+    /// Backend that generates the program, any [`CodegenBackend`] can be plugged in
+    pub backend: Box<dyn CodegenBackend>,
+    /// Whether to fold constants and drop dead branches before generating code
+    pub optimize: bool,
+    /// Whether warnings make the compilation fail like errors do
+    pub deny_warnings: bool,
+}
+
+impl Default for CompileOptions {
+    fn default() -> Self {
+        Self {
+            file_name: String::from("<input>"),
+            backend: Target::default().backend(),
+            optimize: false,
+            deny_warnings: false,
+        }
+    }
+}
+
+/// Every artifact produced by a successful compilation
+pub struct CompilationOutput {
+    /// Problems that didn't stop the compilation
+    pub warnings: Vec<Diagnostic>,
+    /// One line for each token read by the lexer
+    pub tokens: String,
+    /// One line for each grammar rule reduced by the parser
+    pub parse_trace: String,
+    /// The symbol table as it was after parsing, before code generation adds its internal symbols
+    pub symbol_table: SymbolTable,
+    /// Root of the AST
+    pub ast: Rc<Node>,
+    /// The AST in graphviz dot format
+    pub ast_graph: String,
+    /// The program in three address code
+    pub ir: IrProgram,
+    /// The program as generated by the chosen backend
+    pub asm: String,
+}
+
+/// What the front end found in a source, available even when it doesn't compile
+pub struct Analysis {
+    /// Errors and warnings in the order they were found
+    pub diagnostics: Vec<Diagnostic>,
+    /// The symbol table with everything declared before parsing stopped
+    pub symbol_table: SymbolTable,
+    /// Tokens read by the parser in source order
+    pub tokens: Vec<(Range<usize>, TokenKind)>,
+    /// Declarations and uses of variables and functions in source order
+    pub references: Vec<Reference>,
+}
+
+/// Lexes, parses and checks `source` without generating any code, for tools that need to know
+/// about the program while it is being written
+pub fn analyze(source: &str, file_name: &str) -> Analysis {
+    let compiler = Compiler::new(file_name.to_string(), source.to_string());
+    let result = parse(&compiler, source);
+
+    let mut context = compiler.inner.borrow_mut();
+    let mut diagnostics = context.take_diagnostics();
+    if let Err(error) = result
+        && !diagnostics.iter().any(Diagnostic::is_error)
+    {
+        diagnostics.push(Diagnostic::parser_internal(error, source));
+    }
+    Analysis {
+        diagnostics,
+        symbol_table: context.symbol_table().clone(),
+        tokens: context.tokens().to_vec(),
+        references: context.references().to_vec(),
+    }
+}
+
+/// Lexes, parses and checks `source` without generating any code
+///
+/// Returns the warnings if there are no errors, every diagnostic found otherwise
+pub fn check_str(
+    source: &str,
+    options: &CompileOptions,
+) -> Result<Vec<Diagnostic>, Vec<Diagnostic>> {
+    let compiler = Compiler::new(options.file_name.clone(), source.to_string());
+    front_end(&compiler, source, options)?;
+    Ok(compiler.inner.borrow_mut().take_diagnostics())
+}
+
+/// Compiles `source` in memory without touching the filesystem
+///
+/// Returns every diagnostic found if compilation fails
+pub fn compile_str(
+    source: &str,
+    options: &CompileOptions,
+) -> Result<CompilationOutput, Vec<Diagnostic>> {
+    let compiler = Compiler::new(options.file_name.clone(), source.to_string());
+    let parse_trace = front_end(&compiler, source, options)?;
+
+    let mut context = compiler.inner.borrow_mut();
+    if options.optimize {
+        context.optimize();
+    }
+    let symbol_table = context.symbol_table().clone();
+    let ast = context.ast.get_node_from_ptr(AstPtr::Program);
+    let ast_graph = context
+        .create_ast_graph(AstPtr::Program)
+        .map_err(|error| vec![*error])?;
+    let ir = context.generate_ir().map_err(|error| vec![*error])?;
+    let asm = context
+        .generate_asm(&ir, options.backend.as_ref())
+        .map_err(|error| vec![*error])?;
+
+    Ok(CompilationOutput {
+        warnings: context.take_diagnostics(),
+        tokens: context.lexer_output().to_string(),
+        parse_trace,
+        symbol_table,
+        ast,
+        ast_graph,
+        ir,
+        asm,
+    })
+}
+
+/// Runs the parser and the checks that need the whole program, returning the parse trace
+fn parse(compiler: &Compiler, source: &str) -> Result<String, rustemo::Error> {
+    let result = RulesParser::new(compiler.clone(), compiler.clone()).parse(source);
+    compiler.inner.borrow_mut().warn_unused_variables();
+    result
+}
+
+/// Parses and checks `source` failing with every diagnostic if there is any error, returns the
+/// parse trace
+fn front_end(
+    compiler: &Compiler,
+    source: &str,
+    options: &CompileOptions,
+) -> Result<String, Vec<Diagnostic>> {
+    let result = parse(compiler, source);
+
+    let mut context = compiler.inner.borrow_mut();
+    if options.deny_warnings {
+        context.deny_warnings();
+    }
+    if context.has_errors() {
+        return Err(context.take_diagnostics());
+    }
+    result.map_err(|error| vec![Diagnostic::parser_internal(error, source)])
+}
@@ -1,6 +1,9 @@
 use crate::compiler::context::CompilerContext;
-use owo_colors::OwoColorize;
-use std::{io, ops::Range};
+use clap::ValueEnum;
+use owo_colors::{AnsiColors, OwoColorize};
+use rustemo::{LineColumn, Location, Position};
+use serde_json::json;
+use std::{fmt::Display, io, iter, ops::Range};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -15,48 +18,281 @@ pub enum CompilerError {
     Context(String),
     #[error("Type mismatch error: {0}")]
     TypeMismatch(String),
+    #[error("String too long: {0}")]
+    StringTooLong(String),
     #[error("Use of undeclared variable: {0}")]
     UndeclaredVariable(String),
+    #[error("Use of undeclared function: {0}")]
+    UndeclaredFunction(String),
+    #[error("Wrong number of arguments: {0}")]
+    ArgumentCount(String),
+    #[error("Invalid return: {0}")]
+    InvalidReturn(String),
+    #[error("Invalid use of array: {0}")]
+    ArrayUse(String),
+    #[error("Index out of bounds: {0}")]
+    IndexOutOfBounds(String),
+    #[error("Redeclaration of {0}")]
+    Redeclaration(String),
+    #[error("Integer out of range: {0}")]
+    IntegerOutOfRange(String),
+    #[error("Unused variable: {0}")]
+    UnusedVariable(String),
+    #[error("Variable read before being assigned: {0}")]
+    UnassignedRead(String),
+    #[error("Unreachable code: {0}")]
+    UnreachableCode(String),
+    #[error("Infinite loop: {0}")]
+    InfiniteLoop(String),
+    #[error("Runtime error: {0}")]
+    Runtime(String),
     #[error("IO error: {0}")]
     IO(String),
     #[error("Compiler internal error: {0}. This is a bug.")]
     Internal(String),
 }
 
+impl CompilerError {
+    /// Stable identifier of the error for tools and documentation, a code is never reused for
+    /// a different error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Lexer(_) => "E0001",
+            Self::Parser(_) => "E0002",
+            Self::TypeMismatch(_) => "E0003",
+            Self::StringTooLong(_) => "E0004",
+            Self::UndeclaredVariable(_) => "E0005",
+            Self::UndeclaredFunction(_) => "E0006",
+            Self::ArgumentCount(_) => "E0007",
+            Self::InvalidReturn(_) => "E0008",
+            Self::ArrayUse(_) => "E0009",
+            Self::IndexOutOfBounds(_) => "E0010",
+            Self::Redeclaration(_) => "E0011",
+            Self::Context(_) => "E0012",
+            Self::Runtime(_) => "E0013",
+            Self::IO(_) => "E0014",
+            Self::ParserInternal(_) => "E0015",
+            Self::Internal(_) => "E0016",
+            Self::IntegerOutOfRange(_) => "E0017",
+            Self::UnusedVariable(_) => "W0001",
+            Self::UnassignedRead(_) => "W0002",
+            Self::UnreachableCode(_) => "W0003",
+            Self::InfiniteLoop(_) => "W0004",
+        }
+    }
+}
+
 impl From<io::Error> for CompilerError {
     fn from(value: io::Error) -> Self {
         Self::IO(value.to_string())
     }
 }
 
-pub fn log_error_and_exit(
-    pos: Range<usize>,
-    error: CompilerError,
-    offset: usize,
-    trace: bool,
-    context: &mut CompilerContext,
-) -> ! {
-    if let Err(e) = context.dump_symbol_table_to_file() {
-        eprintln!("Failed to write symbol table: {e}")
+/// How bad a diagnostic is, only errors stop the compilation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+        }
     }
-    log_error(pos, error, offset, context, trace);
-    std::process::exit(1)
 }
 
-fn log_error(
-    token_pos: Range<usize>,
-    err: CompilerError,
-    offset: usize,
-    context: &CompilerContext,
-    trace: bool,
-) {
-    let path = context.path();
-    let source = context.source();
+/// How diagnostics are printed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ErrorFormat {
+    /// Source lines with the problem underlined
+    #[default]
+    Human,
+    /// One JSON object per line for tools
+    Json,
+}
+
+/// Another piece of the source related to a diagnostic, like the first declaration of a
+/// redeclared variable
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub span: Range<usize>,
+    pub message: String,
+}
+
+/// A problem found while compiling, collected on the `CompilerContext` so compilation can keep
+/// going and every error can be reported at the end
+#[derive(Debug)]
+pub struct Diagnostic {
+    /// Absolute byte range of the offending source
+    pub span: Range<usize>,
+    pub error: CompilerError,
+    /// Whether to print the source line with the span underlined
+    pub trace: bool,
+    pub severity: Severity,
+    /// Secondary spans shown after the primary one
+    pub labels: Vec<Label>,
+    /// Suggestions on how to fix the problem
+    pub help: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(span: Range<usize>, error: CompilerError, trace: bool) -> Self {
+        Self {
+            span,
+            error,
+            trace,
+            severity: Severity::Error,
+            labels: Vec::new(),
+            help: Vec::new(),
+        }
+    }
+
+    /// Diagnostic for code that compiles but is most likely a mistake
+    pub fn warning(span: Range<usize>, error: CompilerError) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..Self::new(span, error, true)
+        }
+    }
+
+    pub fn with_label(mut self, span: Range<usize>, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.into(),
+        });
+        self
+    }
+
+    pub fn with_help(mut self, message: impl Into<String>) -> Self {
+        self.help.push(message.into());
+        self
+    }
+
+    /// Diagnostic for an error the parser returned instead of logging it, at the location the
+    /// parser stopped
+    pub fn parser_internal(error: rustemo::Error, source: &str) -> Self {
+        let offset = |position: Position| match position {
+            Position::Position(pos) => pos,
+            Position::LineBased(LineColumn { line, column }) => source
+                .split_inclusive('\n')
+                .take(line.saturating_sub(1))
+                .map(str::len)
+                .sum::<usize>()
+                .saturating_add(column),
+        };
+        let span = match &error {
+            rustemo::Error::Error {
+                location: Some(Location { start, end }),
+                ..
+            } => {
+                let start = offset(*start).min(source.len());
+                start..end.map_or(start, offset).clamp(start, source.len())
+            }
+            _ => source.len()..source.len(),
+        };
+        Self::new(span, CompilerError::ParserInternal(error), true)
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
 
-    let (line_starts, (line_in_file, col_in_file)) =
-        pos_to_line_col(source, token_pos.start + offset);
+    /// Prints the diagnostic to stderr in the given format
+    pub fn emit(&self, path: &str, source: &str, format: ErrorFormat) {
+        match format {
+            ErrorFormat::Human => self.emit_human(path, source),
+            ErrorFormat::Json => eprintln!("{}", self.to_json(path, source)),
+        }
+    }
 
-    let line_start = line_starts[line_in_file - 1];
+    /// Prints the diagnostic to stderr pointing at the line and column of the source
+    fn emit_human(&self, path: &str, source: &str) {
+        let err = &self.error;
+        let (line_in_file, col_in_file) = pos_to_line_col(source, self.span.start);
+        let color = match self.severity {
+            Severity::Error => AnsiColors::Red,
+            Severity::Warning => AnsiColors::Yellow,
+        };
+        eprintln!(
+            "{}: {}",
+            format!("{}[{}]", self.severity, err.code())
+                .color(color)
+                .bold(),
+            err.to_string().bold()
+        );
+        eprintln!(
+            "  --> {}:{}:{}",
+            path.bright_blue(),
+            line_in_file.blue(),
+            col_in_file.blue()
+        );
+        if self.trace {
+            eprintln!("   {}", "|".dimmed());
+            emit_source_line(source, &self.span, '^', &err.to_string(), color);
+        } else {
+            eprintln!("   {}", err.bold().color(color));
+        }
+        for label in &self.labels {
+            eprintln!("   {}", "|".dimmed());
+            emit_source_line(source, &label.span, '-', &label.message, AnsiColors::Blue);
+        }
+        for help in &self.help {
+            eprintln!("   {} {}: {help}", "=".dimmed(), "help".bold());
+        }
+        eprintln!()
+    }
+
+    /// Machine readable version of the diagnostic, lines and columns count from 1
+    pub fn to_json(&self, path: &str, source: &str) -> serde_json::Value {
+        let span = |range: &Range<usize>| {
+            let (line, column) = pos_to_line_col(source, range.start);
+            let (end_line, end_column) = pos_to_line_col(source, range.end);
+            json!({
+                "start": range.start,
+                "end": range.end,
+                "line": line,
+                "column": column,
+                "end_line": end_line,
+                "end_column": end_column,
+            })
+        };
+        let primary = json!({
+            "span": span(&self.span),
+            "message": self.error.to_string(),
+            "primary": true,
+        });
+        let labels = iter::once(primary).chain(self.labels.iter().map(|label| {
+            json!({
+                "span": span(&label.span),
+                "message": label.message,
+                "primary": false,
+            })
+        }));
+        json!({
+            "code": self.error.code(),
+            "severity": self.severity.to_string(),
+            "message": self.error.to_string(),
+            "file": path,
+            "span": span(&self.span),
+            "labels": labels.collect::<Vec<_>>(),
+            "help": self.help,
+        })
+    }
+}
+
+/// Prints the line of `source` where `span` starts with the span marked by `marker`
+fn emit_source_line(
+    source: &str,
+    span: &Range<usize>,
+    marker: char,
+    message: &str,
+    color: AnsiColors,
+) {
+    let (line_in_file, col_in_file) = pos_to_line_col(source, span.start);
+    let line_start = span.start.min(source.len()) + 1 - col_in_file;
     let line_end = source[line_start..]
         .find('\n')
         .map(|e| line_start + e)
@@ -64,50 +300,88 @@ fn log_error(
     let line_text = &source[line_start..line_end];
 
     let span_len = std::cmp::min(
-        token_pos.end - token_pos.start,
-        line_text.len() - (col_in_file - 1),
+        span.end.saturating_sub(span.start),
+        line_text.len().saturating_sub(col_in_file - 1),
     );
     let mut underline = String::new();
     underline.push_str(&" ".repeat(col_in_file - 1));
-    underline.push_str(&"^".repeat(span_len));
-    eprintln!("{}: {}", "error".red().bold(), err.to_string().bold());
+    underline.push_str(&marker.to_string().repeat(span_len.max(1)));
     eprintln!(
-        "  --> {}:{}:{}",
-        path.bright_blue(),
-        line_in_file.blue(),
-        col_in_file.blue()
+        "{:>3}{} {}",
+        line_in_file.to_string().blue(),
+        "|".dimmed(),
+        line_text
     );
-    if trace {
-        eprintln!("   {}", "|".dimmed());
+    eprintln!(
+        "   {} {} {}",
+        "|".dimmed(),
+        underline.bold().color(color),
+        message.bold().color(color)
+    );
+}
+
+/// Prints every diagnostic in source order, in the human format followed by a summary line
+pub fn emit_diagnostics(path: &str, source: &str, diagnostics: &[Diagnostic], format: ErrorFormat) {
+    let mut sorted: Vec<_> = diagnostics.iter().collect();
+    sorted.sort_by_key(|diagnostic| diagnostic.span.start);
+    for diagnostic in sorted {
+        diagnostic.emit(path, source, format);
+    }
+    if format == ErrorFormat::Json {
+        return;
+    }
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.is_error())
+        .count();
+    let warnings = diagnostics.len() - errors;
+    let plural = |count: usize| if count == 1 { "" } else { "s" };
+    if errors > 0 {
+        let warnings = match warnings {
+            0 => String::new(),
+            warnings => format!("; {warnings} warning{} emitted", plural(warnings)),
+        };
         eprintln!(
-            "{:>3}{} {}",
-            line_in_file.to_string().blue(),
-            "|".dimmed(),
-            line_text
+            "{}: could not compile `{path}` due to {errors} previous error{}{warnings}",
+            "error".red().bold(),
+            plural(errors)
         );
+    } else if warnings > 0 {
         eprintln!(
-            "   {} {} {}",
-            "|".dimmed(),
-            underline.bold().red(),
-            err.bold().red()
+            "{}: `{path}` generated {warnings} warning{}",
+            "warning".yellow().bold(),
+            plural(warnings)
         );
-    } else {
-        eprintln!("   {}", err.bold().red());
     }
-    eprintln!()
 }
 
-fn pos_to_line_col(source: &str, pos: usize) -> (Vec<usize>, (usize, usize)) {
-    let mut line_starts = vec![0];
-    for (i, ch) in source.char_indices() {
-        if ch == '\n' {
-            line_starts.push(i + 1);
-        }
-    }
-
-    let line = line_starts.partition_point(|&start| start <= pos);
+/// Records a warning in the context so it gets reported once compilation finishes
+pub fn log_warning(pos: Range<usize>, warning: CompilerError, context: &mut CompilerContext) {
+    context.push_diagnostic(Diagnostic::warning(pos, warning));
+}
 
-    let col = pos - line_starts[line - 1] + 1;
+/// Records an error in the context so it gets reported once compilation finishes
+pub fn log_error(
+    pos: Range<usize>,
+    error: CompilerError,
+    offset: usize,
+    trace: bool,
+    context: &mut CompilerContext,
+) {
+    context.push_diagnostic(Diagnostic::new(
+        (pos.start + offset)..(pos.end + offset),
+        error,
+        trace,
+    ));
+}
 
-    (line_starts, (line, col))
+/// Line and column where the byte `pos` of `source` is, both counting from 1
+fn pos_to_line_col(source: &str, pos: usize) -> (usize, usize) {
+    let before = &source.as_bytes()[..pos.min(source.len())];
+    let line_start = before
+        .iter()
+        .rposition(|&byte| byte == b'\n')
+        .map_or(0, |newline| newline + 1);
+    let line = before.iter().filter(|&&byte| byte == b'\n').count() + 1;
+    (line, before.len() - line_start + 1)
 }
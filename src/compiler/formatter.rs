@@ -0,0 +1,202 @@
+use crate::{
+    compiler::{
+        driver::analyze,
+        error::{CompilerError, Diagnostic},
+    },
+    grammar::TokenKind,
+    lexer::tokens::{Token, TriviaKind, ends_operand, starts_statement, tokenize},
+};
+
+/// Spaces added for each level of nesting
+const INDENT: &str = "    ";
+
+/// Rewrites `source` in the canonical layout keeping its comments
+///
+/// Blocks are indented four spaces, every statement and declaration group goes on its own line,
+/// binary operators and `:=` are surrounded by spaces and at most one blank line is kept between
+/// statements. Programs with syntax errors aren't formatted and their errors are returned instead
+pub fn format(source: &str) -> Result<String, Vec<Diagnostic>> {
+    let syntax_errors = analyze(source, "")
+        .diagnostics
+        .into_iter()
+        .filter(|diagnostic| {
+            matches!(
+                diagnostic.error,
+                CompilerError::Lexer(_)
+                    | CompilerError::Parser(_)
+                    | CompilerError::ParserInternal(_)
+            )
+        })
+        .collect::<Vec<_>>();
+    if !syntax_errors.is_empty() {
+        return Err(syntax_errors);
+    }
+
+    let tokens = tokenize(source)?;
+    let mut formatter = Formatter::default();
+    // Where the output of each token ends
+    let mut ends = Vec::new();
+    for token in &tokens {
+        formatter.token(token);
+        ends.push(formatter.output.len());
+    }
+    let formatted = formatter.output;
+
+    // Only the layout can change, anything else is a bug in the formatter
+    let changed = match tokenize(&formatted) {
+        Ok(formatted_tokens) => formatted_tokens
+            .iter()
+            .zip(&tokens)
+            .position(|(formatted, token)| formatted.text != token.text)
+            .or((formatted_tokens.len() != tokens.len())
+                .then(|| formatted_tokens.len().min(tokens.len()))),
+        // The token whose output can't be read back
+        Err(diagnostics) => {
+            let at = diagnostics
+                .first()
+                .map_or(0, |diagnostic| diagnostic.span.start);
+            Some(ends.partition_point(|&end| end <= at))
+        }
+    };
+    if let Some(index) = changed {
+        return Err(vec![Diagnostic::new(
+            tokens[index.min(tokens.len() - 1)].span.clone(),
+            CompilerError::Internal(String::from("formatting changed the tokens of the program")),
+            true,
+        )]);
+    }
+    Ok(formatted)
+}
+
+#[derive(Default)]
+struct Formatter {
+    output: String,
+    indent: usize,
+    /// Open parentheses and brackets, lines are never broken inside them
+    nesting: usize,
+    previous: Option<TokenKind>,
+    /// Whether the last `-` negates a literal instead of subtracting
+    negation: bool,
+}
+
+impl Formatter {
+    fn token(&mut self, token: &Token) {
+        // Line breaks in the source are only kept after comments and as blank lines
+        let mut line_breaks = 0;
+        let mut after_comment = false;
+        for trivia in &token.leading_trivia {
+            match trivia.kind {
+                TriviaKind::Whitespace => line_breaks += trivia.line_breaks(),
+                TriviaKind::Comment => {
+                    if line_breaks > 0 {
+                        self.new_line(line_breaks > 1);
+                    }
+                    self.separate(true);
+                    self.output.push_str(trivia.text);
+                    line_breaks = 0;
+                    after_comment = true;
+                }
+            }
+        }
+
+        if token.kind == TokenKind::STOP {
+            if !self.output.is_empty() {
+                self.new_line(false);
+            }
+            return;
+        }
+
+        if token.kind == TokenKind::TokenCBClose {
+            self.indent = self.indent.saturating_sub(1);
+        }
+        if self.breaks_before(token.kind) || (after_comment && line_breaks > 0) {
+            let blank = line_breaks > 1
+                && token.kind != TokenKind::TokenCBClose
+                && self.previous != Some(TokenKind::TokenCBOpen);
+            self.new_line(blank);
+        }
+        let space = after_comment || self.spaced_from_previous(token.kind);
+        self.separate(space);
+        self.output.push_str(token.text);
+
+        match token.kind {
+            TokenKind::TokenCBOpen => self.indent += 1,
+            TokenKind::TokenParOpen | TokenKind::TokenSBOpen => self.nesting += 1,
+            TokenKind::TokenParClose | TokenKind::TokenSBClose => {
+                self.nesting = self.nesting.saturating_sub(1)
+            }
+            TokenKind::TokenSub => self.negation = !self.previous.is_some_and(ends_operand),
+            _ => {}
+        }
+        self.previous = Some(token.kind);
+    }
+
+    /// Whether `kind` starts a new line
+    fn breaks_before(&self, kind: TokenKind) -> bool {
+        let Some(previous) = self.previous else {
+            return false;
+        };
+        if self.nesting > 0 {
+            return false;
+        }
+        match (previous, kind) {
+            (_, TokenKind::TokenCBClose) | (TokenKind::TokenCBOpen, _) => true,
+            (TokenKind::TokenCBClose, kind) => kind != TokenKind::TokenElse,
+            // Statements and declaration groups aren't separated by anything, a new one starts
+            // right where the previous one can't continue
+            (previous, kind) => ends_operand(previous) && starts_statement(kind),
+        }
+    }
+
+    fn spaced_from_previous(&self, kind: TokenKind) -> bool {
+        let Some(previous) = self.previous else {
+            return false;
+        };
+        !matches!(
+            (previous, kind),
+            (
+                _,
+                TokenKind::TokenParClose | TokenKind::TokenSBClose | TokenKind::TokenComma
+            ) | (TokenKind::TokenParOpen | TokenKind::TokenSBOpen, _)
+                | (
+                    TokenKind::TokenId
+                        | TokenKind::TokenWrite
+                        | TokenKind::TokenRead
+                        | TokenKind::TokenIsZero
+                        | TokenKind::TokenConvDate,
+                    TokenKind::TokenParOpen
+                )
+                | (
+                    TokenKind::TokenId
+                        | TokenKind::TokenInt
+                        | TokenKind::TokenFloat
+                        | TokenKind::TokenString
+                        | TokenKind::TokenBool,
+                    TokenKind::TokenSBOpen
+                )
+        ) && !(previous == TokenKind::TokenSub && self.negation)
+    }
+
+    /// Ends the current line, adding an empty one after it if `blank` and there is something
+    /// before
+    fn new_line(&mut self, blank: bool) {
+        if self.output.is_empty() {
+            return;
+        }
+        if !self.output.ends_with('\n') {
+            self.output.push('\n');
+        }
+        if blank && !self.output.ends_with("\n\n") {
+            self.output.push('\n');
+        }
+    }
+
+    /// Indents the line if nothing was written on it yet, otherwise adds a space if `space`
+    fn separate(&mut self, space: bool) {
+        if self.output.ends_with('\n') {
+            self.output.push_str(&INDENT.repeat(self.indent));
+        } else if space && !self.output.is_empty() {
+            self.output.push(' ');
+        }
+    }
+}
@@ -0,0 +1,564 @@
+use crate::{
+    compiler::{
+        ast::{AstAction, Node, NodeValue},
+        context::{MAX_STRING_LENGTH, SymbolTable, SymbolTableElementType},
+        error::CompilerError,
+    },
+    grammar::types::DataType,
+};
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::Display,
+    io::{BufRead, Write},
+    mem,
+    rc::Rc,
+};
+
+/// Deepest nesting of calls a program may reach before it is stopped with a stack overflow. Every
+/// call nests several frames of the interpreter, so the thread running it needs a stack of about
+/// [`INTERPRETER_STACK_SIZE`] to reach this depth
+pub const MAX_CALL_DEPTH: usize = 10_000;
+
+/// Stack size that lets a debug build of the interpreter reach [`MAX_CALL_DEPTH`]
+pub const INTERPRETER_STACK_SIZE: usize = 512 * 1024 * 1024;
+
+/// Runtime value of an expression
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f32),
+    String(String),
+    Bool(bool),
+}
+
+impl Value {
+    /// Value of a declared variable that was never assigned
+    fn default_for(data_type: &DataType) -> Self {
+        match data_type {
+            DataType::IntType(_) => Self::Int(0),
+            DataType::FloatType(_) => Self::Float(0.0),
+            DataType::StringType(_) => Self::String(String::new()),
+            DataType::BoolType(_) => Self::Bool(false),
+        }
+    }
+
+    /// Converts the value to the type of the variable it is being stored in
+    fn convert_to(self, data_type: &DataType) -> Result<Self, CompilerError> {
+        Ok(match (self, data_type) {
+            (Self::Int(value), DataType::IntType(_)) => Self::Int(value),
+            (Self::Float(value), DataType::IntType(_)) => Self::Int(value as i64),
+            (Self::Int(value), DataType::FloatType(_)) => Self::Float(value as f32),
+            (Self::Float(value), DataType::FloatType(_)) => Self::Float(value),
+            (Self::String(value), DataType::StringType(_)) => Self::String(truncate(value)),
+            (Self::Bool(value), DataType::BoolType(_)) => Self::Bool(value),
+            (value, data_type) => {
+                return Err(CompilerError::Runtime(format!(
+                    "can't store `{value}` in a variable of type {data_type}"
+                )));
+            }
+        })
+    }
+
+    fn as_float(&self) -> Option<f32> {
+        match self {
+            Self::Int(value) => Some(*value as f32),
+            Self::Float(value) => Some(*value),
+            Self::String(_) | Self::Bool(_) => None,
+        }
+    }
+}
+
+/// Cuts a string down to the characters a string variable can hold
+fn truncate(mut value: String) -> String {
+    let mut length = MAX_STRING_LENGTH.min(value.len());
+    while !value.is_char_boundary(length) {
+        length -= 1;
+    }
+    value.truncate(length);
+    value
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{value}"),
+            // Same precision used by the DisplayFloat macro in the TASM output
+            Self::Float(value) => write!(f, "{value:.2}"),
+            Self::String(value) => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Executes the AST directly, reading `read` input from `input` and writing `write` output to
+/// `output`
+pub struct Interpreter<'a, R: BufRead, W: Write> {
+    symbol_table: &'a SymbolTable,
+    /// Variables of the program or of the function being executed
+    variables: HashMap<String, Value>,
+    /// Body of every function declared so far by its name
+    functions: HashMap<String, Rc<Node>>,
+    /// Number of calls currently being executed
+    depth: usize,
+    input: R,
+    output: W,
+}
+
+impl<'a, R: BufRead, W: Write> Interpreter<'a, R, W> {
+    pub fn new(symbol_table: &'a SymbolTable, input: R, output: W) -> Self {
+        Self {
+            symbol_table,
+            variables: HashMap::new(),
+            functions: HashMap::new(),
+            depth: 0,
+            input,
+            output,
+        }
+    }
+
+    pub fn run(mut self, root: &Rc<Node>) -> Result<(), CompilerError> {
+        self.execute(root)?;
+        Ok(self.output.flush()?)
+    }
+
+    /// Executes the statement returning the value of the `return` that ended it, if any
+    fn execute(&mut self, node: &Rc<Node>) -> Result<Option<Value>, CompilerError> {
+        let NodeValue::Action(action) = &node.value else {
+            return Err(CompilerError::Internal(format!(
+                "Tried to execute {} as a statement",
+                node.value
+            )));
+        };
+        match action {
+            AstAction::S => {
+                let (left_child, right_child) =
+                    Self::get_left_and_right_child_or_error(node, "Invalid S node")?;
+                match self.execute(&left_child)? {
+                    Some(value) => Ok(Some(value)),
+                    None => self.execute(&right_child),
+                }
+            }
+            AstAction::Assign => self.execute_assign(node).map(|_| None),
+            AstAction::If => self.execute_if(node),
+            AstAction::While => self.execute_while(node),
+            AstAction::Read => self.execute_read(node).map(|_| None),
+            AstAction::Write => self.execute_write(node).map(|_| None),
+            AstAction::Function => {
+                let (left_child, right_child) =
+                    Self::get_left_and_right_child_or_error(node, "Invalid Function node")?;
+                let NodeValue::Value(name) = &left_child.value else {
+                    return Err(CompilerError::Internal(
+                        "Invalid Function node left child is not a value".into(),
+                    ));
+                };
+                self.functions.insert(name.clone(), right_child);
+                Ok(None)
+            }
+            AstAction::Call => self.call(node).map(|_| None),
+            AstAction::Return => {
+                let left_child =
+                    Self::get_left_child_or_error(node, "No left child on Return node")?;
+                self.evaluate(&left_child).map(Some)
+            }
+            AstAction::Noop => Ok(None),
+            action => Err(CompilerError::Internal(format!(
+                "Tried to execute {action} as a statement"
+            ))),
+        }
+    }
+
+    fn execute_assign(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
+        let (left_child, right_child) =
+            Self::get_left_and_right_child_or_error(node, "Invalid assignment node")?;
+        let (lhs, data_type) = self.location(&left_child)?;
+        let value = self.evaluate(&right_child)?.convert_to(&data_type)?;
+        self.variables.insert(lhs, value);
+        Ok(())
+    }
+
+    /// Key the value of a variable or array element is stored under along with its type
+    ///
+    /// Elements are stored as `name[index]` after checking the index is within the bounds of the
+    /// array
+    fn location(&mut self, node: &Rc<Node>) -> Result<(String, DataType), CompilerError> {
+        match &node.value {
+            NodeValue::Value(name) => Ok((name.clone(), self.get_variable_type(name)?)),
+            NodeValue::Action(AstAction::Index) => {
+                let (array, index) =
+                    Self::get_left_and_right_child_or_error(node, "Invalid Index node")?;
+                let NodeValue::Value(name) = &array.value else {
+                    return Err(CompilerError::Internal(
+                        "Invalid Index node left child is not a value".into(),
+                    ));
+                };
+                let length = self
+                    .symbol_table
+                    .get_array_length(name)
+                    .ok_or(CompilerError::Internal(format!("{name} is not an array")))?;
+                let Value::Int(index) = self.evaluate(&index)? else {
+                    return Err(CompilerError::Internal(
+                        "Index of an array is not an int".into(),
+                    ));
+                };
+                if usize::try_from(index).is_ok_and(|index| index < length) {
+                    Ok((format!("{name}[{index}]"), self.get_variable_type(name)?))
+                } else {
+                    Err(CompilerError::Runtime(format!(
+                        "index {index} is out of bounds for `{name}` of {length} elements"
+                    )))
+                }
+            }
+            _ => Err(CompilerError::Internal(format!(
+                "{} is not a variable",
+                node.value
+            ))),
+        }
+    }
+
+    fn execute_if(&mut self, node: &Rc<Node>) -> Result<Option<Value>, CompilerError> {
+        let (left_child, right_child) =
+            Self::get_left_and_right_child_or_error(node, "Invalid If node")?;
+        let condition = self.evaluate_condition(&left_child)?;
+
+        if let NodeValue::Action(AstAction::Else) = right_child.value {
+            let (true_body, false_body) =
+                Self::get_left_and_right_child_or_error(&right_child, "Invalid Else node")?;
+            if condition {
+                self.execute(&true_body)
+            } else {
+                self.execute(&false_body)
+            }
+        } else if condition {
+            self.execute(&right_child)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn execute_while(&mut self, node: &Rc<Node>) -> Result<Option<Value>, CompilerError> {
+        let (left_child, right_child) =
+            Self::get_left_and_right_child_or_error(node, "Invalid While node")?;
+        while self.evaluate_condition(&left_child)? {
+            if let Some(value) = self.execute(&right_child)? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Calls a function with its own variables returning the value it returned, `None` for
+    /// procedures
+    fn call(&mut self, node: &Rc<Node>) -> Result<Option<Value>, CompilerError> {
+        let (left_child, mut argument) =
+            Self::get_left_and_right_child_or_error(node, "Invalid Call node")?;
+        let NodeValue::Value(name) = &left_child.value else {
+            return Err(CompilerError::Internal(
+                "Invalid Call node left child is not a value".into(),
+            ));
+        };
+        let (Some(function), Some(body)) = (
+            self.symbol_table.get_function(name),
+            self.functions.get(name).cloned(),
+        ) else {
+            return Err(CompilerError::Internal(format!(
+                "Function {name} was called before being declared"
+            )));
+        };
+
+        // Arguments are evaluated with the variables of the caller
+        let mut variables = HashMap::new();
+        for parameter in &function.parameters {
+            let (value, next) =
+                Self::get_left_and_right_child_or_error(&argument, "Invalid Argument node")?;
+            let value = self
+                .evaluate(&value)?
+                .convert_to(&self.get_variable_type(parameter)?)?;
+            variables.insert(parameter.clone(), value);
+            argument = next;
+        }
+
+        if self.depth == MAX_CALL_DEPTH {
+            return Err(CompilerError::Runtime(format!(
+                "stack overflow, calls nested deeper than {MAX_CALL_DEPTH} levels in `{name}`"
+            )));
+        }
+        let caller_variables = mem::replace(&mut self.variables, variables);
+        self.depth += 1;
+        let value = self.execute(&body);
+        self.depth -= 1;
+        self.variables = caller_variables;
+        value
+    }
+
+    fn execute_read(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
+        let left_child = Self::get_left_child_or_error(node, "No left child on Read node")?;
+        let (name, data_type) = self.location(&left_child)?;
+
+        // Anything written so far should be visible before waiting for input
+        self.output.flush()?;
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            return Err(CompilerError::Runtime(format!(
+                "reached end of input while reading `{name}`"
+            )));
+        }
+        let line = line.trim_end_matches(['\r', '\n']);
+
+        let value = match data_type {
+            DataType::IntType(_) => Value::Int(line.trim().parse().map_err(|_| {
+                CompilerError::Runtime(format!("expected an int for `{name}` but read `{line}`"))
+            })?),
+            DataType::FloatType(_) => Value::Float(line.trim().parse().map_err(|_| {
+                CompilerError::Runtime(format!("expected a float for `{name}` but read `{line}`"))
+            })?),
+            DataType::StringType(_) => Value::String(truncate(line.to_string())),
+            DataType::BoolType(_) => {
+                return Err(CompilerError::Internal(format!(
+                    "Tried to read the bool `{name}`"
+                )));
+            }
+        };
+        self.variables.insert(name, value);
+        Ok(())
+    }
+
+    fn execute_write(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
+        let left_child = Self::get_left_child_or_error(node, "No left child on Write node")?;
+        let value = self.evaluate(&left_child)?;
+        writeln!(self.output, "{value}")?;
+        Ok(())
+    }
+
+    fn evaluate(&mut self, node: &Rc<Node>) -> Result<Value, CompilerError> {
+        let action = match &node.value {
+            NodeValue::Value(name) => return self.evaluate_value(name),
+            NodeValue::True => return Ok(Value::Bool(true)),
+            NodeValue::False => return Ok(Value::Bool(false)),
+            NodeValue::Action(action) => action,
+        };
+        match action {
+            AstAction::Plus | AstAction::Sub | AstAction::Mult | AstAction::Div => {
+                let (left_child, right_child) =
+                    Self::get_left_and_right_child_or_error(node, "Invalid arithmetic node")?;
+                let left = self.evaluate(&left_child)?;
+                let right = self.evaluate(&right_child)?;
+                Self::evaluate_arithmetic(action, left, right)
+            }
+            AstAction::Negative => {
+                let left_child =
+                    Self::get_left_child_or_error(node, "No left child on Negative node")?;
+                match self.evaluate(&left_child)? {
+                    Value::Int(value) => Ok(Value::Int(value.wrapping_neg())),
+                    Value::Float(value) => Ok(Value::Float(-value)),
+                    value @ (Value::String(_) | Value::Bool(_)) => Err(CompilerError::Internal(
+                        format!("Left child of Negative node is `{value}`"),
+                    )),
+                }
+            }
+            AstAction::IntToFloat => {
+                let left_child =
+                    Self::get_left_child_or_error(node, "No left child on IntToFloat node")?;
+                let value = self.evaluate(&left_child)?;
+                value
+                    .as_float()
+                    .map(Value::Float)
+                    .ok_or(CompilerError::Internal(
+                        "Left child of IntToFloat node is not a number".into(),
+                    ))
+            }
+            AstAction::Call => self.call(node)?.ok_or(CompilerError::Internal(
+                "Called a procedure as an expression".into(),
+            )),
+            AstAction::Index => {
+                let (key, data_type) = self.location(node)?;
+                Ok(self
+                    .variables
+                    .get(&key)
+                    .cloned()
+                    .unwrap_or_else(|| Value::default_for(&data_type)))
+            }
+            AstAction::And
+            | AstAction::Or
+            | AstAction::GT
+            | AstAction::GTE
+            | AstAction::EQ
+            | AstAction::NE
+            | AstAction::LT
+            | AstAction::LTE => self.evaluate_condition(node).map(Value::Bool),
+            action => Err(CompilerError::Internal(format!(
+                "Tried to evaluate {action} as an expression"
+            ))),
+        }
+    }
+
+    fn evaluate_value(&self, name: &str) -> Result<Value, CompilerError> {
+        let symbol =
+            self.symbol_table
+                .get_symbol_from_name(name)
+                .ok_or(CompilerError::Internal(format!(
+                    "Symbol {name} not found when evaluating NodeValue::Value"
+                )))?;
+        let literal = || {
+            symbol.value.clone().ok_or(CompilerError::Internal(format!(
+                "Constant {name} has no value in the symbol table"
+            )))
+        };
+        let invalid_literal =
+            || CompilerError::Internal(format!("Constant {name} has an invalid value"));
+
+        Ok(match &symbol.data_type {
+            SymbolTableElementType::DataType(data_type) => self
+                .variables
+                .get(name)
+                .cloned()
+                .unwrap_or_else(|| Value::default_for(data_type)),
+            SymbolTableElementType::Int => {
+                Value::Int(literal()?.parse().map_err(|_| invalid_literal())?)
+            }
+            SymbolTableElementType::Float => {
+                Value::Float(literal()?.parse().map_err(|_| invalid_literal())?)
+            }
+            SymbolTableElementType::String => Value::String(literal()?),
+            SymbolTableElementType::Bool => {
+                Value::Bool(literal()?.parse::<i64>().map_err(|_| invalid_literal())? != 0)
+            }
+            SymbolTableElementType::Array(..) => {
+                return Err(CompilerError::Internal(format!(
+                    "Array {name} evaluated without an index"
+                )));
+            }
+        })
+    }
+
+    fn evaluate_arithmetic(
+        action: &AstAction,
+        left: Value,
+        right: Value,
+    ) -> Result<Value, CompilerError> {
+        if let (Value::Int(left), Value::Int(right)) = (&left, &right) {
+            return Ok(Value::Int(match action {
+                AstAction::Plus => left.wrapping_add(*right),
+                AstAction::Sub => left.wrapping_sub(*right),
+                AstAction::Mult => left.wrapping_mul(*right),
+                _ if *right == 0 => {
+                    return Err(CompilerError::Runtime("division by zero".into()));
+                }
+                // The smallest int divided by -1 is the only quotient that doesn't fit
+                _ => left
+                    .checked_div(*right)
+                    .ok_or(CompilerError::Runtime("integer overflow".into()))?,
+            }));
+        }
+        if let (AstAction::Plus, Value::String(left), Value::String(right)) =
+            (action, &left, &right)
+        {
+            return Ok(Value::String(truncate(format!("{left}{right}"))));
+        }
+        let (Some(left), Some(right)) = (left.as_float(), right.as_float()) else {
+            return Err(CompilerError::Internal(format!(
+                "Invalid operands for {action}: {left} and {right}"
+            )));
+        };
+        Ok(Value::Float(match action {
+            AstAction::Plus => left + right,
+            AstAction::Sub => left - right,
+            AstAction::Mult => left * right,
+            _ => left / right,
+        }))
+    }
+
+    fn evaluate_condition(&mut self, node: &Rc<Node>) -> Result<bool, CompilerError> {
+        let action = match &node.value {
+            NodeValue::True => return Ok(true),
+            NodeValue::False => return Ok(false),
+            NodeValue::Value(_) => {
+                return Err(CompilerError::Internal(
+                    "Invalid condition node is a value".into(),
+                ));
+            }
+            NodeValue::Action(action) => action,
+        };
+        match action {
+            AstAction::And | AstAction::Or => {
+                let (left_child, right_child) =
+                    Self::get_left_and_right_child_or_error(node, "Invalid conjunction node")?;
+                let left = self.evaluate_condition(&left_child)?;
+                // Only evaluate the right side when the left side doesn't decide the result
+                match action {
+                    AstAction::And if !left => Ok(false),
+                    AstAction::Or if left => Ok(true),
+                    _ => self.evaluate_condition(&right_child),
+                }
+            }
+            AstAction::GT
+            | AstAction::GTE
+            | AstAction::EQ
+            | AstAction::NE
+            | AstAction::LT
+            | AstAction::LTE => {
+                let (left_child, right_child) =
+                    Self::get_left_and_right_child_or_error(node, "Invalid comparison node")?;
+                let left = self.evaluate(&left_child)?;
+                let right = self.evaluate(&right_child)?;
+                let ordering = match (&left, &right) {
+                    (Value::Int(left), Value::Int(right)) => Some(left.cmp(right)),
+                    (Value::String(left), Value::String(right)) => Some(left.cmp(right)),
+                    (Value::Bool(left), Value::Bool(right)) => Some(left.cmp(right)),
+                    _ => match (left.as_float(), right.as_float()) {
+                        (Some(left), Some(right)) => left.partial_cmp(&right),
+                        _ => {
+                            return Err(CompilerError::Internal(format!(
+                                "Invalid operands for {action}: {left} and {right}"
+                            )));
+                        }
+                    },
+                };
+                Ok(match action {
+                    AstAction::GT => ordering == Some(Ordering::Greater),
+                    AstAction::GTE => ordering.is_some_and(Ordering::is_ge),
+                    AstAction::EQ => ordering == Some(Ordering::Equal),
+                    AstAction::NE => ordering != Some(Ordering::Equal),
+                    AstAction::LT => ordering == Some(Ordering::Less),
+                    _ => ordering.is_some_and(Ordering::is_le),
+                })
+            }
+            action => Err(CompilerError::Internal(format!(
+                "Invalid action: {action} in condition"
+            ))),
+        }
+    }
+
+    fn get_variable_type(&self, name: &str) -> Result<DataType, CompilerError> {
+        match self.symbol_table.get_symbol_from_name(name) {
+            Some(symbol) => match symbol.data_type {
+                SymbolTableElementType::DataType(data_type)
+                | SymbolTableElementType::Array(data_type, _) => Ok(data_type),
+                _ => Err(CompilerError::Internal(format!("{name} is not a variable"))),
+            },
+            None => Err(CompilerError::Internal(format!(
+                "Variable {name} is not in the symbol table"
+            ))),
+        }
+    }
+
+    fn get_left_and_right_child_or_error(
+        node: &Rc<Node>,
+        err: &str,
+    ) -> Result<(Rc<Node>, Rc<Node>), CompilerError> {
+        let left_child = Self::get_left_child_or_error(node, err)?;
+        let right_child = node
+            .right_child
+            .as_ref()
+            .cloned()
+            .ok_or(CompilerError::Internal(err.into()))?;
+        Ok((left_child, right_child))
+    }
+
+    fn get_left_child_or_error(node: &Rc<Node>, err: &str) -> Result<Rc<Node>, CompilerError> {
+        node.left_child
+            .as_ref()
+            .cloned()
+            .ok_or(CompilerError::Internal(err.into()))
+    }
+}
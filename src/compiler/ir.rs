@@ -0,0 +1,874 @@
+use crate::compiler::{
+    ast::{AstAction, ExpressionType, Node, NodeValue},
+    context::{SymbolTable, SymbolTableElementType},
+    error::{CompilerError, Diagnostic},
+};
+use std::{
+    fmt::{Display, Formatter},
+    mem,
+    ops::Range,
+    rc::Rc,
+};
+
+/// Value read or written by an instruction
+#[derive(Clone, Debug, PartialEq)]
+pub enum Operand {
+    /// Variable declared by the program, by its name in the source
+    Variable(String),
+    /// Literal in the symbol table, by its value in the source
+    Constant(String, ExpressionType),
+    /// Temporary introduced by the lowering, each one is written once
+    Temp(usize),
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Variable(name) => write!(f, "{name}"),
+            Self::Constant(value, ExpressionType::String) => write!(f, "{value:?}"),
+            Self::Constant(value, _) => write!(f, "{value}"),
+            Self::Temp(temp) => write!(f, "t{temp}"),
+        }
+    }
+}
+
+/// Target of a jump
+pub type Label = usize;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Display for BinaryOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Add => write!(f, "+"),
+            Self::Sub => write!(f, "-"),
+            Self::Mul => write!(f, "*"),
+            Self::Div => write!(f, "/"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Eq => write!(f, "=="),
+            Self::Ne => write!(f, "!="),
+            Self::Lt => write!(f, "<"),
+            Self::Lte => write!(f, "<="),
+            Self::Gt => write!(f, ">"),
+            Self::Gte => write!(f, ">="),
+        }
+    }
+}
+
+/// Three address code instruction
+///
+/// Every operand of an instruction has the type of the instruction, the lowering inserts a
+/// [`Instruction::Convert`] wherever the source mixes ints and floats
+#[derive(Clone, Debug, PartialEq)]
+pub enum Instruction {
+    /// `dest = left op right`, adding strings concatenates them
+    Binary {
+        op: BinaryOp,
+        r#type: ExpressionType,
+        dest: Operand,
+        left: Operand,
+        right: Operand,
+    },
+    /// `dest = -src`
+    Negate {
+        r#type: ExpressionType,
+        dest: Operand,
+        src: Operand,
+    },
+    /// `dest = (to) src`, float to int conversions truncate
+    Convert {
+        from: ExpressionType,
+        to: ExpressionType,
+        dest: Operand,
+        src: Operand,
+    },
+    /// `dest = src`
+    Copy {
+        r#type: ExpressionType,
+        dest: Operand,
+        src: Operand,
+    },
+    /// `dest = array[index]`, `array` has `length` elements
+    Load {
+        r#type: ExpressionType,
+        dest: Operand,
+        array: String,
+        index: Operand,
+        length: usize,
+    },
+    /// `array[index] = src`, `array` has `length` elements
+    ///
+    /// Backends check that indexes which aren't constants are within the bounds of the array,
+    /// constant ones were checked by the compiler
+    Store {
+        r#type: ExpressionType,
+        array: String,
+        index: Operand,
+        src: Operand,
+        length: usize,
+    },
+    /// Reads a line from the input into `dest`
+    Read {
+        r#type: ExpressionType,
+        dest: Operand,
+    },
+    /// Writes `src` followed by a new line
+    Write {
+        r#type: ExpressionType,
+        src: Operand,
+    },
+    Label(Label),
+    Jump(Label),
+    /// Jumps to `target` if `left comparison right` holds, falls through if not
+    JumpIf {
+        comparison: Comparison,
+        r#type: ExpressionType,
+        left: Operand,
+        right: Operand,
+        target: Label,
+    },
+    /// Calls `function` with arguments of the types of its parameters storing what it returns in
+    /// `result`, `None` for procedures
+    Call {
+        function: String,
+        arguments: Vec<(Operand, ExpressionType)>,
+        result: Option<(Operand, ExpressionType)>,
+    },
+    /// Returns `src` from the function being executed
+    Return {
+        r#type: ExpressionType,
+        src: Operand,
+    },
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Binary {
+                op,
+                r#type,
+                dest,
+                left,
+                right,
+            } => write!(
+                f,
+                "    {dest}: {type_name} = {left} {op} {right}",
+                type_name = type_name(r#type)
+            ),
+            Self::Negate { r#type, dest, src } => {
+                write!(f, "    {dest}: {} = -{src}", type_name(r#type))
+            }
+            Self::Convert { to, dest, src, .. } => {
+                write!(f, "    {dest}: {0} = ({0}) {src}", type_name(to))
+            }
+            Self::Copy { dest, src, .. } => write!(f, "    {dest} = {src}"),
+            Self::Load {
+                r#type,
+                dest,
+                array,
+                index,
+                ..
+            } => write!(f, "    {dest}: {} = {array}[{index}]", type_name(r#type)),
+            Self::Store {
+                array, index, src, ..
+            } => write!(f, "    {array}[{index}] = {src}"),
+            Self::Read { dest, .. } => write!(f, "    read {dest}"),
+            Self::Write { src, .. } => write!(f, "    write {src}"),
+            Self::Label(label) => write!(f, "L{label}:"),
+            Self::Jump(label) => write!(f, "    goto L{label}"),
+            Self::JumpIf {
+                comparison,
+                left,
+                right,
+                target,
+                ..
+            } => write!(f, "    if {left} {comparison} {right} goto L{target}"),
+            Self::Call {
+                function,
+                arguments,
+                result,
+            } => {
+                let arguments = arguments
+                    .iter()
+                    .map(|(argument, _)| argument.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match result {
+                    Some((dest, r#type)) => write!(
+                        f,
+                        "    {dest}: {} = call {function}({arguments})",
+                        type_name(r#type)
+                    ),
+                    None => write!(f, "    call {function}({arguments})"),
+                }
+            }
+            Self::Return { src, .. } => write!(f, "    return {src}"),
+        }
+    }
+}
+
+fn type_name(r#type: &ExpressionType) -> &'static str {
+    match r#type {
+        ExpressionType::Float => "float",
+        ExpressionType::Int => "int",
+        ExpressionType::String => "string",
+        ExpressionType::Bool => "bool",
+    }
+}
+
+/// Linear representation of a program consumed by the backends
+#[derive(Clone, Debug, Default)]
+pub struct IrProgram {
+    pub instructions: Vec<Instruction>,
+    /// Source each instruction was lowered from, in the same order as the instructions
+    pub spans: Vec<Range<usize>>,
+    /// Type of each temporary indexed by its number
+    pub temps: Vec<ExpressionType>,
+    /// Number of labels used by the program, labels are shared with the functions
+    pub labels: usize,
+    pub functions: Vec<IrFunction>,
+}
+
+impl Display for IrProgram {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for instruction in &self.instructions {
+            writeln!(f, "{instruction}")?;
+        }
+        for function in &self.functions {
+            writeln!(f)?;
+            write!(f, "{function}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Function or procedure of the program, its temporaries are numbered on their own
+#[derive(Clone, Debug, Default)]
+pub struct IrFunction {
+    pub name: String,
+    /// Source of the whole function
+    pub span: Range<usize>,
+    /// Variables the arguments are stored in, in the order they are passed
+    pub parameters: Vec<(String, ExpressionType)>,
+    /// Variables declared in the body
+    pub locals: Vec<(String, ExpressionType)>,
+    /// `None` for procedures
+    pub return_type: Option<ExpressionType>,
+    pub instructions: Vec<Instruction>,
+    /// Source each instruction was lowered from, in the same order as the instructions
+    pub spans: Vec<Range<usize>>,
+    /// Type of each temporary indexed by its number
+    pub temps: Vec<ExpressionType>,
+}
+
+impl Display for IrFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|(name, r#type)| format!("{name}: {}", type_name(r#type)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match &self.return_type {
+            Some(r#type) => writeln!(
+                f,
+                "function {}({parameters}): {}",
+                self.name,
+                type_name(r#type)
+            )?,
+            None => writeln!(f, "procedure {}({parameters})", self.name)?,
+        }
+        for instruction in &self.instructions {
+            writeln!(f, "{instruction}")?;
+        }
+        Ok(())
+    }
+}
+
+impl IrProgram {
+    /// Lowers the AST rooted at `root` to three address code, an error points at the node that
+    /// couldn't be lowered
+    pub fn lower(root: &Rc<Node>, symbol_table: &SymbolTable) -> Result<Self, Box<Diagnostic>> {
+        let mut lowering = Lowering {
+            program: Self::default(),
+            function: None,
+            symbol_table,
+            span: root.span.range.clone(),
+        };
+        if let Err(error) = lowering.lower_statement(root) {
+            return Err(Box::new(Diagnostic::new(lowering.span, error, true)));
+        }
+        let mut program = lowering.program;
+        remove_redundant_jumps(&mut program.instructions, &mut program.spans);
+        Ok(program)
+    }
+}
+
+/// Removes jumps to the label right after them along with their spans
+fn remove_redundant_jumps(instructions: &mut Vec<Instruction>, spans: &mut Vec<Range<usize>>) {
+    let redundant = instructions
+        .windows(2)
+        .map(|pair| matches!(pair, [Instruction::Jump(to), Instruction::Label(label)] if to == label))
+        .collect::<Vec<_>>();
+    let mut redundant_instructions = redundant.iter();
+    instructions.retain(|_| !redundant_instructions.next().copied().unwrap_or_default());
+    let mut redundant_spans = redundant.iter();
+    spans.retain(|_| !redundant_spans.next().copied().unwrap_or_default());
+}
+
+struct Lowering<'a> {
+    program: IrProgram,
+    /// Function being lowered, instructions and temporaries go to the program when `None`
+    function: Option<IrFunction>,
+    symbol_table: &'a SymbolTable,
+    /// Source of the node being lowered, given to the instructions emitted for it
+    span: Range<usize>,
+}
+
+impl Lowering<'_> {
+    /// Runs `lower` with the span of `node` as the current one, which is left as it is on an
+    /// error so it points at the innermost node being lowered
+    fn within<T>(
+        &mut self,
+        node: &Node,
+        lower: impl FnOnce(&mut Self) -> Result<T, CompilerError>,
+    ) -> Result<T, CompilerError> {
+        let outer = mem::replace(&mut self.span, node.span.range.clone());
+        let result = lower(self)?;
+        self.span = outer;
+        Ok(result)
+    }
+
+    fn lower_statement(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
+        self.within(node, |lowering| lowering.statement(node))
+    }
+
+    fn statement(&mut self, node: &Rc<Node>) -> Result<(), CompilerError> {
+        let NodeValue::Action(action) = &node.value else {
+            return Err(CompilerError::Internal(format!(
+                "Tried to lower {} as a statement",
+                node.value
+            )));
+        };
+        match action {
+            AstAction::S => {
+                let (left_child, right_child) = children(node, "Invalid S node")?;
+                self.lower_statement(&left_child)?;
+                self.lower_statement(&right_child)?;
+            }
+            AstAction::Assign => {
+                let (left_child, right_child) = children(node, "Invalid assignment node")?;
+                if let NodeValue::Action(AstAction::Index) = left_child.value {
+                    let (array, index, length, r#type) = self.lower_index(&left_child)?;
+                    let (src, src_type) = self.lower_expression(&right_child)?;
+                    let src = self.convert(src, &src_type, &r#type);
+                    self.emit(Instruction::Store {
+                        r#type,
+                        array,
+                        index,
+                        src,
+                        length,
+                    });
+                } else {
+                    let (dest, r#type) = self.lower_expression(&left_child)?;
+                    let (src, src_type) = self.lower_expression(&right_child)?;
+                    let src = self.convert(src, &src_type, &r#type);
+                    self.emit(Instruction::Copy { r#type, dest, src });
+                }
+            }
+            AstAction::If => {
+                let (condition, right_child) = children(node, "Invalid If node")?;
+                let label_then = self.new_label();
+                let label_end = self.new_label();
+                if let NodeValue::Action(AstAction::Else) = right_child.value {
+                    let (true_body, false_body) = children(&right_child, "Invalid Else node")?;
+                    let label_else = self.new_label();
+                    self.lower_condition(&condition, label_then, label_else)?;
+                    self.emit(Instruction::Label(label_then));
+                    self.lower_statement(&true_body)?;
+                    self.emit(Instruction::Jump(label_end));
+                    self.emit(Instruction::Label(label_else));
+                    self.lower_statement(&false_body)?;
+                } else {
+                    self.lower_condition(&condition, label_then, label_end)?;
+                    self.emit(Instruction::Label(label_then));
+                    self.lower_statement(&right_child)?;
+                }
+                self.emit(Instruction::Label(label_end));
+            }
+            AstAction::While => {
+                let (condition, body) = children(node, "Invalid While node")?;
+                let label_cond = self.new_label();
+                let label_body = self.new_label();
+                let label_end = self.new_label();
+                self.emit(Instruction::Label(label_cond));
+                self.lower_condition(&condition, label_body, label_end)?;
+                self.emit(Instruction::Label(label_body));
+                self.lower_statement(&body)?;
+                self.emit(Instruction::Jump(label_cond));
+                self.emit(Instruction::Label(label_end));
+            }
+            AstAction::Read => {
+                let left_child = left_child(node, "No left child on Read node")?;
+                if let NodeValue::Action(AstAction::Index) = left_child.value {
+                    // Elements are read into a temporary and then stored in the array
+                    let (array, index, length, r#type) = self.lower_index(&left_child)?;
+                    let src = self.new_temp(&r#type);
+                    self.emit(Instruction::Read {
+                        r#type: r#type.clone(),
+                        dest: src.clone(),
+                    });
+                    self.emit(Instruction::Store {
+                        r#type,
+                        array,
+                        index,
+                        src,
+                        length,
+                    });
+                } else {
+                    let (dest, r#type) = self.lower_expression(&left_child)?;
+                    self.emit(Instruction::Read { r#type, dest });
+                }
+            }
+            AstAction::Write => {
+                let left_child = left_child(node, "No left child on Write node")?;
+                let (src, r#type) = self.lower_expression(&left_child)?;
+                self.emit(Instruction::Write { r#type, src });
+            }
+            AstAction::Function => {
+                let (name, body) = children(node, "Invalid Function node")?;
+                let NodeValue::Value(name) = &name.value else {
+                    return Err(CompilerError::Internal(
+                        "Invalid Function node left child is not a value".into(),
+                    ));
+                };
+                let signature =
+                    self.symbol_table
+                        .get_function(name)
+                        .ok_or(CompilerError::Internal(format!(
+                            "Function {name} is not in the symbol table"
+                        )))?;
+                self.function = Some(IrFunction {
+                    name: name.clone(),
+                    span: self.span.clone(),
+                    parameters: self.typed_variables(&signature.parameters)?,
+                    locals: self.typed_variables(&signature.locals)?,
+                    return_type: signature.return_type.clone(),
+                    ..Default::default()
+                });
+                self.lower_statement(&body)?;
+                if let Some(mut function) = self.function.take() {
+                    remove_redundant_jumps(&mut function.instructions, &mut function.spans);
+                    self.program.functions.push(function);
+                }
+            }
+            AstAction::Call => {
+                self.lower_call(node)?;
+            }
+            AstAction::Return => {
+                let left_child = left_child(node, "No left child on Return node")?;
+                let (src, src_type) = self.lower_expression(&left_child)?;
+                let r#type = self
+                    .function
+                    .as_ref()
+                    .and_then(|function| function.return_type.clone())
+                    .ok_or(CompilerError::Internal(
+                        "Return outside of a function".into(),
+                    ))?;
+                let src = self.convert(src, &src_type, &r#type);
+                self.emit(Instruction::Return { r#type, src });
+            }
+            AstAction::Noop => {}
+            action => {
+                return Err(CompilerError::Internal(format!(
+                    "Tried to lower {action} as a statement"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Emits the jumps that go to `label_true` if the condition holds and to `label_false` if not
+    fn lower_condition(
+        &mut self,
+        node: &Rc<Node>,
+        label_true: Label,
+        label_false: Label,
+    ) -> Result<(), CompilerError> {
+        self.within(node, |lowering| {
+            lowering.condition(node, label_true, label_false)
+        })
+    }
+
+    fn condition(
+        &mut self,
+        node: &Rc<Node>,
+        label_true: Label,
+        label_false: Label,
+    ) -> Result<(), CompilerError> {
+        let action = match &node.value {
+            NodeValue::True => {
+                self.emit(Instruction::Jump(label_true));
+                return Ok(());
+            }
+            NodeValue::False => {
+                self.emit(Instruction::Jump(label_false));
+                return Ok(());
+            }
+            NodeValue::Value(_) => {
+                return Err(CompilerError::Internal(
+                    "Invalid condition node is a value".into(),
+                ));
+            }
+            NodeValue::Action(action) => action,
+        };
+        let comparison = match action {
+            AstAction::And | AstAction::Or => {
+                let (left_child, right_child) = children(node, "Invalid conjunction node")?;
+                let label_right = self.new_label();
+                // The right side is only evaluated when the left side doesn't decide the result
+                if let AstAction::And = action {
+                    self.lower_condition(&left_child, label_right, label_false)?;
+                } else {
+                    self.lower_condition(&left_child, label_true, label_right)?;
+                }
+                self.emit(Instruction::Label(label_right));
+                return self.lower_condition(&right_child, label_true, label_false);
+            }
+            AstAction::EQ => Comparison::Eq,
+            AstAction::NE => Comparison::Ne,
+            AstAction::LT => Comparison::Lt,
+            AstAction::LTE => Comparison::Lte,
+            AstAction::GT => Comparison::Gt,
+            AstAction::GTE => Comparison::Gte,
+            action => {
+                return Err(CompilerError::Internal(format!(
+                    "Invalid action: {action} in condition"
+                )));
+            }
+        };
+        let (left_child, right_child) = children(node, "Invalid comparison node")?;
+        let (r#type, left, right) = self.lower_operands(&left_child, &right_child)?;
+        self.emit(Instruction::JumpIf {
+            comparison,
+            r#type,
+            left,
+            right,
+            target: label_true,
+        });
+        self.emit(Instruction::Jump(label_false));
+        Ok(())
+    }
+
+    fn lower_expression(
+        &mut self,
+        node: &Rc<Node>,
+    ) -> Result<(Operand, ExpressionType), CompilerError> {
+        self.within(node, |lowering| lowering.expression(node))
+    }
+
+    fn expression(&mut self, node: &Rc<Node>) -> Result<(Operand, ExpressionType), CompilerError> {
+        let action = match &node.value {
+            NodeValue::Value(name) => return self.lower_value(name),
+            NodeValue::True => return self.lower_value("true"),
+            NodeValue::False => return self.lower_value("false"),
+            NodeValue::Action(action) => action,
+        };
+        let op = match action {
+            AstAction::Plus => BinaryOp::Add,
+            AstAction::Sub => BinaryOp::Sub,
+            AstAction::Mult => BinaryOp::Mul,
+            AstAction::Div => BinaryOp::Div,
+            AstAction::Negative => {
+                let left_child = left_child(node, "No left child on Negative node")?;
+                let (src, r#type) = self.lower_expression(&left_child)?;
+                let dest = self.new_temp(&r#type);
+                self.emit(Instruction::Negate {
+                    r#type: r#type.clone(),
+                    dest: dest.clone(),
+                    src,
+                });
+                return Ok((dest, r#type));
+            }
+            AstAction::IntToFloat => {
+                let left_child = left_child(node, "No left child on IntToFloat node")?;
+                let (src, r#type) = self.lower_expression(&left_child)?;
+                let dest = self.convert(src, &r#type, &ExpressionType::Float);
+                return Ok((dest, ExpressionType::Float));
+            }
+            AstAction::Call => {
+                return self.lower_call(node)?.ok_or(CompilerError::Internal(
+                    "Called a procedure as an expression".into(),
+                ));
+            }
+            AstAction::Index => {
+                let (array, index, length, r#type) = self.lower_index(node)?;
+                let dest = self.new_temp(&r#type);
+                self.emit(Instruction::Load {
+                    r#type: r#type.clone(),
+                    dest: dest.clone(),
+                    array,
+                    index,
+                    length,
+                });
+                return Ok((dest, r#type));
+            }
+            AstAction::And
+            | AstAction::Or
+            | AstAction::GT
+            | AstAction::GTE
+            | AstAction::EQ
+            | AstAction::NE
+            | AstAction::LT
+            | AstAction::LTE => return self.lower_bool(node),
+            action => {
+                return Err(CompilerError::Internal(format!(
+                    "Tried to lower {action} as an expression"
+                )));
+            }
+        };
+        let (left_child, right_child) = children(node, "Invalid arithmetic node")?;
+        let (r#type, left, right) = self.lower_operands(&left_child, &right_child)?;
+        // Adding strings concatenates them, no other arithmetic applies to strings or bools
+        if (r#type == ExpressionType::String && op != BinaryOp::Add)
+            || r#type == ExpressionType::Bool
+        {
+            return Err(CompilerError::Internal(format!(
+                "Invalid operands for {action}: {type} and {type}"
+            )));
+        }
+        let dest = self.new_temp(&r#type);
+        self.emit(Instruction::Binary {
+            op,
+            r#type: r#type.clone(),
+            dest: dest.clone(),
+            left,
+            right,
+        });
+        Ok((dest, r#type))
+    }
+
+    /// Stores the result of a condition in a temporary as `true` or `false`
+    fn lower_bool(&mut self, node: &Rc<Node>) -> Result<(Operand, ExpressionType), CompilerError> {
+        let r#type = ExpressionType::Bool;
+        let dest = self.new_temp(&r#type);
+        let label_true = self.new_label();
+        let label_false = self.new_label();
+        let label_end = self.new_label();
+        self.lower_condition(node, label_true, label_false)?;
+        for (label, value) in [(label_true, "true"), (label_false, "false")] {
+            let (src, _) = self.lower_value(value)?;
+            self.emit(Instruction::Label(label));
+            self.emit(Instruction::Copy {
+                r#type: r#type.clone(),
+                dest: dest.clone(),
+                src,
+            });
+            self.emit(Instruction::Jump(label_end));
+        }
+        self.emit(Instruction::Label(label_end));
+        Ok((dest, r#type))
+    }
+
+    /// Lowers both operands of a binary operation converting ints to floats if they are mixed
+    fn lower_operands(
+        &mut self,
+        left_child: &Rc<Node>,
+        right_child: &Rc<Node>,
+    ) -> Result<(ExpressionType, Operand, Operand), CompilerError> {
+        let (left, left_type) = self.lower_expression(left_child)?;
+        let (right, right_type) = self.lower_expression(right_child)?;
+        let r#type = match (&left_type, &right_type) {
+            (ExpressionType::String, ExpressionType::String) => ExpressionType::String,
+            (ExpressionType::Int, ExpressionType::Int) => ExpressionType::Int,
+            (ExpressionType::Bool, ExpressionType::Bool) => ExpressionType::Bool,
+            (ExpressionType::String | ExpressionType::Bool, _)
+            | (_, ExpressionType::String | ExpressionType::Bool) => {
+                return Err(CompilerError::Internal(format!(
+                    "Invalid operands: {left_type} and {right_type}"
+                )));
+            }
+            _ => ExpressionType::Float,
+        };
+        let left = self.convert(left, &left_type, &r#type);
+        let right = self.convert(right, &right_type, &r#type);
+        Ok((r#type, left, right))
+    }
+
+    fn lower_value(&mut self, name: &str) -> Result<(Operand, ExpressionType), CompilerError> {
+        let symbol =
+            self.symbol_table
+                .get_symbol_from_name(name)
+                .ok_or(CompilerError::Internal(format!(
+                    "Symbol {name} is not in the symbol table"
+                )))?;
+        Ok(match symbol.data_type {
+            SymbolTableElementType::DataType(data_type) => {
+                (Operand::Variable(symbol.original), data_type.into())
+            }
+            literal_type => {
+                let r#type = ExpressionType::from(literal_type);
+                (Operand::Constant(symbol.original, r#type.clone()), r#type)
+            }
+        })
+    }
+
+    /// Lowers the index of an array access returning the array, the index, the number of
+    /// elements of the array and their type
+    fn lower_index(
+        &mut self,
+        node: &Rc<Node>,
+    ) -> Result<(String, Operand, usize, ExpressionType), CompilerError> {
+        let (array, index) = children(node, "Invalid Index node")?;
+        let NodeValue::Value(array) = &array.value else {
+            return Err(CompilerError::Internal(
+                "Invalid Index node left child is not a value".into(),
+            ));
+        };
+        let (Some(length), Some(r#type)) = (
+            self.symbol_table.get_array_length(array),
+            self.symbol_table.get_variable_type(array),
+        ) else {
+            return Err(CompilerError::Internal(format!(
+                "Array {array} is not in the symbol table"
+            )));
+        };
+        let (index, index_type) = self.lower_expression(&index)?;
+        if index_type != ExpressionType::Int {
+            return Err(CompilerError::Internal(format!(
+                "Index of {array} is {index_type}"
+            )));
+        }
+        Ok((array.clone(), index, length, r#type))
+    }
+
+    /// Lowers the arguments and emits the call, returning where the result was stored
+    fn lower_call(
+        &mut self,
+        node: &Rc<Node>,
+    ) -> Result<Option<(Operand, ExpressionType)>, CompilerError> {
+        let (name, mut argument) = children(node, "Invalid Call node")?;
+        let NodeValue::Value(name) = &name.value else {
+            return Err(CompilerError::Internal(
+                "Invalid Call node left child is not a value".into(),
+            ));
+        };
+        let symbol_table = self.symbol_table;
+        let signature = symbol_table
+            .get_function(name)
+            .ok_or(CompilerError::Internal(format!(
+                "Function {name} is not in the symbol table"
+            )))?;
+
+        let mut arguments = Vec::new();
+        for (_, parameter_type) in self.typed_variables(&signature.parameters)? {
+            let (value, next) = children(&argument, "Invalid Argument node")?;
+            let (src, r#type) = self.lower_expression(&value)?;
+            let src = self.convert(src, &r#type, &parameter_type);
+            arguments.push((src, parameter_type));
+            argument = next;
+        }
+        let result = signature
+            .return_type
+            .as_ref()
+            .map(|r#type| (self.new_temp(r#type), r#type.clone()));
+        self.emit(Instruction::Call {
+            function: name.clone(),
+            arguments,
+            result: result.clone(),
+        });
+        Ok(result)
+    }
+
+    /// Pairs the variables stored under `keys` with their types
+    fn typed_variables(
+        &self,
+        keys: &[String],
+    ) -> Result<Vec<(String, ExpressionType)>, CompilerError> {
+        keys.iter()
+            .map(|key| {
+                self.symbol_table
+                    .get_variable_type(key)
+                    .map(|r#type| (key.clone(), r#type))
+                    .ok_or(CompilerError::Internal(format!(
+                        "Variable {key} is not in the symbol table"
+                    )))
+            })
+            .collect()
+    }
+
+    fn convert(&mut self, src: Operand, from: &ExpressionType, to: &ExpressionType) -> Operand {
+        if from == to {
+            return src;
+        }
+        let dest = self.new_temp(to);
+        self.emit(Instruction::Convert {
+            from: from.clone(),
+            to: to.clone(),
+            dest: dest.clone(),
+            src,
+        });
+        dest
+    }
+
+    fn emit(&mut self, instruction: Instruction) {
+        let (instructions, spans) = match &mut self.function {
+            Some(function) => (&mut function.instructions, &mut function.spans),
+            None => (&mut self.program.instructions, &mut self.program.spans),
+        };
+        instructions.push(instruction);
+        spans.push(self.span.clone());
+    }
+
+    fn new_temp(&mut self, r#type: &ExpressionType) -> Operand {
+        let temps = match &mut self.function {
+            Some(function) => &mut function.temps,
+            None => &mut self.program.temps,
+        };
+        temps.push(r#type.clone());
+        Operand::Temp(temps.len() - 1)
+    }
+
+    fn new_label(&mut self) -> Label {
+        self.program.labels += 1;
+        self.program.labels - 1
+    }
+}
+
+fn children(node: &Rc<Node>, err: &str) -> Result<(Rc<Node>, Rc<Node>), CompilerError> {
+    let right_child = node
+        .right_child
+        .as_ref()
+        .cloned()
+        .ok_or(CompilerError::Internal(err.into()))?;
+    Ok((left_child(node, err)?, right_child))
+}
+
+fn left_child(node: &Rc<Node>, err: &str) -> Result<Rc<Node>, CompilerError> {
+    node.left_child
+        .as_ref()
+        .cloned()
+        .ok_or(CompilerError::Internal(err.into()))
+}
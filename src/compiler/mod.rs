@@ -1,4 +1,9 @@
 pub mod asm;
 pub mod ast;
 pub mod context;
+pub mod driver;
 pub mod error;
+pub mod formatter;
+pub mod interpreter;
+pub mod ir;
+pub mod optimizer;
@@ -0,0 +1,339 @@
+use crate::{
+    compiler::{
+        ast::{AstAction, ExpressionType, Node, NodeValue, Span},
+        context::{SymbolTable, SymbolTableElement, SymbolTableElementType},
+    },
+    grammar::types::{TokenFloatLiteral, TokenIntLiteral},
+};
+use std::rc::Rc;
+
+/// Returns an equivalent tree where constant subtrees are folded, operations that don't change
+/// their operand (`*1`, `+0`, double negation) are removed and branches that can never run are
+/// dropped
+///
+/// The literals created by folding are added to `symbol_table` so the backends can find them
+pub fn optimize(root: &Rc<Node>, symbol_table: &mut SymbolTable) -> Rc<Node> {
+    Optimizer { symbol_table }.fold(root)
+}
+
+/// Value of an expression known at compile time
+#[derive(Clone, Copy)]
+enum Constant {
+    Int(i64),
+    Float(f32),
+    Bool(bool),
+}
+
+impl Constant {
+    fn as_float(self) -> Option<f32> {
+        match self {
+            Self::Int(value) => Some(value as f32),
+            Self::Float(value) => Some(value),
+            Self::Bool(_) => None,
+        }
+    }
+
+    fn is(self, number: i64) -> bool {
+        match self {
+            Self::Int(value) => value == number,
+            Self::Float(value) => value == number as f32,
+            Self::Bool(_) => false,
+        }
+    }
+}
+
+struct Optimizer<'a> {
+    symbol_table: &'a mut SymbolTable,
+}
+
+impl Optimizer<'_> {
+    /// Optimizes the children of `node` first so the rules only have to look one level down
+    fn fold(&mut self, node: &Rc<Node>) -> Rc<Node> {
+        let (NodeValue::Action(action), Some(left), Some(right)) =
+            (&node.value, &node.left_child, &node.right_child)
+        else {
+            return node.clone();
+        };
+        let left = self.fold(left);
+        let right = self.fold(right);
+
+        let node = Node::new_action(
+            action.clone(),
+            left,
+            right,
+            node.r#type.clone(),
+            node.span.clone(),
+        );
+        self.simplify(&node).unwrap_or(node)
+    }
+
+    /// Rewrites a node whose children are already optimized, `None` if no rule applies
+    fn simplify(&mut self, node: &Rc<Node>) -> Option<Rc<Node>> {
+        let (NodeValue::Action(action), Some(left), Some(right)) =
+            (&node.value, &node.left_child, &node.right_child)
+        else {
+            return None;
+        };
+        match action {
+            AstAction::Plus | AstAction::Sub | AstAction::Mult | AstAction::Div => {
+                if let Some(result) = self.constant(node)
+                    && let Some(folded) = self.constant_node(result, &node.span)
+                {
+                    return Some(folded);
+                }
+                // The operand is kept only when it already has the type of the result, ints
+                // added to a float still need their conversion
+                let same_type = |operand: &Rc<Node>| operand.r#type == node.r#type;
+                match (action, (self.constant(left), self.constant(right))) {
+                    (AstAction::Plus, (Some(zero), _)) if zero.is(0) && same_type(right) => {
+                        Some(right.clone())
+                    }
+                    (AstAction::Mult, (Some(one), _)) if one.is(1) && same_type(right) => {
+                        Some(right.clone())
+                    }
+                    (AstAction::Plus | AstAction::Sub, (_, Some(zero)))
+                        if zero.is(0) && same_type(left) =>
+                    {
+                        Some(left.clone())
+                    }
+                    (AstAction::Mult | AstAction::Div, (_, Some(one)))
+                        if one.is(1) && same_type(left) =>
+                    {
+                        Some(left.clone())
+                    }
+                    _ => None,
+                }
+            }
+            AstAction::Negative => {
+                if let NodeValue::Action(AstAction::Negative) = left.value {
+                    return left.left_child.clone();
+                }
+                // A negated literal is already how negative constants are written
+                left.left_child.as_ref()?;
+                let result = self.constant(node)?;
+                self.constant_node(result, &node.span)
+            }
+            AstAction::IntToFloat
+            | AstAction::GT
+            | AstAction::GTE
+            | AstAction::EQ
+            | AstAction::NE
+            | AstAction::LT
+            | AstAction::LTE => {
+                let result = self.constant(node)?;
+                self.constant_node(result, &node.span)
+            }
+            // The right side is only evaluated when the left side doesn't decide the result, so
+            // it can be dropped but the left side can't
+            AstAction::And => match self.constant(left) {
+                Some(Constant::Bool(true)) => Some(right.clone()),
+                Some(Constant::Bool(false)) => Some(left.clone()),
+                _ => match self.constant(right) {
+                    Some(Constant::Bool(true)) => Some(left.clone()),
+                    _ => None,
+                },
+            },
+            AstAction::Or => match self.constant(left) {
+                Some(Constant::Bool(true)) => Some(left.clone()),
+                Some(Constant::Bool(false)) => Some(right.clone()),
+                _ => match self.constant(right) {
+                    Some(Constant::Bool(false)) => Some(left.clone()),
+                    _ => None,
+                },
+            },
+            AstAction::If => {
+                let taken = match self.constant(left)? {
+                    Constant::Bool(taken) => taken,
+                    _ => return None,
+                };
+                let branch = match (&right.value, taken) {
+                    (NodeValue::Action(AstAction::Else), true) => right.left_child.clone(),
+                    (NodeValue::Action(AstAction::Else), false) => right.right_child.clone(),
+                    (_, true) => Some(right.clone()),
+                    (_, false) => None,
+                };
+                Some(branch.unwrap_or_else(|| noop(&node.span)))
+            }
+            AstAction::While => match self.constant(left)? {
+                Constant::Bool(false) => Some(noop(&node.span)),
+                _ => None,
+            },
+            AstAction::S => match (&left.value, &right.value) {
+                (NodeValue::Action(AstAction::Noop), _) => Some(right.clone()),
+                (_, NodeValue::Action(AstAction::Noop)) => Some(left.clone()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn constant(&self, node: &Node) -> Option<Constant> {
+        evaluate(node, self.symbol_table)
+    }
+
+    /// Builds the literal for `constant` the same way the parser does, negative numbers are a
+    /// negation of a positive literal
+    fn constant_node(&mut self, constant: Constant, span: &Span) -> Option<Rc<Node>> {
+        let (literal, negative) = match constant {
+            Constant::Bool(value) => {
+                self.symbol_table
+                    .insert(SymbolTableElement::bool_literal(value));
+                let value = if value {
+                    NodeValue::True
+                } else {
+                    NodeValue::False
+                };
+                return Some(Rc::new(Node::new_leaf(
+                    value,
+                    Some(ExpressionType::Bool),
+                    span.clone(),
+                )));
+            }
+            Constant::Int(value) => {
+                let parsed = i64::try_from(value.unsigned_abs()).ok()?;
+                let original = parsed.to_string();
+                self.symbol_table
+                    .insert(TokenIntLiteral { original, parsed }.into());
+                let leaf = Node::new_leaf(
+                    NodeValue::Value(parsed.to_string()),
+                    Some(ExpressionType::Int),
+                    span.clone(),
+                );
+                (leaf, value < 0)
+            }
+            Constant::Float(value) => {
+                // Results that can't be written as a literal are left to be computed at runtime
+                if !value.is_finite() {
+                    return None;
+                }
+                let parsed = value.abs();
+                let mut original = parsed.to_string();
+                if !original.contains('.') {
+                    original.push_str(".0");
+                }
+                let leaf = Node::new_leaf(
+                    NodeValue::Value(original.clone()),
+                    Some(ExpressionType::Float),
+                    span.clone(),
+                );
+                self.symbol_table
+                    .insert(TokenFloatLiteral { original, parsed }.into());
+                (leaf, value.is_sign_negative())
+            }
+        };
+
+        let r#type = literal.r#type.clone();
+        let literal = Rc::new(literal);
+        Some(if negative {
+            Node::new_action(
+                AstAction::Negative,
+                literal,
+                noop(span),
+                r#type,
+                span.clone(),
+            )
+        } else {
+            literal
+        })
+    }
+}
+
+/// Value of `condition` if it doesn't depend on anything known only at runtime
+pub fn constant_condition(condition: &Node, symbol_table: &SymbolTable) -> Option<bool> {
+    match evaluate(condition, symbol_table)? {
+        Constant::Bool(value) => Some(value),
+        _ => None,
+    }
+}
+
+/// Value of `node` if it is made only of literals
+fn evaluate(node: &Node, symbol_table: &SymbolTable) -> Option<Constant> {
+    let action = match &node.value {
+        NodeValue::True => return Some(Constant::Bool(true)),
+        NodeValue::False => return Some(Constant::Bool(false)),
+        NodeValue::Value(name) => {
+            let symbol = symbol_table.get_symbol_from_name(name)?;
+            let value = symbol.value?;
+            return match symbol.data_type {
+                SymbolTableElementType::Int => value.parse().ok().map(Constant::Int),
+                SymbolTableElementType::Float => value.parse().ok().map(Constant::Float),
+                _ => None,
+            };
+        }
+        NodeValue::Action(action) => action,
+    };
+    let left = || evaluate(node.left_child.as_ref()?, symbol_table);
+    let right = || evaluate(node.right_child.as_ref()?, symbol_table);
+    match action {
+        AstAction::Plus | AstAction::Sub | AstAction::Mult | AstAction::Div => {
+            arithmetic(action, left()?, right()?)
+        }
+        AstAction::Negative => match left()? {
+            Constant::Int(value) => value.checked_neg().map(Constant::Int),
+            Constant::Float(value) => Some(Constant::Float(-value)),
+            Constant::Bool(_) => None,
+        },
+        AstAction::IntToFloat => match left()? {
+            Constant::Int(value) => Some(Constant::Float(value as f32)),
+            _ => None,
+        },
+        AstAction::GT
+        | AstAction::GTE
+        | AstAction::EQ
+        | AstAction::NE
+        | AstAction::LT
+        | AstAction::LTE => comparison(action, left()?, right()?).map(Constant::Bool),
+        AstAction::And => match left()? {
+            Constant::Bool(true) => right(),
+            constant => Some(constant),
+        },
+        AstAction::Or => match left()? {
+            Constant::Bool(false) => right(),
+            constant => Some(constant),
+        },
+        _ => None,
+    }
+}
+
+/// Result of the arithmetic operation, `None` if it has to fail at runtime
+fn arithmetic(action: &AstAction, left: Constant, right: Constant) -> Option<Constant> {
+    if let (Constant::Int(left), Constant::Int(right)) = (left, right) {
+        return Some(Constant::Int(match action {
+            AstAction::Plus => left.wrapping_add(right),
+            AstAction::Sub => left.wrapping_sub(right),
+            AstAction::Mult => left.wrapping_mul(right),
+            _ => left.checked_div(right)?,
+        }));
+    }
+    let (left, right) = (left.as_float()?, right.as_float()?);
+    Some(Constant::Float(match action {
+        AstAction::Plus => left + right,
+        AstAction::Sub => left - right,
+        AstAction::Mult => left * right,
+        _ => left / right,
+    }))
+}
+
+fn comparison(action: &AstAction, left: Constant, right: Constant) -> Option<bool> {
+    let ordering = match (left, right) {
+        (Constant::Int(left), Constant::Int(right)) => left.partial_cmp(&right),
+        (Constant::Bool(left), Constant::Bool(right)) => left.partial_cmp(&right),
+        (left, right) => left.as_float()?.partial_cmp(&right.as_float()?),
+    }?;
+    Some(match action {
+        AstAction::GT => ordering.is_gt(),
+        AstAction::GTE => ordering.is_ge(),
+        AstAction::EQ => ordering.is_eq(),
+        AstAction::NE => ordering.is_ne(),
+        AstAction::LT => ordering.is_lt(),
+        _ => ordering.is_le(),
+    })
+}
+
+fn noop(span: &Span) -> Rc<Node> {
+    Rc::new(Node::new_leaf(
+        NodeValue::Action(AstAction::Noop),
+        None,
+        span.clone(),
+    ))
+}
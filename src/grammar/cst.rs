@@ -0,0 +1,286 @@
+use crate::{
+    compiler::{context::Compiler, error::Diagnostic},
+    grammar::{
+        NonTermKind, RulesParser, TokenKind,
+        rules::{Context, Input, ProdKind, State},
+    },
+    lexer::tokens::{self, Trivia, TriviaKind, tokenize},
+};
+use rustemo::{Builder, LRBuilder, Parser, Token};
+use std::{
+    fmt::{self, Display, Write},
+    ops::Range,
+    vec,
+};
+
+/// Concrete syntax tree of a whole source, writing it back with `Display` gives the exact text
+/// it was parsed from
+pub struct SyntaxTree<'a> {
+    pub root: SyntaxNode<'a>,
+    /// Holds whatever follows the last token of the program as its leading trivia
+    pub eof: SyntaxToken<'a>,
+}
+
+/// A grammar production along with everything it was reduced from
+pub struct SyntaxNode<'a> {
+    pub production: ProdKind,
+    pub children: Vec<SyntaxElement<'a>>,
+}
+
+pub enum SyntaxElement<'a> {
+    Node(SyntaxNode<'a>),
+    Token(SyntaxToken<'a>),
+}
+
+/// A token with the trivia that belongs to it, the trailing trivia runs until the end of its
+/// line and everything after that is leading trivia of the next token
+pub struct SyntaxToken<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    /// Byte range of the token in the source
+    pub span: Range<usize>,
+    pub leading_trivia: Vec<Trivia<'a>>,
+    pub trailing_trivia: Vec<Trivia<'a>>,
+}
+
+/// Parses `source` into a lossless tree, without running any of the semantic actions
+///
+/// Only sources without lexer or syntax errors have a tree, otherwise their errors are returned
+pub fn parse(source: &str) -> Result<SyntaxTree<'_>, Vec<Diagnostic>> {
+    let (tokens, eof) = attach_trivia(tokenize(source)?);
+    let compiler = Compiler::new(String::new(), source.to_string());
+    let builder = CstBuilder {
+        tokens: tokens.into_iter(),
+        res_stack: Vec::new(),
+    };
+    let result = RulesParser::new(compiler.clone(), builder).parse(source);
+
+    let errors = compiler
+        .inner
+        .borrow_mut()
+        .take_diagnostics()
+        .into_iter()
+        .filter(Diagnostic::is_error)
+        .collect::<Vec<_>>();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    let root = result.map_err(|error| vec![Diagnostic::parser_internal(error, source)])?;
+    Ok(SyntaxTree { root, eof })
+}
+
+impl SyntaxTree<'_> {
+    /// Indented listing of every node, token and trivia in the tree
+    pub fn dump(&self) -> String {
+        let mut output = String::new();
+        self.root.dump(&mut output, 0);
+        self.eof.dump(&mut output, 0);
+        output
+    }
+}
+
+impl SyntaxNode<'_> {
+    pub fn kind(&self) -> NonTermKind {
+        self.production.into()
+    }
+
+    /// Tokens under the node in source order
+    pub fn tokens(&self) -> Box<dyn Iterator<Item = &SyntaxToken<'_>> + '_> {
+        Box::new(self.children.iter().flat_map(|child| match child {
+            SyntaxElement::Node(node) => node.tokens(),
+            SyntaxElement::Token(token) => Box::new(std::iter::once(token)),
+        }))
+    }
+
+    /// Byte range from the first token to the last one without their trivia, `None` for nodes
+    /// reduced from nothing
+    pub fn span(&self) -> Option<Range<usize>> {
+        let mut tokens = self.tokens();
+        let start = tokens.next()?.span.clone();
+        let end = tokens.last().map_or(start.end, |token| token.span.end);
+        Some(start.start..end)
+    }
+
+    fn dump(&self, output: &mut String, depth: usize) {
+        let span = self
+            .span()
+            .map(|span| format!(" {span:?}"))
+            .unwrap_or_default();
+        let _ = writeln!(output, "{}{:?}{span}", indent(depth), self.production);
+        for child in &self.children {
+            match child {
+                SyntaxElement::Node(node) => node.dump(output, depth + 1),
+                SyntaxElement::Token(token) => token.dump(output, depth + 1),
+            }
+        }
+    }
+}
+
+impl SyntaxToken<'_> {
+    fn dump(&self, output: &mut String, depth: usize) {
+        let trivia = |output: &mut String, position, trivia: &[Trivia]| {
+            for trivia in trivia {
+                let kind = match trivia.kind {
+                    TriviaKind::Whitespace => "whitespace",
+                    TriviaKind::Comment => "comment",
+                };
+                let _ = writeln!(
+                    output,
+                    "{}{position} {kind} {:?} {:?}",
+                    indent(depth + 1),
+                    trivia.span,
+                    trivia.text
+                );
+            }
+        };
+        let _ = writeln!(
+            output,
+            "{}{:?} {:?} {:?}",
+            indent(depth),
+            self.kind,
+            self.span,
+            self.text
+        );
+        trivia(output, "leading", &self.leading_trivia);
+        trivia(output, "trailing", &self.trailing_trivia);
+    }
+}
+
+impl Display for SyntaxTree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.root, self.eof)
+    }
+}
+
+impl Display for SyntaxNode<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.children.iter().try_for_each(|child| match child {
+            SyntaxElement::Node(node) => write!(f, "{node}"),
+            SyntaxElement::Token(token) => write!(f, "{token}"),
+        })
+    }
+}
+
+impl Display for SyntaxToken<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for trivia in &self.leading_trivia {
+            f.write_str(trivia.text)?;
+        }
+        f.write_str(self.text)?;
+        for trivia in &self.trailing_trivia {
+            f.write_str(trivia.text)?;
+        }
+        Ok(())
+    }
+}
+
+fn indent(depth: usize) -> String {
+    "  ".repeat(depth)
+}
+
+/// Splits the trivia before each token between the end of the line of the previous token and
+/// the token itself, returning the tokens apart from the final `STOP`
+fn attach_trivia(tokens: Vec<tokens::Token<'_>>) -> (Vec<SyntaxToken<'_>>, SyntaxToken<'_>) {
+    let mut syntax_tokens: Vec<SyntaxToken> = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        // Trivia belongs to the previous token until the first line break
+        let mut previous = syntax_tokens.last_mut();
+        let mut leading_trivia = Vec::new();
+        for trivia in token.leading_trivia {
+            let Some(previous_token) = previous.as_deref_mut() else {
+                leading_trivia.push(trivia);
+                continue;
+            };
+            let line_break = match trivia.kind {
+                TriviaKind::Whitespace => trivia.text.find(['\r', '\n']),
+                TriviaKind::Comment => None,
+            };
+            match line_break {
+                Some(index) => {
+                    let (same_line, next_lines) = split_at(trivia, index);
+                    previous_token.trailing_trivia.extend(same_line);
+                    leading_trivia.extend(next_lines);
+                    previous = None;
+                }
+                None => previous_token.trailing_trivia.push(trivia),
+            }
+        }
+        syntax_tokens.push(SyntaxToken {
+            kind: token.kind,
+            text: token.text,
+            span: token.span,
+            leading_trivia,
+            trailing_trivia: Vec::new(),
+        });
+    }
+    // `tokenize` always ends with a `STOP`
+    let eof = syntax_tokens.pop().unwrap_or_else(|| SyntaxToken {
+        kind: TokenKind::STOP,
+        text: "",
+        span: 0..0,
+        leading_trivia: Vec::new(),
+        trailing_trivia: Vec::new(),
+    });
+    (syntax_tokens, eof)
+}
+
+/// Splits whitespace trivia at byte `index`, leaving out the sides that end up empty
+fn split_at(trivia: Trivia<'_>, index: usize) -> (Option<Trivia<'_>>, Option<Trivia<'_>>) {
+    let (before, after) = trivia.text.split_at(index);
+    let middle = trivia.span.start + index;
+    (
+        (!before.is_empty()).then_some(Trivia {
+            kind: trivia.kind,
+            text: before,
+            span: trivia.span.start..middle,
+        }),
+        (!after.is_empty()).then_some(Trivia {
+            kind: trivia.kind,
+            text: after,
+            span: middle..trivia.span.end,
+        }),
+    )
+}
+
+/// Builds the tree from the tokens prepared by `attach_trivia`, which are the same ones the
+/// parser shifts
+struct CstBuilder<'i> {
+    tokens: vec::IntoIter<SyntaxToken<'i>>,
+    res_stack: Vec<SyntaxElement<'i>>,
+}
+
+impl<'i> Builder for CstBuilder<'i> {
+    type Output = SyntaxNode<'i>;
+
+    fn get_result(&mut self) -> Self::Output {
+        match self.res_stack.pop() {
+            Some(SyntaxElement::Node(node)) => node,
+            _ => panic!("Internal error: the parser didn't reduce to a single node"),
+        }
+    }
+}
+
+impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> for CstBuilder<'i> {
+    fn shift_action(&mut self, context: &Context<'i, Input>, token: Token<'i, Input, TokenKind>) {
+        let syntax_token = self
+            .tokens
+            .next()
+            .filter(|syntax_token| syntax_token.kind == token.kind)
+            .unwrap_or_else(|| SyntaxToken {
+                kind: token.kind,
+                text: token.value,
+                span: rustemo::Context::range(context),
+                leading_trivia: Vec::new(),
+                trailing_trivia: Vec::new(),
+            });
+        self.res_stack.push(SyntaxElement::Token(syntax_token));
+    }
+
+    fn reduce_action(&mut self, _context: &Context<'i, Input>, prod: ProdKind, prod_len: usize) {
+        let children = self.res_stack.split_off(self.res_stack.len() - prod_len);
+        self.res_stack.push(SyntaxElement::Node(SyntaxNode {
+            production: prod,
+            children,
+        }));
+    }
+}
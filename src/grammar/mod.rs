@@ -2,9 +2,10 @@
 #[allow(clippy::all)]
 #[allow(warnings)]
 mod rules;
+pub mod cst;
 mod rules_actions;
 pub mod rules_builder;
 pub mod rules_lexer;
 pub mod types;
 
-pub use rules::{RulesParser, TokenKind};
+pub use rules::{NonTermKind, ProdKind, RulesParser, TokenKind};
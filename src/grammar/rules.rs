@@ -15,10 +15,10 @@ use rustemo::debug::{log, logn};
 #[cfg(debug_assertions)]
 use rustemo::colored::*;
 pub type Input = str;
-const STATE_COUNT: usize = 118usize;
-const MAX_RECOGNIZERS: usize = 20usize;
+const STATE_COUNT: usize = 179usize;
+const MAX_RECOGNIZERS: usize = 25usize;
 #[allow(dead_code)]
-const TERMINAL_COUNT: usize = 39usize;
+const TERMINAL_COUNT: usize = 45usize;
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TokenKind {
@@ -27,6 +27,7 @@ pub enum TokenKind {
     TokenInt,
     TokenFloat,
     TokenString,
+    TokenBool,
     TokenIntLiteral,
     TokenFloatLiteral,
     TokenStringLiteral,
@@ -40,6 +41,8 @@ pub enum TokenKind {
     TokenParClose,
     TokenCBOpen,
     TokenCBClose,
+    TokenSBOpen,
+    TokenSBClose,
     TokenColon,
     TokenInit,
     TokenWhile,
@@ -62,6 +65,9 @@ pub enum TokenKind {
     TokenIsZero,
     TokenConvDate,
     TokenDate,
+    TokenFunction,
+    TokenProcedure,
+    TokenReturn,
 }
 use TokenKind as TK;
 impl From<TokenKind> for usize {
@@ -73,19 +79,38 @@ impl From<TokenKind> for usize {
 #[derive(Clone, Copy, PartialEq)]
 pub enum ProdKind {
     ProgramProgramWithMain,
+    ProgramProgramWithFunctions,
+    ProgramProgramFunctionsOnlyBody,
     ProgramProgramOnlyBody,
+    FunctionsFunctionsSingle,
+    FunctionsFunctionsRecursive,
+    FunctionFunctionDefinition,
+    FunctionSignatureFunctionNoParameters,
+    FunctionSignatureFunctionWithParameters,
+    FunctionSignatureProcedureNoParameters,
+    FunctionSignatureProcedureWithParameters,
+    FunctionNameFunctionName,
+    ParametersParametersSingle,
+    ParametersParametersRecursive,
+    ParameterParameter,
     BodyBodyInitExpressions,
     BodyBodyInit,
     BodyBodyExpressions,
     BodyBodyEmpty,
     InitBodyInitBody,
     FunctionReadFunctionReadCall,
+    FunctionReadFunctionReadIndexedCall,
     FunctionWriteFunctionWriteCall,
     FunctionIsZeroFunctionIsZeroCall,
     FunctionConvDateFunctionConvDateVariableCall,
+    FunctionCallFunctionCallNoArguments,
+    FunctionCallFunctionCallWithArguments,
+    ArgumentsArgumentsSingle,
+    ArgumentsArgumentsRecursive,
     VarDeclarationsVarDeclarationsSingle,
     VarDeclarationsVarDeclarationsRecursive,
     VarDeclarationVarDeclarationSingle,
+    VarDeclarationVarDeclarationArray,
     VarDeclarationVarDeclarationRecursive,
     ExpressionsExpressionSingle,
     ExpressionsExpressionRecursive,
@@ -94,24 +119,26 @@ pub enum ProdKind {
     StatementStatementWhile,
     StatementStatementWrite,
     StatementStatementRead,
+    StatementStatementCall,
+    StatementStatementReturn,
     AssignmentAssignmentExpression,
+    AssignmentAssignmentIndexed,
     AssignmentAssignmentConvDate,
     DataTypeIntType,
     DataTypeFloatType,
     DataTypeStringType,
+    DataTypeBoolType,
     WhileLoopWhile,
     IfStatementIfStatement,
     IfStatementIfElseStatement,
     DummyElseP1,
     ElseStatementElseStatement,
+    BlockOpenBlockOpen,
     BooleanExpressionBooleanExpressionSimpleExpression,
-    BooleanExpressionBooleanExpressionTrue,
-    BooleanExpressionBooleanExpressionFalse,
     BooleanExpressionBooleanExpressionNotStatement,
     BooleanExpressionBooleanExpressionIsZero,
-    BooleanExpressionBooleanExpressionVar,
+    BooleanExpressionBooleanExpressionValue,
     SimpleExpressionSimpleExpressionArithmetic,
-    SimpleExpressionSimpleExpressionString,
     ConjunctionConjunctionAnd,
     ConjunctionConjunctionOr,
     ConjunctionConjunctionBoolean,
@@ -135,8 +162,14 @@ pub enum ProdKind {
     TermTermFactor,
     DummyTP1,
     FactorFactorId,
+    FactorFactorIndex,
     FactorFactorNumber,
+    FactorFactorString,
+    FactorFactorTrue,
+    FactorFactorFalse,
+    FactorFactorCall,
     FactorFactorParen,
+    IndexIndex,
 }
 use ProdKind as PK;
 impl std::fmt::Debug for ProdKind {
@@ -145,7 +178,34 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::ProgramProgramWithMain => {
                 "Program: TokenId TokenParOpen TokenParClose TokenCBOpen Body TokenCBClose"
             }
+            ProdKind::ProgramProgramWithFunctions => {
+                "Program: Functions TokenId TokenParOpen TokenParClose TokenCBOpen Body TokenCBClose"
+            }
+            ProdKind::ProgramProgramFunctionsOnlyBody => "Program: Functions Body",
             ProdKind::ProgramProgramOnlyBody => "Program: Body",
+            ProdKind::FunctionsFunctionsSingle => "Functions: Function",
+            ProdKind::FunctionsFunctionsRecursive => "Functions: Function Functions",
+            ProdKind::FunctionFunctionDefinition => {
+                "Function: FunctionSignature TokenCBOpen Body TokenCBClose"
+            }
+            ProdKind::FunctionSignatureFunctionNoParameters => {
+                "FunctionSignature: TokenFunction FunctionName TokenParOpen TokenParClose TokenColon DataType"
+            }
+            ProdKind::FunctionSignatureFunctionWithParameters => {
+                "FunctionSignature: TokenFunction FunctionName TokenParOpen Parameters TokenParClose TokenColon DataType"
+            }
+            ProdKind::FunctionSignatureProcedureNoParameters => {
+                "FunctionSignature: TokenProcedure FunctionName TokenParOpen TokenParClose"
+            }
+            ProdKind::FunctionSignatureProcedureWithParameters => {
+                "FunctionSignature: TokenProcedure FunctionName TokenParOpen Parameters TokenParClose"
+            }
+            ProdKind::FunctionNameFunctionName => "FunctionName: TokenId",
+            ProdKind::ParametersParametersSingle => "Parameters: Parameter",
+            ProdKind::ParametersParametersRecursive => {
+                "Parameters: Parameter TokenComma Parameters"
+            }
+            ProdKind::ParameterParameter => "Parameter: TokenId TokenColon DataType",
             ProdKind::BodyBodyInitExpressions => "Body: TokenInit InitBody Expressions",
             ProdKind::BodyBodyInit => "Body: TokenInit InitBody",
             ProdKind::BodyBodyExpressions => "Body: Expressions",
@@ -156,8 +216,11 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::FunctionReadFunctionReadCall => {
                 "FunctionRead: TokenRead TokenParOpen TokenId TokenParClose"
             }
+            ProdKind::FunctionReadFunctionReadIndexedCall => {
+                "FunctionRead: TokenRead TokenParOpen TokenId Index TokenParClose"
+            }
             ProdKind::FunctionWriteFunctionWriteCall => {
-                "FunctionWrite: TokenWrite TokenParOpen SimpleExpression TokenParClose"
+                "FunctionWrite: TokenWrite TokenParOpen Conjunction TokenParClose"
             }
             ProdKind::FunctionIsZeroFunctionIsZeroCall => {
                 "FunctionIsZero: TokenIsZero TokenParOpen ArithmeticExpression TokenParClose"
@@ -165,6 +228,16 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::FunctionConvDateFunctionConvDateVariableCall => {
                 "FunctionConvDate: TokenConvDate TokenParOpen TokenDate TokenParClose"
             }
+            ProdKind::FunctionCallFunctionCallNoArguments => {
+                "FunctionCall: TokenId TokenParOpen TokenParClose"
+            }
+            ProdKind::FunctionCallFunctionCallWithArguments => {
+                "FunctionCall: TokenId TokenParOpen Arguments TokenParClose"
+            }
+            ProdKind::ArgumentsArgumentsSingle => "Arguments: Conjunction",
+            ProdKind::ArgumentsArgumentsRecursive => {
+                "Arguments: Arguments TokenComma Conjunction"
+            }
             ProdKind::VarDeclarationsVarDeclarationsSingle => {
                 "VarDeclarations: VarDeclaration"
             }
@@ -174,6 +247,9 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::VarDeclarationVarDeclarationSingle => {
                 "VarDeclaration: TokenId TokenColon DataType"
             }
+            ProdKind::VarDeclarationVarDeclarationArray => {
+                "VarDeclaration: TokenId TokenColon DataType TokenSBOpen TokenIntLiteral TokenSBClose"
+            }
             ProdKind::VarDeclarationVarDeclarationRecursive => {
                 "VarDeclaration: TokenId TokenComma VarDeclaration"
             }
@@ -186,8 +262,13 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::StatementStatementWhile => "Statement: WhileLoop",
             ProdKind::StatementStatementWrite => "Statement: FunctionWrite",
             ProdKind::StatementStatementRead => "Statement: FunctionRead",
+            ProdKind::StatementStatementCall => "Statement: FunctionCall",
+            ProdKind::StatementStatementReturn => "Statement: TokenReturn Conjunction",
             ProdKind::AssignmentAssignmentExpression => {
-                "Assignment: TokenId TokenAssign SimpleExpression"
+                "Assignment: TokenId TokenAssign Conjunction"
+            }
+            ProdKind::AssignmentAssignmentIndexed => {
+                "Assignment: TokenId Index TokenAssign Conjunction"
             }
             ProdKind::AssignmentAssignmentConvDate => {
                 "Assignment: TokenId TokenAssign FunctionConvDate"
@@ -195,43 +276,36 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::DataTypeIntType => "DataType: TokenInt",
             ProdKind::DataTypeFloatType => "DataType: TokenFloat",
             ProdKind::DataTypeStringType => "DataType: TokenString",
+            ProdKind::DataTypeBoolType => "DataType: TokenBool",
             ProdKind::WhileLoopWhile => {
-                "WhileLoop: TokenWhile TokenParOpen Conjunction TokenParClose TokenCBOpen Body TokenCBClose"
+                "WhileLoop: TokenWhile TokenParOpen Conjunction TokenParClose BlockOpen Body TokenCBClose"
             }
             ProdKind::IfStatementIfStatement => {
-                "IfStatement: TokenIf TokenParOpen Conjunction TokenParClose TokenCBOpen Body TokenCBClose"
+                "IfStatement: TokenIf TokenParOpen Conjunction TokenParClose BlockOpen Body TokenCBClose"
             }
             ProdKind::IfStatementIfElseStatement => {
-                "IfStatement: TokenIf TokenParOpen Conjunction TokenParClose TokenCBOpen Body TokenCBClose DummyElse ElseStatement"
+                "IfStatement: TokenIf TokenParOpen Conjunction TokenParClose BlockOpen Body TokenCBClose DummyElse ElseStatement"
             }
             ProdKind::DummyElseP1 => "DummyElse: ",
             ProdKind::ElseStatementElseStatement => {
-                "ElseStatement: TokenElse TokenCBOpen Body TokenCBClose"
+                "ElseStatement: TokenElse BlockOpen Body TokenCBClose"
             }
+            ProdKind::BlockOpenBlockOpen => "BlockOpen: TokenCBOpen",
             ProdKind::BooleanExpressionBooleanExpressionSimpleExpression => {
                 "BooleanExpression: SimpleExpression ComparisonOp SimpleExpression"
             }
-            ProdKind::BooleanExpressionBooleanExpressionTrue => {
-                "BooleanExpression: TokenTrue"
-            }
-            ProdKind::BooleanExpressionBooleanExpressionFalse => {
-                "BooleanExpression: TokenFalse"
-            }
             ProdKind::BooleanExpressionBooleanExpressionNotStatement => {
                 "BooleanExpression: NotStatement"
             }
             ProdKind::BooleanExpressionBooleanExpressionIsZero => {
                 "BooleanExpression: FunctionIsZero"
             }
-            ProdKind::BooleanExpressionBooleanExpressionVar => {
-                "BooleanExpression: TokenId"
+            ProdKind::BooleanExpressionBooleanExpressionValue => {
+                "BooleanExpression: SimpleExpression"
             }
             ProdKind::SimpleExpressionSimpleExpressionArithmetic => {
                 "SimpleExpression: ArithmeticExpression"
             }
-            ProdKind::SimpleExpressionSimpleExpressionString => {
-                "SimpleExpression: TokenStringLiteral"
-            }
             ProdKind::ConjunctionConjunctionAnd => {
                 "Conjunction: BooleanExpression TokenAnd Conjunction"
             }
@@ -267,10 +341,16 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::TermTermFactor => "Term: Factor",
             ProdKind::DummyTP1 => "DummyT: ",
             ProdKind::FactorFactorId => "Factor: TokenId",
+            ProdKind::FactorFactorIndex => "Factor: TokenId Index",
             ProdKind::FactorFactorNumber => "Factor: Number",
+            ProdKind::FactorFactorString => "Factor: TokenStringLiteral",
+            ProdKind::FactorFactorTrue => "Factor: TokenTrue",
+            ProdKind::FactorFactorFalse => "Factor: TokenFalse",
+            ProdKind::FactorFactorCall => "Factor: FunctionCall",
             ProdKind::FactorFactorParen => {
                 "Factor: TokenParOpen ArithmeticExpression TokenParClose"
             }
+            ProdKind::IndexIndex => "Index: TokenSBOpen SimpleExpression TokenSBClose",
         };
         write!(f, "{name}")
     }
@@ -282,12 +362,20 @@ pub enum NonTermKind {
     EMPTY,
     AUG,
     Program,
+    Functions,
+    Function,
+    FunctionSignature,
+    FunctionName,
+    Parameters,
+    Parameter,
     Body,
     InitBody,
     FunctionRead,
     FunctionWrite,
     FunctionIsZero,
     FunctionConvDate,
+    FunctionCall,
+    Arguments,
     VarDeclarations,
     VarDeclaration,
     Expressions,
@@ -298,6 +386,7 @@ pub enum NonTermKind {
     IfStatement,
     DummyElse,
     ElseStatement,
+    BlockOpen,
     BooleanExpression,
     SimpleExpression,
     Conjunction,
@@ -309,23 +398,50 @@ pub enum NonTermKind {
     Term,
     DummyT,
     Factor,
+    Index,
 }
 impl From<ProdKind> for NonTermKind {
     fn from(prod: ProdKind) -> Self {
         match prod {
             ProdKind::ProgramProgramWithMain => NonTermKind::Program,
+            ProdKind::ProgramProgramWithFunctions => NonTermKind::Program,
+            ProdKind::ProgramProgramFunctionsOnlyBody => NonTermKind::Program,
             ProdKind::ProgramProgramOnlyBody => NonTermKind::Program,
+            ProdKind::FunctionsFunctionsSingle => NonTermKind::Functions,
+            ProdKind::FunctionsFunctionsRecursive => NonTermKind::Functions,
+            ProdKind::FunctionFunctionDefinition => NonTermKind::Function,
+            ProdKind::FunctionSignatureFunctionNoParameters => {
+                NonTermKind::FunctionSignature
+            }
+            ProdKind::FunctionSignatureFunctionWithParameters => {
+                NonTermKind::FunctionSignature
+            }
+            ProdKind::FunctionSignatureProcedureNoParameters => {
+                NonTermKind::FunctionSignature
+            }
+            ProdKind::FunctionSignatureProcedureWithParameters => {
+                NonTermKind::FunctionSignature
+            }
+            ProdKind::FunctionNameFunctionName => NonTermKind::FunctionName,
+            ProdKind::ParametersParametersSingle => NonTermKind::Parameters,
+            ProdKind::ParametersParametersRecursive => NonTermKind::Parameters,
+            ProdKind::ParameterParameter => NonTermKind::Parameter,
             ProdKind::BodyBodyInitExpressions => NonTermKind::Body,
             ProdKind::BodyBodyInit => NonTermKind::Body,
             ProdKind::BodyBodyExpressions => NonTermKind::Body,
             ProdKind::BodyBodyEmpty => NonTermKind::Body,
             ProdKind::InitBodyInitBody => NonTermKind::InitBody,
             ProdKind::FunctionReadFunctionReadCall => NonTermKind::FunctionRead,
+            ProdKind::FunctionReadFunctionReadIndexedCall => NonTermKind::FunctionRead,
             ProdKind::FunctionWriteFunctionWriteCall => NonTermKind::FunctionWrite,
             ProdKind::FunctionIsZeroFunctionIsZeroCall => NonTermKind::FunctionIsZero,
             ProdKind::FunctionConvDateFunctionConvDateVariableCall => {
                 NonTermKind::FunctionConvDate
             }
+            ProdKind::FunctionCallFunctionCallNoArguments => NonTermKind::FunctionCall,
+            ProdKind::FunctionCallFunctionCallWithArguments => NonTermKind::FunctionCall,
+            ProdKind::ArgumentsArgumentsSingle => NonTermKind::Arguments,
+            ProdKind::ArgumentsArgumentsRecursive => NonTermKind::Arguments,
             ProdKind::VarDeclarationsVarDeclarationsSingle => {
                 NonTermKind::VarDeclarations
             }
@@ -333,6 +449,7 @@ impl From<ProdKind> for NonTermKind {
                 NonTermKind::VarDeclarations
             }
             ProdKind::VarDeclarationVarDeclarationSingle => NonTermKind::VarDeclaration,
+            ProdKind::VarDeclarationVarDeclarationArray => NonTermKind::VarDeclaration,
             ProdKind::VarDeclarationVarDeclarationRecursive => {
                 NonTermKind::VarDeclaration
             }
@@ -343,40 +460,36 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::StatementStatementWhile => NonTermKind::Statement,
             ProdKind::StatementStatementWrite => NonTermKind::Statement,
             ProdKind::StatementStatementRead => NonTermKind::Statement,
+            ProdKind::StatementStatementCall => NonTermKind::Statement,
+            ProdKind::StatementStatementReturn => NonTermKind::Statement,
             ProdKind::AssignmentAssignmentExpression => NonTermKind::Assignment,
+            ProdKind::AssignmentAssignmentIndexed => NonTermKind::Assignment,
             ProdKind::AssignmentAssignmentConvDate => NonTermKind::Assignment,
             ProdKind::DataTypeIntType => NonTermKind::DataType,
             ProdKind::DataTypeFloatType => NonTermKind::DataType,
             ProdKind::DataTypeStringType => NonTermKind::DataType,
+            ProdKind::DataTypeBoolType => NonTermKind::DataType,
             ProdKind::WhileLoopWhile => NonTermKind::WhileLoop,
             ProdKind::IfStatementIfStatement => NonTermKind::IfStatement,
             ProdKind::IfStatementIfElseStatement => NonTermKind::IfStatement,
             ProdKind::DummyElseP1 => NonTermKind::DummyElse,
             ProdKind::ElseStatementElseStatement => NonTermKind::ElseStatement,
+            ProdKind::BlockOpenBlockOpen => NonTermKind::BlockOpen,
             ProdKind::BooleanExpressionBooleanExpressionSimpleExpression => {
                 NonTermKind::BooleanExpression
             }
-            ProdKind::BooleanExpressionBooleanExpressionTrue => {
-                NonTermKind::BooleanExpression
-            }
-            ProdKind::BooleanExpressionBooleanExpressionFalse => {
-                NonTermKind::BooleanExpression
-            }
             ProdKind::BooleanExpressionBooleanExpressionNotStatement => {
                 NonTermKind::BooleanExpression
             }
             ProdKind::BooleanExpressionBooleanExpressionIsZero => {
                 NonTermKind::BooleanExpression
             }
-            ProdKind::BooleanExpressionBooleanExpressionVar => {
+            ProdKind::BooleanExpressionBooleanExpressionValue => {
                 NonTermKind::BooleanExpression
             }
             ProdKind::SimpleExpressionSimpleExpressionArithmetic => {
                 NonTermKind::SimpleExpression
             }
-            ProdKind::SimpleExpressionSimpleExpressionString => {
-                NonTermKind::SimpleExpression
-            }
             ProdKind::ConjunctionConjunctionAnd => NonTermKind::Conjunction,
             ProdKind::ConjunctionConjunctionOr => NonTermKind::Conjunction,
             ProdKind::ConjunctionConjunctionBoolean => NonTermKind::Conjunction,
@@ -406,8 +519,14 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::TermTermFactor => NonTermKind::Term,
             ProdKind::DummyTP1 => NonTermKind::DummyT,
             ProdKind::FactorFactorId => NonTermKind::Factor,
+            ProdKind::FactorFactorIndex => NonTermKind::Factor,
             ProdKind::FactorFactorNumber => NonTermKind::Factor,
+            ProdKind::FactorFactorString => NonTermKind::Factor,
+            ProdKind::FactorFactorTrue => NonTermKind::Factor,
+            ProdKind::FactorFactorFalse => NonTermKind::Factor,
+            ProdKind::FactorFactorCall => NonTermKind::Factor,
             ProdKind::FactorFactorParen => NonTermKind::Factor,
+            ProdKind::IndexIndex => NonTermKind::Index,
         }
     }
 }
@@ -422,117 +541,178 @@ pub enum State {
     TokenIfS4,
     TokenReadS5,
     TokenWriteS6,
-    ProgramS7,
-    BodyS8,
-    FunctionReadS9,
-    FunctionWriteS10,
-    ExpressionsS11,
-    StatementS12,
-    AssignmentS13,
-    WhileLoopS14,
-    IfStatementS15,
-    TokenAssignS16,
-    TokenParOpenS17,
-    TokenCBOpenS18,
-    InitBodyS19,
-    TokenParOpenS20,
-    TokenParOpenS21,
-    TokenParOpenS22,
-    TokenParOpenS23,
-    TokenIdS24,
-    ExpressionsS25,
-    TokenIntLiteralS26,
-    TokenFloatLiteralS27,
-    TokenStringLiteralS28,
-    TokenIdS29,
-    TokenSubS30,
+    TokenFunctionS7,
+    TokenProcedureS8,
+    TokenReturnS9,
+    ProgramS10,
+    FunctionsS11,
+    FunctionS12,
+    FunctionSignatureS13,
+    BodyS14,
+    FunctionReadS15,
+    FunctionWriteS16,
+    FunctionCallS17,
+    ExpressionsS18,
+    StatementS19,
+    AssignmentS20,
+    WhileLoopS21,
+    IfStatementS22,
+    TokenAssignS23,
+    TokenParOpenS24,
+    TokenSBOpenS25,
+    IndexS26,
+    TokenCBOpenS27,
+    InitBodyS28,
+    TokenParOpenS29,
+    TokenParOpenS30,
     TokenParOpenS31,
-    TokenConvDateS32,
-    FunctionConvDateS33,
-    SimpleExpressionS34,
-    NumberS35,
-    ArithmeticExpressionS36,
-    TermS37,
-    FactorS38,
-    TokenParCloseS39,
-    TokenIdS40,
-    VarDeclarationsS41,
-    VarDeclarationS42,
-    ExpressionsS43,
-    TokenIdS44,
-    TokenTrueS45,
-    TokenFalseS46,
-    TokenNotS47,
-    TokenIsZeroS48,
-    FunctionIsZeroS49,
-    BooleanExpressionS50,
-    SimpleExpressionS51,
-    ConjunctionS52,
-    NotStatementS53,
-    ConjunctionS54,
-    TokenIdS55,
-    SimpleExpressionS56,
-    TokenIntLiteralS57,
-    TokenFloatLiteralS58,
-    ArithmeticExpressionS59,
-    TokenParOpenS60,
-    DummyAES61,
-    DummyTS62,
-    TokenCBOpenS63,
-    TokenColonS64,
-    TokenCommaS65,
-    TokenCBCloseS66,
-    VarDeclarationsS67,
-    BooleanExpressionS68,
-    TokenParOpenS69,
-    TokenAndS70,
-    TokenOrS71,
-    TokenEqualS72,
-    TokenNotEqualS73,
-    TokenLessS74,
-    TokenLessEqualS75,
-    TokenGreaterS76,
-    TokenGreaterEqualS77,
-    ComparisonOpS78,
-    TokenParCloseS79,
-    TokenParCloseS80,
-    TokenParCloseS81,
-    TokenParCloseS82,
-    TokenParCloseS83,
-    TokenDateS84,
-    TokenSumS85,
-    TokenSubS86,
-    TokenMulS87,
-    TokenDivS88,
-    BodyS89,
-    TokenIntS90,
-    TokenFloatS91,
-    TokenStringS92,
-    DataTypeS93,
-    VarDeclarationS94,
-    ArithmeticExpressionS95,
-    ConjunctionS96,
-    ConjunctionS97,
-    SimpleExpressionS98,
-    TokenCBOpenS99,
-    TokenCBOpenS100,
-    TokenParCloseS101,
-    TermS102,
-    TermS103,
-    FactorS104,
-    FactorS105,
-    TokenCBCloseS106,
-    TokenParCloseS107,
-    BodyS108,
-    BodyS109,
-    TokenCBCloseS110,
-    TokenCBCloseS111,
-    DummyElseS112,
-    TokenElseS113,
-    ElseStatementS114,
-    TokenCBOpenS115,
-    BodyS116,
-    TokenCBCloseS117,
+    TokenParOpenS32,
+    TokenIdS33,
+    FunctionNameS34,
+    FunctionNameS35,
+    TokenIntLiteralS36,
+    TokenFloatLiteralS37,
+    TokenStringLiteralS38,
+    TokenIdS39,
+    TokenSubS40,
+    TokenParOpenS41,
+    TokenTrueS42,
+    TokenFalseS43,
+    TokenNotS44,
+    TokenIsZeroS45,
+    FunctionIsZeroS46,
+    FunctionCallS47,
+    BooleanExpressionS48,
+    SimpleExpressionS49,
+    ConjunctionS50,
+    NumberS51,
+    NotStatementS52,
+    ArithmeticExpressionS53,
+    TermS54,
+    FactorS55,
+    TokenIdS56,
+    BodyS57,
+    FunctionsS58,
+    TokenCBOpenS59,
+    TokenIdS60,
+    ExpressionsS61,
+    TokenConvDateS62,
+    FunctionConvDateS63,
+    ConjunctionS64,
+    TokenParCloseS65,
+    ArgumentsS66,
+    ConjunctionS67,
+    SimpleExpressionS68,
+    TokenAssignS69,
+    TokenIdS70,
+    VarDeclarationsS71,
+    VarDeclarationS72,
+    ExpressionsS73,
+    ConjunctionS74,
+    ConjunctionS75,
+    TokenIdS76,
+    ConjunctionS77,
+    TokenParOpenS78,
+    TokenParOpenS79,
+    TokenParOpenS80,
+    IndexS81,
+    TokenIntLiteralS82,
+    TokenFloatLiteralS83,
+    ArithmeticExpressionS84,
+    BooleanExpressionS85,
+    TokenParOpenS86,
+    TokenAndS87,
+    TokenOrS88,
+    TokenEqualS89,
+    TokenNotEqualS90,
+    TokenLessS91,
+    TokenLessEqualS92,
+    TokenGreaterS93,
+    TokenGreaterEqualS94,
+    ComparisonOpS95,
+    DummyAES96,
+    DummyTS97,
+    TokenParOpenS98,
+    BodyS99,
+    TokenParOpenS100,
+    TokenCBOpenS101,
+    TokenParCloseS102,
+    TokenCommaS103,
+    TokenSBCloseS104,
+    ConjunctionS105,
+    TokenColonS106,
+    TokenCommaS107,
+    TokenCBCloseS108,
+    VarDeclarationsS109,
+    TokenParCloseS110,
+    TokenParCloseS111,
+    TokenParCloseS112,
+    IndexS113,
+    TokenParCloseS114,
+    TokenIdS115,
+    TokenParCloseS116,
+    ParametersS117,
+    ParameterS118,
+    TokenParCloseS119,
+    ParametersS120,
+    TokenParCloseS121,
+    TokenParCloseS122,
+    ArithmeticExpressionS123,
+    ConjunctionS124,
+    ConjunctionS125,
+    SimpleExpressionS126,
+    TokenSumS127,
+    TokenSubS128,
+    TokenMulS129,
+    TokenDivS130,
+    TokenParCloseS131,
+    TokenCBCloseS132,
+    TokenDateS133,
+    BodyS134,
+    ConjunctionS135,
+    TokenIntS136,
+    TokenFloatS137,
+    TokenStringS138,
+    TokenBoolS139,
+    DataTypeS140,
+    VarDeclarationS141,
+    TokenCBOpenS142,
+    BlockOpenS143,
+    BlockOpenS144,
+    TokenParCloseS145,
+    TokenColonS146,
+    TokenColonS147,
+    TokenParCloseS148,
+    TokenCommaS149,
+    TokenParCloseS150,
+    TokenParCloseS151,
+    TermS152,
+    TermS153,
+    FactorS154,
+    FactorS155,
+    TokenCBOpenS156,
+    TokenParCloseS157,
+    TokenCBCloseS158,
+    TokenSBOpenS159,
+    BodyS160,
+    BodyS161,
+    DataTypeS162,
+    DataTypeS163,
+    TokenColonS164,
+    ParametersS165,
+    BodyS166,
+    TokenIntLiteralS167,
+    TokenCBCloseS168,
+    TokenCBCloseS169,
+    DataTypeS170,
+    TokenCBCloseS171,
+    TokenSBCloseS172,
+    DummyElseS173,
+    TokenElseS174,
+    ElseStatementS175,
+    BlockOpenS176,
+    BodyS177,
+    TokenCBCloseS178,
 }
 impl StateT for State {
     fn default_layout() -> Option<Self> {
@@ -554,117 +734,178 @@ impl std::fmt::Debug for State {
             State::TokenIfS4 => "4:TokenIf",
             State::TokenReadS5 => "5:TokenRead",
             State::TokenWriteS6 => "6:TokenWrite",
-            State::ProgramS7 => "7:Program",
-            State::BodyS8 => "8:Body",
-            State::FunctionReadS9 => "9:FunctionRead",
-            State::FunctionWriteS10 => "10:FunctionWrite",
-            State::ExpressionsS11 => "11:Expressions",
-            State::StatementS12 => "12:Statement",
-            State::AssignmentS13 => "13:Assignment",
-            State::WhileLoopS14 => "14:WhileLoop",
-            State::IfStatementS15 => "15:IfStatement",
-            State::TokenAssignS16 => "16:TokenAssign",
-            State::TokenParOpenS17 => "17:TokenParOpen",
-            State::TokenCBOpenS18 => "18:TokenCBOpen",
-            State::InitBodyS19 => "19:InitBody",
-            State::TokenParOpenS20 => "20:TokenParOpen",
-            State::TokenParOpenS21 => "21:TokenParOpen",
-            State::TokenParOpenS22 => "22:TokenParOpen",
-            State::TokenParOpenS23 => "23:TokenParOpen",
-            State::TokenIdS24 => "24:TokenId",
-            State::ExpressionsS25 => "25:Expressions",
-            State::TokenIntLiteralS26 => "26:TokenIntLiteral",
-            State::TokenFloatLiteralS27 => "27:TokenFloatLiteral",
-            State::TokenStringLiteralS28 => "28:TokenStringLiteral",
-            State::TokenIdS29 => "29:TokenId",
-            State::TokenSubS30 => "30:TokenSub",
+            State::TokenFunctionS7 => "7:TokenFunction",
+            State::TokenProcedureS8 => "8:TokenProcedure",
+            State::TokenReturnS9 => "9:TokenReturn",
+            State::ProgramS10 => "10:Program",
+            State::FunctionsS11 => "11:Functions",
+            State::FunctionS12 => "12:Function",
+            State::FunctionSignatureS13 => "13:FunctionSignature",
+            State::BodyS14 => "14:Body",
+            State::FunctionReadS15 => "15:FunctionRead",
+            State::FunctionWriteS16 => "16:FunctionWrite",
+            State::FunctionCallS17 => "17:FunctionCall",
+            State::ExpressionsS18 => "18:Expressions",
+            State::StatementS19 => "19:Statement",
+            State::AssignmentS20 => "20:Assignment",
+            State::WhileLoopS21 => "21:WhileLoop",
+            State::IfStatementS22 => "22:IfStatement",
+            State::TokenAssignS23 => "23:TokenAssign",
+            State::TokenParOpenS24 => "24:TokenParOpen",
+            State::TokenSBOpenS25 => "25:TokenSBOpen",
+            State::IndexS26 => "26:Index",
+            State::TokenCBOpenS27 => "27:TokenCBOpen",
+            State::InitBodyS28 => "28:InitBody",
+            State::TokenParOpenS29 => "29:TokenParOpen",
+            State::TokenParOpenS30 => "30:TokenParOpen",
             State::TokenParOpenS31 => "31:TokenParOpen",
-            State::TokenConvDateS32 => "32:TokenConvDate",
-            State::FunctionConvDateS33 => "33:FunctionConvDate",
-            State::SimpleExpressionS34 => "34:SimpleExpression",
-            State::NumberS35 => "35:Number",
-            State::ArithmeticExpressionS36 => "36:ArithmeticExpression",
-            State::TermS37 => "37:Term",
-            State::FactorS38 => "38:Factor",
-            State::TokenParCloseS39 => "39:TokenParClose",
-            State::TokenIdS40 => "40:TokenId",
-            State::VarDeclarationsS41 => "41:VarDeclarations",
-            State::VarDeclarationS42 => "42:VarDeclaration",
-            State::ExpressionsS43 => "43:Expressions",
-            State::TokenIdS44 => "44:TokenId",
-            State::TokenTrueS45 => "45:TokenTrue",
-            State::TokenFalseS46 => "46:TokenFalse",
-            State::TokenNotS47 => "47:TokenNot",
-            State::TokenIsZeroS48 => "48:TokenIsZero",
-            State::FunctionIsZeroS49 => "49:FunctionIsZero",
-            State::BooleanExpressionS50 => "50:BooleanExpression",
-            State::SimpleExpressionS51 => "51:SimpleExpression",
-            State::ConjunctionS52 => "52:Conjunction",
-            State::NotStatementS53 => "53:NotStatement",
-            State::ConjunctionS54 => "54:Conjunction",
-            State::TokenIdS55 => "55:TokenId",
-            State::SimpleExpressionS56 => "56:SimpleExpression",
-            State::TokenIntLiteralS57 => "57:TokenIntLiteral",
-            State::TokenFloatLiteralS58 => "58:TokenFloatLiteral",
-            State::ArithmeticExpressionS59 => "59:ArithmeticExpression",
-            State::TokenParOpenS60 => "60:TokenParOpen",
-            State::DummyAES61 => "61:DummyAE",
-            State::DummyTS62 => "62:DummyT",
-            State::TokenCBOpenS63 => "63:TokenCBOpen",
-            State::TokenColonS64 => "64:TokenColon",
-            State::TokenCommaS65 => "65:TokenComma",
-            State::TokenCBCloseS66 => "66:TokenCBClose",
-            State::VarDeclarationsS67 => "67:VarDeclarations",
-            State::BooleanExpressionS68 => "68:BooleanExpression",
-            State::TokenParOpenS69 => "69:TokenParOpen",
-            State::TokenAndS70 => "70:TokenAnd",
-            State::TokenOrS71 => "71:TokenOr",
-            State::TokenEqualS72 => "72:TokenEqual",
-            State::TokenNotEqualS73 => "73:TokenNotEqual",
-            State::TokenLessS74 => "74:TokenLess",
-            State::TokenLessEqualS75 => "75:TokenLessEqual",
-            State::TokenGreaterS76 => "76:TokenGreater",
-            State::TokenGreaterEqualS77 => "77:TokenGreaterEqual",
-            State::ComparisonOpS78 => "78:ComparisonOp",
-            State::TokenParCloseS79 => "79:TokenParClose",
-            State::TokenParCloseS80 => "80:TokenParClose",
-            State::TokenParCloseS81 => "81:TokenParClose",
-            State::TokenParCloseS82 => "82:TokenParClose",
-            State::TokenParCloseS83 => "83:TokenParClose",
-            State::TokenDateS84 => "84:TokenDate",
-            State::TokenSumS85 => "85:TokenSum",
-            State::TokenSubS86 => "86:TokenSub",
-            State::TokenMulS87 => "87:TokenMul",
-            State::TokenDivS88 => "88:TokenDiv",
-            State::BodyS89 => "89:Body",
-            State::TokenIntS90 => "90:TokenInt",
-            State::TokenFloatS91 => "91:TokenFloat",
-            State::TokenStringS92 => "92:TokenString",
-            State::DataTypeS93 => "93:DataType",
-            State::VarDeclarationS94 => "94:VarDeclaration",
-            State::ArithmeticExpressionS95 => "95:ArithmeticExpression",
-            State::ConjunctionS96 => "96:Conjunction",
-            State::ConjunctionS97 => "97:Conjunction",
-            State::SimpleExpressionS98 => "98:SimpleExpression",
-            State::TokenCBOpenS99 => "99:TokenCBOpen",
-            State::TokenCBOpenS100 => "100:TokenCBOpen",
-            State::TokenParCloseS101 => "101:TokenParClose",
-            State::TermS102 => "102:Term",
-            State::TermS103 => "103:Term",
-            State::FactorS104 => "104:Factor",
-            State::FactorS105 => "105:Factor",
-            State::TokenCBCloseS106 => "106:TokenCBClose",
-            State::TokenParCloseS107 => "107:TokenParClose",
-            State::BodyS108 => "108:Body",
-            State::BodyS109 => "109:Body",
-            State::TokenCBCloseS110 => "110:TokenCBClose",
-            State::TokenCBCloseS111 => "111:TokenCBClose",
-            State::DummyElseS112 => "112:DummyElse",
-            State::TokenElseS113 => "113:TokenElse",
-            State::ElseStatementS114 => "114:ElseStatement",
-            State::TokenCBOpenS115 => "115:TokenCBOpen",
-            State::BodyS116 => "116:Body",
-            State::TokenCBCloseS117 => "117:TokenCBClose",
+            State::TokenParOpenS32 => "32:TokenParOpen",
+            State::TokenIdS33 => "33:TokenId",
+            State::FunctionNameS34 => "34:FunctionName",
+            State::FunctionNameS35 => "35:FunctionName",
+            State::TokenIntLiteralS36 => "36:TokenIntLiteral",
+            State::TokenFloatLiteralS37 => "37:TokenFloatLiteral",
+            State::TokenStringLiteralS38 => "38:TokenStringLiteral",
+            State::TokenIdS39 => "39:TokenId",
+            State::TokenSubS40 => "40:TokenSub",
+            State::TokenParOpenS41 => "41:TokenParOpen",
+            State::TokenTrueS42 => "42:TokenTrue",
+            State::TokenFalseS43 => "43:TokenFalse",
+            State::TokenNotS44 => "44:TokenNot",
+            State::TokenIsZeroS45 => "45:TokenIsZero",
+            State::FunctionIsZeroS46 => "46:FunctionIsZero",
+            State::FunctionCallS47 => "47:FunctionCall",
+            State::BooleanExpressionS48 => "48:BooleanExpression",
+            State::SimpleExpressionS49 => "49:SimpleExpression",
+            State::ConjunctionS50 => "50:Conjunction",
+            State::NumberS51 => "51:Number",
+            State::NotStatementS52 => "52:NotStatement",
+            State::ArithmeticExpressionS53 => "53:ArithmeticExpression",
+            State::TermS54 => "54:Term",
+            State::FactorS55 => "55:Factor",
+            State::TokenIdS56 => "56:TokenId",
+            State::BodyS57 => "57:Body",
+            State::FunctionsS58 => "58:Functions",
+            State::TokenCBOpenS59 => "59:TokenCBOpen",
+            State::TokenIdS60 => "60:TokenId",
+            State::ExpressionsS61 => "61:Expressions",
+            State::TokenConvDateS62 => "62:TokenConvDate",
+            State::FunctionConvDateS63 => "63:FunctionConvDate",
+            State::ConjunctionS64 => "64:Conjunction",
+            State::TokenParCloseS65 => "65:TokenParClose",
+            State::ArgumentsS66 => "66:Arguments",
+            State::ConjunctionS67 => "67:Conjunction",
+            State::SimpleExpressionS68 => "68:SimpleExpression",
+            State::TokenAssignS69 => "69:TokenAssign",
+            State::TokenIdS70 => "70:TokenId",
+            State::VarDeclarationsS71 => "71:VarDeclarations",
+            State::VarDeclarationS72 => "72:VarDeclaration",
+            State::ExpressionsS73 => "73:Expressions",
+            State::ConjunctionS74 => "74:Conjunction",
+            State::ConjunctionS75 => "75:Conjunction",
+            State::TokenIdS76 => "76:TokenId",
+            State::ConjunctionS77 => "77:Conjunction",
+            State::TokenParOpenS78 => "78:TokenParOpen",
+            State::TokenParOpenS79 => "79:TokenParOpen",
+            State::TokenParOpenS80 => "80:TokenParOpen",
+            State::IndexS81 => "81:Index",
+            State::TokenIntLiteralS82 => "82:TokenIntLiteral",
+            State::TokenFloatLiteralS83 => "83:TokenFloatLiteral",
+            State::ArithmeticExpressionS84 => "84:ArithmeticExpression",
+            State::BooleanExpressionS85 => "85:BooleanExpression",
+            State::TokenParOpenS86 => "86:TokenParOpen",
+            State::TokenAndS87 => "87:TokenAnd",
+            State::TokenOrS88 => "88:TokenOr",
+            State::TokenEqualS89 => "89:TokenEqual",
+            State::TokenNotEqualS90 => "90:TokenNotEqual",
+            State::TokenLessS91 => "91:TokenLess",
+            State::TokenLessEqualS92 => "92:TokenLessEqual",
+            State::TokenGreaterS93 => "93:TokenGreater",
+            State::TokenGreaterEqualS94 => "94:TokenGreaterEqual",
+            State::ComparisonOpS95 => "95:ComparisonOp",
+            State::DummyAES96 => "96:DummyAE",
+            State::DummyTS97 => "97:DummyT",
+            State::TokenParOpenS98 => "98:TokenParOpen",
+            State::BodyS99 => "99:Body",
+            State::TokenParOpenS100 => "100:TokenParOpen",
+            State::TokenCBOpenS101 => "101:TokenCBOpen",
+            State::TokenParCloseS102 => "102:TokenParClose",
+            State::TokenCommaS103 => "103:TokenComma",
+            State::TokenSBCloseS104 => "104:TokenSBClose",
+            State::ConjunctionS105 => "105:Conjunction",
+            State::TokenColonS106 => "106:TokenColon",
+            State::TokenCommaS107 => "107:TokenComma",
+            State::TokenCBCloseS108 => "108:TokenCBClose",
+            State::VarDeclarationsS109 => "109:VarDeclarations",
+            State::TokenParCloseS110 => "110:TokenParClose",
+            State::TokenParCloseS111 => "111:TokenParClose",
+            State::TokenParCloseS112 => "112:TokenParClose",
+            State::IndexS113 => "113:Index",
+            State::TokenParCloseS114 => "114:TokenParClose",
+            State::TokenIdS115 => "115:TokenId",
+            State::TokenParCloseS116 => "116:TokenParClose",
+            State::ParametersS117 => "117:Parameters",
+            State::ParameterS118 => "118:Parameter",
+            State::TokenParCloseS119 => "119:TokenParClose",
+            State::ParametersS120 => "120:Parameters",
+            State::TokenParCloseS121 => "121:TokenParClose",
+            State::TokenParCloseS122 => "122:TokenParClose",
+            State::ArithmeticExpressionS123 => "123:ArithmeticExpression",
+            State::ConjunctionS124 => "124:Conjunction",
+            State::ConjunctionS125 => "125:Conjunction",
+            State::SimpleExpressionS126 => "126:SimpleExpression",
+            State::TokenSumS127 => "127:TokenSum",
+            State::TokenSubS128 => "128:TokenSub",
+            State::TokenMulS129 => "129:TokenMul",
+            State::TokenDivS130 => "130:TokenDiv",
+            State::TokenParCloseS131 => "131:TokenParClose",
+            State::TokenCBCloseS132 => "132:TokenCBClose",
+            State::TokenDateS133 => "133:TokenDate",
+            State::BodyS134 => "134:Body",
+            State::ConjunctionS135 => "135:Conjunction",
+            State::TokenIntS136 => "136:TokenInt",
+            State::TokenFloatS137 => "137:TokenFloat",
+            State::TokenStringS138 => "138:TokenString",
+            State::TokenBoolS139 => "139:TokenBool",
+            State::DataTypeS140 => "140:DataType",
+            State::VarDeclarationS141 => "141:VarDeclaration",
+            State::TokenCBOpenS142 => "142:TokenCBOpen",
+            State::BlockOpenS143 => "143:BlockOpen",
+            State::BlockOpenS144 => "144:BlockOpen",
+            State::TokenParCloseS145 => "145:TokenParClose",
+            State::TokenColonS146 => "146:TokenColon",
+            State::TokenColonS147 => "147:TokenColon",
+            State::TokenParCloseS148 => "148:TokenParClose",
+            State::TokenCommaS149 => "149:TokenComma",
+            State::TokenParCloseS150 => "150:TokenParClose",
+            State::TokenParCloseS151 => "151:TokenParClose",
+            State::TermS152 => "152:Term",
+            State::TermS153 => "153:Term",
+            State::FactorS154 => "154:Factor",
+            State::FactorS155 => "155:Factor",
+            State::TokenCBOpenS156 => "156:TokenCBOpen",
+            State::TokenParCloseS157 => "157:TokenParClose",
+            State::TokenCBCloseS158 => "158:TokenCBClose",
+            State::TokenSBOpenS159 => "159:TokenSBOpen",
+            State::BodyS160 => "160:Body",
+            State::BodyS161 => "161:Body",
+            State::DataTypeS162 => "162:DataType",
+            State::DataTypeS163 => "163:DataType",
+            State::TokenColonS164 => "164:TokenColon",
+            State::ParametersS165 => "165:Parameters",
+            State::BodyS166 => "166:Body",
+            State::TokenIntLiteralS167 => "167:TokenIntLiteral",
+            State::TokenCBCloseS168 => "168:TokenCBClose",
+            State::TokenCBCloseS169 => "169:TokenCBClose",
+            State::DataTypeS170 => "170:DataType",
+            State::TokenCBCloseS171 => "171:TokenCBClose",
+            State::TokenSBCloseS172 => "172:TokenSBClose",
+            State::DummyElseS173 => "173:DummyElse",
+            State::TokenElseS174 => "174:TokenElse",
+            State::ElseStatementS175 => "175:ElseStatement",
+            State::BlockOpenS176 => "176:BlockOpen",
+            State::BodyS177 => "177:Body",
+            State::TokenCBCloseS178 => "178:TokenCBClose",
         };
         write!(f, "{name}")
     }
@@ -684,59 +925,124 @@ fn action_aug_s0(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
         TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
         TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
+        TK::TokenFunction => Vec::from(&[Shift(State::TokenFunctionS7)]),
+        TK::TokenProcedure => Vec::from(&[Shift(State::TokenProcedureS8)]),
+        TK::TokenReturn => Vec::from(&[Shift(State::TokenReturnS9)]),
         _ => vec![],
     }
 }
 fn action_tokenid_s1(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenAssign => Vec::from(&[Shift(State::TokenAssignS16)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS17)]),
+        TK::TokenAssign => Vec::from(&[Shift(State::TokenAssignS23)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS24)]),
+        TK::TokenSBOpen => Vec::from(&[Shift(State::TokenSBOpenS25)]),
         _ => vec![],
     }
 }
 fn action_tokeninit_s2(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenCBOpen => Vec::from(&[Shift(State::TokenCBOpenS18)]),
+        TK::TokenCBOpen => Vec::from(&[Shift(State::TokenCBOpenS27)]),
         _ => vec![],
     }
 }
 fn action_tokenwhile_s3(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS20)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS29)]),
         _ => vec![],
     }
 }
 fn action_tokenif_s4(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS21)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS30)]),
         _ => vec![],
     }
 }
 fn action_tokenread_s5(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS22)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
         _ => vec![],
     }
 }
 fn action_tokenwrite_s6(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS23)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS32)]),
+        _ => vec![],
+    }
+}
+fn action_tokenfunction_s7(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS33)]),
+        _ => vec![],
+    }
+}
+fn action_tokenprocedure_s8(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS33)]),
+        _ => vec![],
+    }
+}
+fn action_tokenreturn_s9(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
         _ => vec![],
     }
 }
-fn action_program_s7(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_program_s10(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Accept]),
         _ => vec![],
     }
 }
-fn action_body_s8(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_functions_s11(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::BodyBodyEmpty, 0usize)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS56)]),
+        TK::TokenInit => Vec::from(&[Shift(State::TokenInitS2)]),
+        TK::TokenWhile => Vec::from(&[Shift(State::TokenWhileS3)]),
+        TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
+        TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
+        TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
+        TK::TokenReturn => Vec::from(&[Shift(State::TokenReturnS9)]),
+        _ => vec![],
+    }
+}
+fn action_function_s12(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::FunctionsFunctionsSingle, 1usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::FunctionsFunctionsSingle, 1usize)]),
+        TK::TokenInit => Vec::from(&[Reduce(PK::FunctionsFunctionsSingle, 1usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::FunctionsFunctionsSingle, 1usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::FunctionsFunctionsSingle, 1usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::FunctionsFunctionsSingle, 1usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::FunctionsFunctionsSingle, 1usize)]),
+        TK::TokenFunction => Vec::from(&[Shift(State::TokenFunctionS7)]),
+        TK::TokenProcedure => Vec::from(&[Shift(State::TokenProcedureS8)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::FunctionsFunctionsSingle, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_functionsignature_s13(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenCBOpen => Vec::from(&[Shift(State::TokenCBOpenS59)]),
+        _ => vec![],
+    }
+}
+fn action_body_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::ProgramProgramOnlyBody, 1usize)]),
         _ => vec![],
     }
 }
-fn action_functionread_s9(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_functionread_s15(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::StatementStatementRead, 1usize)]),
         TK::TokenId => Vec::from(&[Reduce(PK::StatementStatementRead, 1usize)]),
@@ -745,10 +1051,11 @@ fn action_functionread_s9(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         TK::TokenIf => Vec::from(&[Reduce(PK::StatementStatementRead, 1usize)]),
         TK::TokenRead => Vec::from(&[Reduce(PK::StatementStatementRead, 1usize)]),
         TK::TokenWrite => Vec::from(&[Reduce(PK::StatementStatementRead, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::StatementStatementRead, 1usize)]),
         _ => vec![],
     }
 }
-fn action_functionwrite_s10(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_functionwrite_s16(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::StatementStatementWrite, 1usize)]),
         TK::TokenId => Vec::from(&[Reduce(PK::StatementStatementWrite, 1usize)]),
@@ -757,29 +1064,44 @@ fn action_functionwrite_s10(token_kind: TokenKind) -> Vec<Action<State, ProdKind
         TK::TokenIf => Vec::from(&[Reduce(PK::StatementStatementWrite, 1usize)]),
         TK::TokenRead => Vec::from(&[Reduce(PK::StatementStatementWrite, 1usize)]),
         TK::TokenWrite => Vec::from(&[Reduce(PK::StatementStatementWrite, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::StatementStatementWrite, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_functioncall_s17(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::StatementStatementCall, 1usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::StatementStatementCall, 1usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::StatementStatementCall, 1usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::StatementStatementCall, 1usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::StatementStatementCall, 1usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::StatementStatementCall, 1usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::StatementStatementCall, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::StatementStatementCall, 1usize)]),
         _ => vec![],
     }
 }
-fn action_expressions_s11(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expressions_s18(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::BodyBodyExpressions, 1usize)]),
         TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyExpressions, 1usize)]),
         _ => vec![],
     }
 }
-fn action_statement_s12(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_statement_s19(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::ExpressionsExpressionSingle, 1usize)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS24)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS60)]),
         TK::TokenCBClose => Vec::from(&[Reduce(PK::ExpressionsExpressionSingle, 1usize)]),
         TK::TokenWhile => Vec::from(&[Shift(State::TokenWhileS3)]),
         TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
         TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
         TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
+        TK::TokenReturn => Vec::from(&[Shift(State::TokenReturnS9)]),
         _ => vec![],
     }
 }
-fn action_assignment_s13(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_assignment_s20(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::StatementStatementAssignment, 1usize)]),
         TK::TokenId => Vec::from(&[Reduce(PK::StatementStatementAssignment, 1usize)]),
@@ -790,10 +1112,11 @@ fn action_assignment_s13(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         TK::TokenIf => Vec::from(&[Reduce(PK::StatementStatementAssignment, 1usize)]),
         TK::TokenRead => Vec::from(&[Reduce(PK::StatementStatementAssignment, 1usize)]),
         TK::TokenWrite => Vec::from(&[Reduce(PK::StatementStatementAssignment, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::StatementStatementAssignment, 1usize)]),
         _ => vec![],
     }
 }
-fn action_whileloop_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_whileloop_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::StatementStatementWhile, 1usize)]),
         TK::TokenId => Vec::from(&[Reduce(PK::StatementStatementWhile, 1usize)]),
@@ -802,10 +1125,11 @@ fn action_whileloop_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::TokenIf => Vec::from(&[Reduce(PK::StatementStatementWhile, 1usize)]),
         TK::TokenRead => Vec::from(&[Reduce(PK::StatementStatementWhile, 1usize)]),
         TK::TokenWrite => Vec::from(&[Reduce(PK::StatementStatementWhile, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::StatementStatementWhile, 1usize)]),
         _ => vec![],
     }
 }
-fn action_ifstatement_s15(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ifstatement_s22(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::StatementStatementIfStatement, 1usize)]),
         TK::TokenId => Vec::from(&[Reduce(PK::StatementStatementIfStatement, 1usize)]),
@@ -816,108 +1140,152 @@ fn action_ifstatement_s15(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         TK::TokenIf => Vec::from(&[Reduce(PK::StatementStatementIfStatement, 1usize)]),
         TK::TokenRead => Vec::from(&[Reduce(PK::StatementStatementIfStatement, 1usize)]),
         TK::TokenWrite => Vec::from(&[Reduce(PK::StatementStatementIfStatement, 1usize)]),
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::StatementStatementIfStatement, 1usize)])
+        }
         _ => vec![],
     }
 }
-fn action_tokenassign_s16(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenassign_s23(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS28)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS29)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
-        TK::TokenConvDate => Vec::from(&[Shift(State::TokenConvDateS32)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
+        TK::TokenConvDate => Vec::from(&[Shift(State::TokenConvDateS62)]),
         _ => vec![],
     }
 }
-fn action_tokenparopen_s17(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparopen_s24(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS39)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS65)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
         _ => vec![],
     }
 }
-fn action_tokencbopen_s18(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokensbopen_s25(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS40)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
         _ => vec![],
     }
 }
-fn action_initbody_s19(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_index_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenAssign => Vec::from(&[Shift(State::TokenAssignS69)]),
+        _ => vec![],
+    }
+}
+fn action_tokencbopen_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS70)]),
+        _ => vec![],
+    }
+}
+fn action_initbody_s28(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::BodyBodyInit, 2usize)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS24)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS60)]),
         TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyInit, 2usize)]),
         TK::TokenWhile => Vec::from(&[Shift(State::TokenWhileS3)]),
         TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
         TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
         TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
+        TK::TokenReturn => Vec::from(&[Shift(State::TokenReturnS9)]),
         _ => vec![],
     }
 }
-fn action_tokenparopen_s20(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparopen_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS28)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS44)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
-        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS45)]),
-        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS46)]),
-        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS47)]),
-        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS48)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
         _ => vec![],
     }
 }
-fn action_tokenparopen_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparopen_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS28)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS44)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
-        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS45)]),
-        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS46)]),
-        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS47)]),
-        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS48)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
         _ => vec![],
     }
 }
-fn action_tokenparopen_s22(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparopen_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS55)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS76)]),
         _ => vec![],
     }
 }
-fn action_tokenparopen_s23(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparopen_s32(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS28)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS29)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
         _ => vec![],
     }
 }
-fn action_tokenid_s24(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenid_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenAssign => Vec::from(&[Shift(State::TokenAssignS16)]),
+        TK::TokenParOpen => Vec::from(&[Reduce(PK::FunctionNameFunctionName, 1usize)]),
         _ => vec![],
     }
 }
-fn action_expressions_s25(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_functionname_s34(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::ExpressionsExpressionRecursive, 2usize)]),
-        TK::TokenCBClose => {
-            Vec::from(&[Reduce(PK::ExpressionsExpressionRecursive, 2usize)])
-        }
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS78)]),
         _ => vec![],
     }
 }
-fn action_tokenintliteral_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_functionname_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS79)]),
+        _ => vec![],
+    }
+}
+fn action_tokenintliteral_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         TK::TokenId => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
@@ -927,6 +1295,7 @@ fn action_tokenintliteral_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKi
         TK::TokenDiv => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         TK::TokenParClose => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         TK::TokenCBClose => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         TK::TokenWhile => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         TK::TokenEqual => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         TK::TokenNotEqual => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
@@ -935,14 +1304,16 @@ fn action_tokenintliteral_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKi
         TK::TokenGreater => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         TK::TokenIf => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         TK::TokenAnd => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         TK::TokenOr => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         TK::TokenRead => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         TK::TokenWrite => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::NumberNumberInt, 1usize)]),
         _ => vec![],
     }
 }
-fn action_tokenfloatliteral_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenfloatliteral_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         TK::TokenId => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
@@ -952,6 +1323,7 @@ fn action_tokenfloatliteral_s27(token_kind: TokenKind) -> Vec<Action<State, Prod
         TK::TokenDiv => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         TK::TokenParClose => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         TK::TokenCBClose => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         TK::TokenWhile => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         TK::TokenEqual => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         TK::TokenNotEqual => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
@@ -960,67 +1332,44 @@ fn action_tokenfloatliteral_s27(token_kind: TokenKind) -> Vec<Action<State, Prod
         TK::TokenGreater => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         TK::TokenIf => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         TK::TokenAnd => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         TK::TokenOr => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         TK::TokenRead => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         TK::TokenWrite => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::NumberNumberFloat, 1usize)]),
         _ => vec![],
     }
 }
-fn action_tokenstringliteral_s28(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenstringliteral_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenId => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenParClose => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenCBClose => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenWhile => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenEqual => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenNotEqual => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenLess => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenLessEqual => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenGreater => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenGreaterEqual => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenIf => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenAnd => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenOr => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenRead => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
-        TK::TokenWrite => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionString, 1usize)])
-        }
+        TK::STOP => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenSum => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenMul => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenSub => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenDiv => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenEqual => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenNotEqual => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenLess => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenLessEqual => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenGreater => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenAnd => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenOr => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::FactorFactorString, 1usize)]),
         _ => vec![],
     }
 }
-fn action_tokenid_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenid_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenId => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
@@ -1028,8 +1377,11 @@ fn action_tokenid_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::TokenMul => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenSub => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenDiv => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS80)]),
         TK::TokenParClose => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenCBClose => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
+        TK::TokenSBOpen => Vec::from(&[Shift(State::TokenSBOpenS25)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenWhile => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenEqual => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenNotEqual => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
@@ -1038,69 +1390,265 @@ fn action_tokenid_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::TokenGreater => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenIf => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenAnd => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenOr => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenRead => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         TK::TokenWrite => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
         _ => vec![],
     }
 }
-fn action_tokensub_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokensub_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS57)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS58)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS82)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS83)]),
         _ => vec![],
     }
 }
-fn action_tokenparopen_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparopen_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS29)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
         _ => vec![],
     }
 }
-fn action_tokenconvdate_s32(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokentrue_s42(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS60)]),
+        TK::STOP => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenSum => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenMul => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenSub => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenDiv => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenEqual => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenNotEqual => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenLess => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenLessEqual => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenGreater => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenAnd => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenOr => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::FactorFactorTrue, 1usize)]),
         _ => vec![],
     }
 }
-fn action_functionconvdate_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenfalse_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
-        TK::TokenId => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenSum => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenMul => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenSub => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenDiv => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenEqual => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenNotEqual => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenLess => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenLessEqual => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenGreater => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenAnd => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenOr => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::FactorFactorFalse, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_tokennot_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
+        _ => vec![],
+    }
+}
+fn action_tokeniszero_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS86)]),
+        _ => vec![],
+    }
+}
+fn action_functioniszero_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
+        }
+        TK::TokenId => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
+        }
+        TK::TokenParClose => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
+        }
         TK::TokenCBClose => {
-            Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)])
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
+        }
+        TK::TokenWhile => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
+        }
+        TK::TokenIf => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
+        }
+        TK::TokenComma => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
+        }
+        TK::TokenAnd => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
+        }
+        TK::TokenOr => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
+        }
+        TK::TokenRead => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
+        }
+        TK::TokenWrite => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
+        }
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
         }
-        TK::TokenWhile => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
-        TK::TokenIf => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
-        TK::TokenRead => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
-        TK::TokenWrite => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
         _ => vec![],
     }
 }
-fn action_simpleexpression_s34(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_functioncall_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)]),
-        TK::TokenId => Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenSum => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenMul => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenSub => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenDiv => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenEqual => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenNotEqual => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenLess => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenLessEqual => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenGreater => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenAnd => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenOr => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::FactorFactorCall, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_booleanexpression_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::ConjunctionConjunctionBoolean, 1usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::ConjunctionConjunctionBoolean, 1usize)]),
+        TK::TokenParClose => {
+            Vec::from(&[Reduce(PK::ConjunctionConjunctionBoolean, 1usize)])
+        }
         TK::TokenCBClose => {
-            Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)])
+            Vec::from(&[Reduce(PK::ConjunctionConjunctionBoolean, 1usize)])
+        }
+        TK::TokenWhile => Vec::from(&[Reduce(PK::ConjunctionConjunctionBoolean, 1usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::ConjunctionConjunctionBoolean, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::ConjunctionConjunctionBoolean, 1usize)]),
+        TK::TokenAnd => Vec::from(&[Shift(State::TokenAndS87)]),
+        TK::TokenOr => Vec::from(&[Shift(State::TokenOrS88)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::ConjunctionConjunctionBoolean, 1usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::ConjunctionConjunctionBoolean, 1usize)]),
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::ConjunctionConjunctionBoolean, 1usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_simpleexpression_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionValue, 1usize)])
+        }
+        TK::TokenId => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionValue, 1usize)])
+        }
+        TK::TokenParClose => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionValue, 1usize)])
+        }
+        TK::TokenCBClose => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionValue, 1usize)])
         }
         TK::TokenWhile => {
-            Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)])
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionValue, 1usize)])
+        }
+        TK::TokenEqual => Vec::from(&[Shift(State::TokenEqualS89)]),
+        TK::TokenNotEqual => Vec::from(&[Shift(State::TokenNotEqualS90)]),
+        TK::TokenLess => Vec::from(&[Shift(State::TokenLessS91)]),
+        TK::TokenLessEqual => Vec::from(&[Shift(State::TokenLessEqualS92)]),
+        TK::TokenGreater => Vec::from(&[Shift(State::TokenGreaterS93)]),
+        TK::TokenGreaterEqual => Vec::from(&[Shift(State::TokenGreaterEqualS94)]),
+        TK::TokenIf => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionValue, 1usize)])
+        }
+        TK::TokenComma => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionValue, 1usize)])
+        }
+        TK::TokenAnd => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionValue, 1usize)])
+        }
+        TK::TokenOr => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionValue, 1usize)])
+        }
+        TK::TokenRead => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionValue, 1usize)])
         }
-        TK::TokenIf => Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)]),
-        TK::TokenRead => Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)]),
         TK::TokenWrite => {
-            Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)])
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionValue, 1usize)])
         }
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionValue, 1usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_conjunction_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::StatementStatementReturn, 2usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::StatementStatementReturn, 2usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::StatementStatementReturn, 2usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::StatementStatementReturn, 2usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::StatementStatementReturn, 2usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::StatementStatementReturn, 2usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::StatementStatementReturn, 2usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::StatementStatementReturn, 2usize)]),
         _ => vec![],
     }
 }
-fn action_number_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_number_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         TK::TokenId => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
@@ -1110,6 +1658,7 @@ fn action_number_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::TokenDiv => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         TK::TokenParClose => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         TK::TokenCBClose => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         TK::TokenWhile => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         TK::TokenEqual => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         TK::TokenNotEqual => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
@@ -1118,47 +1667,117 @@ fn action_number_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::TokenGreater => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         TK::TokenIf => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         TK::TokenAnd => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         TK::TokenOr => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         TK::TokenRead => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         TK::TokenWrite => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::FactorFactorNumber, 1usize)]),
         _ => vec![],
     }
 }
-fn action_arithmeticexpression_s36(
-    token_kind: TokenKind,
-) -> Vec<Action<State, ProdKind>> {
+fn action_notstatement_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
+            )
         }
         TK::TokenId => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
+            )
         }
-        TK::TokenSum => Vec::from(&[Reduce(PK::DummyAEP1, 0usize)]),
-        TK::TokenSub => Vec::from(&[Reduce(PK::DummyAEP1, 0usize)]),
         TK::TokenParClose => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
+            )
         }
         TK::TokenCBClose => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
+            )
         }
         TK::TokenWhile => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
-        }
-        TK::TokenEqual => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
+            )
         }
-        TK::TokenNotEqual => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        TK::TokenIf => {
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
+            )
         }
-        TK::TokenLess => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        TK::TokenComma => {
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
+            )
         }
-        TK::TokenLessEqual => {
-            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        TK::TokenAnd => {
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
+            )
         }
-        TK::TokenGreater => {
+        TK::TokenOr => {
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
+            )
+        }
+        TK::TokenRead => {
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
+            )
+        }
+        TK::TokenWrite => {
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
+            )
+        }
+        TK::TokenReturn => {
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
+            )
+        }
+        _ => vec![],
+    }
+}
+fn action_arithmeticexpression_s53(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => {
+            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        }
+        TK::TokenId => {
+            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        }
+        TK::TokenSum => Vec::from(&[Reduce(PK::DummyAEP1, 0usize)]),
+        TK::TokenSub => Vec::from(&[Reduce(PK::DummyAEP1, 0usize)]),
+        TK::TokenParClose => {
+            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        }
+        TK::TokenCBClose => {
+            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        }
+        TK::TokenSBClose => {
+            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        }
+        TK::TokenWhile => {
+            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        }
+        TK::TokenEqual => {
+            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        }
+        TK::TokenNotEqual => {
+            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        }
+        TK::TokenLess => {
+            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        }
+        TK::TokenLessEqual => {
+            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        }
+        TK::TokenGreater => {
             Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
         }
         TK::TokenGreaterEqual => {
@@ -1167,6 +1786,9 @@ fn action_arithmeticexpression_s36(
         TK::TokenIf => {
             Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
         }
+        TK::TokenComma => {
+            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        }
         TK::TokenAnd => {
             Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
         }
@@ -1179,10 +1801,13 @@ fn action_arithmeticexpression_s36(
         TK::TokenWrite => {
             Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
         }
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::SimpleExpressionSimpleExpressionArithmetic, 1usize)])
+        }
         _ => vec![],
     }
 }
-fn action_term_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_term_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => {
             Vec::from(
@@ -1216,6 +1841,11 @@ fn action_term_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
                 &[Reduce(PK::ArithmeticExpressionArithmeticExpressionTerm, 1usize)],
             )
         }
+        TK::TokenSBClose => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionTerm, 1usize)],
+            )
+        }
         TK::TokenWhile => {
             Vec::from(
                 &[Reduce(PK::ArithmeticExpressionArithmeticExpressionTerm, 1usize)],
@@ -1256,6 +1886,11 @@ fn action_term_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
                 &[Reduce(PK::ArithmeticExpressionArithmeticExpressionTerm, 1usize)],
             )
         }
+        TK::TokenComma => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionTerm, 1usize)],
+            )
+        }
         TK::TokenAnd => {
             Vec::from(
                 &[Reduce(PK::ArithmeticExpressionArithmeticExpressionTerm, 1usize)],
@@ -1276,10 +1911,15 @@ fn action_term_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
                 &[Reduce(PK::ArithmeticExpressionArithmeticExpressionTerm, 1usize)],
             )
         }
+        TK::TokenReturn => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionTerm, 1usize)],
+            )
+        }
         _ => vec![],
     }
 }
-fn action_factor_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_factor_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
         TK::TokenId => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
@@ -1289,6 +1929,7 @@ fn action_factor_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::TokenDiv => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
         TK::TokenParClose => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
         TK::TokenCBClose => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
         TK::TokenWhile => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
         TK::TokenEqual => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
         TK::TokenNotEqual => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
@@ -1297,201 +1938,287 @@ fn action_factor_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::TokenGreater => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
         TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
         TK::TokenIf => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
         TK::TokenAnd => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
         TK::TokenOr => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
         TK::TokenRead => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
         TK::TokenWrite => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::TermTermFactor, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_tokenid_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenAssign => Vec::from(&[Shift(State::TokenAssignS23)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS98)]),
+        TK::TokenSBOpen => Vec::from(&[Shift(State::TokenSBOpenS25)]),
+        _ => vec![],
+    }
+}
+fn action_body_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::ProgramProgramFunctionsOnlyBody, 2usize)]),
         _ => vec![],
     }
 }
-fn action_tokenparclose_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_functions_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenCBOpen => Vec::from(&[Shift(State::TokenCBOpenS63)]),
+        TK::STOP => Vec::from(&[Reduce(PK::FunctionsFunctionsRecursive, 2usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::FunctionsFunctionsRecursive, 2usize)]),
+        TK::TokenInit => Vec::from(&[Reduce(PK::FunctionsFunctionsRecursive, 2usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::FunctionsFunctionsRecursive, 2usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::FunctionsFunctionsRecursive, 2usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::FunctionsFunctionsRecursive, 2usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::FunctionsFunctionsRecursive, 2usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::FunctionsFunctionsRecursive, 2usize)]),
         _ => vec![],
     }
 }
-fn action_tokenid_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokencbopen_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenColon => Vec::from(&[Shift(State::TokenColonS64)]),
-        TK::TokenComma => Vec::from(&[Shift(State::TokenCommaS65)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS60)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyEmpty, 0usize)]),
+        TK::TokenInit => Vec::from(&[Shift(State::TokenInitS2)]),
+        TK::TokenWhile => Vec::from(&[Shift(State::TokenWhileS3)]),
+        TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
+        TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
+        TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
+        TK::TokenReturn => Vec::from(&[Shift(State::TokenReturnS9)]),
         _ => vec![],
     }
 }
-fn action_vardeclarations_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenid_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenCBClose => Vec::from(&[Shift(State::TokenCBCloseS66)]),
+        TK::TokenAssign => Vec::from(&[Shift(State::TokenAssignS23)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS80)]),
+        TK::TokenSBOpen => Vec::from(&[Shift(State::TokenSBOpenS25)]),
         _ => vec![],
     }
 }
-fn action_vardeclaration_s42(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expressions_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS40)]),
+        TK::STOP => Vec::from(&[Reduce(PK::ExpressionsExpressionRecursive, 2usize)]),
         TK::TokenCBClose => {
-            Vec::from(&[Reduce(PK::VarDeclarationsVarDeclarationsSingle, 1usize)])
+            Vec::from(&[Reduce(PK::ExpressionsExpressionRecursive, 2usize)])
         }
         _ => vec![],
     }
 }
-fn action_expressions_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenconvdate_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::BodyBodyInitExpressions, 3usize)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyInitExpressions, 3usize)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS100)]),
         _ => vec![],
     }
 }
-fn action_tokenid_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_functionconvdate_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenSum => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
-        TK::TokenMul => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
-        TK::TokenSub => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
-        TK::TokenDiv => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
-        TK::TokenParClose => {
-            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionVar, 1usize)])
-        }
-        TK::TokenEqual => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
-        TK::TokenNotEqual => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
-        TK::TokenLess => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
-        TK::TokenLessEqual => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
-        TK::TokenGreater => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
-        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::FactorFactorId, 1usize)]),
-        TK::TokenAnd => {
-            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionVar, 1usize)])
-        }
-        TK::TokenOr => {
-            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionVar, 1usize)])
+        TK::STOP => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
+        TK::TokenCBClose => {
+            Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)])
         }
+        TK::TokenWhile => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::AssignmentAssignmentConvDate, 3usize)]),
         _ => vec![],
     }
 }
-fn action_tokentrue_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_conjunction_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => {
-            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionTrue, 1usize)])
+        TK::STOP => Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)]),
+        TK::TokenCBClose => {
+            Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)])
         }
-        TK::TokenAnd => {
-            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionTrue, 1usize)])
+        TK::TokenWhile => {
+            Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)])
         }
-        TK::TokenOr => {
-            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionTrue, 1usize)])
+        TK::TokenIf => Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)]),
+        TK::TokenWrite => {
+            Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)])
+        }
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::AssignmentAssignmentExpression, 3usize)])
         }
         _ => vec![],
     }
 }
-fn action_tokenfalse_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparclose_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => {
-            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionFalse, 1usize)])
+        TK::STOP => Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)]),
+        TK::TokenId => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
         }
-        TK::TokenAnd => {
-            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionFalse, 1usize)])
+        TK::TokenCBOpen => Vec::from(&[Shift(State::TokenCBOpenS101)]),
+        TK::TokenWhile => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
         }
-        TK::TokenOr => {
-            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionFalse, 1usize)])
+        TK::TokenIf => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenRead => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenWrite => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
         }
         _ => vec![],
     }
 }
-fn action_tokennot_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_arguments_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS28)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS44)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
-        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS45)]),
-        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS46)]),
-        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS47)]),
-        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS48)]),
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS102)]),
+        TK::TokenComma => Vec::from(&[Shift(State::TokenCommaS103)]),
         _ => vec![],
     }
 }
-fn action_tokeniszero_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_conjunction_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS69)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::ArgumentsArgumentsSingle, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::ArgumentsArgumentsSingle, 1usize)]),
         _ => vec![],
     }
 }
-fn action_functioniszero_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_simpleexpression_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => {
-            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
-        }
-        TK::TokenAnd => {
-            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
-        }
-        TK::TokenOr => {
-            Vec::from(&[Reduce(PK::BooleanExpressionBooleanExpressionIsZero, 1usize)])
-        }
+        TK::TokenSBClose => Vec::from(&[Shift(State::TokenSBCloseS104)]),
         _ => vec![],
     }
 }
-fn action_booleanexpression_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenassign_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => {
-            Vec::from(&[Reduce(PK::ConjunctionConjunctionBoolean, 1usize)])
-        }
-        TK::TokenAnd => Vec::from(&[Shift(State::TokenAndS70)]),
-        TK::TokenOr => Vec::from(&[Shift(State::TokenOrS71)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
         _ => vec![],
     }
 }
-fn action_simpleexpression_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenid_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenEqual => Vec::from(&[Shift(State::TokenEqualS72)]),
-        TK::TokenNotEqual => Vec::from(&[Shift(State::TokenNotEqualS73)]),
-        TK::TokenLess => Vec::from(&[Shift(State::TokenLessS74)]),
-        TK::TokenLessEqual => Vec::from(&[Shift(State::TokenLessEqualS75)]),
-        TK::TokenGreater => Vec::from(&[Shift(State::TokenGreaterS76)]),
-        TK::TokenGreaterEqual => Vec::from(&[Shift(State::TokenGreaterEqualS77)]),
+        TK::TokenColon => Vec::from(&[Shift(State::TokenColonS106)]),
+        TK::TokenComma => Vec::from(&[Shift(State::TokenCommaS107)]),
         _ => vec![],
     }
 }
-fn action_conjunction_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_vardeclarations_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS79)]),
+        TK::TokenCBClose => Vec::from(&[Shift(State::TokenCBCloseS108)]),
         _ => vec![],
     }
 }
-fn action_notstatement_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_vardeclaration_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => {
-            Vec::from(
-                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
-            )
-        }
-        TK::TokenAnd => {
-            Vec::from(
-                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
-            )
-        }
-        TK::TokenOr => {
-            Vec::from(
-                &[Reduce(PK::BooleanExpressionBooleanExpressionNotStatement, 1usize)],
-            )
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS70)]),
+        TK::TokenCBClose => {
+            Vec::from(&[Reduce(PK::VarDeclarationsVarDeclarationsSingle, 1usize)])
         }
         _ => vec![],
     }
 }
-fn action_conjunction_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expressions_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::BodyBodyInitExpressions, 3usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyInitExpressions, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_conjunction_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS110)]),
+        _ => vec![],
+    }
+}
+fn action_conjunction_s75(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS111)]),
+        _ => vec![],
+    }
+}
+fn action_tokenid_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS112)]),
+        TK::TokenSBOpen => Vec::from(&[Shift(State::TokenSBOpenS25)]),
+        _ => vec![],
+    }
+}
+fn action_conjunction_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS114)]),
+        _ => vec![],
+    }
+}
+fn action_tokenparopen_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS80)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS115)]),
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS116)]),
         _ => vec![],
     }
 }
-fn action_tokenid_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparopen_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS81)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS115)]),
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS119)]),
         _ => vec![],
     }
 }
-fn action_simpleexpression_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparopen_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS82)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS121)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
         _ => vec![],
     }
 }
-fn action_tokenintliteral_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_index_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenSum => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenMul => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenSub => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenDiv => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenEqual => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenNotEqual => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenLess => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenLessEqual => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenGreater => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenAnd => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenOr => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::FactorFactorIndex, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_tokenintliteral_s82(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
         TK::TokenId => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
@@ -1501,6 +2228,7 @@ fn action_tokenintliteral_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKi
         TK::TokenDiv => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
         TK::TokenParClose => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
         TK::TokenCBClose => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
         TK::TokenWhile => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
         TK::TokenEqual => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
         TK::TokenNotEqual => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
@@ -1511,14 +2239,16 @@ fn action_tokenintliteral_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKi
             Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)])
         }
         TK::TokenIf => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
         TK::TokenAnd => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
         TK::TokenOr => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
         TK::TokenRead => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
         TK::TokenWrite => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::NumberNumberNegativeInt, 2usize)]),
         _ => vec![],
     }
 }
-fn action_tokenfloatliteral_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenfloatliteral_s83(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
         TK::TokenId => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
@@ -1528,6 +2258,7 @@ fn action_tokenfloatliteral_s58(token_kind: TokenKind) -> Vec<Action<State, Prod
         TK::TokenDiv => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
         TK::TokenParClose => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
         TK::TokenCBClose => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
         TK::TokenWhile => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
         TK::TokenEqual => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
         TK::TokenNotEqual => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
@@ -1538,138 +2269,86 @@ fn action_tokenfloatliteral_s58(token_kind: TokenKind) -> Vec<Action<State, Prod
             Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)])
         }
         TK::TokenIf => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
         TK::TokenAnd => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
         TK::TokenOr => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
         TK::TokenRead => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
         TK::TokenWrite => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::NumberNumberNegativeFloat, 2usize)]),
         _ => vec![],
     }
 }
-fn action_arithmeticexpression_s59(
+fn action_arithmeticexpression_s84(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::TokenSum => Vec::from(&[Reduce(PK::DummyAEP1, 0usize)]),
         TK::TokenSub => Vec::from(&[Reduce(PK::DummyAEP1, 0usize)]),
-        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS83)]),
-        _ => vec![],
-    }
-}
-fn action_tokenparopen_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::TokenDate => Vec::from(&[Shift(State::TokenDateS84)]),
-        _ => vec![],
-    }
-}
-fn action_dummyae_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::TokenSum => Vec::from(&[Shift(State::TokenSumS85)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS86)]),
-        _ => vec![],
-    }
-}
-fn action_dummyt_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::TokenMul => Vec::from(&[Shift(State::TokenMulS87)]),
-        TK::TokenDiv => Vec::from(&[Shift(State::TokenDivS88)]),
-        _ => vec![],
-    }
-}
-fn action_tokencbopen_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS24)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyEmpty, 0usize)]),
-        TK::TokenInit => Vec::from(&[Shift(State::TokenInitS2)]),
-        TK::TokenWhile => Vec::from(&[Shift(State::TokenWhileS3)]),
-        TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
-        TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
-        TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
-        _ => vec![],
-    }
-}
-fn action_tokencolon_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::TokenInt => Vec::from(&[Shift(State::TokenIntS90)]),
-        TK::TokenFloat => Vec::from(&[Shift(State::TokenFloatS91)]),
-        TK::TokenString => Vec::from(&[Shift(State::TokenStringS92)]),
-        _ => vec![],
-    }
-}
-fn action_tokencomma_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS40)]),
-        _ => vec![],
-    }
-}
-fn action_tokencbclose_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
-        TK::TokenId => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
-        TK::TokenWhile => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
-        TK::TokenIf => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
-        TK::TokenRead => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
-        TK::TokenWrite => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
-        _ => vec![],
-    }
-}
-fn action_vardeclarations_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
-    match token_kind {
-        TK::TokenCBClose => {
-            Vec::from(&[Reduce(PK::VarDeclarationsVarDeclarationsRecursive, 2usize)])
-        }
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS122)]),
         _ => vec![],
     }
 }
-fn action_booleanexpression_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_booleanexpression_s85(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::NotStatementNot, 2usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::NotStatementNot, 2usize)]),
         TK::TokenParClose => Vec::from(&[Reduce(PK::NotStatementNot, 2usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::NotStatementNot, 2usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::NotStatementNot, 2usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::NotStatementNot, 2usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::NotStatementNot, 2usize)]),
         TK::TokenAnd => Vec::from(&[Reduce(PK::NotStatementNot, 2usize)]),
         TK::TokenOr => Vec::from(&[Reduce(PK::NotStatementNot, 2usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::NotStatementNot, 2usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::NotStatementNot, 2usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::NotStatementNot, 2usize)]),
         _ => vec![],
     }
 }
-fn action_tokenparopen_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparopen_s86(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS29)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
         _ => vec![],
     }
 }
-fn action_tokenand_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenand_s87(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS28)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS44)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
-        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS45)]),
-        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS46)]),
-        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS47)]),
-        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS48)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
         _ => vec![],
     }
 }
-fn action_tokenor_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenor_s88(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS28)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS44)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
-        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS45)]),
-        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS46)]),
-        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS47)]),
-        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS48)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
         _ => vec![],
     }
 }
-fn action_tokenequal_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenequal_s89(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::TokenIntLiteral => {
             Vec::from(&[Reduce(PK::ComparisonOpComparisonOpEqual, 1usize)])
@@ -1685,10 +2364,12 @@ fn action_tokenequal_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         TK::TokenParOpen => {
             Vec::from(&[Reduce(PK::ComparisonOpComparisonOpEqual, 1usize)])
         }
+        TK::TokenTrue => Vec::from(&[Reduce(PK::ComparisonOpComparisonOpEqual, 1usize)]),
+        TK::TokenFalse => Vec::from(&[Reduce(PK::ComparisonOpComparisonOpEqual, 1usize)]),
         _ => vec![],
     }
 }
-fn action_tokennotequal_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokennotequal_s90(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::TokenIntLiteral => {
             Vec::from(&[Reduce(PK::ComparisonOpComparisonOpNotEqual, 1usize)])
@@ -1706,10 +2387,16 @@ fn action_tokennotequal_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind
         TK::TokenParOpen => {
             Vec::from(&[Reduce(PK::ComparisonOpComparisonOpNotEqual, 1usize)])
         }
+        TK::TokenTrue => {
+            Vec::from(&[Reduce(PK::ComparisonOpComparisonOpNotEqual, 1usize)])
+        }
+        TK::TokenFalse => {
+            Vec::from(&[Reduce(PK::ComparisonOpComparisonOpNotEqual, 1usize)])
+        }
         _ => vec![],
     }
 }
-fn action_tokenless_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenless_s91(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::TokenIntLiteral => {
             Vec::from(&[Reduce(PK::ComparisonOpComparisonOpLess, 1usize)])
@@ -1725,10 +2412,12 @@ fn action_tokenless_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
         TK::TokenParOpen => {
             Vec::from(&[Reduce(PK::ComparisonOpComparisonOpLess, 1usize)])
         }
+        TK::TokenTrue => Vec::from(&[Reduce(PK::ComparisonOpComparisonOpLess, 1usize)]),
+        TK::TokenFalse => Vec::from(&[Reduce(PK::ComparisonOpComparisonOpLess, 1usize)]),
         _ => vec![],
     }
 }
-fn action_tokenlessequal_s75(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenlessequal_s92(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::TokenIntLiteral => {
             Vec::from(&[Reduce(PK::ComparisonOpComparisonOpLessEqual, 1usize)])
@@ -1748,10 +2437,16 @@ fn action_tokenlessequal_s75(token_kind: TokenKind) -> Vec<Action<State, ProdKin
         TK::TokenParOpen => {
             Vec::from(&[Reduce(PK::ComparisonOpComparisonOpLessEqual, 1usize)])
         }
+        TK::TokenTrue => {
+            Vec::from(&[Reduce(PK::ComparisonOpComparisonOpLessEqual, 1usize)])
+        }
+        TK::TokenFalse => {
+            Vec::from(&[Reduce(PK::ComparisonOpComparisonOpLessEqual, 1usize)])
+        }
         _ => vec![],
     }
 }
-fn action_tokengreater_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokengreater_s93(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::TokenIntLiteral => {
             Vec::from(&[Reduce(PK::ComparisonOpComparisonOpGreater, 1usize)])
@@ -1767,10 +2462,16 @@ fn action_tokengreater_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>
         TK::TokenParOpen => {
             Vec::from(&[Reduce(PK::ComparisonOpComparisonOpGreater, 1usize)])
         }
-        _ => vec![],
+        TK::TokenTrue => {
+            Vec::from(&[Reduce(PK::ComparisonOpComparisonOpGreater, 1usize)])
+        }
+        TK::TokenFalse => {
+            Vec::from(&[Reduce(PK::ComparisonOpComparisonOpGreater, 1usize)])
+        }
+        _ => vec![],
     }
 }
-fn action_tokengreaterequal_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokengreaterequal_s94(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::TokenIntLiteral => {
             Vec::from(&[Reduce(PK::ComparisonOpComparisonOpGreaterEqual, 1usize)])
@@ -1790,1395 +2491,5084 @@ fn action_tokengreaterequal_s77(token_kind: TokenKind) -> Vec<Action<State, Prod
         TK::TokenParOpen => {
             Vec::from(&[Reduce(PK::ComparisonOpComparisonOpGreaterEqual, 1usize)])
         }
+        TK::TokenTrue => {
+            Vec::from(&[Reduce(PK::ComparisonOpComparisonOpGreaterEqual, 1usize)])
+        }
+        TK::TokenFalse => {
+            Vec::from(&[Reduce(PK::ComparisonOpComparisonOpGreaterEqual, 1usize)])
+        }
         _ => vec![],
     }
 }
-fn action_comparisonop_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_comparisonop_s95(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS28)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS29)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
         _ => vec![],
     }
 }
-fn action_tokenparclose_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_dummyae_s96(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenCBOpen => Vec::from(&[Shift(State::TokenCBOpenS99)]),
+        TK::TokenSum => Vec::from(&[Shift(State::TokenSumS127)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS128)]),
         _ => vec![],
     }
 }
-fn action_tokenparclose_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_dummyt_s97(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenCBOpen => Vec::from(&[Shift(State::TokenCBOpenS100)]),
+        TK::TokenMul => Vec::from(&[Shift(State::TokenMulS129)]),
+        TK::TokenDiv => Vec::from(&[Shift(State::TokenDivS130)]),
         _ => vec![],
     }
 }
-fn action_tokenparclose_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparopen_s98(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
-        TK::TokenId => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
-        TK::TokenCBClose => {
-            Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)])
-        }
-        TK::TokenWhile => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
-        TK::TokenIf => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
-        TK::TokenRead => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
-        TK::TokenWrite => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS131)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
         _ => vec![],
     }
 }
-fn action_tokenparclose_s82(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_body_s99(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)]),
-        TK::TokenId => Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)]),
+        TK::TokenCBClose => Vec::from(&[Shift(State::TokenCBCloseS132)]),
+        _ => vec![],
+    }
+}
+fn action_tokenparopen_s100(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenDate => Vec::from(&[Shift(State::TokenDateS133)]),
+        _ => vec![],
+    }
+}
+fn action_tokencbopen_s101(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS60)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyEmpty, 0usize)]),
+        TK::TokenInit => Vec::from(&[Shift(State::TokenInitS2)]),
+        TK::TokenWhile => Vec::from(&[Shift(State::TokenWhileS3)]),
+        TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
+        TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
+        TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
+        TK::TokenReturn => Vec::from(&[Shift(State::TokenReturnS9)]),
+        _ => vec![],
+    }
+}
+fn action_tokenparclose_s102(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenId => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenSum => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenMul => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenSub => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenDiv => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenParClose => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
         TK::TokenCBClose => {
-            Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)])
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenSBClose => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
         }
         TK::TokenWhile => {
-            Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)])
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenEqual => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenNotEqual => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenLess => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenLessEqual => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenGreater => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenGreaterEqual => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenIf => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenComma => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenAnd => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenOr => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenRead => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
         }
-        TK::TokenIf => Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)]),
-        TK::TokenRead => Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)]),
         TK::TokenWrite => {
-            Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)])
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
+        }
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallWithArguments, 4usize)])
         }
         _ => vec![],
     }
 }
-fn action_tokenparclose_s83(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokencomma_s103(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenId => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenSum => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenMul => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenSub => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenDiv => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenParClose => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenWhile => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenEqual => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenNotEqual => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenLess => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenLessEqual => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenGreater => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenIf => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenAnd => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenOr => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenRead => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
-        TK::TokenWrite => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        TK::TokenNot => Vec::from(&[Shift(State::TokenNotS44)]),
+        TK::TokenIsZero => Vec::from(&[Shift(State::TokenIsZeroS45)]),
         _ => vec![],
     }
 }
-fn action_tokendate_s84(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokensbclose_s104(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS101)]),
+        TK::STOP => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenAssign => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenSum => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenMul => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenSub => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenDiv => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenEqual => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenNotEqual => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenLess => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenLessEqual => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenGreater => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenAnd => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenOr => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::IndexIndex, 3usize)]),
         _ => vec![],
     }
 }
-fn action_tokensum_s85(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_conjunction_s105(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS29)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
+        TK::STOP => Vec::from(&[Reduce(PK::AssignmentAssignmentIndexed, 4usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::AssignmentAssignmentIndexed, 4usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::AssignmentAssignmentIndexed, 4usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::AssignmentAssignmentIndexed, 4usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::AssignmentAssignmentIndexed, 4usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::AssignmentAssignmentIndexed, 4usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::AssignmentAssignmentIndexed, 4usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::AssignmentAssignmentIndexed, 4usize)]),
         _ => vec![],
     }
 }
-fn action_tokensub_s86(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokencolon_s106(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS29)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
+        TK::TokenInt => Vec::from(&[Shift(State::TokenIntS136)]),
+        TK::TokenFloat => Vec::from(&[Shift(State::TokenFloatS137)]),
+        TK::TokenString => Vec::from(&[Shift(State::TokenStringS138)]),
+        TK::TokenBool => Vec::from(&[Shift(State::TokenBoolS139)]),
         _ => vec![],
     }
 }
-fn action_tokenmul_s87(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokencomma_s107(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS29)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS70)]),
         _ => vec![],
     }
 }
-fn action_tokendiv_s88(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokencbclose_s108(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS26)]),
-        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS27)]),
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS29)]),
-        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS30)]),
-        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS31)]),
+        TK::STOP => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::InitBodyInitBody, 3usize)]),
         _ => vec![],
     }
 }
-fn action_body_s89(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_vardeclarations_s109(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenCBClose => Vec::from(&[Shift(State::TokenCBCloseS106)]),
+        TK::TokenCBClose => {
+            Vec::from(&[Reduce(PK::VarDeclarationsVarDeclarationsRecursive, 2usize)])
+        }
         _ => vec![],
     }
 }
-fn action_tokenint_s90(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparclose_s110(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenId => Vec::from(&[Reduce(PK::DataTypeIntType, 1usize)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::DataTypeIntType, 1usize)]),
+        TK::TokenCBOpen => Vec::from(&[Shift(State::TokenCBOpenS142)]),
         _ => vec![],
     }
 }
-fn action_tokenfloat_s91(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparclose_s111(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenId => Vec::from(&[Reduce(PK::DataTypeFloatType, 1usize)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::DataTypeFloatType, 1usize)]),
+        TK::TokenCBOpen => Vec::from(&[Shift(State::TokenCBOpenS142)]),
         _ => vec![],
     }
 }
-fn action_tokenstring_s92(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparclose_s112(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenId => Vec::from(&[Reduce(PK::DataTypeStringType, 1usize)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::DataTypeStringType, 1usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
+        TK::TokenCBClose => {
+            Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)])
+        }
+        TK::TokenWhile => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::FunctionReadFunctionReadCall, 4usize)]),
         _ => vec![],
     }
 }
-fn action_datatype_s93(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_index_s113(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenId => {
-            Vec::from(&[Reduce(PK::VarDeclarationVarDeclarationSingle, 3usize)])
-        }
-        TK::TokenCBClose => {
-            Vec::from(&[Reduce(PK::VarDeclarationVarDeclarationSingle, 3usize)])
-        }
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS145)]),
         _ => vec![],
     }
 }
-fn action_vardeclaration_s94(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparclose_s114(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenId => {
-            Vec::from(&[Reduce(PK::VarDeclarationVarDeclarationRecursive, 3usize)])
-        }
+        TK::STOP => Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)]),
         TK::TokenCBClose => {
-            Vec::from(&[Reduce(PK::VarDeclarationVarDeclarationRecursive, 3usize)])
+            Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)])
+        }
+        TK::TokenWhile => {
+            Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)])
+        }
+        TK::TokenIf => Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)]),
+        TK::TokenWrite => {
+            Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)])
+        }
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::FunctionWriteFunctionWriteCall, 4usize)])
         }
         _ => vec![],
     }
 }
-fn action_arithmeticexpression_s95(
-    token_kind: TokenKind,
-) -> Vec<Action<State, ProdKind>> {
+fn action_tokenid_s115(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenSum => Vec::from(&[Reduce(PK::DummyAEP1, 0usize)]),
-        TK::TokenSub => Vec::from(&[Reduce(PK::DummyAEP1, 0usize)]),
-        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS107)]),
+        TK::TokenColon => Vec::from(&[Shift(State::TokenColonS146)]),
         _ => vec![],
     }
 }
-fn action_conjunction_s96(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparclose_s116(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => Vec::from(&[Reduce(PK::ConjunctionConjunctionAnd, 3usize)]),
+        TK::TokenColon => Vec::from(&[Shift(State::TokenColonS147)]),
         _ => vec![],
     }
 }
-fn action_conjunction_s97(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_parameters_s117(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => Vec::from(&[Reduce(PK::ConjunctionConjunctionOr, 3usize)]),
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS148)]),
         _ => vec![],
     }
 }
-fn action_simpleexpression_s98(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_parameter_s118(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => {
-            Vec::from(
-                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
-            )
-        }
-        TK::TokenAnd => {
-            Vec::from(
-                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
-            )
-        }
-        TK::TokenOr => {
-            Vec::from(
-                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
-            )
-        }
+        TK::TokenParClose => Vec::from(&[Reduce(PK::ParametersParametersSingle, 1usize)]),
+        TK::TokenComma => Vec::from(&[Shift(State::TokenCommaS149)]),
         _ => vec![],
     }
 }
-fn action_tokencbopen_s99(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparclose_s119(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS24)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyEmpty, 0usize)]),
-        TK::TokenInit => Vec::from(&[Shift(State::TokenInitS2)]),
-        TK::TokenWhile => Vec::from(&[Shift(State::TokenWhileS3)]),
-        TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
-        TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
-        TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
+        TK::TokenCBOpen => {
+            Vec::from(&[Reduce(PK::FunctionSignatureProcedureNoParameters, 4usize)])
+        }
         _ => vec![],
     }
 }
-fn action_tokencbopen_s100(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_parameters_s120(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS24)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyEmpty, 0usize)]),
-        TK::TokenInit => Vec::from(&[Shift(State::TokenInitS2)]),
-        TK::TokenWhile => Vec::from(&[Shift(State::TokenWhileS3)]),
-        TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
-        TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
-        TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS150)]),
         _ => vec![],
     }
 }
-fn action_tokenparclose_s101(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparclose_s121(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => {
-            Vec::from(
-                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
-            )
-        }
+        TK::STOP => Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)]),
         TK::TokenId => {
-            Vec::from(
-                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
-            )
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenSum => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenMul => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenSub => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenDiv => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenParClose => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
         }
         TK::TokenCBClose => {
-            Vec::from(
-                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
-            )
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenSBClose => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
         }
         TK::TokenWhile => {
-            Vec::from(
-                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
-            )
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenEqual => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenNotEqual => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenLess => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenLessEqual => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenGreater => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenGreaterEqual => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
         }
         TK::TokenIf => {
-            Vec::from(
-                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
-            )
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenComma => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenAnd => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenOr => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
         }
         TK::TokenRead => {
-            Vec::from(
-                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
-            )
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
         }
         TK::TokenWrite => {
-            Vec::from(
-                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
-            )
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
         }
         _ => vec![],
     }
 }
-fn action_term_s102(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparclose_s122(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenId => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenSum => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenMul => Vec::from(&[Reduce(PK::DummyTP1, 0usize)]),
-        TK::TokenSub => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenDiv => Vec::from(&[Reduce(PK::DummyTP1, 0usize)]),
-        TK::TokenParClose => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenCBClose => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenWhile => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenEqual => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenNotEqual => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenLess => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenLessEqual => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenGreater => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenGreaterEqual => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenIf => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenAnd => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenOr => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenRead => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
-        TK::TokenWrite => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
-            )
-        }
+        TK::STOP => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenSum => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenMul => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenSub => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenDiv => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenEqual => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenNotEqual => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenLess => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenLessEqual => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenGreater => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenAnd => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenOr => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::FactorFactorParen, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_arithmeticexpression_s123(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenSum => Vec::from(&[Reduce(PK::DummyAEP1, 0usize)]),
+        TK::TokenSub => Vec::from(&[Reduce(PK::DummyAEP1, 0usize)]),
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS151)]),
+        _ => vec![],
+    }
+}
+fn action_conjunction_s124(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::ConjunctionConjunctionAnd, 3usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::ConjunctionConjunctionAnd, 3usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::ConjunctionConjunctionAnd, 3usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::ConjunctionConjunctionAnd, 3usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::ConjunctionConjunctionAnd, 3usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::ConjunctionConjunctionAnd, 3usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::ConjunctionConjunctionAnd, 3usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::ConjunctionConjunctionAnd, 3usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::ConjunctionConjunctionAnd, 3usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::ConjunctionConjunctionAnd, 3usize)]),
         _ => vec![],
     }
 }
-fn action_term_s103(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_conjunction_s125(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::ConjunctionConjunctionOr, 3usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::ConjunctionConjunctionOr, 3usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::ConjunctionConjunctionOr, 3usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::ConjunctionConjunctionOr, 3usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::ConjunctionConjunctionOr, 3usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::ConjunctionConjunctionOr, 3usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::ConjunctionConjunctionOr, 3usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::ConjunctionConjunctionOr, 3usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::ConjunctionConjunctionOr, 3usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::ConjunctionConjunctionOr, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_simpleexpression_s126(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => {
             Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
             )
         }
         TK::TokenId => {
             Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
-            )
-        }
-        TK::TokenSum => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
-            )
-        }
-        TK::TokenMul => Vec::from(&[Reduce(PK::DummyTP1, 0usize)]),
-        TK::TokenSub => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
             )
         }
-        TK::TokenDiv => Vec::from(&[Reduce(PK::DummyTP1, 0usize)]),
         TK::TokenParClose => {
             Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
             )
         }
         TK::TokenCBClose => {
             Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
             )
         }
         TK::TokenWhile => {
             Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
-            )
-        }
-        TK::TokenEqual => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
-            )
-        }
-        TK::TokenNotEqual => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
-            )
-        }
-        TK::TokenLess => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
-            )
-        }
-        TK::TokenLessEqual => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
-            )
-        }
-        TK::TokenGreater => {
-            Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
             )
         }
-        TK::TokenGreaterEqual => {
+        TK::TokenIf => {
             Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
             )
         }
-        TK::TokenIf => {
+        TK::TokenComma => {
             Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
             )
         }
         TK::TokenAnd => {
             Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
             )
         }
         TK::TokenOr => {
             Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
             )
         }
         TK::TokenRead => {
             Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
             )
         }
         TK::TokenWrite => {
             Vec::from(
-                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
+            )
+        }
+        TK::TokenReturn => {
+            Vec::from(
+                &[Reduce(PK::BooleanExpressionBooleanExpressionSimpleExpression, 3usize)],
             )
         }
         _ => vec![],
     }
 }
-fn action_factor_s104(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokensum_s127(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenId => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenSum => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenMul => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenSub => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenDiv => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenParClose => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenWhile => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenEqual => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenNotEqual => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenLess => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenLessEqual => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenGreater => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenIf => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenAnd => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenOr => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenRead => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
-        TK::TokenWrite => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
         _ => vec![],
     }
 }
-fn action_factor_s105(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokensub_s128(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenId => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenSum => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenMul => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenSub => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenDiv => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenParClose => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenWhile => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenEqual => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenNotEqual => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenLess => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenLessEqual => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenGreater => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenIf => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenAnd => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenOr => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenRead => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
-        TK::TokenWrite => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
         _ => vec![],
     }
 }
-fn action_tokencbclose_s106(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenmul_s129(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::ProgramProgramWithMain, 6usize)]),
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
         _ => vec![],
     }
 }
-fn action_tokenparclose_s107(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokendiv_s130(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenParClose => {
-            Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)])
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS36)]),
+        TK::TokenFloatLiteral => Vec::from(&[Shift(State::TokenFloatLiteralS37)]),
+        TK::TokenStringLiteral => Vec::from(&[Shift(State::TokenStringLiteralS38)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS39)]),
+        TK::TokenSub => Vec::from(&[Shift(State::TokenSubS40)]),
+        TK::TokenParOpen => Vec::from(&[Shift(State::TokenParOpenS41)]),
+        TK::TokenTrue => Vec::from(&[Shift(State::TokenTrueS42)]),
+        TK::TokenFalse => Vec::from(&[Shift(State::TokenFalseS43)]),
+        _ => vec![],
+    }
+}
+fn action_tokenparclose_s131(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)]),
+        TK::TokenId => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
         }
-        TK::TokenAnd => {
-            Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)])
+        TK::TokenCBOpen => Vec::from(&[Shift(State::TokenCBOpenS156)]),
+        TK::TokenWhile => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenIf => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenRead => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenWrite => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
+        }
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::FunctionCallFunctionCallNoArguments, 3usize)])
         }
-        TK::TokenOr => Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)]),
         _ => vec![],
     }
 }
-fn action_body_s108(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokencbclose_s132(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenCBClose => Vec::from(&[Shift(State::TokenCBCloseS110)]),
+        TK::STOP => Vec::from(&[Reduce(PK::FunctionFunctionDefinition, 4usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::FunctionFunctionDefinition, 4usize)]),
+        TK::TokenInit => Vec::from(&[Reduce(PK::FunctionFunctionDefinition, 4usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::FunctionFunctionDefinition, 4usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::FunctionFunctionDefinition, 4usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::FunctionFunctionDefinition, 4usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::FunctionFunctionDefinition, 4usize)]),
+        TK::TokenFunction => Vec::from(&[Reduce(PK::FunctionFunctionDefinition, 4usize)]),
+        TK::TokenProcedure => {
+            Vec::from(&[Reduce(PK::FunctionFunctionDefinition, 4usize)])
+        }
+        TK::TokenReturn => Vec::from(&[Reduce(PK::FunctionFunctionDefinition, 4usize)]),
         _ => vec![],
     }
 }
-fn action_body_s109(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokendate_s133(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenCBClose => Vec::from(&[Shift(State::TokenCBCloseS111)]),
+        TK::TokenParClose => Vec::from(&[Shift(State::TokenParCloseS157)]),
         _ => vec![],
     }
 }
-fn action_tokencbclose_s110(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_body_s134(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
-        TK::TokenId => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
-        TK::TokenWhile => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
-        TK::TokenIf => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
-        TK::TokenRead => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
-        TK::TokenWrite => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
+        TK::TokenCBClose => Vec::from(&[Shift(State::TokenCBCloseS158)]),
         _ => vec![],
     }
 }
-fn action_tokencbclose_s111(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_conjunction_s135(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
-        TK::TokenId => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
-        TK::TokenWhile => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
-        TK::TokenIf => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
-        TK::TokenElse => Vec::from(&[Reduce(PK::DummyElseP1, 0usize)]),
-        TK::TokenRead => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
-        TK::TokenWrite => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
+        TK::TokenParClose => {
+            Vec::from(&[Reduce(PK::ArgumentsArgumentsRecursive, 3usize)])
+        }
+        TK::TokenComma => Vec::from(&[Reduce(PK::ArgumentsArgumentsRecursive, 3usize)]),
         _ => vec![],
     }
 }
-fn action_dummyelse_s112(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenint_s136(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenElse => Vec::from(&[Shift(State::TokenElseS113)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::DataTypeIntType, 1usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::DataTypeIntType, 1usize)]),
+        TK::TokenCBOpen => Vec::from(&[Reduce(PK::DataTypeIntType, 1usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::DataTypeIntType, 1usize)]),
+        TK::TokenSBOpen => Vec::from(&[Reduce(PK::DataTypeIntType, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::DataTypeIntType, 1usize)]),
         _ => vec![],
     }
 }
-fn action_tokenelse_s113(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenfloat_s137(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenCBOpen => Vec::from(&[Shift(State::TokenCBOpenS115)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::DataTypeFloatType, 1usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::DataTypeFloatType, 1usize)]),
+        TK::TokenCBOpen => Vec::from(&[Reduce(PK::DataTypeFloatType, 1usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::DataTypeFloatType, 1usize)]),
+        TK::TokenSBOpen => Vec::from(&[Reduce(PK::DataTypeFloatType, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::DataTypeFloatType, 1usize)]),
         _ => vec![],
     }
 }
-fn action_elsestatement_s114(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenstring_s138(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
-        TK::TokenId => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
-        TK::TokenWhile => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
-        TK::TokenIf => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
-        TK::TokenRead => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
-        TK::TokenWrite => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::DataTypeStringType, 1usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::DataTypeStringType, 1usize)]),
+        TK::TokenCBOpen => Vec::from(&[Reduce(PK::DataTypeStringType, 1usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::DataTypeStringType, 1usize)]),
+        TK::TokenSBOpen => Vec::from(&[Reduce(PK::DataTypeStringType, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::DataTypeStringType, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_tokenbool_s139(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenId => Vec::from(&[Reduce(PK::DataTypeBoolType, 1usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::DataTypeBoolType, 1usize)]),
+        TK::TokenCBOpen => Vec::from(&[Reduce(PK::DataTypeBoolType, 1usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::DataTypeBoolType, 1usize)]),
+        TK::TokenSBOpen => Vec::from(&[Reduce(PK::DataTypeBoolType, 1usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::DataTypeBoolType, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_datatype_s140(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenId => {
+            Vec::from(&[Reduce(PK::VarDeclarationVarDeclarationSingle, 3usize)])
+        }
+        TK::TokenCBClose => {
+            Vec::from(&[Reduce(PK::VarDeclarationVarDeclarationSingle, 3usize)])
+        }
+        TK::TokenSBOpen => Vec::from(&[Shift(State::TokenSBOpenS159)]),
+        _ => vec![],
+    }
+}
+fn action_vardeclaration_s141(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenId => {
+            Vec::from(&[Reduce(PK::VarDeclarationVarDeclarationRecursive, 3usize)])
+        }
+        TK::TokenCBClose => {
+            Vec::from(&[Reduce(PK::VarDeclarationVarDeclarationRecursive, 3usize)])
+        }
         _ => vec![],
     }
 }
-fn action_tokencbopen_s115(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokencbopen_s142(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenId => Vec::from(&[Shift(State::TokenIdS24)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::BlockOpenBlockOpen, 1usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::BlockOpenBlockOpen, 1usize)]),
+        TK::TokenInit => Vec::from(&[Reduce(PK::BlockOpenBlockOpen, 1usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::BlockOpenBlockOpen, 1usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::BlockOpenBlockOpen, 1usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::BlockOpenBlockOpen, 1usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::BlockOpenBlockOpen, 1usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::BlockOpenBlockOpen, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_blockopen_s143(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS60)]),
         TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyEmpty, 0usize)]),
         TK::TokenInit => Vec::from(&[Shift(State::TokenInitS2)]),
         TK::TokenWhile => Vec::from(&[Shift(State::TokenWhileS3)]),
         TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
         TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
         TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
+        TK::TokenReturn => Vec::from(&[Shift(State::TokenReturnS9)]),
         _ => vec![],
     }
 }
-fn action_body_s116(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_blockopen_s144(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::TokenCBClose => Vec::from(&[Shift(State::TokenCBCloseS117)]),
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS60)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyEmpty, 0usize)]),
+        TK::TokenInit => Vec::from(&[Shift(State::TokenInitS2)]),
+        TK::TokenWhile => Vec::from(&[Shift(State::TokenWhileS3)]),
+        TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
+        TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
+        TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
+        TK::TokenReturn => Vec::from(&[Shift(State::TokenReturnS9)]),
         _ => vec![],
     }
 }
-fn action_tokencbclose_s117(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tokenparclose_s145(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
-        TK::TokenId => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
-        TK::TokenCBClose => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
-        TK::TokenWhile => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
-        TK::TokenIf => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
-        TK::TokenRead => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
-        TK::TokenWrite => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::FunctionReadFunctionReadIndexedCall, 5usize)]),
+        TK::TokenId => {
+            Vec::from(&[Reduce(PK::FunctionReadFunctionReadIndexedCall, 5usize)])
+        }
+        TK::TokenCBClose => {
+            Vec::from(&[Reduce(PK::FunctionReadFunctionReadIndexedCall, 5usize)])
+        }
+        TK::TokenWhile => {
+            Vec::from(&[Reduce(PK::FunctionReadFunctionReadIndexedCall, 5usize)])
+        }
+        TK::TokenIf => {
+            Vec::from(&[Reduce(PK::FunctionReadFunctionReadIndexedCall, 5usize)])
+        }
+        TK::TokenRead => {
+            Vec::from(&[Reduce(PK::FunctionReadFunctionReadIndexedCall, 5usize)])
+        }
+        TK::TokenWrite => {
+            Vec::from(&[Reduce(PK::FunctionReadFunctionReadIndexedCall, 5usize)])
+        }
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::FunctionReadFunctionReadIndexedCall, 5usize)])
+        }
         _ => vec![],
     }
 }
-fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Program => State::ProgramS7,
-        NonTermKind::Body => State::BodyS8,
-        NonTermKind::FunctionRead => State::FunctionReadS9,
-        NonTermKind::FunctionWrite => State::FunctionWriteS10,
-        NonTermKind::Expressions => State::ExpressionsS11,
-        NonTermKind::Statement => State::StatementS12,
-        NonTermKind::Assignment => State::AssignmentS13,
-        NonTermKind::WhileLoop => State::WhileLoopS14,
-        NonTermKind::IfStatement => State::IfStatementS15,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::AUGS0
-            )
-        }
+fn action_tokencolon_s146(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenInt => Vec::from(&[Shift(State::TokenIntS136)]),
+        TK::TokenFloat => Vec::from(&[Shift(State::TokenFloatS137)]),
+        TK::TokenString => Vec::from(&[Shift(State::TokenStringS138)]),
+        TK::TokenBool => Vec::from(&[Shift(State::TokenBoolS139)]),
+        _ => vec![],
     }
 }
-fn goto_tokeninit_s2(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::InitBody => State::InitBodyS19,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenInitS2
-            )
-        }
+fn action_tokencolon_s147(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenInt => Vec::from(&[Shift(State::TokenIntS136)]),
+        TK::TokenFloat => Vec::from(&[Shift(State::TokenFloatS137)]),
+        TK::TokenString => Vec::from(&[Shift(State::TokenStringS138)]),
+        TK::TokenBool => Vec::from(&[Shift(State::TokenBoolS139)]),
+        _ => vec![],
     }
 }
-fn goto_statement_s12(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::FunctionRead => State::FunctionReadS9,
-        NonTermKind::FunctionWrite => State::FunctionWriteS10,
-        NonTermKind::Expressions => State::ExpressionsS25,
-        NonTermKind::Statement => State::StatementS12,
-        NonTermKind::Assignment => State::AssignmentS13,
-        NonTermKind::WhileLoop => State::WhileLoopS14,
-        NonTermKind::IfStatement => State::IfStatementS15,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::StatementS12
-            )
-        }
+fn action_tokenparclose_s148(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenColon => Vec::from(&[Shift(State::TokenColonS164)]),
+        _ => vec![],
     }
 }
-fn goto_tokenassign_s16(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::FunctionConvDate => State::FunctionConvDateS33,
-        NonTermKind::SimpleExpression => State::SimpleExpressionS34,
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS36,
-        NonTermKind::Term => State::TermS37,
-        NonTermKind::Factor => State::FactorS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenAssignS16
-            )
-        }
+fn action_tokencomma_s149(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS115)]),
+        _ => vec![],
     }
 }
-fn goto_tokencbopen_s18(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::VarDeclarations => State::VarDeclarationsS41,
-        NonTermKind::VarDeclaration => State::VarDeclarationS42,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenCBOpenS18
-            )
+fn action_tokenparclose_s150(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenCBOpen => {
+            Vec::from(&[Reduce(PK::FunctionSignatureProcedureWithParameters, 5usize)])
         }
+        _ => vec![],
     }
 }
-fn goto_initbody_s19(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::FunctionRead => State::FunctionReadS9,
-        NonTermKind::FunctionWrite => State::FunctionWriteS10,
-        NonTermKind::Expressions => State::ExpressionsS43,
-        NonTermKind::Statement => State::StatementS12,
-        NonTermKind::Assignment => State::AssignmentS13,
-        NonTermKind::WhileLoop => State::WhileLoopS14,
-        NonTermKind::IfStatement => State::IfStatementS15,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::InitBodyS19
-            )
+fn action_tokenparclose_s151(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)]),
+        TK::TokenParClose => {
+            Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)])
         }
-    }
-}
-fn goto_tokenparopen_s20(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::FunctionIsZero => State::FunctionIsZeroS49,
-        NonTermKind::BooleanExpression => State::BooleanExpressionS50,
-        NonTermKind::SimpleExpression => State::SimpleExpressionS51,
-        NonTermKind::Conjunction => State::ConjunctionS52,
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::NotStatement => State::NotStatementS53,
-        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS36,
-        NonTermKind::Term => State::TermS37,
-        NonTermKind::Factor => State::FactorS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenParOpenS20
-            )
+        TK::TokenCBClose => {
+            Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)])
         }
-    }
-}
-fn goto_tokenparopen_s21(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::FunctionIsZero => State::FunctionIsZeroS49,
-        NonTermKind::BooleanExpression => State::BooleanExpressionS50,
-        NonTermKind::SimpleExpression => State::SimpleExpressionS51,
-        NonTermKind::Conjunction => State::ConjunctionS54,
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::NotStatement => State::NotStatementS53,
-        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS36,
-        NonTermKind::Term => State::TermS37,
-        NonTermKind::Factor => State::FactorS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenParOpenS21
-            )
+        TK::TokenWhile => {
+            Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)])
+        }
+        TK::TokenIf => Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)]),
+        TK::TokenComma => {
+            Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)])
+        }
+        TK::TokenAnd => {
+            Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)])
+        }
+        TK::TokenOr => Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)]),
+        TK::TokenRead => {
+            Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)])
+        }
+        TK::TokenWrite => {
+            Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)])
+        }
+        TK::TokenReturn => {
+            Vec::from(&[Reduce(PK::FunctionIsZeroFunctionIsZeroCall, 4usize)])
         }
+        _ => vec![],
     }
 }
-fn goto_tokenparopen_s23(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::SimpleExpression => State::SimpleExpressionS56,
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS36,
-        NonTermKind::Term => State::TermS37,
-        NonTermKind::Factor => State::FactorS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenParOpenS23
+fn action_term_s152(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokenparopen_s31(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS59,
-        NonTermKind::Term => State::TermS37,
-        NonTermKind::Factor => State::FactorS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenParOpenS31
+        TK::TokenId => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_arithmeticexpression_s36(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::DummyAE => State::DummyAES61,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ArithmeticExpressionS36
+        TK::TokenSum => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_term_s37(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::DummyT => State::DummyTS62,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TermS37
+        TK::TokenMul => Vec::from(&[Reduce(PK::DummyTP1, 0usize)]),
+        TK::TokenSub => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_vardeclaration_s42(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::VarDeclarations => State::VarDeclarationsS67,
-        NonTermKind::VarDeclaration => State::VarDeclarationS42,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::VarDeclarationS42
+        TK::TokenDiv => Vec::from(&[Reduce(PK::DummyTP1, 0usize)]),
+        TK::TokenParClose => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokennot_s47(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::FunctionIsZero => State::FunctionIsZeroS49,
-        NonTermKind::BooleanExpression => State::BooleanExpressionS68,
-        NonTermKind::SimpleExpression => State::SimpleExpressionS51,
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::NotStatement => State::NotStatementS53,
-        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS36,
-        NonTermKind::Term => State::TermS37,
-        NonTermKind::Factor => State::FactorS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenNotS47
+        TK::TokenCBClose => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_simpleexpression_s51(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::ComparisonOp => State::ComparisonOpS78,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::SimpleExpressionS51
+        TK::TokenSBClose => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_arithmeticexpression_s59(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::DummyAE => State::DummyAES61,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ArithmeticExpressionS59
+        TK::TokenWhile => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokencbopen_s63(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Body => State::BodyS89,
-        NonTermKind::FunctionRead => State::FunctionReadS9,
-        NonTermKind::FunctionWrite => State::FunctionWriteS10,
-        NonTermKind::Expressions => State::ExpressionsS11,
-        NonTermKind::Statement => State::StatementS12,
-        NonTermKind::Assignment => State::AssignmentS13,
-        NonTermKind::WhileLoop => State::WhileLoopS14,
-        NonTermKind::IfStatement => State::IfStatementS15,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenCBOpenS63
+        TK::TokenEqual => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokencolon_s64(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::DataType => State::DataTypeS93,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenColonS64
+        TK::TokenNotEqual => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokencomma_s65(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::VarDeclaration => State::VarDeclarationS94,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenCommaS65
+        TK::TokenLess => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokenparopen_s69(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS95,
-        NonTermKind::Term => State::TermS37,
-        NonTermKind::Factor => State::FactorS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenParOpenS69
+        TK::TokenLessEqual => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokenand_s70(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::FunctionIsZero => State::FunctionIsZeroS49,
-        NonTermKind::BooleanExpression => State::BooleanExpressionS50,
-        NonTermKind::SimpleExpression => State::SimpleExpressionS51,
-        NonTermKind::Conjunction => State::ConjunctionS96,
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::NotStatement => State::NotStatementS53,
-        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS36,
-        NonTermKind::Term => State::TermS37,
-        NonTermKind::Factor => State::FactorS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenAndS70
+        TK::TokenGreater => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokenor_s71(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::FunctionIsZero => State::FunctionIsZeroS49,
-        NonTermKind::BooleanExpression => State::BooleanExpressionS50,
-        NonTermKind::SimpleExpression => State::SimpleExpressionS51,
-        NonTermKind::Conjunction => State::ConjunctionS97,
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::NotStatement => State::NotStatementS53,
-        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS36,
-        NonTermKind::Term => State::TermS37,
-        NonTermKind::Factor => State::FactorS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenOrS71
+        TK::TokenGreaterEqual => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_comparisonop_s78(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::SimpleExpression => State::SimpleExpressionS98,
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS36,
-        NonTermKind::Term => State::TermS37,
-        NonTermKind::Factor => State::FactorS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ComparisonOpS78
+        TK::TokenIf => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokensum_s85(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::Term => State::TermS102,
-        NonTermKind::Factor => State::FactorS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenSumS85
+        TK::TokenComma => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokensub_s86(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::Term => State::TermS103,
-        NonTermKind::Factor => State::FactorS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenSubS86
+        TK::TokenAnd => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokenmul_s87(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::Factor => State::FactorS104,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenMulS87
+        TK::TokenOr => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokendiv_s88(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Number => State::NumberS35,
-        NonTermKind::Factor => State::FactorS105,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenDivS88
+        TK::TokenRead => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_arithmeticexpression_s95(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::DummyAE => State::DummyAES61,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ArithmeticExpressionS95
+        TK::TokenWrite => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokencbopen_s99(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Body => State::BodyS108,
-        NonTermKind::FunctionRead => State::FunctionReadS9,
-        NonTermKind::FunctionWrite => State::FunctionWriteS10,
-        NonTermKind::Expressions => State::ExpressionsS11,
-        NonTermKind::Statement => State::StatementS12,
-        NonTermKind::Assignment => State::AssignmentS13,
-        NonTermKind::WhileLoop => State::WhileLoopS14,
-        NonTermKind::IfStatement => State::IfStatementS15,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenCBOpenS99
+        TK::TokenReturn => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSumTerm, 4usize)],
             )
         }
+        _ => vec![],
     }
 }
-fn goto_tokencbopen_s100(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Body => State::BodyS109,
-        NonTermKind::FunctionRead => State::FunctionReadS9,
-        NonTermKind::FunctionWrite => State::FunctionWriteS10,
-        NonTermKind::Expressions => State::ExpressionsS11,
-        NonTermKind::Statement => State::StatementS12,
-        NonTermKind::Assignment => State::AssignmentS13,
-        NonTermKind::WhileLoop => State::WhileLoopS14,
-        NonTermKind::IfStatement => State::IfStatementS15,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenCBOpenS100
+fn action_term_s153(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_term_s102(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::DummyT => State::DummyTS62,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TermS102
+        TK::TokenId => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_term_s103(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::DummyT => State::DummyTS62,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TermS103
+        TK::TokenSum => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokencbclose_s111(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::DummyElse => State::DummyElseS112,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenCBCloseS111
+        TK::TokenMul => Vec::from(&[Reduce(PK::DummyTP1, 0usize)]),
+        TK::TokenSub => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_dummyelse_s112(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::ElseStatement => State::ElseStatementS114,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::DummyElseS112
+        TK::TokenDiv => Vec::from(&[Reduce(PK::DummyTP1, 0usize)]),
+        TK::TokenParClose => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
             )
         }
-    }
-}
-fn goto_tokencbopen_s115(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Body => State::BodyS116,
-        NonTermKind::FunctionRead => State::FunctionReadS9,
-        NonTermKind::FunctionWrite => State::FunctionWriteS10,
-        NonTermKind::Expressions => State::ExpressionsS11,
-        NonTermKind::Statement => State::StatementS12,
-        NonTermKind::Assignment => State::AssignmentS13,
-        NonTermKind::WhileLoop => State::WhileLoopS14,
-        NonTermKind::IfStatement => State::IfStatementS15,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::TokenCBOpenS115
+        TK::TokenCBClose => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenSBClose => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenWhile => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenEqual => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenNotEqual => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenLess => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenLessEqual => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenGreater => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenGreaterEqual => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenIf => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenComma => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenAnd => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenOr => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenRead => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenWrite => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
+            )
+        }
+        TK::TokenReturn => {
+            Vec::from(
+                &[Reduce(PK::ArithmeticExpressionArithmeticExpressionSubTerm, 4usize)],
             )
         }
+        _ => vec![],
     }
 }
-fn goto_invalid(_nonterm_kind: NonTermKind) -> State {
-    panic!("Invalid GOTO entry!");
+fn action_factor_s154(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenSum => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenMul => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenSub => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenDiv => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenEqual => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenNotEqual => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenLess => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenLessEqual => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenGreater => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenAnd => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenOr => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::TermTermMulFactor, 4usize)]),
+        _ => vec![],
+    }
 }
-pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefinition {
-    actions: [
-        action_aug_s0,
-        action_tokenid_s1,
-        action_tokeninit_s2,
-        action_tokenwhile_s3,
-        action_tokenif_s4,
-        action_tokenread_s5,
-        action_tokenwrite_s6,
-        action_program_s7,
-        action_body_s8,
-        action_functionread_s9,
-        action_functionwrite_s10,
-        action_expressions_s11,
-        action_statement_s12,
-        action_assignment_s13,
-        action_whileloop_s14,
-        action_ifstatement_s15,
-        action_tokenassign_s16,
-        action_tokenparopen_s17,
-        action_tokencbopen_s18,
-        action_initbody_s19,
-        action_tokenparopen_s20,
-        action_tokenparopen_s21,
-        action_tokenparopen_s22,
-        action_tokenparopen_s23,
-        action_tokenid_s24,
-        action_expressions_s25,
-        action_tokenintliteral_s26,
-        action_tokenfloatliteral_s27,
-        action_tokenstringliteral_s28,
-        action_tokenid_s29,
-        action_tokensub_s30,
-        action_tokenparopen_s31,
-        action_tokenconvdate_s32,
-        action_functionconvdate_s33,
-        action_simpleexpression_s34,
-        action_number_s35,
-        action_arithmeticexpression_s36,
-        action_term_s37,
-        action_factor_s38,
-        action_tokenparclose_s39,
-        action_tokenid_s40,
-        action_vardeclarations_s41,
-        action_vardeclaration_s42,
-        action_expressions_s43,
-        action_tokenid_s44,
-        action_tokentrue_s45,
-        action_tokenfalse_s46,
-        action_tokennot_s47,
-        action_tokeniszero_s48,
-        action_functioniszero_s49,
-        action_booleanexpression_s50,
-        action_simpleexpression_s51,
-        action_conjunction_s52,
-        action_notstatement_s53,
-        action_conjunction_s54,
-        action_tokenid_s55,
-        action_simpleexpression_s56,
-        action_tokenintliteral_s57,
-        action_tokenfloatliteral_s58,
-        action_arithmeticexpression_s59,
-        action_tokenparopen_s60,
-        action_dummyae_s61,
-        action_dummyt_s62,
-        action_tokencbopen_s63,
-        action_tokencolon_s64,
-        action_tokencomma_s65,
-        action_tokencbclose_s66,
-        action_vardeclarations_s67,
-        action_booleanexpression_s68,
-        action_tokenparopen_s69,
-        action_tokenand_s70,
-        action_tokenor_s71,
-        action_tokenequal_s72,
-        action_tokennotequal_s73,
-        action_tokenless_s74,
-        action_tokenlessequal_s75,
-        action_tokengreater_s76,
-        action_tokengreaterequal_s77,
-        action_comparisonop_s78,
-        action_tokenparclose_s79,
-        action_tokenparclose_s80,
-        action_tokenparclose_s81,
-        action_tokenparclose_s82,
-        action_tokenparclose_s83,
-        action_tokendate_s84,
-        action_tokensum_s85,
-        action_tokensub_s86,
-        action_tokenmul_s87,
-        action_tokendiv_s88,
-        action_body_s89,
-        action_tokenint_s90,
-        action_tokenfloat_s91,
-        action_tokenstring_s92,
-        action_datatype_s93,
-        action_vardeclaration_s94,
-        action_arithmeticexpression_s95,
-        action_conjunction_s96,
-        action_conjunction_s97,
-        action_simpleexpression_s98,
-        action_tokencbopen_s99,
-        action_tokencbopen_s100,
-        action_tokenparclose_s101,
-        action_term_s102,
-        action_term_s103,
-        action_factor_s104,
-        action_factor_s105,
-        action_tokencbclose_s106,
-        action_tokenparclose_s107,
-        action_body_s108,
-        action_body_s109,
-        action_tokencbclose_s110,
-        action_tokencbclose_s111,
-        action_dummyelse_s112,
-        action_tokenelse_s113,
-        action_elsestatement_s114,
-        action_tokencbopen_s115,
-        action_body_s116,
-        action_tokencbclose_s117,
-    ],
-    gotos: [
-        goto_aug_s0,
-        goto_invalid,
-        goto_tokeninit_s2,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_invalid,
-        goto_statement_s12,
-        goto_invalid,
+fn action_factor_s155(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenSum => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenMul => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenSub => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenDiv => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenParClose => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenSBClose => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenEqual => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenNotEqual => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenLess => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenLessEqual => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenGreater => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenGreaterEqual => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenAnd => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenOr => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::TermTermDivFactor, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_tokencbopen_s156(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS60)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyEmpty, 0usize)]),
+        TK::TokenInit => Vec::from(&[Shift(State::TokenInitS2)]),
+        TK::TokenWhile => Vec::from(&[Shift(State::TokenWhileS3)]),
+        TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
+        TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
+        TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
+        TK::TokenReturn => Vec::from(&[Shift(State::TokenReturnS9)]),
+        _ => vec![],
+    }
+}
+fn action_tokenparclose_s157(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => {
+            Vec::from(
+                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
+            )
+        }
+        TK::TokenId => {
+            Vec::from(
+                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
+            )
+        }
+        TK::TokenCBClose => {
+            Vec::from(
+                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
+            )
+        }
+        TK::TokenWhile => {
+            Vec::from(
+                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
+            )
+        }
+        TK::TokenIf => {
+            Vec::from(
+                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
+            )
+        }
+        TK::TokenRead => {
+            Vec::from(
+                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
+            )
+        }
+        TK::TokenWrite => {
+            Vec::from(
+                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
+            )
+        }
+        TK::TokenReturn => {
+            Vec::from(
+                &[Reduce(PK::FunctionConvDateFunctionConvDateVariableCall, 4usize)],
+            )
+        }
+        _ => vec![],
+    }
+}
+fn action_tokencbclose_s158(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::ProgramProgramWithMain, 6usize)]),
+        _ => vec![],
+    }
+}
+fn action_tokensbopen_s159(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenIntLiteral => Vec::from(&[Shift(State::TokenIntLiteralS167)]),
+        _ => vec![],
+    }
+}
+fn action_body_s160(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenCBClose => Vec::from(&[Shift(State::TokenCBCloseS168)]),
+        _ => vec![],
+    }
+}
+fn action_body_s161(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenCBClose => Vec::from(&[Shift(State::TokenCBCloseS169)]),
+        _ => vec![],
+    }
+}
+fn action_datatype_s162(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenParClose => Vec::from(&[Reduce(PK::ParameterParameter, 3usize)]),
+        TK::TokenComma => Vec::from(&[Reduce(PK::ParameterParameter, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_datatype_s163(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenCBOpen => {
+            Vec::from(&[Reduce(PK::FunctionSignatureFunctionNoParameters, 6usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_tokencolon_s164(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenInt => Vec::from(&[Shift(State::TokenIntS136)]),
+        TK::TokenFloat => Vec::from(&[Shift(State::TokenFloatS137)]),
+        TK::TokenString => Vec::from(&[Shift(State::TokenStringS138)]),
+        TK::TokenBool => Vec::from(&[Shift(State::TokenBoolS139)]),
+        _ => vec![],
+    }
+}
+fn action_parameters_s165(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenParClose => {
+            Vec::from(&[Reduce(PK::ParametersParametersRecursive, 3usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_body_s166(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenCBClose => Vec::from(&[Shift(State::TokenCBCloseS171)]),
+        _ => vec![],
+    }
+}
+fn action_tokenintliteral_s167(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenSBClose => Vec::from(&[Shift(State::TokenSBCloseS172)]),
+        _ => vec![],
+    }
+}
+fn action_tokencbclose_s168(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::WhileLoopWhile, 7usize)]),
+        _ => vec![],
+    }
+}
+fn action_tokencbclose_s169(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
+        TK::TokenElse => Vec::from(&[Reduce(PK::DummyElseP1, 0usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::IfStatementIfStatement, 7usize)]),
+        _ => vec![],
+    }
+}
+fn action_datatype_s170(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenCBOpen => {
+            Vec::from(&[Reduce(PK::FunctionSignatureFunctionWithParameters, 7usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_tokencbclose_s171(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::ProgramProgramWithFunctions, 7usize)]),
+        _ => vec![],
+    }
+}
+fn action_tokensbclose_s172(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenId => {
+            Vec::from(&[Reduce(PK::VarDeclarationVarDeclarationArray, 6usize)])
+        }
+        TK::TokenCBClose => {
+            Vec::from(&[Reduce(PK::VarDeclarationVarDeclarationArray, 6usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_dummyelse_s173(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenElse => Vec::from(&[Shift(State::TokenElseS174)]),
+        _ => vec![],
+    }
+}
+fn action_tokenelse_s174(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenCBOpen => Vec::from(&[Shift(State::TokenCBOpenS142)]),
+        _ => vec![],
+    }
+}
+fn action_elsestatement_s175(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::IfStatementIfElseStatement, 9usize)]),
+        _ => vec![],
+    }
+}
+fn action_blockopen_s176(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenId => Vec::from(&[Shift(State::TokenIdS60)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::BodyBodyEmpty, 0usize)]),
+        TK::TokenInit => Vec::from(&[Shift(State::TokenInitS2)]),
+        TK::TokenWhile => Vec::from(&[Shift(State::TokenWhileS3)]),
+        TK::TokenIf => Vec::from(&[Shift(State::TokenIfS4)]),
+        TK::TokenRead => Vec::from(&[Shift(State::TokenReadS5)]),
+        TK::TokenWrite => Vec::from(&[Shift(State::TokenWriteS6)]),
+        TK::TokenReturn => Vec::from(&[Shift(State::TokenReturnS9)]),
+        _ => vec![],
+    }
+}
+fn action_body_s177(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::TokenCBClose => Vec::from(&[Shift(State::TokenCBCloseS178)]),
+        _ => vec![],
+    }
+}
+fn action_tokencbclose_s178(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
+        TK::TokenId => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
+        TK::TokenCBClose => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
+        TK::TokenWhile => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
+        TK::TokenIf => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
+        TK::TokenRead => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
+        TK::TokenWrite => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
+        TK::TokenReturn => Vec::from(&[Reduce(PK::ElseStatementElseStatement, 4usize)]),
+        _ => vec![],
+    }
+}
+fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Program => State::ProgramS10,
+        NonTermKind::Functions => State::FunctionsS11,
+        NonTermKind::Function => State::FunctionS12,
+        NonTermKind::FunctionSignature => State::FunctionSignatureS13,
+        NonTermKind::Body => State::BodyS14,
+        NonTermKind::FunctionRead => State::FunctionReadS15,
+        NonTermKind::FunctionWrite => State::FunctionWriteS16,
+        NonTermKind::FunctionCall => State::FunctionCallS17,
+        NonTermKind::Expressions => State::ExpressionsS18,
+        NonTermKind::Statement => State::StatementS19,
+        NonTermKind::Assignment => State::AssignmentS20,
+        NonTermKind::WhileLoop => State::WhileLoopS21,
+        NonTermKind::IfStatement => State::IfStatementS22,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::AUGS0
+            )
+        }
+    }
+}
+fn goto_tokenid_s1(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Index => State::IndexS26,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenIdS1
+            )
+        }
+    }
+}
+fn goto_tokeninit_s2(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::InitBody => State::InitBodyS28,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenInitS2
+            )
+        }
+    }
+}
+fn goto_tokenfunction_s7(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionName => State::FunctionNameS34,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenFunctionS7
+            )
+        }
+    }
+}
+fn goto_tokenprocedure_s8(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionName => State::FunctionNameS35,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenProcedureS8
+            )
+        }
+    }
+}
+fn goto_tokenreturn_s9(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS48,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Conjunction => State::ConjunctionS50,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenReturnS9
+            )
+        }
+    }
+}
+fn goto_functions_s11(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Body => State::BodyS57,
+        NonTermKind::FunctionRead => State::FunctionReadS15,
+        NonTermKind::FunctionWrite => State::FunctionWriteS16,
+        NonTermKind::FunctionCall => State::FunctionCallS17,
+        NonTermKind::Expressions => State::ExpressionsS18,
+        NonTermKind::Statement => State::StatementS19,
+        NonTermKind::Assignment => State::AssignmentS20,
+        NonTermKind::WhileLoop => State::WhileLoopS21,
+        NonTermKind::IfStatement => State::IfStatementS22,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::FunctionsS11
+            )
+        }
+    }
+}
+fn goto_function_s12(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Functions => State::FunctionsS58,
+        NonTermKind::Function => State::FunctionS12,
+        NonTermKind::FunctionSignature => State::FunctionSignatureS13,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::FunctionS12
+            )
+        }
+    }
+}
+fn goto_statement_s19(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionRead => State::FunctionReadS15,
+        NonTermKind::FunctionWrite => State::FunctionWriteS16,
+        NonTermKind::FunctionCall => State::FunctionCallS17,
+        NonTermKind::Expressions => State::ExpressionsS61,
+        NonTermKind::Statement => State::StatementS19,
+        NonTermKind::Assignment => State::AssignmentS20,
+        NonTermKind::WhileLoop => State::WhileLoopS21,
+        NonTermKind::IfStatement => State::IfStatementS22,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::StatementS19
+            )
+        }
+    }
+}
+fn goto_tokenassign_s23(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionConvDate => State::FunctionConvDateS63,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS48,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Conjunction => State::ConjunctionS64,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenAssignS23
+            )
+        }
+    }
+}
+fn goto_tokenparopen_s24(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::Arguments => State::ArgumentsS66,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS48,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Conjunction => State::ConjunctionS67,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenParOpenS24
+            )
+        }
+    }
+}
+fn goto_tokensbopen_s25(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS68,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenSBOpenS25
+            )
+        }
+    }
+}
+fn goto_tokencbopen_s27(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::VarDeclarations => State::VarDeclarationsS71,
+        NonTermKind::VarDeclaration => State::VarDeclarationS72,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenCBOpenS27
+            )
+        }
+    }
+}
+fn goto_initbody_s28(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionRead => State::FunctionReadS15,
+        NonTermKind::FunctionWrite => State::FunctionWriteS16,
+        NonTermKind::FunctionCall => State::FunctionCallS17,
+        NonTermKind::Expressions => State::ExpressionsS73,
+        NonTermKind::Statement => State::StatementS19,
+        NonTermKind::Assignment => State::AssignmentS20,
+        NonTermKind::WhileLoop => State::WhileLoopS21,
+        NonTermKind::IfStatement => State::IfStatementS22,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::InitBodyS28
+            )
+        }
+    }
+}
+fn goto_tokenparopen_s29(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS48,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Conjunction => State::ConjunctionS74,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenParOpenS29
+            )
+        }
+    }
+}
+fn goto_tokenparopen_s30(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS48,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Conjunction => State::ConjunctionS75,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenParOpenS30
+            )
+        }
+    }
+}
+fn goto_tokenparopen_s32(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS48,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Conjunction => State::ConjunctionS77,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenParOpenS32
+            )
+        }
+    }
+}
+fn goto_tokenid_s39(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Index => State::IndexS81,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenIdS39
+            )
+        }
+    }
+}
+fn goto_tokenparopen_s41(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS84,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenParOpenS41
+            )
+        }
+    }
+}
+fn goto_tokennot_s44(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS85,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenNotS44
+            )
+        }
+    }
+}
+fn goto_simpleexpression_s49(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::ComparisonOp => State::ComparisonOpS95,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::SimpleExpressionS49
+            )
+        }
+    }
+}
+fn goto_arithmeticexpression_s53(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::DummyAE => State::DummyAES96,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ArithmeticExpressionS53
+            )
+        }
+    }
+}
+fn goto_term_s54(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::DummyT => State::DummyTS97,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TermS54
+            )
+        }
+    }
+}
+fn goto_tokenid_s56(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Index => State::IndexS26,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenIdS56
+            )
+        }
+    }
+}
+fn goto_tokencbopen_s59(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Body => State::BodyS99,
+        NonTermKind::FunctionRead => State::FunctionReadS15,
+        NonTermKind::FunctionWrite => State::FunctionWriteS16,
+        NonTermKind::FunctionCall => State::FunctionCallS17,
+        NonTermKind::Expressions => State::ExpressionsS18,
+        NonTermKind::Statement => State::StatementS19,
+        NonTermKind::Assignment => State::AssignmentS20,
+        NonTermKind::WhileLoop => State::WhileLoopS21,
+        NonTermKind::IfStatement => State::IfStatementS22,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenCBOpenS59
+            )
+        }
+    }
+}
+fn goto_tokenid_s60(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Index => State::IndexS26,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenIdS60
+            )
+        }
+    }
+}
+fn goto_tokenassign_s69(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS48,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Conjunction => State::ConjunctionS105,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenAssignS69
+            )
+        }
+    }
+}
+fn goto_vardeclaration_s72(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::VarDeclarations => State::VarDeclarationsS109,
+        NonTermKind::VarDeclaration => State::VarDeclarationS72,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::VarDeclarationS72
+            )
+        }
+    }
+}
+fn goto_tokenid_s76(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Index => State::IndexS113,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenIdS76
+            )
+        }
+    }
+}
+fn goto_tokenparopen_s78(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Parameters => State::ParametersS117,
+        NonTermKind::Parameter => State::ParameterS118,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenParOpenS78
+            )
+        }
+    }
+}
+fn goto_tokenparopen_s79(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Parameters => State::ParametersS120,
+        NonTermKind::Parameter => State::ParameterS118,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenParOpenS79
+            )
+        }
+    }
+}
+fn goto_tokenparopen_s80(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::Arguments => State::ArgumentsS66,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS48,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Conjunction => State::ConjunctionS67,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenParOpenS80
+            )
+        }
+    }
+}
+fn goto_arithmeticexpression_s84(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::DummyAE => State::DummyAES96,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ArithmeticExpressionS84
+            )
+        }
+    }
+}
+fn goto_tokenparopen_s86(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS123,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenParOpenS86
+            )
+        }
+    }
+}
+fn goto_tokenand_s87(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS48,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Conjunction => State::ConjunctionS124,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenAndS87
+            )
+        }
+    }
+}
+fn goto_tokenor_s88(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS48,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Conjunction => State::ConjunctionS125,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenOrS88
+            )
+        }
+    }
+}
+fn goto_comparisonop_s95(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS126,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ComparisonOpS95
+            )
+        }
+    }
+}
+fn goto_tokenparopen_s98(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::Arguments => State::ArgumentsS66,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS48,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Conjunction => State::ConjunctionS67,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenParOpenS98
+            )
+        }
+    }
+}
+fn goto_tokencbopen_s101(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Body => State::BodyS134,
+        NonTermKind::FunctionRead => State::FunctionReadS15,
+        NonTermKind::FunctionWrite => State::FunctionWriteS16,
+        NonTermKind::FunctionCall => State::FunctionCallS17,
+        NonTermKind::Expressions => State::ExpressionsS18,
+        NonTermKind::Statement => State::StatementS19,
+        NonTermKind::Assignment => State::AssignmentS20,
+        NonTermKind::WhileLoop => State::WhileLoopS21,
+        NonTermKind::IfStatement => State::IfStatementS22,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenCBOpenS101
+            )
+        }
+    }
+}
+fn goto_tokencomma_s103(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionIsZero => State::FunctionIsZeroS46,
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::BooleanExpression => State::BooleanExpressionS48,
+        NonTermKind::SimpleExpression => State::SimpleExpressionS49,
+        NonTermKind::Conjunction => State::ConjunctionS135,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::NotStatement => State::NotStatementS52,
+        NonTermKind::ArithmeticExpression => State::ArithmeticExpressionS53,
+        NonTermKind::Term => State::TermS54,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenCommaS103
+            )
+        }
+    }
+}
+fn goto_tokencolon_s106(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::DataType => State::DataTypeS140,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenColonS106
+            )
+        }
+    }
+}
+fn goto_tokencomma_s107(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::VarDeclaration => State::VarDeclarationS141,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenCommaS107
+            )
+        }
+    }
+}
+fn goto_tokenparclose_s110(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::BlockOpen => State::BlockOpenS143,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenParCloseS110
+            )
+        }
+    }
+}
+fn goto_tokenparclose_s111(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::BlockOpen => State::BlockOpenS144,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenParCloseS111
+            )
+        }
+    }
+}
+fn goto_arithmeticexpression_s123(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::DummyAE => State::DummyAES96,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ArithmeticExpressionS123
+            )
+        }
+    }
+}
+fn goto_tokensum_s127(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::Term => State::TermS152,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenSumS127
+            )
+        }
+    }
+}
+fn goto_tokensub_s128(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::Term => State::TermS153,
+        NonTermKind::Factor => State::FactorS55,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenSubS128
+            )
+        }
+    }
+}
+fn goto_tokenmul_s129(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::Factor => State::FactorS154,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenMulS129
+            )
+        }
+    }
+}
+fn goto_tokendiv_s130(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FunctionCall => State::FunctionCallS47,
+        NonTermKind::Number => State::NumberS51,
+        NonTermKind::Factor => State::FactorS155,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenDivS130
+            )
+        }
+    }
+}
+fn goto_blockopen_s143(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Body => State::BodyS160,
+        NonTermKind::FunctionRead => State::FunctionReadS15,
+        NonTermKind::FunctionWrite => State::FunctionWriteS16,
+        NonTermKind::FunctionCall => State::FunctionCallS17,
+        NonTermKind::Expressions => State::ExpressionsS18,
+        NonTermKind::Statement => State::StatementS19,
+        NonTermKind::Assignment => State::AssignmentS20,
+        NonTermKind::WhileLoop => State::WhileLoopS21,
+        NonTermKind::IfStatement => State::IfStatementS22,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::BlockOpenS143
+            )
+        }
+    }
+}
+fn goto_blockopen_s144(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Body => State::BodyS161,
+        NonTermKind::FunctionRead => State::FunctionReadS15,
+        NonTermKind::FunctionWrite => State::FunctionWriteS16,
+        NonTermKind::FunctionCall => State::FunctionCallS17,
+        NonTermKind::Expressions => State::ExpressionsS18,
+        NonTermKind::Statement => State::StatementS19,
+        NonTermKind::Assignment => State::AssignmentS20,
+        NonTermKind::WhileLoop => State::WhileLoopS21,
+        NonTermKind::IfStatement => State::IfStatementS22,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::BlockOpenS144
+            )
+        }
+    }
+}
+fn goto_tokencolon_s146(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::DataType => State::DataTypeS162,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenColonS146
+            )
+        }
+    }
+}
+fn goto_tokencolon_s147(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::DataType => State::DataTypeS163,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenColonS147
+            )
+        }
+    }
+}
+fn goto_tokencomma_s149(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Parameters => State::ParametersS165,
+        NonTermKind::Parameter => State::ParameterS118,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenCommaS149
+            )
+        }
+    }
+}
+fn goto_term_s152(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::DummyT => State::DummyTS97,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TermS152
+            )
+        }
+    }
+}
+fn goto_term_s153(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::DummyT => State::DummyTS97,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TermS153
+            )
+        }
+    }
+}
+fn goto_tokencbopen_s156(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Body => State::BodyS166,
+        NonTermKind::FunctionRead => State::FunctionReadS15,
+        NonTermKind::FunctionWrite => State::FunctionWriteS16,
+        NonTermKind::FunctionCall => State::FunctionCallS17,
+        NonTermKind::Expressions => State::ExpressionsS18,
+        NonTermKind::Statement => State::StatementS19,
+        NonTermKind::Assignment => State::AssignmentS20,
+        NonTermKind::WhileLoop => State::WhileLoopS21,
+        NonTermKind::IfStatement => State::IfStatementS22,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenCBOpenS156
+            )
+        }
+    }
+}
+fn goto_tokencolon_s164(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::DataType => State::DataTypeS170,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenColonS164
+            )
+        }
+    }
+}
+fn goto_tokencbclose_s169(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::DummyElse => State::DummyElseS173,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenCBCloseS169
+            )
+        }
+    }
+}
+fn goto_dummyelse_s173(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::ElseStatement => State::ElseStatementS175,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::DummyElseS173
+            )
+        }
+    }
+}
+fn goto_tokenelse_s174(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::BlockOpen => State::BlockOpenS176,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TokenElseS174
+            )
+        }
+    }
+}
+fn goto_blockopen_s176(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Body => State::BodyS177,
+        NonTermKind::FunctionRead => State::FunctionReadS15,
+        NonTermKind::FunctionWrite => State::FunctionWriteS16,
+        NonTermKind::FunctionCall => State::FunctionCallS17,
+        NonTermKind::Expressions => State::ExpressionsS18,
+        NonTermKind::Statement => State::StatementS19,
+        NonTermKind::Assignment => State::AssignmentS20,
+        NonTermKind::WhileLoop => State::WhileLoopS21,
+        NonTermKind::IfStatement => State::IfStatementS22,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::BlockOpenS176
+            )
+        }
+    }
+}
+fn goto_invalid(_nonterm_kind: NonTermKind) -> State {
+    panic!("Invalid GOTO entry!");
+}
+pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefinition {
+    actions: [
+        action_aug_s0,
+        action_tokenid_s1,
+        action_tokeninit_s2,
+        action_tokenwhile_s3,
+        action_tokenif_s4,
+        action_tokenread_s5,
+        action_tokenwrite_s6,
+        action_tokenfunction_s7,
+        action_tokenprocedure_s8,
+        action_tokenreturn_s9,
+        action_program_s10,
+        action_functions_s11,
+        action_function_s12,
+        action_functionsignature_s13,
+        action_body_s14,
+        action_functionread_s15,
+        action_functionwrite_s16,
+        action_functioncall_s17,
+        action_expressions_s18,
+        action_statement_s19,
+        action_assignment_s20,
+        action_whileloop_s21,
+        action_ifstatement_s22,
+        action_tokenassign_s23,
+        action_tokenparopen_s24,
+        action_tokensbopen_s25,
+        action_index_s26,
+        action_tokencbopen_s27,
+        action_initbody_s28,
+        action_tokenparopen_s29,
+        action_tokenparopen_s30,
+        action_tokenparopen_s31,
+        action_tokenparopen_s32,
+        action_tokenid_s33,
+        action_functionname_s34,
+        action_functionname_s35,
+        action_tokenintliteral_s36,
+        action_tokenfloatliteral_s37,
+        action_tokenstringliteral_s38,
+        action_tokenid_s39,
+        action_tokensub_s40,
+        action_tokenparopen_s41,
+        action_tokentrue_s42,
+        action_tokenfalse_s43,
+        action_tokennot_s44,
+        action_tokeniszero_s45,
+        action_functioniszero_s46,
+        action_functioncall_s47,
+        action_booleanexpression_s48,
+        action_simpleexpression_s49,
+        action_conjunction_s50,
+        action_number_s51,
+        action_notstatement_s52,
+        action_arithmeticexpression_s53,
+        action_term_s54,
+        action_factor_s55,
+        action_tokenid_s56,
+        action_body_s57,
+        action_functions_s58,
+        action_tokencbopen_s59,
+        action_tokenid_s60,
+        action_expressions_s61,
+        action_tokenconvdate_s62,
+        action_functionconvdate_s63,
+        action_conjunction_s64,
+        action_tokenparclose_s65,
+        action_arguments_s66,
+        action_conjunction_s67,
+        action_simpleexpression_s68,
+        action_tokenassign_s69,
+        action_tokenid_s70,
+        action_vardeclarations_s71,
+        action_vardeclaration_s72,
+        action_expressions_s73,
+        action_conjunction_s74,
+        action_conjunction_s75,
+        action_tokenid_s76,
+        action_conjunction_s77,
+        action_tokenparopen_s78,
+        action_tokenparopen_s79,
+        action_tokenparopen_s80,
+        action_index_s81,
+        action_tokenintliteral_s82,
+        action_tokenfloatliteral_s83,
+        action_arithmeticexpression_s84,
+        action_booleanexpression_s85,
+        action_tokenparopen_s86,
+        action_tokenand_s87,
+        action_tokenor_s88,
+        action_tokenequal_s89,
+        action_tokennotequal_s90,
+        action_tokenless_s91,
+        action_tokenlessequal_s92,
+        action_tokengreater_s93,
+        action_tokengreaterequal_s94,
+        action_comparisonop_s95,
+        action_dummyae_s96,
+        action_dummyt_s97,
+        action_tokenparopen_s98,
+        action_body_s99,
+        action_tokenparopen_s100,
+        action_tokencbopen_s101,
+        action_tokenparclose_s102,
+        action_tokencomma_s103,
+        action_tokensbclose_s104,
+        action_conjunction_s105,
+        action_tokencolon_s106,
+        action_tokencomma_s107,
+        action_tokencbclose_s108,
+        action_vardeclarations_s109,
+        action_tokenparclose_s110,
+        action_tokenparclose_s111,
+        action_tokenparclose_s112,
+        action_index_s113,
+        action_tokenparclose_s114,
+        action_tokenid_s115,
+        action_tokenparclose_s116,
+        action_parameters_s117,
+        action_parameter_s118,
+        action_tokenparclose_s119,
+        action_parameters_s120,
+        action_tokenparclose_s121,
+        action_tokenparclose_s122,
+        action_arithmeticexpression_s123,
+        action_conjunction_s124,
+        action_conjunction_s125,
+        action_simpleexpression_s126,
+        action_tokensum_s127,
+        action_tokensub_s128,
+        action_tokenmul_s129,
+        action_tokendiv_s130,
+        action_tokenparclose_s131,
+        action_tokencbclose_s132,
+        action_tokendate_s133,
+        action_body_s134,
+        action_conjunction_s135,
+        action_tokenint_s136,
+        action_tokenfloat_s137,
+        action_tokenstring_s138,
+        action_tokenbool_s139,
+        action_datatype_s140,
+        action_vardeclaration_s141,
+        action_tokencbopen_s142,
+        action_blockopen_s143,
+        action_blockopen_s144,
+        action_tokenparclose_s145,
+        action_tokencolon_s146,
+        action_tokencolon_s147,
+        action_tokenparclose_s148,
+        action_tokencomma_s149,
+        action_tokenparclose_s150,
+        action_tokenparclose_s151,
+        action_term_s152,
+        action_term_s153,
+        action_factor_s154,
+        action_factor_s155,
+        action_tokencbopen_s156,
+        action_tokenparclose_s157,
+        action_tokencbclose_s158,
+        action_tokensbopen_s159,
+        action_body_s160,
+        action_body_s161,
+        action_datatype_s162,
+        action_datatype_s163,
+        action_tokencolon_s164,
+        action_parameters_s165,
+        action_body_s166,
+        action_tokenintliteral_s167,
+        action_tokencbclose_s168,
+        action_tokencbclose_s169,
+        action_datatype_s170,
+        action_tokencbclose_s171,
+        action_tokensbclose_s172,
+        action_dummyelse_s173,
+        action_tokenelse_s174,
+        action_elsestatement_s175,
+        action_blockopen_s176,
+        action_body_s177,
+        action_tokencbclose_s178,
+    ],
+    gotos: [
+        goto_aug_s0,
+        goto_tokenid_s1,
+        goto_tokeninit_s2,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_tokenfunction_s7,
+        goto_tokenprocedure_s8,
+        goto_tokenreturn_s9,
+        goto_invalid,
+        goto_functions_s11,
+        goto_function_s12,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_statement_s19,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_tokenassign_s23,
+        goto_tokenparopen_s24,
+        goto_tokensbopen_s25,
+        goto_invalid,
+        goto_tokencbopen_s27,
+        goto_initbody_s28,
+        goto_tokenparopen_s29,
+        goto_tokenparopen_s30,
+        goto_invalid,
+        goto_tokenparopen_s32,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_tokenassign_s16,
+        goto_tokenid_s39,
         goto_invalid,
-        goto_tokencbopen_s18,
-        goto_initbody_s19,
-        goto_tokenparopen_s20,
-        goto_tokenparopen_s21,
+        goto_tokenparopen_s41,
         goto_invalid,
-        goto_tokenparopen_s23,
         goto_invalid,
+        goto_tokennot_s44,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_simpleexpression_s49,
         goto_invalid,
         goto_invalid,
-        goto_tokenparopen_s31,
         goto_invalid,
+        goto_arithmeticexpression_s53,
+        goto_term_s54,
         goto_invalid,
+        goto_tokenid_s56,
         goto_invalid,
         goto_invalid,
-        goto_arithmeticexpression_s36,
-        goto_term_s37,
+        goto_tokencbopen_s59,
+        goto_tokenid_s60,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_vardeclaration_s42,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_tokennot_s47,
+        goto_tokenassign_s69,
         goto_invalid,
         goto_invalid,
+        goto_vardeclaration_s72,
         goto_invalid,
-        goto_simpleexpression_s51,
         goto_invalid,
         goto_invalid,
+        goto_tokenid_s76,
         goto_invalid,
+        goto_tokenparopen_s78,
+        goto_tokenparopen_s79,
+        goto_tokenparopen_s80,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_arithmeticexpression_s84,
         goto_invalid,
-        goto_arithmeticexpression_s59,
+        goto_tokenparopen_s86,
+        goto_tokenand_s87,
+        goto_tokenor_s88,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_tokencbopen_s63,
-        goto_tokencolon_s64,
-        goto_tokencomma_s65,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_tokenparopen_s69,
-        goto_tokenand_s70,
-        goto_tokenor_s71,
+        goto_comparisonop_s95,
         goto_invalid,
         goto_invalid,
+        goto_tokenparopen_s98,
         goto_invalid,
         goto_invalid,
+        goto_tokencbopen_s101,
         goto_invalid,
+        goto_tokencomma_s103,
         goto_invalid,
-        goto_comparisonop_s78,
         goto_invalid,
+        goto_tokencolon_s106,
+        goto_tokencomma_s107,
         goto_invalid,
         goto_invalid,
+        goto_tokenparclose_s110,
+        goto_tokenparclose_s111,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_tokensum_s85,
-        goto_tokensub_s86,
-        goto_tokenmul_s87,
-        goto_tokendiv_s88,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_arithmeticexpression_s95,
         goto_invalid,
         goto_invalid,
+        goto_arithmeticexpression_s123,
         goto_invalid,
-        goto_tokencbopen_s99,
-        goto_tokencbopen_s100,
         goto_invalid,
-        goto_term_s102,
-        goto_term_s103,
         goto_invalid,
+        goto_tokensum_s127,
+        goto_tokensub_s128,
+        goto_tokenmul_s129,
+        goto_tokendiv_s130,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_tokencbclose_s111,
-        goto_dummyelse_s112,
         goto_invalid,
         goto_invalid,
-        goto_tokencbopen_s115,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_blockopen_s143,
+        goto_blockopen_s144,
+        goto_invalid,
+        goto_tokencolon_s146,
+        goto_tokencolon_s147,
+        goto_invalid,
+        goto_tokencomma_s149,
+        goto_invalid,
+        goto_invalid,
+        goto_term_s152,
+        goto_term_s153,
+        goto_invalid,
+        goto_invalid,
+        goto_tokencbopen_s156,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_tokencolon_s164,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_tokencbclose_s169,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_dummyelse_s173,
+        goto_tokenelse_s174,
+        goto_invalid,
+        goto_blockopen_s176,
         goto_invalid,
         goto_invalid,
     ],
     token_kinds: [
         [
-            Some((TK::STOP, true)),
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenInit, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenFunction, false)),
+            Some((TK::TokenProcedure, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenAssign, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenSBOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenCBOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenId, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenId, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenInit, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenInit, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenFunction, false)),
+            Some((TK::TokenProcedure, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenCBOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenCBClose, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            Some((TK::TokenConvDate, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenAssign, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenId, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenId, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBOpen, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenAssign, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenSBOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenInit, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenInit, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenAssign, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenSBOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenCBClose, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBOpen, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenComma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenComma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenSBClose, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenColon, false)),
+            Some((TK::TokenComma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenCBClose, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenCBClose, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParClose, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParClose, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenSBOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenParClose, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenId, false)),
+            Some((TK::TokenParClose, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenId, false)),
+            Some((TK::TokenParClose, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParClose, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
             Some((TK::TokenId, false)),
-            Some((TK::TokenInit, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3194,9 +7584,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenAssign, false)),
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
             Some((TK::TokenParOpen, false)),
-            None,
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
             None,
             None,
             None,
@@ -3216,9 +7611,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenCBOpen, false)),
-            None,
-            None,
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
             None,
             None,
             None,
@@ -3238,9 +7638,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
             Some((TK::TokenParOpen, false)),
-            None,
-            None,
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
             None,
             None,
             None,
@@ -3260,9 +7665,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
             Some((TK::TokenParOpen, false)),
-            None,
-            None,
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
             None,
             None,
             None,
@@ -3282,9 +7692,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
             Some((TK::TokenParOpen, false)),
-            None,
-            None,
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
             None,
             None,
             None,
@@ -3304,9 +7719,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
             Some((TK::TokenParOpen, false)),
-            None,
-            None,
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
             None,
             None,
             None,
@@ -3326,7 +7746,12 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::STOP, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenSub, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3348,7 +7773,12 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::STOP, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenDiv, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3370,13 +7800,18 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::STOP, true)),
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
             Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            None,
             None,
             None,
             None,
@@ -3392,13 +7827,7 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
             Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
             None,
             None,
             None,
@@ -3412,10 +7841,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
@@ -3427,6 +7852,13 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenDate, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3434,15 +7866,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
             None,
             None,
             None,
@@ -3458,13 +7881,18 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::STOP, true)),
             Some((TK::TokenId, false)),
             Some((TK::TokenCBClose, false)),
+            Some((TK::TokenInit, false)),
             Some((TK::TokenWhile, false)),
             Some((TK::TokenIf, false)),
             Some((TK::TokenRead, false)),
             Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3482,11 +7910,43 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
         [
             Some((TK::STOP, true)),
             Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
             Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
             Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
             Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
             Some((TK::TokenRead, false)),
             Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            Some((TK::TokenNot, false)),
+            Some((TK::TokenIsZero, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -3504,12 +7964,39 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
         [
             Some((TK::STOP, true)),
             Some((TK::TokenId, false)),
+            Some((TK::TokenAssign, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
             Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
             Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
             Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
             Some((TK::TokenRead, false)),
             Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
             None,
@@ -3522,21 +8009,17 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
-            Some((TK::TokenConvDate, false)),
-            None,
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenInt, false)),
+            Some((TK::TokenFloat, false)),
+            Some((TK::TokenString, false)),
+            Some((TK::TokenBool, false)),
             None,
             None,
             None,
@@ -3544,9 +8027,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenParClose, false)),
             None,
             None,
             None,
@@ -3561,14 +8041,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenId, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenId, false)),
             None,
             None,
             None,
@@ -3597,6 +8077,7 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             Some((TK::TokenIf, false)),
             Some((TK::TokenRead, false)),
             Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
             None,
@@ -3610,40 +8091,18 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
-            Some((TK::TokenTrue, false)),
-            Some((TK::TokenFalse, false)),
-            Some((TK::TokenNot, false)),
-            Some((TK::TokenIsZero, false)),
-            None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
-            Some((TK::TokenTrue, false)),
-            Some((TK::TokenFalse, false)),
-            Some((TK::TokenNot, false)),
-            Some((TK::TokenIsZero, false)),
             None,
             None,
             None,
@@ -3654,9 +8113,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenId, false)),
             None,
             None,
             None,
@@ -3666,6 +8122,9 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenCBOpen, false)),
             None,
             None,
             None,
@@ -3676,14 +8135,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
             None,
             None,
             None,
@@ -3700,7 +8151,12 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenAssign, false)),
+            Some((TK::TokenCBOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -3723,7 +8179,13 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
         ],
         [
             Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
             Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
             None,
@@ -3741,99 +8203,15 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-            None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenDiv, false)),
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenDiv, false)),
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
             Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenDiv, false)),
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-        ],
-        [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -3854,11 +8232,16 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::STOP, true)),
             Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -3876,7 +8259,7 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenColon, false)),
             None,
             None,
             None,
@@ -3896,20 +8279,15 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenColon, false)),
+            None,
             None,
             None,
             None,
@@ -3918,15 +8296,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
             None,
             None,
             None,
@@ -3940,97 +8309,16 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenDiv, false)),
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
             None,
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenDiv, false)),
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenDiv, false)),
             Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-        ],
-        [
-            Some((TK::TokenCBOpen, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -4052,7 +8340,7 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenColon, false)),
+            Some((TK::TokenParClose, false)),
             Some((TK::TokenComma, false)),
             None,
             None,
@@ -4072,14 +8360,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenCBOpen, false)),
             None,
             None,
             None,
@@ -4094,10 +8382,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
@@ -4108,6 +8392,9 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenParClose, false)),
             None,
             None,
             None,
@@ -4116,10 +8403,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
@@ -4136,35 +8419,70 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
         ],
         [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
             Some((TK::TokenSum, false)),
             Some((TK::TokenMul, false)),
             Some((TK::TokenSub, false)),
             Some((TK::TokenDiv, false)),
             Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
             Some((TK::TokenEqual, false)),
             Some((TK::TokenNotEqual, false)),
             Some((TK::TokenLess, false)),
             Some((TK::TokenLessEqual, false)),
             Some((TK::TokenGreater, false)),
             Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
             Some((TK::TokenAnd, false)),
             Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
+        ],
+        [
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParClose, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
             None,
             None,
             None,
@@ -4184,11 +8502,16 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
             Some((TK::TokenParClose, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            None,
-            None,
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
             None,
@@ -4206,33 +8529,16 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
+            Some((TK::STOP, true)),
             Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
-            Some((TK::TokenTrue, false)),
-            Some((TK::TokenFalse, false)),
-            Some((TK::TokenNot, false)),
-            Some((TK::TokenIsZero, false)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        ],
-        [
-            Some((TK::TokenParOpen, false)),
-            None,
-            None,
-            None,
-            None,
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
             None,
@@ -4250,13 +8556,18 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
             Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
             Some((TK::TokenAnd, false)),
             Some((TK::TokenOr, false)),
-            None,
-            None,
-            None,
-            None,
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
             None,
@@ -4272,9 +8583,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
             None,
             None,
             None,
@@ -4294,12 +8610,15 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
+            None,
             None,
             None,
             None,
@@ -4314,11 +8633,18 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenParClose, false)),
             None,
             None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
             None,
             None,
             None,
@@ -4338,9 +8664,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
+            Some((TK::TokenIntLiteral, false)),
+            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::TokenStringLiteral, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenTrue, false)),
+            Some((TK::TokenFalse, false)),
             None,
             None,
             None,
@@ -4360,9 +8691,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenParClose, false)),
-            None,
-            None,
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBOpen, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
             None,
@@ -4382,11 +8718,16 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenParClose, false)),
-            None,
-            None,
-            None,
-            None,
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenInit, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenFunction, false)),
+            Some((TK::TokenProcedure, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
             None,
@@ -4424,62 +8765,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenDiv, false)),
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenDiv, false)),
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-        ],
-        [
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParClose, false)),
-            None,
-            None,
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
@@ -4490,9 +8783,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenDate, false)),
             None,
             None,
             None,
@@ -4507,15 +8797,15 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenComma, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenSub, false)),
             None,
             None,
             None,
@@ -4536,8 +8826,13 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenDiv, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBOpen, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBOpen, false)),
+            Some((TK::TokenComma, false)),
+            None,
             None,
             None,
             None,
@@ -4559,12 +8854,17 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
         ],
         [
             Some((TK::TokenId, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBOpen, false)),
             Some((TK::TokenCBClose, false)),
-            Some((TK::TokenInit, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
+            Some((TK::TokenSBOpen, false)),
+            Some((TK::TokenComma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -4580,9 +8880,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenInt, false)),
-            Some((TK::TokenFloat, false)),
-            Some((TK::TokenString, false)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBOpen, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBOpen, false)),
+            Some((TK::TokenComma, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -4603,6 +8908,11 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
         ],
         [
             Some((TK::TokenId, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBOpen, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBOpen, false)),
+            Some((TK::TokenComma, false)),
             None,
             None,
             None,
@@ -4624,14 +8934,9 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::STOP, true)),
             Some((TK::TokenId, false)),
             Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-            None,
+            Some((TK::TokenSBOpen, false)),
             None,
             None,
             None,
@@ -4644,12 +8949,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenCBClose, false)),
-            None,
-            None,
-            None,
             None,
             None,
             None,
@@ -4660,17 +8959,16 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
             None,
             None,
             None,
@@ -4690,11 +8988,16 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
             Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenInit, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -4712,19 +9015,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
             Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
-            Some((TK::TokenTrue, false)),
-            Some((TK::TokenFalse, false)),
-            Some((TK::TokenNot, false)),
-            Some((TK::TokenIsZero, false)),
-            None,
-            None,
-            None,
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenInit, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
             None,
@@ -4732,18 +9030,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
-            Some((TK::TokenTrue, false)),
-            Some((TK::TokenFalse, false)),
-            Some((TK::TokenNot, false)),
-            Some((TK::TokenIsZero, false)),
             None,
             None,
             None,
@@ -4756,12 +9042,17 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
             Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenInit, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -4778,12 +9069,17 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
+            Some((TK::STOP, true)),
             Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -4800,12 +9096,10 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenInt, false)),
+            Some((TK::TokenFloat, false)),
+            Some((TK::TokenString, false)),
+            Some((TK::TokenBool, false)),
             None,
             None,
             None,
@@ -4820,14 +9114,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
             None,
             None,
             None,
@@ -4835,6 +9121,12 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenInt, false)),
+            Some((TK::TokenFloat, false)),
+            Some((TK::TokenString, false)),
+            Some((TK::TokenBool, false)),
             None,
             None,
             None,
@@ -4842,14 +9134,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
             None,
             None,
             None,
@@ -4866,12 +9150,7 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenColon, false)),
             None,
             None,
             None,
@@ -4886,14 +9165,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenStringLiteral, false)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
             None,
             None,
             None,
@@ -4904,13 +9175,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenId, false)),
+            None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenCBOpen, false)),
             None,
             None,
             None,
@@ -4952,15 +9224,25 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
             Some((TK::TokenId, false)),
+            Some((TK::TokenParClose, false)),
             Some((TK::TokenCBClose, false)),
             Some((TK::TokenWhile, false)),
             Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
             Some((TK::TokenRead, false)),
             Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
             None,
@@ -4978,22 +9260,27 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
         [
             Some((TK::STOP, true)),
             Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
             Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
             Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
             Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
             Some((TK::TokenRead, false)),
             Some((TK::TokenWrite, false)),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
+            Some((TK::TokenReturn, false)),
             None,
             None,
         ],
@@ -5006,6 +9293,7 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             Some((TK::TokenDiv, false)),
             Some((TK::TokenParClose, false)),
             Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
             Some((TK::TokenWhile, false)),
             Some((TK::TokenEqual, false)),
             Some((TK::TokenNotEqual, false)),
@@ -5014,39 +9302,80 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             Some((TK::TokenGreater, false)),
             Some((TK::TokenGreaterEqual, false)),
             Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
             Some((TK::TokenAnd, false)),
             Some((TK::TokenOr, false)),
             Some((TK::TokenRead, false)),
             Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
         ],
         [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
             Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::TokenId, false)),
+            Some((TK::TokenSum, false)),
+            Some((TK::TokenMul, false)),
+            Some((TK::TokenSub, false)),
+            Some((TK::TokenDiv, false)),
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenSBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenEqual, false)),
+            Some((TK::TokenNotEqual, false)),
+            Some((TK::TokenLess, false)),
+            Some((TK::TokenLessEqual, false)),
+            Some((TK::TokenGreater, false)),
+            Some((TK::TokenGreaterEqual, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenComma, false)),
+            Some((TK::TokenAnd, false)),
+            Some((TK::TokenOr, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
         ],
         [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
             Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenInit, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -5064,11 +9393,16 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
+            Some((TK::STOP, true)),
             Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
+            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenWhile, false)),
+            Some((TK::TokenIf, false)),
+            Some((TK::TokenRead, false)),
+            Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -5086,12 +9420,7 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
-            None,
+            Some((TK::STOP, false)),
             None,
             None,
             None,
@@ -5106,13 +9435,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenIntLiteral, false)),
-            Some((TK::TokenFloatLiteral, false)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParOpen, false)),
             None,
             None,
             None,
@@ -5123,14 +9445,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenIntLiteral, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
@@ -5152,7 +9474,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenId, false)),
             Some((TK::TokenCBClose, false)),
             None,
             None,
@@ -5172,17 +9493,15 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
-            None,
             None,
             None,
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
@@ -5194,10 +9513,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
@@ -5211,15 +9526,15 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenParClose, false)),
+            Some((TK::TokenComma, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
@@ -5240,8 +9555,10 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
+            Some((TK::TokenCBOpen, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5260,14 +9577,15 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenParClose, false)),
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenInt, false)),
+            Some((TK::TokenFloat, false)),
+            Some((TK::TokenString, false)),
+            Some((TK::TokenBool, false)),
             None,
             None,
             None,
@@ -5282,9 +9600,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenParClose, false)),
             None,
             None,
             None,
@@ -5292,6 +9607,9 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenParClose, false)),
             None,
             None,
             None,
@@ -5304,9 +9622,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenParClose, false)),
             None,
             None,
             None,
@@ -5319,6 +9634,9 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
@@ -5326,11 +9644,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
             None,
             None,
             None,
@@ -5350,14 +9663,7 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenInit, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-            None,
+            Some((TK::TokenSBClose, false)),
             None,
             None,
             None,
@@ -5370,16 +9676,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenInit, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-            None,
             None,
             None,
             None,
@@ -5401,6 +9697,11 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             Some((TK::TokenIf, false)),
             Some((TK::TokenRead, false)),
             Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5418,93 +9719,13 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
         [
             Some((TK::STOP, true)),
             Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenDiv, false)),
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenDiv, false)),
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenDiv, false)),
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenSum, false)),
-            Some((TK::TokenMul, false)),
-            Some((TK::TokenSub, false)),
-            Some((TK::TokenDiv, false)),
-            Some((TK::TokenParClose, false)),
             Some((TK::TokenCBClose, false)),
             Some((TK::TokenWhile, false)),
-            Some((TK::TokenEqual, false)),
-            Some((TK::TokenNotEqual, false)),
-            Some((TK::TokenLess, false)),
-            Some((TK::TokenLessEqual, false)),
-            Some((TK::TokenGreater, false)),
-            Some((TK::TokenGreaterEqual, false)),
             Some((TK::TokenIf, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
+            Some((TK::TokenElse, false)),
             Some((TK::TokenRead, false)),
             Some((TK::TokenWrite, false)),
-        ],
-        [
-            Some((TK::STOP, false)),
+            Some((TK::TokenReturn, false)),
             None,
             None,
             None,
@@ -5521,14 +9742,12 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenCBOpen, false)),
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenParClose, false)),
-            Some((TK::TokenAnd, false)),
-            Some((TK::TokenOr, false)),
             None,
             None,
             None,
@@ -5546,13 +9765,13 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::STOP, false)),
             None,
             None,
             None,
@@ -5568,9 +9787,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
@@ -5580,6 +9796,10 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenId, false)),
+            Some((TK::TokenCBClose, false)),
             None,
             None,
             None,
@@ -5590,15 +9810,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenIf, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
             None,
             None,
             None,
@@ -5614,14 +9825,7 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
         ],
         [
-            Some((TK::STOP, true)),
-            Some((TK::TokenId, false)),
-            Some((TK::TokenCBClose, false)),
-            Some((TK::TokenWhile, false)),
-            Some((TK::TokenIf, false)),
             Some((TK::TokenElse, false)),
-            Some((TK::TokenRead, false)),
-            Some((TK::TokenWrite, false)),
             None,
             None,
             None,
@@ -5634,11 +9838,6 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenElse, false)),
-            None,
-            None,
             None,
             None,
             None,
@@ -5651,14 +9850,14 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::TokenCBOpen, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::TokenCBOpen, false)),
             None,
             None,
             None,
@@ -5687,6 +9886,11 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             Some((TK::TokenIf, false)),
             Some((TK::TokenRead, false)),
             Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5709,6 +9913,11 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             Some((TK::TokenIf, false)),
             Some((TK::TokenRead, false)),
             Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -5744,6 +9953,11 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         ],
         [
             Some((TK::STOP, true)),
@@ -5753,6 +9967,11 @@ pub(crate) static PARSER_DEFINITION: RulesParserDefinition = RulesParserDefiniti
             Some((TK::TokenIf, false)),
             Some((TK::TokenRead, false)),
             Some((TK::TokenWrite, false)),
+            Some((TK::TokenReturn, false)),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
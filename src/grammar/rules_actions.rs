@@ -1,21 +1,25 @@
 use crate::compiler::{
-    ast::{AstAction, AstPtr, ExpressionType, Node, NodeValue},
-    context::CompilerContext,
-    error::{CompilerError, log_error_and_exit},
+    ast::{AstAction, AstPtr, ExpressionType, Node, NodeValue, Span},
+    context::{
+        CompilerContext, MAX_STRING_LENGTH, ReferenceKind, SymbolTableElement,
+        SymbolTableElementType,
+    },
+    error::{CompilerError, Diagnostic, log_error, log_warning},
+    optimizer::constant_condition,
 };
 pub use crate::grammar::types::*;
 use rustemo::{Context, Input};
-use std::rc::Rc;
+use std::{mem, rc::Rc};
 
 /// Parses the keyword "int"
 pub fn token_int(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenInt {
-    compiler_context.write_to_lexer_file(&format!("INT: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("INT: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "float"
 pub fn token_float(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenFloat {
-    compiler_context.write_to_lexer_file(&format!("FLOAT: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("FLOAT: {}", token.value));
     token.value.into()
 }
 
@@ -25,41 +29,43 @@ pub fn token_string(
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenString {
-    compiler_context.write_to_lexer_file(&format!("STRING: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("STRING: {}", token.value));
+    token.value.into()
+}
+
+/// Parses the keyword "bool"
+pub fn token_bool(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenBool {
+    compiler_context.write_to_lexer_output(&format!("BOOL: {}", token.value));
     token.value.into()
 }
 
 /// Parses an integer literal into i64
 ///
-/// # Safety
-///
-/// The parsing can't fail because we succesfully parse it in the lexer
+/// If the literal doesn't fit the lexer already reported the error so we fall back to 0
 pub fn token_int_literal(
     _ctx: &Ctx,
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenIntLiteral {
-    compiler_context.write_to_lexer_file(&format!("INT_LITERAL: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("INT_LITERAL: {}", token.value));
     TokenIntLiteral {
         original: token.value.into(),
-        parsed: token.value.parse().unwrap(),
+        parsed: token.value.parse().unwrap_or_default(),
     }
 }
 
-/// Parses a float literal into i64
+/// Parses a float literal into f32
 ///
-/// # Safety
-///
-/// The parsing can't fail because we succesfully parse it in the lexer
+/// If the literal is invalid the lexer already reported the error so we fall back to 0
 pub fn token_float_literal(
     _ctx: &Ctx,
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenFloatLiteral {
-    compiler_context.write_to_lexer_file(&format!("FLOAT_LITERAL: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("FLOAT_LITERAL: {}", token.value));
     TokenFloatLiteral {
         original: token.value.to_string(),
-        parsed: token.value.parse::<f32>().unwrap(),
+        parsed: token.value.parse::<f32>().unwrap_or_default(),
     }
 }
 
@@ -70,13 +76,13 @@ pub fn token_string_literal(
     compiler_context: &mut CompilerContext,
 ) -> TokenStringLiteral {
     token.value = token.value.slice(1..token.value.len() - 1);
-    compiler_context.write_to_lexer_file(&format!("STRING_LITERAL: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("STRING_LITERAL: {}", token.value));
     token.value.into()
 }
 
 /// Parses a TokenId
 pub fn token_id(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenId {
-    compiler_context.write_to_lexer_file(&format!("ID: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("ID: {}", token.value));
     token.value.into()
 }
 
@@ -86,31 +92,31 @@ pub fn token_assign(
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenAssign {
-    compiler_context.write_to_lexer_file(&format!("ASSIGN: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("ASSIGN: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "+"
 pub fn token_sum(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenSum {
-    compiler_context.write_to_lexer_file(&format!("SUM: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("SUM: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "*"
 pub fn token_mul(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenMul {
-    compiler_context.write_to_lexer_file(&format!("MUL: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("MUL: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "-"
 pub fn token_sub(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenSub {
-    compiler_context.write_to_lexer_file(&format!("SUB: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("SUB: {}", token.value));
     token.value.into()
 }
 
 /// Parses the token "/"
 pub fn token_div(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenDiv {
-    compiler_context.write_to_lexer_file(&format!("DIV: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("DIV: {}", token.value));
     token.value.into()
 }
 
@@ -120,7 +126,7 @@ pub fn token_par_open(
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenParOpen {
-    compiler_context.write_to_lexer_file(&format!("PAR_OPEN: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("PAR_OPEN: {}", token.value));
     token.value.into()
 }
 
@@ -130,7 +136,7 @@ pub fn token_par_close(
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenParClose {
-    compiler_context.write_to_lexer_file(&format!("PAR_CLOSE: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("PAR_CLOSE: {}", token.value));
     token.value.into()
 }
 
@@ -140,7 +146,7 @@ pub fn token_cbopen(
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenCBOpen {
-    compiler_context.write_to_lexer_file(&format!("CB_OPEN: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("CB_OPEN: {}", token.value));
     token.value.into()
 }
 
@@ -150,31 +156,51 @@ pub fn token_cbclose(
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenCBClose {
-    compiler_context.write_to_lexer_file(&format!("CB_CLOSE: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("CB_CLOSE: {}", token.value));
+    token.value.into()
+}
+
+/// Parses the keyword "["
+pub fn token_sbopen(
+    _ctx: &Ctx,
+    token: Token,
+    compiler_context: &mut CompilerContext,
+) -> TokenSBOpen {
+    compiler_context.write_to_lexer_output(&format!("SB_OPEN: {}", token.value));
+    token.value.into()
+}
+
+/// Parses the keyword "]"
+pub fn token_sbclose(
+    _ctx: &Ctx,
+    token: Token,
+    compiler_context: &mut CompilerContext,
+) -> TokenSBClose {
+    compiler_context.write_to_lexer_output(&format!("SB_CLOSE: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword ":"
 pub fn token_colon(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenColon {
-    compiler_context.write_to_lexer_file(&format!("COLON: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("COLON: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "init"
 pub fn token_init(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenInit {
-    compiler_context.write_to_lexer_file(&format!("INIT: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("INIT: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "while"
 pub fn token_while(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenWhile {
-    compiler_context.write_to_lexer_file(&format!("WHILE: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("WHILE: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "=="
 pub fn token_equal(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenEqual {
-    compiler_context.write_to_lexer_file(&format!("EQUAL: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("EQUAL: {}", token.value));
     token.value.into()
 }
 
@@ -184,13 +210,13 @@ pub fn token_not_equal(
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenNotEqual {
-    compiler_context.write_to_lexer_file(&format!("NOT_EQUAL: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("NOT_EQUAL: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "<"
 pub fn token_less(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenLess {
-    compiler_context.write_to_lexer_file(&format!("LESS: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("LESS: {}", token.value));
     token.value.into()
 }
 
@@ -200,7 +226,7 @@ pub fn token_less_equal(
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenLessEqual {
-    compiler_context.write_to_lexer_file(&format!("LESS_EQUAL: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("LESS_EQUAL: {}", token.value));
     token.value.into()
 }
 
@@ -210,7 +236,7 @@ pub fn token_greater(
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenGreater {
-    compiler_context.write_to_lexer_file(&format!("GREATER: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("GREATER: {}", token.value));
     token.value.into()
 }
 
@@ -220,67 +246,67 @@ pub fn token_greater_equal(
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenGreaterEqual {
-    compiler_context.write_to_lexer_file(&format!("GREATER_EQUAL: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("GREATER_EQUAL: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "true"
 pub fn token_true(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenTrue {
-    compiler_context.write_to_lexer_file(&format!("TRUE: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("TRUE: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "false"
 pub fn token_false(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenFalse {
-    compiler_context.write_to_lexer_file(&format!("FALSE: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("FALSE: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "if"
 pub fn token_if(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenIf {
-    compiler_context.write_to_lexer_file(&format!("IF: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("IF: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "else"
 pub fn token_else(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenElse {
-    compiler_context.write_to_lexer_file(&format!("ELSE: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("ELSE: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword ","
 pub fn token_comma(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenComma {
-    compiler_context.write_to_lexer_file(&format!("COMMA: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("COMMA: {}", token.value));
     token.value.into()
 }
 
 /// Pareses the keyword "and"
 pub fn token_and(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenAnd {
-    compiler_context.write_to_lexer_file(&format!("AND: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("AND: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "or"
 pub fn token_or(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenOr {
-    compiler_context.write_to_lexer_file(&format!("OR: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("OR: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "not"
 pub fn token_not(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenNot {
-    compiler_context.write_to_lexer_file(&format!("NOT: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("NOT: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "read"
 pub fn token_read(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenRead {
-    compiler_context.write_to_lexer_file(&format!("READ: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("READ: {}", token.value));
     token.value.into()
 }
 
 /// Parses the keyword "write"
 pub fn token_write(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenWrite {
-    compiler_context.write_to_lexer_file(&format!("WRITE: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("WRITE: {}", token.value));
     token.value.into()
 }
 
@@ -290,7 +316,7 @@ pub fn token_is_zero(
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenIsZero {
-    compiler_context.write_to_lexer_file(&format!("IS_ZERO: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("IS_ZERO: {}", token.value));
     token.value.into()
 }
 
@@ -300,13 +326,13 @@ pub fn token_conv_date(
     token: Token,
     compiler_context: &mut CompilerContext,
 ) -> TokenConvDate {
-    compiler_context.write_to_lexer_file(&format!("CONV_DATE: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("CONV_DATE: {}", token.value));
     token.value.into()
 }
 
 /// Parses a date in “DD-MM-YYYY” format
 pub fn token_date(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerContext) -> TokenDate {
-    compiler_context.write_to_lexer_file(&format!("DATE: {}", token.value));
+    compiler_context.write_to_lexer_output(&format!("DATE: {}", token.value));
     TokenDate {
         day: token.value.get(0..2).unwrap().into(),
         month: token.value.get(3..5).unwrap().into(),
@@ -314,6 +340,36 @@ pub fn token_date(_ctx: &Ctx, token: Token, compiler_context: &mut CompilerConte
     }
 }
 
+/// Parses the keyword "function"
+pub fn token_function(
+    _ctx: &Ctx,
+    token: Token,
+    compiler_context: &mut CompilerContext,
+) -> TokenFunction {
+    compiler_context.write_to_lexer_output(&format!("FUNCTION: {}", token.value));
+    token.value.into()
+}
+
+/// Parses the keyword "procedure"
+pub fn token_procedure(
+    _ctx: &Ctx,
+    token: Token,
+    compiler_context: &mut CompilerContext,
+) -> TokenProcedure {
+    compiler_context.write_to_lexer_output(&format!("PROCEDURE: {}", token.value));
+    token.value.into()
+}
+
+/// Parses the keyword "return"
+pub fn token_return(
+    _ctx: &Ctx,
+    token: Token,
+    compiler_context: &mut CompilerContext,
+) -> TokenReturn {
+    compiler_context.write_to_lexer_output(&format!("RETURN: {}", token.value));
+    token.value.into()
+}
+
 /// Parses the rule `<Program> -> TokenId TokenParOpen TokenParClose TokenCBOpen <Body> TokenCBClose`
 #[expect(clippy::too_many_arguments)]
 pub fn program_program_with_main(
@@ -326,7 +382,7 @@ pub fn program_program_with_main(
     token_cbclose: TokenCBClose,
     compiler_context: &mut CompilerContext,
 ) -> Program {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<Program> -> {token_id} {token_par_open} {token_par_close} {token_cbopen} <Body> {token_cbclose}"
     ));
 
@@ -343,13 +399,57 @@ pub fn program_program_with_main(
     })
 }
 
+/// Parses the rule `<Program> -> <Functions> TokenId TokenParOpen TokenParClose TokenCBOpen <Body> TokenCBClose`
+#[expect(clippy::too_many_arguments)]
+pub fn program_program_with_functions(
+    ctx: &Ctx,
+    functions: Functions,
+    token_id: TokenId,
+    token_par_open: TokenParOpen,
+    token_par_close: TokenParClose,
+    token_cbopen: TokenCBOpen,
+    body: Body,
+    token_cbclose: TokenCBClose,
+    compiler_context: &mut CompilerContext,
+) -> Program {
+    compiler_context.write_to_parser_output(&format!(
+        "<Program> -> <Functions> {token_id} {token_par_open} {token_par_close} {token_cbopen} <Body> {token_cbclose}"
+    ));
+
+    prepend_functions(ctx, compiler_context);
+
+    Program::ProgramWithFunctions(ProgramWithFunctions {
+        functions,
+        token_id,
+        token_par_open,
+        token_par_close,
+        token_cbopen,
+        body,
+        token_cbclose,
+    })
+}
+
+/// Parses the rule `<Program> -> <Functions> <Body>`
+pub fn program_program_functions_only_body(
+    ctx: &Ctx,
+    functions: Functions,
+    body: Body,
+    compiler_context: &mut CompilerContext,
+) -> Program {
+    compiler_context.write_to_parser_output("<Program> -> <Functions> <Body>");
+
+    prepend_functions(ctx, compiler_context);
+
+    Program::ProgramFunctionsOnlyBody(ProgramFunctionsOnlyBody { functions, body })
+}
+
 /// Parses the rule `<Program> -> <Body>`
 pub fn program_program_only_body(
     _ctx: &Ctx,
     body: Body,
     compiler_context: &mut CompilerContext,
 ) -> Program {
-    compiler_context.write_to_parser_file("<Program> -> <Body>");
+    compiler_context.write_to_parser_output("<Program> -> <Body>");
 
     let ast = &mut compiler_context.ast;
     ast.assign_node_to_ptr(AstPtr::Body.into(), AstPtr::Program);
@@ -357,6 +457,287 @@ pub fn program_program_only_body(
     Program::ProgramOnlyBody(body)
 }
 
+/// Parses the rule `<Functions> -> <Function>`
+pub fn functions_functions_single(
+    _ctx: &Ctx,
+    function: Function,
+    compiler_context: &mut CompilerContext,
+) -> Functions {
+    compiler_context.write_to_parser_output("<Functions> -> <Function>");
+    Functions::FunctionsSingle(function)
+}
+
+/// Parses the rule `<Functions> -> <Function> <Functions>`
+pub fn functions_functions_recursive(
+    _ctx: &Ctx,
+    function: Function,
+    functions: Functions,
+    compiler_context: &mut CompilerContext,
+) -> Functions {
+    compiler_context.write_to_parser_output("<Functions> -> <Function> <Functions>");
+    Functions::FunctionsRecursive(FunctionsRecursive {
+        function,
+        functions: Box::new(functions),
+    })
+}
+
+/// Parses the rule `<Function> -> <FunctionSignature> TokenCBOpen <Body> TokenCBClose`
+pub fn function_function_definition(
+    ctx: &Ctx,
+    function_signature: FunctionSignature,
+    token_cbopen: TokenCBOpen,
+    body: Body,
+    token_cbclose: TokenCBClose,
+    compiler_context: &mut CompilerContext,
+) -> Function {
+    compiler_context.write_to_parser_output(&format!(
+        "<Function> -> <FunctionSignature> {token_cbopen} <Body> {token_cbclose}"
+    ));
+
+    let body_node = compiler_context.ast.get_node_from_ptr(AstPtr::Body);
+    let (name, return_type) = compiler_context
+        .current_function()
+        .map(|function| (function.name.clone(), function.return_type.clone()))
+        .unwrap_or_default();
+    if return_type.is_some() && !always_returns(&body_node) {
+        log_error(
+            ctx.range(),
+            CompilerError::InvalidReturn(format!(
+                "function {name} can finish without returning a value"
+            )),
+            0,
+            true,
+            compiler_context,
+        );
+    }
+
+    let ast = &mut compiler_context.ast;
+    let name_leaf = Rc::new(Node::new_leaf(
+        NodeValue::Value(name),
+        return_type,
+        Span::from(ctx),
+    ));
+    let function_node = ast.create_node(
+        AstAction::Function,
+        name_leaf.into(),
+        body_node.into(),
+        AstPtr::Function,
+        None,
+        Span::from(ctx),
+    );
+    ast.function_stack.push(function_node);
+    compiler_context.exit_function();
+
+    Function {
+        function_signature,
+        token_cbopen,
+        body,
+        token_cbclose,
+    }
+}
+
+/// Parses the rule `<FunctionSignature> -> TokenFunction <FunctionName> TokenParOpen TokenParClose TokenColon <DataType>`
+#[expect(clippy::too_many_arguments)]
+pub fn function_signature_function_no_parameters(
+    _ctx: &Ctx,
+    token_function: TokenFunction,
+    function_name: FunctionName,
+    token_par_open: TokenParOpen,
+    token_par_close: TokenParClose,
+    token_colon: TokenColon,
+    data_type: DataType,
+    compiler_context: &mut CompilerContext,
+) -> FunctionSignature {
+    compiler_context.write_to_parser_output(&format!(
+        "<FunctionSignature> -> {token_function} <FunctionName> {token_par_open} {token_par_close} {token_colon} <DataType>"
+    ));
+
+    compiler_context.set_return_type(data_type.clone().into());
+
+    FunctionSignature::FunctionNoParameters(FunctionNoParameters {
+        token_function,
+        function_name,
+        token_par_open,
+        token_par_close,
+        token_colon,
+        data_type,
+    })
+}
+
+/// Parses the rule `<FunctionSignature> -> TokenFunction <FunctionName> TokenParOpen <Parameters> TokenParClose TokenColon <DataType>`
+#[expect(clippy::too_many_arguments)]
+pub fn function_signature_function_with_parameters(
+    _ctx: &Ctx,
+    token_function: TokenFunction,
+    function_name: FunctionName,
+    token_par_open: TokenParOpen,
+    parameters: Parameters,
+    token_par_close: TokenParClose,
+    token_colon: TokenColon,
+    data_type: DataType,
+    compiler_context: &mut CompilerContext,
+) -> FunctionSignature {
+    compiler_context.write_to_parser_output(&format!(
+        "<FunctionSignature> -> {token_function} <FunctionName> {token_par_open} <Parameters> {token_par_close} {token_colon} <DataType>"
+    ));
+
+    compiler_context.set_return_type(data_type.clone().into());
+
+    FunctionSignature::FunctionWithParameters(FunctionWithParameters {
+        token_function,
+        function_name,
+        token_par_open,
+        parameters,
+        token_par_close,
+        token_colon,
+        data_type,
+    })
+}
+
+/// Parses the rule `<FunctionSignature> -> TokenProcedure <FunctionName> TokenParOpen TokenParClose`
+pub fn function_signature_procedure_no_parameters(
+    _ctx: &Ctx,
+    token_procedure: TokenProcedure,
+    function_name: FunctionName,
+    token_par_open: TokenParOpen,
+    token_par_close: TokenParClose,
+    compiler_context: &mut CompilerContext,
+) -> FunctionSignature {
+    compiler_context.write_to_parser_output(&format!(
+        "<FunctionSignature> -> {token_procedure} <FunctionName> {token_par_open} {token_par_close}"
+    ));
+    FunctionSignature::ProcedureNoParameters(ProcedureNoParameters {
+        token_procedure,
+        function_name,
+        token_par_open,
+        token_par_close,
+    })
+}
+
+/// Parses the rule `<FunctionSignature> -> TokenProcedure <FunctionName> TokenParOpen <Parameters> TokenParClose`
+pub fn function_signature_procedure_with_parameters(
+    _ctx: &Ctx,
+    token_procedure: TokenProcedure,
+    function_name: FunctionName,
+    token_par_open: TokenParOpen,
+    parameters: Parameters,
+    token_par_close: TokenParClose,
+    compiler_context: &mut CompilerContext,
+) -> FunctionSignature {
+    compiler_context.write_to_parser_output(&format!(
+        "<FunctionSignature> -> {token_procedure} <FunctionName> {token_par_open} <Parameters> {token_par_close}"
+    ));
+    FunctionSignature::ProcedureWithParameters(ProcedureWithParameters {
+        token_procedure,
+        function_name,
+        token_par_open,
+        parameters,
+        token_par_close,
+    })
+}
+
+/// Parses the rule `<FunctionName> -> TokenId`
+///
+/// Reduced before the parameters so they and the body are declared inside the function
+pub fn function_name_function_name(
+    ctx: &Ctx,
+    token_id: TokenId,
+    compiler_context: &mut CompilerContext,
+) -> FunctionName {
+    compiler_context.write_to_parser_output(&format!("<FunctionName> -> {token_id}"));
+
+    let span = Span::from(ctx);
+    compiler_context.push_reference(
+        span.range.clone(),
+        token_id.clone(),
+        ReferenceKind::Function,
+    );
+    if let Some(function) = compiler_context.get_function(&token_id) {
+        let diagnostic = Diagnostic::new(
+            span.range.clone(),
+            CompilerError::Redeclaration(format!("function {token_id}")),
+            true,
+        )
+        .with_label(
+            function.span.range.clone(),
+            format!("`{token_id}` first declared here"),
+        );
+        compiler_context.push_diagnostic(diagnostic);
+        // Identifiers can't contain `@` so the variables of the duplicate don't clash with the
+        // ones of the first declaration
+        let count = compiler_context.symbol_table().functions().count();
+        compiler_context.enter_function(format!("{token_id}@{count}"), span);
+    } else {
+        compiler_context.enter_function(token_id.clone(), span);
+    }
+
+    token_id
+}
+
+/// Parses the rule `<Parameters> -> <Parameter>`
+pub fn parameters_parameters_single(
+    _ctx: &Ctx,
+    parameter: Parameter,
+    compiler_context: &mut CompilerContext,
+) -> Parameters {
+    compiler_context.write_to_parser_output("<Parameters> -> <Parameter>");
+    Parameters::ParametersSingle(parameter)
+}
+
+/// Parses the rule `<Parameters> -> <Parameter> TokenComma <Parameters>`
+pub fn parameters_parameters_recursive(
+    _ctx: &Ctx,
+    parameter: Parameter,
+    token_comma: TokenComma,
+    parameters: Parameters,
+    compiler_context: &mut CompilerContext,
+) -> Parameters {
+    compiler_context.write_to_parser_output(&format!(
+        "<Parameters> -> <Parameter> {token_comma} <Parameters>"
+    ));
+    Parameters::ParametersRecursive(ParametersRecursive {
+        parameter,
+        token_comma,
+        parameters: Box::new(parameters),
+    })
+}
+
+/// Parses the rule `<Parameter> -> TokenId TokenColon <DataType>`
+pub fn parameter_parameter(
+    ctx: &Ctx,
+    token_id: TokenId,
+    token_colon: TokenColon,
+    data_type: DataType,
+    compiler_context: &mut CompilerContext,
+) -> Parameter {
+    compiler_context.write_to_parser_output(&format!(
+        "<Parameter> -> {token_id} {token_colon} <DataType>"
+    ));
+
+    let span = token_span(&token_id, ctx);
+    let first_declaration = compiler_context.declaration_span(&token_id);
+    if !compiler_context.declare_parameter(&token_id, data_type.clone(), span.clone()) {
+        let mut diagnostic = Diagnostic::new(
+            span.range,
+            CompilerError::Redeclaration(format!("parameter {token_id}")),
+            true,
+        );
+        if let Some(first_declaration) = first_declaration {
+            diagnostic = diagnostic.with_label(
+                first_declaration.range,
+                format!("`{token_id}` first declared here"),
+            );
+        }
+        compiler_context.push_diagnostic(diagnostic);
+    }
+
+    Parameter {
+        token_id,
+        token_colon,
+        data_type,
+    }
+}
+
 /// Parses the rule `<Body> -> TokenInit <InitBody> <Expressions>`
 pub fn body_body_init_expressions(
     _ctx: &Ctx,
@@ -366,7 +747,7 @@ pub fn body_body_init_expressions(
     compiler_context: &mut CompilerContext,
 ) -> Body {
     compiler_context
-        .write_to_parser_file(&format!("<Body> -> {token_init} <InitBody> <Expressions>"));
+        .write_to_parser_output(&format!("<Body> -> {token_init} <InitBody> <Expressions>"));
 
     let ast = &mut compiler_context.ast;
     ast.assign_node_to_ptr(AstPtr::Expressions.into(), AstPtr::Body);
@@ -380,12 +761,21 @@ pub fn body_body_init_expressions(
 
 /// Parses the rule `<Body> -> TokenInit <InitBody>`
 pub fn body_body_init(
-    _ctx: &Ctx,
+    ctx: &Ctx,
     token_init: TokenInit,
     init_body: InitBody,
     compiler_context: &mut CompilerContext,
 ) -> Body {
-    compiler_context.write_to_parser_file(&format!("<Body> -> {token_init} <InitBody>"));
+    compiler_context.write_to_parser_output(&format!("<Body> -> {token_init} <InitBody>"));
+
+    let ast = &mut compiler_context.ast;
+    let leaf = Rc::new(Node::new_leaf(
+        NodeValue::Action(AstAction::Noop),
+        None,
+        Span::from(ctx),
+    ));
+    ast.assign_node_to_ptr(leaf.into(), AstPtr::Body);
+
     Some(BodyNoO::BodyInit(BodyInit {
         token_init,
         init_body,
@@ -398,7 +788,7 @@ pub fn body_body_expressions(
     expressions: Expressions,
     compiler_context: &mut CompilerContext,
 ) -> Body {
-    compiler_context.write_to_parser_file("<Body> -> <Expressions>");
+    compiler_context.write_to_parser_output("<Body> -> <Expressions>");
 
     let ast = &mut compiler_context.ast;
     ast.assign_node_to_ptr(AstPtr::Expressions.into(), AstPtr::Body);
@@ -407,11 +797,15 @@ pub fn body_body_expressions(
 }
 
 /// Parses the rule `<Body> -> EMPTY`
-pub fn body_body_empty(_ctx: &Ctx, compiler_context: &mut CompilerContext) -> Body {
-    compiler_context.write_to_parser_file("<Body> -> EMPTY");
+pub fn body_body_empty(ctx: &Ctx, compiler_context: &mut CompilerContext) -> Body {
+    compiler_context.write_to_parser_output("<Body> -> EMPTY");
 
     let ast = &mut compiler_context.ast;
-    let leaf = Rc::new(Node::new_leaf(NodeValue::Action(AstAction::Noop), None));
+    let leaf = Rc::new(Node::new_leaf(
+        NodeValue::Action(AstAction::Noop),
+        None,
+        Span::from(ctx),
+    ));
     ast.assign_node_to_ptr(leaf.into(), AstPtr::Body);
 
     None
@@ -425,9 +819,10 @@ pub fn init_body_init_body(
     token_cbclose: TokenCBClose,
     compiler_context: &mut CompilerContext,
 ) -> InitBody {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<InitBody> -> {token_cbopen} <VarDeclarations> {token_cbclose}"
     ));
+    var_declarations.push_to_symbol_table(compiler_context);
     InitBody {
         token_cbopen,
         var_declarations,
@@ -444,20 +839,28 @@ pub fn function_read_function_read_call(
     token_par_close: TokenParClose,
     compiler_context: &mut CompilerContext,
 ) -> FunctionRead {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<FunctionRead> -> {token_read} {token_par_open} {token_id} {token_par_close}"
     ));
 
-    let Some(rhs_type) = compiler_context.get_symbol_type(&token_id) else {
-        log_undeclared_variable_error(&token_id, ctx, compiler_context)
-    };
+    let key = resolve_variable(&token_id, ctx, compiler_context);
+    let rhs_type = compiler_context.get_symbol_type(&key);
+    check_scalar(&token_id, &rhs_type, ctx, compiler_context);
+    let rhs_type = rhs_type.map(ExpressionType::from);
+    check_readable(&token_id, &rhs_type, ctx, compiler_context);
+    compiler_context.assign_variable(&key);
 
     let ast = &mut compiler_context.ast;
 
-    let right_child = Rc::new(Node::new_leaf(NodeValue::Action(AstAction::Noop), None));
+    let right_child = Rc::new(Node::new_leaf(
+        NodeValue::Action(AstAction::Noop),
+        None,
+        Span::from(ctx),
+    ));
     let left_child = Rc::new(Node::new_leaf(
-        NodeValue::Value(token_id.clone()),
-        Some(rhs_type.into()),
+        NodeValue::Value(key),
+        rhs_type,
+        Span::from(ctx),
     ));
 
     ast.create_node(
@@ -466,57 +869,109 @@ pub fn function_read_function_read_call(
         right_child.into(),
         AstPtr::Read,
         None,
+        Span::from(ctx),
     );
 
-    FunctionRead {
+    FunctionRead::FunctionReadCall(FunctionReadCall {
         token_read,
         token_par_open,
         token_id,
         token_par_close,
-    }
+    })
+}
+
+/// Parses the rule `<FunctionRead> -> TokenRead TokenParOpen TokenId <Index> TokenParClose`
+pub fn function_read_function_read_indexed_call(
+    ctx: &Ctx,
+    token_read: TokenRead,
+    token_par_open: TokenParOpen,
+    token_id: TokenId,
+    index: Index,
+    token_par_close: TokenParClose,
+    compiler_context: &mut CompilerContext,
+) -> FunctionRead {
+    compiler_context.write_to_parser_output(&format!(
+        "<FunctionRead> -> {token_read} {token_par_open} {token_id} <Index> {token_par_close}"
+    ));
+
+    let left_child = create_index(&token_id, AstPtr::Read, ctx, compiler_context);
+    check_readable(&token_id, &left_child.r#type, ctx, compiler_context);
+    let key = resolve_variable(&token_id, ctx, compiler_context);
+    compiler_context.assign_variable(&key);
+    let right_child = Rc::new(Node::new_leaf(
+        NodeValue::Action(AstAction::Noop),
+        None,
+        Span::from(ctx),
+    ));
+    compiler_context.ast.create_node(
+        AstAction::Read,
+        left_child.into(),
+        right_child.into(),
+        AstPtr::Read,
+        None,
+        Span::from(ctx),
+    );
+
+    FunctionRead::FunctionReadIndexedCall(FunctionReadIndexedCall {
+        token_read,
+        token_par_open,
+        token_id,
+        index,
+        token_par_close,
+    })
 }
 
-/// Parses the rule `<FunctionWrite> -> TokenWrite TokenParOpen <SimpleExpression> TokenParClose`
+/// Parses the rule `<FunctionWrite> -> TokenWrite TokenParOpen <Conjunction> TokenParClose`
 pub fn function_write_function_write_call(
-    _ctx: &Ctx,
+    ctx: &Ctx,
     token_write: TokenWrite,
     token_par_open: TokenParOpen,
-    simple_expression: SimpleExpression,
+    conjunction: Conjunction,
     token_par_close: TokenParClose,
     compiler_context: &mut CompilerContext,
 ) -> FunctionWrite {
-    compiler_context.write_to_parser_file(&format!(
-        "<FunctionWrite> -> {token_write} {token_par_open} <SimpleExpression> {token_par_close}"
+    compiler_context.write_to_parser_output(&format!(
+        "<FunctionWrite> -> {token_write} {token_par_open} <Conjunction> {token_par_close}"
     ));
 
+    let value = pop_conjunction_value(
+        "Conjunction stack was empty when parsing `<FunctionWrite> -> TokenWrite TokenParOpen <Conjunction> TokenParClose`",
+        ctx,
+        compiler_context,
+    );
     let ast = &mut compiler_context.ast;
-    let leaf = Rc::new(Node::new_leaf(NodeValue::Action(AstAction::Noop), None));
+    let leaf = Rc::new(Node::new_leaf(
+        NodeValue::Action(AstAction::Noop),
+        None,
+        Span::from(ctx),
+    ));
     ast.create_node(
         AstAction::Write,
-        AstPtr::SimpleExpression.into(),
+        value.into(),
         leaf.into(),
         AstPtr::Write,
         None,
+        Span::from(ctx),
     );
 
     FunctionWrite {
         token_write,
         token_par_open,
-        simple_expression,
+        conjunction,
         token_par_close,
     }
 }
 
 /// Parses the rule `<FunctionIsZero>: TokenIsZero TokenParOpen <ArithmeticExpression> TokenParClose`
 pub fn function_is_zero_function_is_zero_call(
-    _ctx: &Ctx,
+    ctx: &Ctx,
     token_is_zero: TokenIsZero,
     token_par_open: TokenParOpen,
     arithmetic_expression: ArithmeticExpression,
     token_par_close: TokenParClose,
     compiler_context: &mut CompilerContext,
 ) -> FunctionIsZero {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<FunctionIsZero> -> {token_is_zero} {token_par_open} <E> {token_par_close}"
     ));
 
@@ -530,13 +985,21 @@ pub fn function_is_zero_function_is_zero_call(
     let zero_leaf = Rc::new(Node::new_leaf(
         NodeValue::Value("0".into()),
         Some(ExpressionType::Int),
+        Span::from(ctx),
     ));
+    let expression = compiler_context
+        .ast
+        .get_node_from_ptr(AstPtr::ArithmeticExpression);
+    if let Some(r#type @ (ExpressionType::String | ExpressionType::Bool)) = &expression.r#type {
+        log_type_error(&format!("isZero({type})"), ctx, compiler_context)
+    }
     compiler_context.ast.create_node(
         AstAction::EQ,
-        AstPtr::ArithmeticExpression.into(),
+        expression.into(),
         zero_leaf.into(),
         AstPtr::IsZero,
-        None,
+        Some(ExpressionType::Bool),
+        Span::from(ctx),
     );
 
     FunctionIsZero {
@@ -549,14 +1012,14 @@ pub fn function_is_zero_function_is_zero_call(
 
 /// Parses the rule `<FunctionConvDate>: TokenConvDate TokenParOpen TokenDate TokenParClose`
 pub fn function_conv_date_function_conv_date_variable_call(
-    _ctx: &Ctx,
+    ctx: &Ctx,
     token_conv_date: TokenConvDate,
     token_par_open: TokenParOpen,
     token_date: TokenDate,
     token_par_close: TokenParClose,
     compiler_context: &mut CompilerContext,
 ) -> FunctionConvDate {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<FunctionConvDate> -> {token_conv_date} {token_par_open} {token_date} {token_par_close}"
     ));
 
@@ -591,27 +1054,33 @@ pub fn function_conv_date_function_conv_date_variable_call(
     let ten_thousand_leaf = Rc::new(Node::new_leaf(
         NodeValue::Value(ten_thousand_symbol.original.clone()),
         Some(ExpressionType::Int),
+        Span::from(ctx),
     ));
     let hundread_leaf = Rc::new(Node::new_leaf(
         NodeValue::Value(hundread_symbol.original.clone()),
         Some(ExpressionType::Int),
+        Span::from(ctx),
     ));
     let one_leaf = Rc::new(Node::new_leaf(
         NodeValue::Value(one_symbol.original.clone()),
         Some(ExpressionType::Int),
+        Span::from(ctx),
     ));
 
     let year_leaf = Rc::new(Node::new_leaf(
         NodeValue::Value(year_symbol.original.clone()),
         Some(ExpressionType::Int),
+        Span::from(ctx),
     ));
     let month_leaf = Rc::new(Node::new_leaf(
         NodeValue::Value(month_symbol.original.clone()),
         Some(ExpressionType::Int),
+        Span::from(ctx),
     ));
     let day_leaf = Rc::new(Node::new_leaf(
         NodeValue::Value(day_symbol.original.clone()),
         Some(ExpressionType::Int),
+        Span::from(ctx),
     ));
 
     let year_node = ast.create_node(
@@ -620,6 +1089,7 @@ pub fn function_conv_date_function_conv_date_variable_call(
         ten_thousand_leaf.into(),
         AstPtr::ConvDate,
         Some(ExpressionType::Int),
+        Span::from(ctx),
     );
     let month_node = ast.create_node(
         AstAction::Mult,
@@ -627,6 +1097,7 @@ pub fn function_conv_date_function_conv_date_variable_call(
         hundread_leaf.into(),
         AstPtr::ConvDate,
         Some(ExpressionType::Int),
+        Span::from(ctx),
     );
     let day_node = ast.create_node(
         AstAction::Mult,
@@ -634,6 +1105,7 @@ pub fn function_conv_date_function_conv_date_variable_call(
         one_leaf.into(),
         AstPtr::ConvDate,
         Some(ExpressionType::Int),
+        Span::from(ctx),
     );
 
     let sum_year_month_node = ast.create_node(
@@ -642,6 +1114,7 @@ pub fn function_conv_date_function_conv_date_variable_call(
         month_node.into(),
         AstPtr::ConvDate,
         Some(ExpressionType::Int),
+        Span::from(ctx),
     );
     ast.create_node(
         AstAction::Plus,
@@ -649,6 +1122,7 @@ pub fn function_conv_date_function_conv_date_variable_call(
         day_node.into(),
         AstPtr::ConvDate,
         Some(ExpressionType::Int),
+        Span::from(ctx),
     );
 
     compiler_context.push_to_symbol_table(ten_thousand_symbol.into());
@@ -666,14 +1140,121 @@ pub fn function_conv_date_function_conv_date_variable_call(
     }
 }
 
-/// Parses the rule `<VarDeclarations> -> <VarDeclaration>`
-pub fn var_declarations_var_declarations_single(
-    _ctx: &Ctx,
-    var_declaration: VarDeclaration,
-    compiler_context: &mut CompilerContext,
+/// Parses the rule `<FunctionCall> -> TokenId TokenParOpen TokenParClose`
+pub fn function_call_function_call_no_arguments(
+    ctx: &Ctx,
+    token_id: TokenId,
+    token_par_open: TokenParOpen,
+    token_par_close: TokenParClose,
+    compiler_context: &mut CompilerContext,
+) -> FunctionCall {
+    compiler_context.write_to_parser_output(&format!(
+        "<FunctionCall> -> {token_id} {token_par_open} {token_par_close}"
+    ));
+
+    create_call(&token_id, Vec::new(), ctx, compiler_context);
+
+    FunctionCall::FunctionCallNoArguments(FunctionCallNoArguments {
+        token_id,
+        token_par_open,
+        token_par_close,
+    })
+}
+
+/// Parses the rule `<FunctionCall> -> TokenId TokenParOpen <Arguments> TokenParClose`
+pub fn function_call_function_call_with_arguments(
+    ctx: &Ctx,
+    token_id: TokenId,
+    token_par_open: TokenParOpen,
+    arguments: Arguments,
+    token_par_close: TokenParClose,
+    compiler_context: &mut CompilerContext,
+) -> FunctionCall {
+    compiler_context.write_to_parser_output(&format!(
+        "<FunctionCall> -> {token_id} {token_par_open} <Arguments> {token_par_close}"
+    ));
+
+    let argument_nodes = compiler_context.ast.call_arguments_stack.pop();
+    let argument_nodes = argument_nodes.unwrap_or_else(|| {
+        vec![log_ast_error(
+            "CallArguments stack was empty when parsing `<FunctionCall> -> TokenId TokenParOpen <Arguments> TokenParClose`",
+            ctx,
+            compiler_context,
+        )]
+    });
+    create_call(&token_id, argument_nodes, ctx, compiler_context);
+
+    FunctionCall::FunctionCallWithArguments(FunctionCallWithArguments {
+        token_id,
+        token_par_open,
+        arguments: Box::new(arguments),
+        token_par_close,
+    })
+}
+
+/// Parses the rule `<Arguments> -> <Conjunction>`
+pub fn arguments_arguments_single(
+    ctx: &Ctx,
+    conjunction: Conjunction,
+    compiler_context: &mut CompilerContext,
+) -> Arguments {
+    compiler_context.write_to_parser_output("<Arguments> -> <Conjunction>");
+
+    let argument = pop_conjunction_value(
+        "Conjunction stack was empty when parsing `<Arguments> -> <Conjunction>`",
+        ctx,
+        compiler_context,
+    );
+    compiler_context
+        .ast
+        .call_arguments_stack
+        .push(vec![argument]);
+
+    Arguments::ArgumentsSingle(conjunction)
+}
+
+/// Parses the rule `<Arguments> -> <Arguments> TokenComma <Conjunction>`
+pub fn arguments_arguments_recursive(
+    ctx: &Ctx,
+    arguments: Arguments,
+    token_comma: TokenComma,
+    conjunction: Conjunction,
+    compiler_context: &mut CompilerContext,
+) -> Arguments {
+    compiler_context.write_to_parser_output(&format!(
+        "<Arguments> -> <Arguments> {token_comma} <Conjunction>"
+    ));
+
+    let argument = pop_conjunction_value(
+        "Conjunction stack was empty when parsing `<Arguments> -> <Arguments> TokenComma <Conjunction>`",
+        ctx,
+        compiler_context,
+    );
+    match compiler_context.ast.call_arguments_stack.last_mut() {
+        Some(argument_nodes) => argument_nodes.push(argument),
+        None => {
+            log_ast_error(
+                "CallArguments stack was empty when parsing `<Arguments> -> <Arguments> TokenComma <Conjunction>`",
+                ctx,
+                compiler_context,
+            );
+        }
+    }
+
+    Arguments::ArgumentsRecursive(ArgumentsRecursive {
+        arguments: Box::new(arguments),
+        token_comma,
+        conjunction,
+    })
+}
+
+/// Parses the rule `<VarDeclarations> -> <VarDeclaration>`
+pub fn var_declarations_var_declarations_single(
+    _ctx: &Ctx,
+    var_declaration: VarDeclaration,
+    compiler_context: &mut CompilerContext,
 ) -> VarDeclarations {
-    var_declaration.push_to_symbol_table(compiler_context);
-    compiler_context.write_to_parser_file("<VarDeclarations> -> <VarDeclaration>");
+    compiler_context.write_to_parser_output("<VarDeclarations> -> <VarDeclaration>");
     VarDeclarations::VarDeclarationsSingle(var_declaration)
 }
 
@@ -684,9 +1265,8 @@ pub fn var_declarations_var_declarations_recursive(
     var_declarations: VarDeclarations,
     compiler_context: &mut CompilerContext,
 ) -> VarDeclarations {
-    var_declaration.push_to_symbol_table(compiler_context);
     compiler_context
-        .write_to_parser_file("<VarDeclarations> -> <VarDeclaration> <VarDeclarations>");
+        .write_to_parser_output("<VarDeclarations> -> <VarDeclaration> <VarDeclarations>");
     VarDeclarations::VarDeclarationsRecursive(VarDeclarationsRecursive {
         var_declaration,
         var_declarations: Box::new(var_declarations),
@@ -695,34 +1275,75 @@ pub fn var_declarations_var_declarations_recursive(
 
 /// Parses the rule `<VarDeclaration> -> TokenId TokenColon <DataType>`
 pub fn var_declaration_var_declaration_single(
-    _ctx: &Ctx,
+    ctx: &Ctx,
     token_id: TokenId,
     token_colon: TokenColon,
     data_type: DataType,
     compiler_context: &mut CompilerContext,
 ) -> VarDeclaration {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<VarDeclaration> -> {token_id} {token_colon} <DataType>"
     ));
     VarDeclaration::VarDeclarationSingle(VarDeclarationSingle {
+        span: token_span(&token_id, ctx),
         token_id,
         token_colon,
         data_type,
     })
 }
 
+/// Parses the rule `<VarDeclaration> -> TokenId TokenColon <DataType> TokenSBOpen TokenIntLiteral TokenSBClose`
+#[expect(clippy::too_many_arguments)]
+pub fn var_declaration_var_declaration_array(
+    ctx: &Ctx,
+    token_id: TokenId,
+    token_colon: TokenColon,
+    data_type: DataType,
+    token_sbopen: TokenSBOpen,
+    token_int_literal: TokenIntLiteral,
+    token_sbclose: TokenSBClose,
+    compiler_context: &mut CompilerContext,
+) -> VarDeclaration {
+    compiler_context.write_to_parser_output(&format!(
+        "<VarDeclaration> -> {token_id} {token_colon} <DataType> {token_sbopen} {} {token_sbclose}",
+        token_int_literal.original
+    ));
+
+    let array = VarDeclarationArray {
+        span: token_span(&token_id, ctx),
+        token_id,
+        token_colon,
+        data_type,
+        token_sbopen,
+        token_int_literal,
+        token_sbclose,
+    };
+    if array.length().is_none() {
+        log_error(
+            ctx.range(),
+            CompilerError::ArrayUse(format!("{} must have at least one element", array.token_id)),
+            0,
+            true,
+            compiler_context,
+        )
+    }
+
+    VarDeclaration::VarDeclarationArray(array)
+}
+
 /// Parses the rule `<VarDeclaration> -> TokenId TokenComma <VarDeclaration>`
 pub fn var_declaration_var_declaration_recursive(
-    _ctx: &Ctx,
+    ctx: &Ctx,
     token_id: TokenId,
     token_comma: TokenComma,
     var_declaration: VarDeclaration,
     compiler_context: &mut CompilerContext,
 ) -> VarDeclaration {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<VarDeclaration> -> {token_id} {token_comma} <VarDeclaration>"
     ));
     VarDeclaration::VarDeclarationRecursive(VarDeclarationRecursive {
+        span: token_span(&token_id, ctx),
         token_id,
         token_comma,
         var_declaration: Box::new(var_declaration),
@@ -735,16 +1356,17 @@ pub fn expressions_expression_single(
     statement: Statement,
     compiler_context: &mut CompilerContext,
 ) -> Expressions {
-    compiler_context.write_to_parser_file("<Expressions> -> <Statement>");
+    compiler_context.write_to_parser_output("<Expressions> -> <Statement>");
 
-    let ast = &mut compiler_context.ast;
-    let Some(statement_node) = ast.statement_stack.pop() else {
+    let statement_node = compiler_context.ast.statement_stack.pop();
+    let statement_node = statement_node.unwrap_or_else(|| {
         log_ast_error(
             "Statement stack was empty when parsing `<Expressions> -> <Statement>`",
             ctx,
             compiler_context,
         )
-    };
+    });
+    let ast = &mut compiler_context.ast;
     ast.assign_node_to_ptr(statement_node.into(), AstPtr::Expressions);
 
     Expressions::ExpressionSingle(statement)
@@ -757,22 +1379,24 @@ pub fn expressions_expression_recursive(
     expressions: Expressions,
     compiler_context: &mut CompilerContext,
 ) -> Expressions {
-    compiler_context.write_to_parser_file("<Expressions> -> <Statement> <Expressions>");
+    compiler_context.write_to_parser_output("<Expressions> -> <Statement> <Expressions>");
 
-    let ast = &mut compiler_context.ast;
-    let Some(statement_node) = ast.statement_stack.pop() else {
+    let statement_node = compiler_context.ast.statement_stack.pop();
+    let statement_node = statement_node.unwrap_or_else(|| {
         log_ast_error(
             "Statement stack was empty when parsing `<Expressions> -> <Statement> <Expressions>`",
             ctx,
             compiler_context,
         )
-    };
+    });
+    let ast = &mut compiler_context.ast;
     ast.create_node(
         AstAction::S,
         statement_node.into(),
         AstPtr::Expressions.into(),
         AstPtr::Expressions,
         None,
+        Span::from(ctx),
     );
     Expressions::ExpressionRecursive(ExpressionRecursive {
         statement,
@@ -786,7 +1410,7 @@ pub fn statement_statement_assignment(
     assignment: Assignment,
     compiler_context: &mut CompilerContext,
 ) -> Statement {
-    compiler_context.write_to_parser_file("<Statement> -> <Assignment>");
+    compiler_context.write_to_parser_output("<Statement> -> <Assignment>");
     let assignment_node = compiler_context.ast.get_node_from_ptr(AstPtr::Assignment);
 
     let ast = &mut compiler_context.ast;
@@ -802,7 +1426,7 @@ pub fn statement_statement_if_statement(
     if_statement: IfStatement,
     compiler_context: &mut CompilerContext,
 ) -> Statement {
-    compiler_context.write_to_parser_file("<Statement> -> <IfStatement>");
+    compiler_context.write_to_parser_output("<Statement> -> <IfStatement>");
 
     let ast = &mut compiler_context.ast;
     let if_node = ast.get_node_from_ptr(AstPtr::If);
@@ -818,7 +1442,7 @@ pub fn statement_statement_while(
     while_loop: WhileLoop,
     compiler_context: &mut CompilerContext,
 ) -> Statement {
-    compiler_context.write_to_parser_file("<Statement> -> <WhileLoop>");
+    compiler_context.write_to_parser_output("<Statement> -> <WhileLoop>");
 
     let ast = &mut compiler_context.ast;
     let while_node = ast.get_node_from_ptr(AstPtr::While);
@@ -834,7 +1458,7 @@ pub fn statement_statement_write(
     function_write: FunctionWrite,
     compiler_context: &mut CompilerContext,
 ) -> Statement {
-    compiler_context.write_to_parser_file("<Statement> -> <FunctionWrite>");
+    compiler_context.write_to_parser_output("<Statement> -> <FunctionWrite>");
 
     let ast = &mut compiler_context.ast;
     let write_node = ast.get_node_from_ptr(AstPtr::Write);
@@ -850,7 +1474,7 @@ pub fn statement_statement_read(
     function_read: FunctionRead,
     compiler_context: &mut CompilerContext,
 ) -> Statement {
-    compiler_context.write_to_parser_file("<Statement> -> <FunctionRead>");
+    compiler_context.write_to_parser_output("<Statement> -> <FunctionRead>");
 
     let ast = &mut compiler_context.ast;
     let read_node = ast.get_node_from_ptr(AstPtr::Read);
@@ -860,52 +1484,225 @@ pub fn statement_statement_read(
     Statement::StatementRead(function_read)
 }
 
-/// Parses the rule `<Assignment> -> TokenId TokenAssign <SimpleExpression>`
+/// Parses the rule `<Statement> -> <FunctionCall>`
+pub fn statement_statement_call(
+    _ctx: &Ctx,
+    function_call: FunctionCall,
+    compiler_context: &mut CompilerContext,
+) -> Statement {
+    compiler_context.write_to_parser_output("<Statement> -> <FunctionCall>");
+
+    let ast = &mut compiler_context.ast;
+    let call_node = ast.get_node_from_ptr(AstPtr::FunctionCall);
+    ast.assign_node_to_ptr(call_node.clone().into(), AstPtr::Statement);
+    ast.statement_stack.push(call_node);
+
+    Statement::StatementCall(function_call)
+}
+
+/// Parses the rule `<Statement> -> TokenReturn <Conjunction>`
+pub fn statement_statement_return(
+    ctx: &Ctx,
+    token_return: TokenReturn,
+    conjunction: Conjunction,
+    compiler_context: &mut CompilerContext,
+) -> Statement {
+    compiler_context
+        .write_to_parser_output(&format!("<Statement> -> {token_return} <Conjunction>"));
+
+    let mut expression = pop_conjunction_value(
+        "Conjunction stack was empty when parsing `<Statement> -> TokenReturn <Conjunction>`",
+        ctx,
+        compiler_context,
+    );
+    let function = compiler_context
+        .current_function()
+        .map(|function| (function.name.clone(), function.return_type.clone()));
+    match function {
+        None => log_error(
+            ctx.range(),
+            CompilerError::InvalidReturn("return outside of a function".into()),
+            0,
+            true,
+            compiler_context,
+        ),
+        Some((name, None)) => log_error(
+            ctx.range(),
+            CompilerError::InvalidReturn(format!("procedure {name} can't return a value")),
+            0,
+            true,
+            compiler_context,
+        ),
+        Some((name, Some(return_type))) => {
+            // Ints are converted implicitly when returned from a float function
+            if return_type == ExpressionType::Float
+                && expression.r#type == Some(ExpressionType::Int)
+            {
+                expression = Node::int_to_float(expression);
+            }
+            if let Some(expression_type) = &expression.r#type
+                && *expression_type != return_type
+            {
+                log_type_error(
+                    &format!("{name} returns {return_type} := {expression_type}"),
+                    ctx,
+                    compiler_context,
+                )
+            }
+        }
+    }
+
+    let ast = &mut compiler_context.ast;
+    let leaf = Rc::new(Node::new_leaf(
+        NodeValue::Action(AstAction::Noop),
+        None,
+        Span::from(ctx),
+    ));
+    let return_node = ast.create_node(
+        AstAction::Return,
+        expression.into(),
+        leaf.into(),
+        AstPtr::Statement,
+        None,
+        Span::from(ctx),
+    );
+    ast.statement_stack.push(return_node);
+
+    Statement::StatementReturn(StatementReturn {
+        token_return,
+        conjunction,
+    })
+}
+
+/// Parses the rule `<Assignment> -> TokenId TokenAssign <Conjunction>`
 pub fn assignment_assignment_expression(
     ctx: &Ctx,
     token_id: TokenId,
     token_assign: TokenAssign,
-    simple_expression: SimpleExpression,
+    conjunction: Conjunction,
     compiler_context: &mut CompilerContext,
 ) -> Assignment {
-    compiler_context.write_to_parser_file(&format!(
-        "<Assignment> -> {token_id} {token_assign} <SimpleExpression>"
+    compiler_context.write_to_parser_output(&format!(
+        "<Assignment> -> {token_id} {token_assign} <Conjunction>"
     ));
 
-    let Some(lhs_type) = compiler_context.get_symbol_type(&token_id) else {
-        log_undeclared_variable_error(&token_id, ctx, compiler_context)
+    let key = resolve_variable(&token_id, ctx, compiler_context);
+    let lhs_type = compiler_context.get_symbol_type(&key);
+    check_scalar(&token_id, &lhs_type, ctx, compiler_context);
+    let lhs_type = lhs_type.map(ExpressionType::from);
+    compiler_context.assign_variable(&key);
+
+    let rhs = pop_conjunction_value(
+        "Conjunction stack was empty when parsing `<Assignment> -> TokenId TokenAssign <Conjunction>`",
+        ctx,
+        compiler_context,
+    );
+    // Ints are converted implicitly when assigned to a float
+    let rhs = match (&lhs_type, &rhs.r#type) {
+        (Some(ExpressionType::Float), Some(ExpressionType::Int)) => Node::int_to_float(rhs),
+        _ => rhs,
     };
-    let lhs_type = lhs_type.into();
+
+    // Either side without a type already reported its error
+    if let (Some(lhs_type), Some(rhs_type)) = (&lhs_type, &rhs.r#type)
+        && lhs_type != rhs_type
+    {
+        log_type_error(&format!("{lhs_type} := {rhs_type}"), ctx, compiler_context)
+    }
+    if let Some(length) = constant_string_length(&rhs, compiler_context)
+        && length > MAX_STRING_LENGTH
+    {
+        log_error(
+            ctx.range(),
+            CompilerError::StringTooLong(format!(
+                "{token_id} can hold {MAX_STRING_LENGTH} characters but is assigned {length}"
+            )),
+            0,
+            true,
+            compiler_context,
+        )
+    }
 
     let ast = &mut compiler_context.ast;
 
-    let rhs = ast.get_node_from_ptr(AstPtr::SimpleExpression);
+    let leaf = Node::new_leaf(NodeValue::Value(key), lhs_type, Span::from(ctx));
+    ast.create_node(
+        AstAction::Assign,
+        Rc::new(leaf).into(),
+        rhs.into(),
+        AstPtr::Assignment,
+        None,
+        Span::from(ctx),
+    );
+
+    Assignment::AssignmentExpression(AssignmentExpression {
+        token_id,
+        token_assign,
+        conjunction,
+    })
+}
 
-    let Some(rhs_type) = rhs.r#type.clone() else {
-        log_ast_error(
-            "The rhs of an assignment is not an expression",
-            ctx,
-            compiler_context,
-        )
+/// Parses the rule `<Assignment> -> TokenId <Index> TokenAssign <Conjunction>`
+pub fn assignment_assignment_indexed(
+    ctx: &Ctx,
+    token_id: TokenId,
+    index: Index,
+    token_assign: TokenAssign,
+    conjunction: Conjunction,
+    compiler_context: &mut CompilerContext,
+) -> Assignment {
+    compiler_context.write_to_parser_output(&format!(
+        "<Assignment> -> {token_id} <Index> {token_assign} <Conjunction>"
+    ));
+
+    let lhs = create_index(&token_id, AstPtr::Assignment, ctx, compiler_context);
+    let key = resolve_variable(&token_id, ctx, compiler_context);
+    compiler_context.assign_variable(&key);
+    let rhs = pop_conjunction_value(
+        "Conjunction stack was empty when parsing `<Assignment> -> TokenId <Index> TokenAssign <Conjunction>`",
+        ctx,
+        compiler_context,
+    );
+    // Ints are converted implicitly when assigned to a float
+    let rhs = match (&lhs.r#type, &rhs.r#type) {
+        (Some(ExpressionType::Float), Some(ExpressionType::Int)) => Node::int_to_float(rhs),
+        _ => rhs,
     };
 
-    if lhs_type != rhs_type {
+    // Either side without a type already reported its error
+    if let (Some(lhs_type), Some(rhs_type)) = (&lhs.r#type, &rhs.r#type)
+        && lhs_type != rhs_type
+    {
         log_type_error(&format!("{lhs_type} := {rhs_type}"), ctx, compiler_context)
     }
+    if let Some(length) = constant_string_length(&rhs, compiler_context)
+        && length > MAX_STRING_LENGTH
+    {
+        log_error(
+            ctx.range(),
+            CompilerError::StringTooLong(format!(
+                "elements of {token_id} can hold {MAX_STRING_LENGTH} characters but are assigned {length}"
+            )),
+            0,
+            true,
+            compiler_context,
+        )
+    }
 
-    let leaf = Node::new_leaf(NodeValue::Value(token_id.clone()), Some(lhs_type));
-    ast.create_node(
+    compiler_context.ast.create_node(
         AstAction::Assign,
-        Rc::new(leaf).into(),
-        AstPtr::SimpleExpression.into(),
+        lhs.into(),
+        rhs.into(),
         AstPtr::Assignment,
         None,
+        Span::from(ctx),
     );
 
-    Assignment::AssignmentExpression(AssignmentExpression {
+    Assignment::AssignmentIndexed(AssignmentIndexed {
         token_id,
+        index,
         token_assign,
-        simple_expression,
+        conjunction,
     })
 }
 
@@ -917,40 +1714,44 @@ pub fn assignment_assignment_conv_date(
     function_conv_date: FunctionConvDate,
     compiler_context: &mut CompilerContext,
 ) -> Assignment {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<Assignment> -> {token_id} {token_assign} <FunctionConvDate>"
     ));
 
-    let Some(lhs_type) = compiler_context.get_symbol_type(&token_id) else {
-        log_undeclared_variable_error(&token_id, ctx, compiler_context)
-    };
-    let lhs_type = lhs_type.into();
-
-    let ast = &mut compiler_context.ast;
-    let rhs = ast.get_node_from_ptr(AstPtr::ConvDate);
-
-    let Some(rhs_type) = rhs.r#type.clone() else {
-        log_ast_error(
-            "The rhs of an assignment is not an expression",
-            ctx,
-            compiler_context,
-        )
+    let key = resolve_variable(&token_id, ctx, compiler_context);
+    let lhs_type = compiler_context.get_symbol_type(&key);
+    check_scalar(&token_id, &lhs_type, ctx, compiler_context);
+    let lhs_type = lhs_type.map(ExpressionType::from);
+    compiler_context.assign_variable(&key);
+
+    let rhs = compiler_context.ast.get_node_from_ptr(AstPtr::ConvDate);
+    // Ints are converted implicitly when assigned to a float
+    let rhs = match (&lhs_type, &rhs.r#type) {
+        (Some(ExpressionType::Float), Some(ExpressionType::Int)) => Node::int_to_float(rhs),
+        _ => rhs,
     };
 
-    if lhs_type != rhs_type {
+    // Either side without a type already reported its error
+    if let (Some(lhs_type), Some(rhs_type)) = (&lhs_type, &rhs.r#type)
+        && lhs_type != rhs_type
+    {
         log_type_error(&format!("{lhs_type} := {rhs_type}"), ctx, compiler_context)
     }
+
+    let ast = &mut compiler_context.ast;
     let leaf = Rc::new(Node::new_leaf(
-        NodeValue::Value(token_id.clone()),
-        Some(lhs_type),
+        NodeValue::Value(key),
+        lhs_type,
+        Span::from(ctx),
     ));
 
     ast.create_node(
         AstAction::Assign,
         leaf.into(),
-        AstPtr::ConvDate.into(),
+        rhs.into(),
         AstPtr::Assignment,
         None,
+        Span::from(ctx),
     );
 
     Assignment::AssignmentConvDate(ConvDate {
@@ -966,7 +1767,7 @@ pub fn data_type_int_type(
     token_int: TokenInt,
     compiler_context: &mut CompilerContext,
 ) -> DataType {
-    compiler_context.write_to_parser_file(&format!("<DataType> -> {token_int}"));
+    compiler_context.write_to_parser_output(&format!("<DataType> -> {token_int}"));
     DataType::IntType(token_int)
 }
 
@@ -976,7 +1777,7 @@ pub fn data_type_float_type(
     token_float: TokenFloat,
     compiler_context: &mut CompilerContext,
 ) -> DataType {
-    compiler_context.write_to_parser_file(&format!("<DataType> -> {token_float}"));
+    compiler_context.write_to_parser_output(&format!("<DataType> -> {token_float}"));
     DataType::FloatType(token_float)
 }
 
@@ -986,11 +1787,21 @@ pub fn data_type_string_type(
     token_string: TokenString,
     compiler_context: &mut CompilerContext,
 ) -> DataType {
-    compiler_context.write_to_parser_file(&format!("<DataType> -> {token_string}"));
+    compiler_context.write_to_parser_output(&format!("<DataType> -> {token_string}"));
     DataType::StringType(token_string)
 }
 
-/// Parses the rule `<WhileLoop> -> TokenWhile TokenParOpen <Conjunction> TokenParClose TokenCBOpen <Body> TokenCBClose`
+/// Parses the rule `<DataType> -> "bool"`
+pub fn data_type_bool_type(
+    _ctx: &Ctx,
+    token_bool: TokenBool,
+    compiler_context: &mut CompilerContext,
+) -> DataType {
+    compiler_context.write_to_parser_output(&format!("<DataType> -> {token_bool}"));
+    DataType::BoolType(token_bool)
+}
+
+/// Parses the rule `<WhileLoop> -> TokenWhile TokenParOpen <Conjunction> TokenParClose <BlockOpen> <Body> TokenCBClose`
 #[expect(clippy::too_many_arguments)]
 pub fn while_loop_while(
     ctx: &Ctx,
@@ -998,29 +1809,48 @@ pub fn while_loop_while(
     token_par_open: TokenParOpen,
     conjunction: Conjunction,
     token_par_close: TokenParClose,
-    token_cbopen: TokenCBOpen,
+    block_open: BlockOpen,
     body: Body,
     token_cbclose: TokenCBClose,
     compiler_context: &mut CompilerContext,
 ) -> WhileLoop {
-    compiler_context.write_to_parser_file(&format!(
-        "<WhileLoop> -> {token_while} {token_par_open} <Conjunction> {token_par_close} {token_cbopen} <Body> {token_cbclose}"
+    compiler_context.write_to_parser_output(&format!(
+        "<WhileLoop> -> {token_while} {token_par_open} <Conjunction> {token_par_close} <BlockOpen> <Body> {token_cbclose}"
     ));
+    compiler_context.close_scope();
 
-    let ast = &mut compiler_context.ast;
-    let Some(conjunction_node) = ast.conjunction_stack.pop() else {
-        log_ast_error(
-            "Conjunction stack was empty when parsing `<WhileLoop> -> TokenWhile TokenParOpen <Conjunction> TokenParClose TokenCBOpen <Body> TokenCBClose`",
+    let conjunction_node = compiler_context.ast.conjunction_stack.pop();
+    let conjunction_node = conjunction_node.unwrap_or_else(|| log_ast_error(
+        "Conjunction stack was empty when parsing `<WhileLoop> -> TokenWhile TokenParOpen <Conjunction> TokenParClose <BlockOpen> <Body> TokenCBClose`",
+        ctx,
+        compiler_context,
+    ));
+    let conjunction_node = condition(conjunction_node, ctx, compiler_context);
+    let body_node = compiler_context.ast.get_node_from_ptr(AstPtr::Body);
+    match constant_condition(&conjunction_node, compiler_context.symbol_table()) {
+        Some(false) => warn_unreachable(
+            &body_node,
+            "the condition of the while is always false",
             ctx,
             compiler_context,
-        )
-    };
+        ),
+        Some(true) if !contains_return(&body_node) => log_warning(
+            ctx.range(),
+            CompilerError::InfiniteLoop(
+                "the condition of the while is always true and its body never returns".into(),
+            ),
+            compiler_context,
+        ),
+        _ => {}
+    }
+    let ast = &mut compiler_context.ast;
     ast.create_node(
         AstAction::While,
         conjunction_node.into(),
         AstPtr::Body.into(),
         AstPtr::While,
         None,
+        Span::from(ctx),
     );
 
     WhileLoop {
@@ -1028,13 +1858,13 @@ pub fn while_loop_while(
         token_par_open,
         conjunction,
         token_par_close,
-        token_cbopen,
+        block_open,
         body: Box::new(body),
         token_cbclose,
     }
 }
 
-/// Parses the rule `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose TokenCBOpen <Body> TokenCBClose`
+/// Parses the rule `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose <BlockOpen> <Body> TokenCBClose`
 #[expect(clippy::too_many_arguments)]
 pub fn if_statement_if_statement(
     ctx: &Ctx,
@@ -1042,29 +1872,40 @@ pub fn if_statement_if_statement(
     token_par_open: TokenParOpen,
     conjunction: Conjunction,
     token_par_close: TokenParClose,
-    token_cbopen: TokenCBOpen,
+    block_open: BlockOpen,
     body: Body,
     token_cbclose: TokenCBClose,
     compiler_context: &mut CompilerContext,
 ) -> IfStatement {
-    compiler_context.write_to_parser_file(&format!(
-        "<IfStatement> -> {token_if} {token_par_open} <Conjunction> {token_par_close} {token_cbopen} <Body> {token_cbclose}"
+    compiler_context.write_to_parser_output(&format!(
+        "<IfStatement> -> {token_if} {token_par_open} <Conjunction> {token_par_close} <BlockOpen> <Body> {token_cbclose}"
     ));
+    compiler_context.close_scope();
 
-    let ast = &mut compiler_context.ast;
-    let Some(conjunction_node) = ast.conjunction_stack.pop() else {
-        log_ast_error(
-            "Conjunction stack was empty when parsing `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose TokenCBOpen <Body> TokenCBClose`",
+    let conjunction_node = compiler_context.ast.conjunction_stack.pop();
+    let conjunction_node = conjunction_node.unwrap_or_else(|| log_ast_error(
+        "Conjunction stack was empty when parsing `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose <BlockOpen> <Body> TokenCBClose`",
+        ctx,
+        compiler_context,
+    ));
+    let conjunction_node = condition(conjunction_node, ctx, compiler_context);
+    if constant_condition(&conjunction_node, compiler_context.symbol_table()) == Some(false) {
+        let body_node = compiler_context.ast.get_node_from_ptr(AstPtr::Body);
+        warn_unreachable(
+            &body_node,
+            "the condition of the if is always false",
             ctx,
             compiler_context,
         );
-    };
+    }
+    let ast = &mut compiler_context.ast;
     ast.create_node(
         AstAction::If,
         conjunction_node.into(),
         AstPtr::Body.into(),
         AstPtr::If,
         None,
+        Span::from(ctx),
     );
 
     IfStatement::IfStatementIfStatement(IfStatementIfStatement {
@@ -1072,13 +1913,13 @@ pub fn if_statement_if_statement(
         token_par_open,
         conjunction,
         token_par_close,
-        token_cbopen,
+        block_open,
         body: Box::new(body),
         token_cbclose,
     })
 }
 
-/// Parses the rule `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose TokenCBOpen <Body> TokenCBClose <DummyElse> <ElseStatement>`
+/// Parses the rule `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose <BlockOpen> <Body> TokenCBClose <DummyElse> <ElseStatement>`
 #[expect(clippy::too_many_arguments)]
 pub fn if_statement_if_statement_else_statement(
     ctx: &Ctx,
@@ -1086,44 +1927,63 @@ pub fn if_statement_if_statement_else_statement(
     token_par_open: TokenParOpen,
     conjunction: Conjunction,
     token_par_close: TokenParClose,
-    token_cbopen: TokenCBOpen,
+    block_open: BlockOpen,
     body: Body,
     token_cbclose: TokenCBClose,
     else_statement: ElseStatement,
     compiler_context: &mut CompilerContext,
 ) -> IfStatement {
-    compiler_context.write_to_parser_file(&format!(
-        "<IfStatement> -> {token_if} {token_par_open} <Conjunction> {token_par_close} {token_cbopen} <Body> {token_cbclose} <DummyElse> <ElseStatement>"
+    compiler_context.write_to_parser_output(&format!(
+        "<IfStatement> -> {token_if} {token_par_open} <Conjunction> {token_par_close} <BlockOpen> <Body> {token_cbclose} <DummyElse> <ElseStatement>"
     ));
 
-    let ast = &mut compiler_context.ast;
-    let Some(if_true_body) = ast.if_body_stack.pop() else {
-        log_ast_error(
-            "IfBody stack was empty when parsing `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose TokenCBOpen <Body> TokenCBClose <DummyElse> <ElseStatement>`",
-            ctx,
-            compiler_context,
-        )
-    };
-    let else_node = ast.create_node(
+    let if_true_body = compiler_context.ast.if_body_stack.pop();
+    let if_true_body = if_true_body.unwrap_or_else(|| log_ast_error(
+        "IfBody stack was empty when parsing `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose <BlockOpen> <Body> TokenCBClose <DummyElse> <ElseStatement>`",
+        ctx,
+        compiler_context,
+    ));
+    let else_node = compiler_context.ast.create_node(
         AstAction::Else,
-        if_true_body.into(),
+        if_true_body.clone().into(),
         AstPtr::Body.into(),
         AstPtr::If,
         None,
+        Span::from(ctx),
     );
-    let Some(conjunction_node) = ast.conjunction_stack.pop() else {
-        log_ast_error(
-            "Conjunction stack was empty when parsing `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose TokenCBOpen <Body> TokenCBClose <DummyElse> <ElseStatement>`",
+    let conjunction_node = compiler_context.ast.conjunction_stack.pop();
+    let conjunction_node = conjunction_node.unwrap_or_else(|| log_ast_error(
+        "Conjunction stack was empty when parsing `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose <BlockOpen> <Body> TokenCBClose <DummyElse> <ElseStatement>`",
+        ctx,
+        compiler_context,
+    ));
+    let conjunction_node = condition(conjunction_node, ctx, compiler_context);
+    match constant_condition(&conjunction_node, compiler_context.symbol_table()) {
+        Some(false) => warn_unreachable(
+            &if_true_body,
+            "the condition of the if is always false",
             ctx,
             compiler_context,
-        )
-    };
+        ),
+        Some(true) => {
+            let else_body = compiler_context.ast.get_node_from_ptr(AstPtr::Body);
+            warn_unreachable(
+                &else_body,
+                "the condition of the if is always true so the else never runs",
+                ctx,
+                compiler_context,
+            )
+        }
+        None => {}
+    }
+    let ast = &mut compiler_context.ast;
     ast.create_node(
         AstAction::If,
         conjunction_node.into(),
         else_node.into(),
         AstPtr::If,
         None,
+        Span::from(ctx),
     );
 
     IfStatement::IfStatementElseStatement(IfStatementElseStatement {
@@ -1131,7 +1991,7 @@ pub fn if_statement_if_statement_else_statement(
         token_par_open,
         conjunction,
         token_par_close,
-        token_cbopen,
+        block_open,
         body: Box::new(body),
         token_cbclose,
         else_statement: Box::new(else_statement),
@@ -1140,7 +2000,8 @@ pub fn if_statement_if_statement_else_statement(
 
 /// Parses the rule `<DummyElse> -> EMPTY`
 pub fn dummy_else_empty(_ctx: &Ctx, compiler_context: &mut CompilerContext) -> DummyElse {
-    compiler_context.write_to_parser_file("<DummyElse> -> EMPTY");
+    compiler_context.write_to_parser_output("<DummyElse> -> EMPTY");
+    compiler_context.close_scope();
 
     let ast = &mut compiler_context.ast;
     let body_node = ast.get_node_from_ptr(AstPtr::Body);
@@ -1149,26 +2010,38 @@ pub fn dummy_else_empty(_ctx: &Ctx, compiler_context: &mut CompilerContext) -> D
     None
 }
 
-/// Parses the rule `<ElseStatement> -> TokenElse TokenCBOpen <Body> TokenCBClose`
+/// Parses the rule `<ElseStatement> -> TokenElse <BlockOpen> <Body> TokenCBClose`
 pub fn else_statement_else_statement(
     _ctx: &Ctx,
     token_else: TokenElse,
-    token_cbopen: TokenCBOpen,
+    block_open: BlockOpen,
     body: Body,
     token_cbclose: TokenCBClose,
     compiler_context: &mut CompilerContext,
 ) -> ElseStatement {
-    compiler_context.write_to_parser_file(&format!(
-        "<ElseStatement> -> {token_else} {token_cbopen} <Body> {token_cbclose}"
+    compiler_context.write_to_parser_output(&format!(
+        "<ElseStatement> -> {token_else} <BlockOpen> <Body> {token_cbclose}"
     ));
+    compiler_context.close_scope();
     ElseStatement {
         token_else,
-        token_cbopen,
+        block_open,
         body: Box::new(body),
         token_cbclose,
     }
 }
 
+/// Parses the rule `<BlockOpen> -> TokenCBOpen`
+pub fn block_open_block_open(
+    _ctx: &Ctx,
+    token_cbopen: TokenCBOpen,
+    compiler_context: &mut CompilerContext,
+) -> BlockOpen {
+    compiler_context.write_to_parser_output(&format!("<BlockOpen> -> {token_cbopen}"));
+    compiler_context.open_scope();
+    token_cbopen
+}
+
 /// Parses the rule `<BooleanExpression> -> <SimpleExpression> <ComparisonOp> <SimpleExpression>`
 pub fn boolean_expression_boolean_expression_simple_expression(
     ctx: &Ctx,
@@ -1177,31 +2050,52 @@ pub fn boolean_expression_boolean_expression_simple_expression(
     simple_expression_2: SimpleExpression,
     compiler_context: &mut CompilerContext,
 ) -> BooleanExpression {
-    compiler_context.write_to_parser_file(
+    compiler_context.write_to_parser_output(
         "<BooleanExpression> -> <SimpleExpression> <ComparisonOp> <SimpleExpression>",
     );
 
-    let ast = &mut compiler_context.ast;
-    let Some(left_child) = ast.comparision_expressions_stack.pop() else {
-        log_ast_error(
-            "ComparisonExpressions stack was empty when parsing `<BooleanExpression> -> <SimpleExpression> <ComparisonOp> <SimpleExpression>`",
-            ctx,
-            compiler_context,
-        );
-    };
-    let Some(operator) = ast.comparision_op_stack.pop() else {
-        log_ast_error(
-            "ComparisonOperator stack was empty when parsing `<BooleanExpression> -> <SimpleExpression> <ComparisonOp> <SimpleExpression>`",
-            ctx,
-            compiler_context,
-        );
+    let left_child = compiler_context.ast.comparision_expressions_stack.pop();
+    let left_child = left_child.unwrap_or_else(|| log_ast_error(
+        "ComparisonExpressions stack was empty when parsing `<BooleanExpression> -> <SimpleExpression> <ComparisonOp> <SimpleExpression>`",
+        ctx,
+        compiler_context,
+    ));
+    let operator = match compiler_context.ast.comparision_op_stack.pop() {
+        Some(operator) => operator.into(),
+        None => {
+            log_ast_error(
+                "ComparisonOperator stack was empty when parsing `<BooleanExpression> -> <SimpleExpression> <ComparisonOp> <SimpleExpression>`",
+                ctx,
+                compiler_context,
+            );
+            AstAction::Noop
+        }
     };
+    let ast = &mut compiler_context.ast;
+    let right_child = ast.get_node_from_ptr(AstPtr::SimpleExpression);
+    let (left_child, right_child) = promote_to_float(left_child, right_child);
+    // Strings and bools can only be compared with their own type and only for equality
+    if let (Some(left_type), Some(right_type)) = (&left_child.r#type, &right_child.r#type) {
+        let is_unordered = [left_type, right_type]
+            .iter()
+            .any(|r#type| matches!(r#type, ExpressionType::String | ExpressionType::Bool));
+        let is_equality = matches!(operator, AstAction::EQ | AstAction::NE);
+        if is_unordered && (left_type != right_type || !is_equality) {
+            log_type_error(
+                &format!("{left_type} {operator} {right_type}"),
+                ctx,
+                compiler_context,
+            );
+        }
+    }
+    let ast = &mut compiler_context.ast;
     let node = ast.create_node(
-        operator.into(),
+        operator,
         left_child.into(),
-        AstPtr::SimpleExpression.into(),
+        right_child.into(),
         AstPtr::BooleanExpression,
-        None,
+        Some(ExpressionType::Bool),
+        Span::from(ctx),
     );
     ast.boolean_expression_stack.push(node);
 
@@ -1212,66 +2106,22 @@ pub fn boolean_expression_boolean_expression_simple_expression(
     })
 }
 
-/// Parses the rule `<BooleanExpression> -> "true"`
-pub fn boolean_expression_boolean_expression_true(
-    _ctx: &Ctx,
-    token_true: TokenTrue,
-    compiler_context: &mut CompilerContext,
-) -> BooleanExpression {
-    compiler_context.write_to_parser_file(&format!("<BooleanExpression> -> {token_true}"));
-
-    let ast = &mut compiler_context.ast;
-    let leaf = Rc::new(Node::new_leaf(NodeValue::True, None));
-    ast.boolean_expression_stack.push(leaf);
-
-    BooleanExpression::BooleanExpressionTrue(token_true)
-}
-
-/// Parses the rule `<BooleanExpression> -> "false"`
-pub fn boolean_expression_boolean_expression_false(
-    _ctx: &Ctx,
-    token_false: TokenFalse,
-    compiler_context: &mut CompilerContext,
-) -> BooleanExpression {
-    compiler_context.write_to_parser_file(&format!("<BooleanExpression> -> {token_false}"));
-
-    let ast = &mut compiler_context.ast;
-    let leaf = Rc::new(Node::new_leaf(NodeValue::False, None));
-    ast.boolean_expression_stack.push(leaf);
-
-    BooleanExpression::BooleanExpressionFalse(token_false)
-}
-
-/// Parses the rule `<BooleanExpression> -> TokenId
-pub fn boolean_expression_boolean_expression_token_id(
+/// Parses the rule `<BooleanExpression> -> <SimpleExpression>`
+///
+/// The value is kept as is so it can be assigned or written, it is checked to be a bool once it
+/// is used as a condition
+pub fn boolean_expression_boolean_expression_value(
     _ctx: &Ctx,
-    token_id: TokenId,
+    simple_expression: SimpleExpression,
     compiler_context: &mut CompilerContext,
 ) -> BooleanExpression {
-    compiler_context.write_to_parser_file(&format!("<BooleanExpression> -> {token_id}"));
-
-    let zero_symbol = TokenIntLiteral {
-        original: "0".into(),
-        parsed: 0,
-    };
-    let token_id_leaf = Rc::new(Node::new_leaf(NodeValue::Value(token_id.clone()), None));
-    let zero_leaf = Rc::new(Node::new_leaf(
-        NodeValue::Value(zero_symbol.original.clone()),
-        None,
-    ));
-    compiler_context.push_to_symbol_table(zero_symbol.into());
+    compiler_context.write_to_parser_output("<BooleanExpression> -> <SimpleExpression>");
 
     let ast = &mut compiler_context.ast;
-    let node = ast.create_node(
-        AstAction::NE,
-        token_id_leaf.into(),
-        zero_leaf.into(),
-        AstPtr::BooleanExpression,
-        None,
-    );
+    let node = ast.get_node_from_ptr(AstPtr::SimpleExpression);
     ast.boolean_expression_stack.push(node);
 
-    BooleanExpression::BooleanExpressionTokenId(token_id)
+    BooleanExpression::BooleanExpressionValue(simple_expression)
 }
 
 /// Parses the rule `<BooleanExpression> -> <NotStatement>`
@@ -1280,7 +2130,7 @@ pub fn boolean_expression_boolean_expression_not_statement(
     not_statement: NotStatement,
     compiler_context: &mut CompilerContext,
 ) -> BooleanExpression {
-    compiler_context.write_to_parser_file("<BooleanExpression> -> <NotStatement>");
+    compiler_context.write_to_parser_output("<BooleanExpression> -> <NotStatement>");
 
     let ast = &mut compiler_context.ast;
     let node = ast.get_node_from_ptr(AstPtr::Not);
@@ -1295,7 +2145,7 @@ pub fn boolean_expression_boolean_expression_is_zero(
     function_is_zero: FunctionIsZero,
     compiler_context: &mut CompilerContext,
 ) -> BooleanExpression {
-    compiler_context.write_to_parser_file("<BooleanExpression> -> <FunctionIsZero>");
+    compiler_context.write_to_parser_output("<BooleanExpression> -> <FunctionIsZero>");
 
     let ast = &mut compiler_context.ast;
     let node = ast.get_node_from_ptr(AstPtr::IsZero);
@@ -1310,7 +2160,7 @@ pub fn simple_expression_simple_expression_arithmetic(
     arithmetic_expression: ArithmeticExpression,
     compiler_context: &mut CompilerContext,
 ) -> SimpleExpression {
-    compiler_context.write_to_parser_file("<SimpleExpression> -> <ArithmeticExpression>");
+    compiler_context.write_to_parser_output("<SimpleExpression> -> <ArithmeticExpression>");
 
     let ast = &mut compiler_context.ast;
     ast.assign_node_to_ptr(
@@ -1321,25 +2171,6 @@ pub fn simple_expression_simple_expression_arithmetic(
     SimpleExpression::SimpleExpressionArithmeticExpression(arithmetic_expression)
 }
 
-/// Parses the rule `<SimpleExpression> -> TokenStringLiteral`
-pub fn simple_expression_simple_expression_string(
-    _ctx: &Ctx,
-    token_string_literal: TokenStringLiteral,
-    compiler_context: &mut CompilerContext,
-) -> SimpleExpression {
-    compiler_context.push_string_literal_to_symbol_table(token_string_literal.clone());
-    compiler_context.write_to_parser_file(&format!("<SimpleExpression> -> {token_string_literal}"));
-
-    let ast = &mut compiler_context.ast;
-    ast.create_leaf(
-        token_string_literal.clone(),
-        AstPtr::SimpleExpression,
-        Some(ExpressionType::String),
-    );
-
-    SimpleExpression::SimpleExpressionString(token_string_literal)
-}
-
 /// Parses the rule `<Conjunction> -> <BooleanExpression> "and" <Conjunction>`
 pub fn conjunction_conjunction_and(
     ctx: &Ctx,
@@ -1348,31 +2179,32 @@ pub fn conjunction_conjunction_and(
     conjunction: Conjunction,
     compiler_context: &mut CompilerContext,
 ) -> Conjunction {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<Conjunction> -> <BooleanExpression> {token_and} <Conjunction>"
     ));
 
+    let boolean_expression_node = compiler_context.ast.boolean_expression_stack.pop();
+    let boolean_expression_node = boolean_expression_node.unwrap_or_else(|| log_ast_error(
+        "BooleanExpression stack was empty when parsing `<Conjunction> -> <BooleanExpression> \"and\" <Conjunction>`",
+        ctx,
+        compiler_context,
+    ));
+    let conjunction_node = compiler_context.ast.conjunction_stack.pop();
+    let conjunction_node = conjunction_node.unwrap_or_else(|| log_ast_error(
+        "Conjunction stack was empty when parsing `<Conjunction> -> <BooleanExpression> \"and\" <Conjunction>`",
+        ctx,
+        compiler_context,
+    ));
+    let boolean_expression_node = condition(boolean_expression_node, ctx, compiler_context);
+    let conjunction_node = condition(conjunction_node, ctx, compiler_context);
     let ast = &mut compiler_context.ast;
-    let Some(boolean_expression_node) = ast.boolean_expression_stack.pop() else {
-        log_ast_error(
-            "BooleanExpression stack was empty when parsing `<Conjunction> -> <BooleanExpression> \"and\" <Conjunction>`",
-            ctx,
-            compiler_context,
-        );
-    };
-    let Some(conjunction_node) = ast.conjunction_stack.pop() else {
-        log_ast_error(
-            "Conjunction stack was empty when parsing `<Conjunction> -> <BooleanExpression> \"and\" <Conjunction>`",
-            ctx,
-            compiler_context,
-        )
-    };
     let conjunction_node = ast.create_node(
         AstAction::And,
         boolean_expression_node.into(),
         conjunction_node.into(),
         AstPtr::Conjunction,
-        None,
+        Some(ExpressionType::Bool),
+        Span::from(ctx),
     );
     ast.conjunction_stack.push(conjunction_node);
 
@@ -1391,31 +2223,32 @@ pub fn conjunction_conjunction_or(
     conjunction: Conjunction,
     compiler_context: &mut CompilerContext,
 ) -> Conjunction {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<Conjunction> -> <BooleanExpression> {token_or} <Conjunction>"
     ));
 
+    let boolean_expression_node = compiler_context.ast.boolean_expression_stack.pop();
+    let boolean_expression_node = boolean_expression_node.unwrap_or_else(|| log_ast_error(
+        "BooleanExpression stack was empty when parsing `<Conjunction> -> <BooleanExpression> \"or\" <Conjunction>`",
+        ctx,
+        compiler_context,
+    ));
+    let conjunction_node = compiler_context.ast.conjunction_stack.pop();
+    let conjunction_node = conjunction_node.unwrap_or_else(|| log_ast_error(
+        "Conjunction stack was empty when parsing `<Conjunction> -> <BooleanExpression> \"or\" <Conjunction>`",
+        ctx,
+        compiler_context,
+    ));
+    let boolean_expression_node = condition(boolean_expression_node, ctx, compiler_context);
+    let conjunction_node = condition(conjunction_node, ctx, compiler_context);
     let ast = &mut compiler_context.ast;
-    let Some(boolean_expression_node) = ast.boolean_expression_stack.pop() else {
-        log_ast_error(
-            "BooleanExpression stack was empty when parsing `<Conjunction> -> <BooleanExpression> \"or\" <Conjunction>`",
-            ctx,
-            compiler_context,
-        );
-    };
-    let Some(conjunction_node) = ast.conjunction_stack.pop() else {
-        log_ast_error(
-            "Conjunction stack was empty when parsing `<Conjunction> -> <BooleanExpression> \"or\" <Conjunction>`",
-            ctx,
-            compiler_context,
-        );
-    };
     let conjunction_node = ast.create_node(
         AstAction::Or,
         boolean_expression_node.into(),
         conjunction_node.into(),
         AstPtr::Conjunction,
-        None,
+        Some(ExpressionType::Bool),
+        Span::from(ctx),
     );
     ast.conjunction_stack.push(conjunction_node);
 
@@ -1432,16 +2265,17 @@ pub fn conjunction_conjunction_boolean_expression(
     boolean_expression: BooleanExpression,
     compiler_context: &mut CompilerContext,
 ) -> Conjunction {
-    compiler_context.write_to_parser_file("<Conjunction> -> <BooleanExpression>");
+    compiler_context.write_to_parser_output("<Conjunction> -> <BooleanExpression>");
 
-    let ast = &mut compiler_context.ast;
-    let Some(boolean_expression_node) = ast.boolean_expression_stack.pop() else {
+    let boolean_expression_node = compiler_context.ast.boolean_expression_stack.pop();
+    let boolean_expression_node = boolean_expression_node.unwrap_or_else(|| {
         log_ast_error(
             "BooleanExpression stack was empty when parsing `<Conjunction> -> <BooleanExpression>`",
             ctx,
             compiler_context,
-        );
-    };
+        )
+    });
+    let ast = &mut compiler_context.ast;
     ast.conjunction_stack.push(boolean_expression_node);
 
     Conjunction::ConjunctionBooleanExpression(boolean_expression)
@@ -1453,7 +2287,7 @@ pub fn comparison_op_comparison_op_equal(
     token_equal: TokenEqual,
     compiler_context: &mut CompilerContext,
 ) -> ComparisonOp {
-    compiler_context.write_to_parser_file(&format!("<ComparisonOp> -> {token_equal}"));
+    compiler_context.write_to_parser_output(&format!("<ComparisonOp> -> {token_equal}"));
     let result = ComparisonOp::ComparisonOpEqual(token_equal);
 
     let ast = &mut compiler_context.ast;
@@ -1470,7 +2304,7 @@ pub fn comparison_op_comparison_op_not_equal(
     token_not_equal: TokenNotEqual,
     compiler_context: &mut CompilerContext,
 ) -> ComparisonOp {
-    compiler_context.write_to_parser_file(&format!("<ComparisonOp> -> {token_not_equal}"));
+    compiler_context.write_to_parser_output(&format!("<ComparisonOp> -> {token_not_equal}"));
     let result = ComparisonOp::ComparisonOpNotEqual(token_not_equal);
 
     let ast = &mut compiler_context.ast;
@@ -1487,7 +2321,7 @@ pub fn comparison_op_comparison_op_less(
     token_less: TokenLess,
     compiler_context: &mut CompilerContext,
 ) -> ComparisonOp {
-    compiler_context.write_to_parser_file(&format!("<ComparisonOp> -> {token_less}"));
+    compiler_context.write_to_parser_output(&format!("<ComparisonOp> -> {token_less}"));
     let result = ComparisonOp::ComparisonOpLess(token_less);
 
     let ast = &mut compiler_context.ast;
@@ -1504,7 +2338,7 @@ pub fn comparison_op_comparison_op_less_equal(
     token_less_equal: TokenLessEqual,
     compiler_context: &mut CompilerContext,
 ) -> ComparisonOp {
-    compiler_context.write_to_parser_file(&format!("<ComparisonOp> -> {token_less_equal}"));
+    compiler_context.write_to_parser_output(&format!("<ComparisonOp> -> {token_less_equal}"));
     let result = ComparisonOp::ComparisonOpLessEqual(token_less_equal);
 
     let ast = &mut compiler_context.ast;
@@ -1521,7 +2355,7 @@ pub fn comparison_op_comparison_op_greater(
     token_greater: TokenGreater,
     compiler_context: &mut CompilerContext,
 ) -> ComparisonOp {
-    compiler_context.write_to_parser_file(&format!("<ComparisonOp> -> {token_greater}"));
+    compiler_context.write_to_parser_output(&format!("<ComparisonOp> -> {token_greater}"));
     let result = ComparisonOp::ComparisonOpGreater(token_greater);
 
     let ast = &mut compiler_context.ast;
@@ -1538,7 +2372,7 @@ pub fn comparison_op_comparison_op_greater_equal(
     token_greater_equal: TokenGreaterEqual,
     compiler_context: &mut CompilerContext,
 ) -> ComparisonOp {
-    compiler_context.write_to_parser_file(&format!("<ComparisonOp> -> {token_greater_equal}"));
+    compiler_context.write_to_parser_output(&format!("<ComparisonOp> -> {token_greater_equal}"));
     let result = ComparisonOp::ComparisonOpGreaterEqual(token_greater_equal);
 
     let ast = &mut compiler_context.ast;
@@ -1551,18 +2385,21 @@ pub fn comparison_op_comparison_op_greater_equal(
 
 /// Parses the rule `<Number> -> TokenIntLiteral`
 pub fn number_number_int(
-    _ctx: &Ctx,
+    ctx: &Ctx,
     token_int_literal: TokenIntLiteral,
     compiler_context: &mut CompilerContext,
 ) -> Number {
-    compiler_context.push_to_symbol_table(token_int_literal.clone().into());
-    compiler_context.write_to_parser_file(&format!("<Number> -> {}", token_int_literal.original));
+    compiler_context.push_to_symbol_table(
+        SymbolTableElement::from(token_int_literal.clone()).with_span(Span::from(ctx)),
+    );
+    compiler_context.write_to_parser_output(&format!("<Number> -> {}", token_int_literal.original));
 
     let ast = &mut compiler_context.ast;
     ast.create_leaf(
         token_int_literal.original.clone(),
         AstPtr::Number,
         Some(ExpressionType::Int),
+        Span::from(ctx),
     );
 
     Number::NumberInt(token_int_literal)
@@ -1570,18 +2407,22 @@ pub fn number_number_int(
 
 /// Parses the rule `<Number> -> TokenFloatLiteral`
 pub fn number_number_float(
-    _ctx: &Ctx,
+    ctx: &Ctx,
     token_float_literal: TokenFloatLiteral,
     compiler_context: &mut CompilerContext,
 ) -> Number {
-    compiler_context.push_to_symbol_table(token_float_literal.clone().into());
-    compiler_context.write_to_parser_file(&format!("<Number> -> {}", token_float_literal.original));
+    compiler_context.push_to_symbol_table(
+        SymbolTableElement::from(token_float_literal.clone()).with_span(Span::from(ctx)),
+    );
+    compiler_context
+        .write_to_parser_output(&format!("<Number> -> {}", token_float_literal.original));
 
     let ast = &mut compiler_context.ast;
     ast.create_leaf(
         token_float_literal.original.clone(),
         AstPtr::Number,
         Some(ExpressionType::Float),
+        Span::from(ctx),
     );
 
     Number::NumberFloat(token_float_literal)
@@ -1589,13 +2430,15 @@ pub fn number_number_float(
 
 /// Parses the rule `<Number> -> TokenSub TokenIntLiteral`
 pub fn number_number_negative_int(
-    _ctx: &Ctx,
+    ctx: &Ctx,
     token_sub: TokenSub,
     token_int_literal: TokenIntLiteral,
     compiler_context: &mut CompilerContext,
 ) -> Number {
-    compiler_context.push_to_symbol_table(token_int_literal.clone().into());
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.push_to_symbol_table(
+        SymbolTableElement::from(token_int_literal.clone()).with_span(Span::from(ctx)),
+    );
+    compiler_context.write_to_parser_output(&format!(
         "<Number> -> {token_sub} {}",
         token_int_literal.original
     ));
@@ -1604,14 +2447,20 @@ pub fn number_number_negative_int(
     let leaf = Rc::new(Node::new_leaf(
         NodeValue::Value(token_int_literal.original.clone()),
         Some(ExpressionType::Int),
+        Span::from(ctx),
+    ));
+    let noop = Rc::new(Node::new_leaf(
+        NodeValue::Action(AstAction::Noop),
+        None,
+        Span::from(ctx),
     ));
-    let noop = Rc::new(Node::new_leaf(NodeValue::Action(AstAction::Noop), None));
     ast.create_node(
         AstAction::Negative,
         leaf.into(),
         noop.into(),
         AstPtr::Number,
         Some(ExpressionType::Int),
+        Span::from(ctx),
     );
 
     Number::NumberInt(token_int_literal)
@@ -1619,13 +2468,15 @@ pub fn number_number_negative_int(
 
 /// Parses the rule `<Number> -> TokenSub TokenFloatLiteral`
 pub fn number_number_negative_float(
-    _ctx: &Ctx,
+    ctx: &Ctx,
     token_sub: TokenSub,
     token_float_literal: TokenFloatLiteral,
     compiler_context: &mut CompilerContext,
 ) -> Number {
-    compiler_context.push_to_symbol_table(token_float_literal.clone().into());
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.push_to_symbol_table(
+        SymbolTableElement::from(token_float_literal.clone()).with_span(Span::from(ctx)),
+    );
+    compiler_context.write_to_parser_output(&format!(
         "<Number> -> {token_sub} {}",
         token_float_literal.original
     ));
@@ -1634,14 +2485,20 @@ pub fn number_number_negative_float(
     let leaf = Rc::new(Node::new_leaf(
         NodeValue::Value(token_float_literal.original.clone()),
         Some(ExpressionType::Float),
+        Span::from(ctx),
+    ));
+    let noop = Rc::new(Node::new_leaf(
+        NodeValue::Action(AstAction::Noop),
+        None,
+        Span::from(ctx),
     ));
-    let noop = Rc::new(Node::new_leaf(NodeValue::Action(AstAction::Noop), None));
     ast.create_node(
         AstAction::Negative,
         leaf.into(),
         noop.into(),
         AstPtr::Number,
         Some(ExpressionType::Float),
+        Span::from(ctx),
     );
 
     Number::NumberFloat(token_float_literal)
@@ -1654,51 +2511,98 @@ pub fn not_statement_not(
     boolean_expression: BooleanExpression,
     compiler_context: &mut CompilerContext,
 ) -> NotStatement {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<NotStatement> -> {token_not} <BooleanExpression>"
     ));
 
-    let ast = &mut compiler_context.ast;
-    let Some(boolean_expression_node) = ast.boolean_expression_stack.pop() else {
-        log_ast_error(
-            "BooleanExpression stack was empty when parsing `<NotStatement> -> TokenNot <BooleanExpression>`",
-            ctx,
-            compiler_context,
-        );
+    let boolean_expression_node = compiler_context.ast.boolean_expression_stack.pop();
+    let boolean_expression_node = boolean_expression_node.unwrap_or_else(|| log_ast_error(
+        "BooleanExpression stack was empty when parsing `<NotStatement> -> TokenNot <BooleanExpression>`",
+        ctx,
+        compiler_context,
+    ));
+    let boolean_expression_node = condition(boolean_expression_node, ctx, compiler_context);
+    // Operands that aren't bools already reported their error, the result is left without a type
+    // so it isn't reported again
+    if boolean_expression_node.r#type != Some(ExpressionType::Bool) {
+        let leaf = Rc::new(Node::new_leaf(
+            NodeValue::Action(AstAction::Noop),
+            None,
+            Span::from(ctx),
+        ));
+        compiler_context
+            .ast
+            .assign_node_to_ptr(leaf.into(), AstPtr::Not);
+        return NotStatement {
+            token_not,
+            boolean_expression: Box::new(boolean_expression),
+        };
+    }
+
+    let constant = match &boolean_expression_node.value {
+        NodeValue::True => Some(NodeValue::False),
+        NodeValue::False => Some(NodeValue::True),
+        _ => None,
     };
+    if let Some(constant) = constant {
+        let leaf = Rc::new(Node::new_leaf(
+            constant,
+            Some(ExpressionType::Bool),
+            Span::from(ctx),
+        ));
+        compiler_context
+            .ast
+            .assign_node_to_ptr(leaf.into(), AstPtr::Not);
+        return NotStatement {
+            token_not,
+            boolean_expression: Box::new(boolean_expression),
+        };
+    }
 
     let opposite = match &boolean_expression_node.value {
-        NodeValue::Action(AstAction::GT) => AstAction::LTE,
-        NodeValue::Action(AstAction::GTE) => AstAction::LT,
-        NodeValue::Action(AstAction::EQ) => AstAction::NE,
-        NodeValue::Action(AstAction::NE) => AstAction::EQ,
-        NodeValue::Action(AstAction::LT) => AstAction::GTE,
-        NodeValue::Action(AstAction::LTE) => AstAction::GT,
-        _ => log_ast_error(
-            "invalid value for booleanExpression in NotStatement",
-            ctx,
-            compiler_context,
-        ),
+        NodeValue::Action(AstAction::GT) => Some(AstAction::LTE),
+        NodeValue::Action(AstAction::GTE) => Some(AstAction::LT),
+        NodeValue::Action(AstAction::EQ) => Some(AstAction::NE),
+        NodeValue::Action(AstAction::NE) => Some(AstAction::EQ),
+        NodeValue::Action(AstAction::LT) => Some(AstAction::GTE),
+        NodeValue::Action(AstAction::LTE) => Some(AstAction::GT),
+        _ => None,
     };
 
-    let (left_child, right_child) = match (
+    // Any other bool, like an `and` or an `or`, is negated as `value == false`
+    let (Some(opposite), Some(left_child), Some(right_child)) = (
+        opposite,
         boolean_expression_node.left_child.as_ref().cloned(),
         boolean_expression_node.right_child.as_ref().cloned(),
-    ) {
-        (Some(l), Some(r)) => (l, r),
-        _ => log_ast_error(
-            "Invalid booleanExpression in NotStatement ",
-            ctx,
-            compiler_context,
-        ),
+    ) else {
+        compiler_context.push_to_symbol_table(SymbolTableElement::bool_literal(false));
+        let false_leaf = Rc::new(Node::new_leaf(
+            NodeValue::False,
+            Some(ExpressionType::Bool),
+            Span::from(ctx),
+        ));
+        compiler_context.ast.create_node(
+            AstAction::EQ,
+            boolean_expression_node.into(),
+            false_leaf.into(),
+            AstPtr::Not,
+            Some(ExpressionType::Bool),
+            Span::from(ctx),
+        );
+        return NotStatement {
+            token_not,
+            boolean_expression: Box::new(boolean_expression),
+        };
     };
 
+    let ast = &mut compiler_context.ast;
     ast.create_node(
         opposite,
         left_child.into(),
         right_child.into(),
         AstPtr::Not,
-        None,
+        Some(ExpressionType::Bool),
+        Span::from(ctx),
     );
 
     NotStatement {
@@ -1715,49 +2619,41 @@ pub fn arithmetic_expression_arithmetic_expression_sum_term(
     term: Term,
     compiler_context: &mut CompilerContext,
 ) -> ArithmeticExpression {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<ArithmeticExpression> -> <ArithmeticExpression> <DummyAE> {token_sum} <Term>"
     ));
 
-    let ast = &mut compiler_context.ast;
-
-    let right_child = ast.get_node_from_ptr(AstPtr::Term);
-    let Some(left_child) = ast.expression_stack.pop() else {
-        log_ast_error(
-            "ArithmeticExpression stack was empty when parsing `<ArithmeticExpression> -> <ArithmeticExpression> <DummyAE> TokenSum <Term>`",
-            ctx,
-            compiler_context,
-        );
-    };
-
-    let Some(left_child_type) = left_child.r#type.as_ref().cloned() else {
-        log_ast_error(
-            "Left hand side of an addition has no type",
-            ctx,
-            compiler_context,
-        )
-    };
-    let Some(right_child_type) = right_child.r#type.as_ref().cloned() else {
-        log_ast_error(
-            "Right hand side of an addition has no type",
-            ctx,
-            compiler_context,
-        )
+    let right_child = compiler_context.ast.get_node_from_ptr(AstPtr::Term);
+    let left_child = compiler_context.ast.expression_stack.pop();
+    let left_child = left_child.unwrap_or_else(|| log_ast_error(
+        "ArithmeticExpression stack was empty when parsing `<ArithmeticExpression> -> <ArithmeticExpression> <DummyAE> TokenSum <Term>`",
+        ctx,
+        compiler_context,
+    ));
+    let (left_child, right_child) = promote_to_float(left_child, right_child);
+    // An operand without a type already reported its error so we only check typed operands
+    let node_type = match (left_child.r#type.clone(), right_child.r#type.clone()) {
+        (Some(left_child_type), Some(right_child_type)) => {
+            // Bools don't support arithmetic
+            if left_child_type != right_child_type || left_child_type == ExpressionType::Bool {
+                log_type_error(
+                    &format!("{left_child_type} + {right_child_type}"),
+                    ctx,
+                    compiler_context,
+                )
+            }
+            Some(left_child_type)
+        }
+        _ => None,
     };
-    if left_child_type != right_child_type {
-        log_type_error(
-            &format!("{left_child_type} + {right_child_type}"),
-            ctx,
-            compiler_context,
-        )
-    }
 
-    ast.create_node(
+    compiler_context.ast.create_node(
         AstAction::Plus,
         left_child.into(),
         right_child.into(),
         AstPtr::ArithmeticExpression,
-        Some(left_child_type),
+        node_type,
+        Span::from(ctx),
     );
 
     ArithmeticExpression::ArithmeticExpressionSumTerm(ArithmeticExpressionSumTerm {
@@ -1775,49 +2671,46 @@ pub fn arithmetic_expression_arithmetic_expression_sub_term(
     term: Term,
     compiler_context: &mut CompilerContext,
 ) -> ArithmeticExpression {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<ArithmeticExpression> -> <ArithmeticExpression> <DummyAE> {token_sub} <Term>"
     ));
 
-    let ast = &mut compiler_context.ast;
-
-    let right_child = ast.get_node_from_ptr(AstPtr::Term);
-    let Some(left_child) = ast.expression_stack.pop() else {
-        log_ast_error(
-            "ArithmeticExpression stack was empty when parsing `<ArithmeticExpression> -> <ArithmeticExpression> <DummyAE> TokenSub <Term>`",
-            ctx,
-            compiler_context,
-        )
-    };
-
-    let Some(left_child_type) = left_child.r#type.as_ref().cloned() else {
-        log_ast_error(
-            "Left hand side of a substraction has no type",
-            ctx,
-            compiler_context,
-        )
-    };
-    let Some(right_child_type) = right_child.r#type.as_ref().cloned() else {
-        log_ast_error(
-            "Right hand side of a substraction has no type",
-            ctx,
-            compiler_context,
-        )
+    let right_child = compiler_context.ast.get_node_from_ptr(AstPtr::Term);
+    let left_child = compiler_context.ast.expression_stack.pop();
+    let left_child = left_child.unwrap_or_else(|| log_ast_error(
+        "ArithmeticExpression stack was empty when parsing `<ArithmeticExpression> -> <ArithmeticExpression> <DummyAE> TokenSub <Term>`",
+        ctx,
+        compiler_context,
+    ));
+    let (left_child, right_child) = promote_to_float(left_child, right_child);
+    // An operand without a type already reported its error so we only check typed operands
+    let node_type = match (left_child.r#type.clone(), right_child.r#type.clone()) {
+        (Some(left_child_type), Some(right_child_type)) => {
+            // Strings only support concatenation and bools don't support arithmetic
+            if left_child_type != right_child_type
+                || matches!(
+                    left_child_type,
+                    ExpressionType::String | ExpressionType::Bool
+                )
+            {
+                log_type_error(
+                    &format!("{left_child_type} - {right_child_type}"),
+                    ctx,
+                    compiler_context,
+                )
+            }
+            Some(left_child_type)
+        }
+        _ => None,
     };
-    if left_child_type != right_child_type {
-        log_type_error(
-            &format!("{left_child_type} - {right_child_type}"),
-            ctx,
-            compiler_context,
-        )
-    }
 
     compiler_context.ast.create_node(
         AstAction::Sub,
         left_child.into(),
         right_child.into(),
         AstPtr::ArithmeticExpression,
-        Some(left_child_type),
+        node_type,
+        Span::from(ctx),
     );
 
     ArithmeticExpression::ArithmeticExpressionSubTerm(ArithmeticExpressionSubTerm {
@@ -1842,7 +2735,7 @@ pub fn arithmetic_expression_arithmetic_expression_term(
     term: Term,
     compiler_context: &mut CompilerContext,
 ) -> ArithmeticExpression {
-    compiler_context.write_to_parser_file("<ArithmeticExpression> -> <Term>");
+    compiler_context.write_to_parser_output("<ArithmeticExpression> -> <Term>");
 
     let ast = &mut compiler_context.ast;
     ast.assign_node_to_ptr(AstPtr::Term.into(), AstPtr::ArithmeticExpression);
@@ -1859,47 +2752,46 @@ pub fn term_term_mul_factor(
     compiler_context: &mut CompilerContext,
 ) -> Term {
     compiler_context
-        .write_to_parser_file(&format!("<Term> -> <Term> <DummyT> {token_mul} <Factor>"));
-
-    let ast = &mut compiler_context.ast;
+        .write_to_parser_output(&format!("<Term> -> <Term> <DummyT> {token_mul} <Factor>"));
 
-    let right_child = ast.get_node_from_ptr(AstPtr::Factor);
-    let Some(left_child) = ast.term_stack.pop() else {
+    let right_child = compiler_context.ast.get_node_from_ptr(AstPtr::Factor);
+    let left_child = compiler_context.ast.term_stack.pop();
+    let left_child = left_child.unwrap_or_else(|| {
         log_ast_error(
             "Term stack was empty when parsing `<Term> -> <Term> <DummyT> TokenMul <Factor>`",
             ctx,
             compiler_context,
-        );
-    };
-
-    let Some(left_child_type) = left_child.r#type.as_ref().cloned() else {
-        log_ast_error(
-            "Left hand side of a multiplication has no type",
-            ctx,
-            compiler_context,
-        )
-    };
-    let Some(right_child_type) = right_child.r#type.as_ref().cloned() else {
-        log_ast_error(
-            "Right hand side of a multiplication has no type",
-            ctx,
-            compiler_context,
         )
+    });
+    let (left_child, right_child) = promote_to_float(left_child, right_child);
+    // An operand without a type already reported its error so we only check typed operands
+    let node_type = match (left_child.r#type.clone(), right_child.r#type.clone()) {
+        (Some(left_child_type), Some(right_child_type)) => {
+            // Strings only support concatenation and bools don't support arithmetic
+            if left_child_type != right_child_type
+                || matches!(
+                    left_child_type,
+                    ExpressionType::String | ExpressionType::Bool
+                )
+            {
+                log_type_error(
+                    &format!("{left_child_type} * {right_child_type}"),
+                    ctx,
+                    compiler_context,
+                )
+            }
+            Some(left_child_type)
+        }
+        _ => None,
     };
-    if left_child_type != right_child_type {
-        log_type_error(
-            &format!("{left_child_type} * {right_child_type}"),
-            ctx,
-            compiler_context,
-        )
-    }
 
-    ast.create_node(
+    compiler_context.ast.create_node(
         AstAction::Mult,
         left_child.into(),
         right_child.into(),
         AstPtr::Term,
-        Some(left_child_type),
+        node_type,
+        Span::from(ctx),
     );
 
     Term::TermMulFactor(TermMulFactor {
@@ -1918,47 +2810,46 @@ pub fn term_term_div_factor(
     compiler_context: &mut CompilerContext,
 ) -> Term {
     compiler_context
-        .write_to_parser_file(&format!("<Term> -> <Term> <DummyT> {token_div} <Factor>"));
+        .write_to_parser_output(&format!("<Term> -> <Term> <DummyT> {token_div} <Factor>"));
 
-    let ast = &mut compiler_context.ast;
-
-    let right_child = ast.get_node_from_ptr(AstPtr::Factor);
-    let Some(left_child) = ast.term_stack.pop() else {
+    let right_child = compiler_context.ast.get_node_from_ptr(AstPtr::Factor);
+    let left_child = compiler_context.ast.term_stack.pop();
+    let left_child = left_child.unwrap_or_else(|| {
         log_ast_error(
             "Term stack was empty when parsing `<Term> -> <Term> <DummyT> TokenDiv <Factor>`",
             ctx,
             compiler_context,
-        );
-    };
-
-    let Some(left_child_type) = left_child.r#type.as_ref().cloned() else {
-        log_ast_error(
-            "Left hand side of a division has no type",
-            ctx,
-            compiler_context,
-        )
-    };
-    let Some(right_child_type) = right_child.r#type.as_ref().cloned() else {
-        log_ast_error(
-            "Right hand side of a division has no type",
-            ctx,
-            compiler_context,
         )
+    });
+    let (left_child, right_child) = promote_to_float(left_child, right_child);
+    // An operand without a type already reported its error so we only check typed operands
+    let node_type = match (left_child.r#type.clone(), right_child.r#type.clone()) {
+        (Some(left_child_type), Some(right_child_type)) => {
+            // Strings only support concatenation and bools don't support arithmetic
+            if left_child_type != right_child_type
+                || matches!(
+                    left_child_type,
+                    ExpressionType::String | ExpressionType::Bool
+                )
+            {
+                log_type_error(
+                    &format!("{left_child_type} / {right_child_type}"),
+                    ctx,
+                    compiler_context,
+                )
+            }
+            Some(left_child_type)
+        }
+        _ => None,
     };
-    if left_child_type != right_child_type {
-        log_type_error(
-            &format!("{left_child_type} / {right_child_type}"),
-            ctx,
-            compiler_context,
-        )
-    }
 
-    ast.create_node(
+    compiler_context.ast.create_node(
         AstAction::Div,
         left_child.into(),
         right_child.into(),
         AstPtr::Term,
-        Some(left_child_type),
+        node_type,
+        Span::from(ctx),
     );
 
     Term::TermDivFactor(TermDivFactor {
@@ -1983,7 +2874,7 @@ pub fn term_term_factor(
     factor: Factor,
     compiler_context: &mut CompilerContext,
 ) -> Term {
-    compiler_context.write_to_parser_file("<Term> -> <Factor>");
+    compiler_context.write_to_parser_output("<Term> -> <Factor>");
 
     let ast = &mut compiler_context.ast;
     ast.assign_node_to_ptr(AstPtr::Factor.into(), AstPtr::Term);
@@ -1997,25 +2888,70 @@ pub fn factor_factor_id(
     token_id: TokenId,
     compiler_context: &mut CompilerContext,
 ) -> Factor {
-    compiler_context.write_to_parser_file(&format!("<Factor> -> {token_id}"));
+    compiler_context.write_to_parser_output(&format!("<Factor> -> {token_id}"));
 
-    let Some(id_type) = compiler_context.get_symbol_type(&token_id) else {
-        log_undeclared_variable_error(&token_id, ctx, compiler_context)
-    };
+    let key = resolve_variable(&token_id, ctx, compiler_context);
+    let id_type = compiler_context.get_symbol_type(&key);
+    check_scalar(&token_id, &id_type, ctx, compiler_context);
+    compiler_context.read_variable(&key, ctx.range());
 
     let ast = &mut compiler_context.ast;
-    ast.create_leaf(token_id.clone(), AstPtr::Factor, Some(id_type.into()));
+    ast.create_leaf(
+        key,
+        AstPtr::Factor,
+        id_type.map(Into::into),
+        Span::from(ctx),
+    );
 
     Factor::FactorId(token_id)
 }
 
+/// Parses the rule `<Factor> -> TokenId <Index>`
+pub fn factor_factor_index(
+    ctx: &Ctx,
+    token_id: TokenId,
+    index: Index,
+    compiler_context: &mut CompilerContext,
+) -> Factor {
+    compiler_context.write_to_parser_output(&format!("<Factor> -> {token_id} <Index>"));
+
+    create_index(&token_id, AstPtr::Factor, ctx, compiler_context);
+    let key = resolve_variable(&token_id, ctx, compiler_context);
+    compiler_context.read_variable(&key, ctx.range());
+
+    Factor::FactorIndex(FactorIndex { token_id, index })
+}
+
+/// Parses the rule `<Index> -> TokenSBOpen <SimpleExpression> TokenSBClose`
+pub fn index_index(
+    _ctx: &Ctx,
+    token_sbopen: TokenSBOpen,
+    simple_expression: SimpleExpression,
+    token_sbclose: TokenSBClose,
+    compiler_context: &mut CompilerContext,
+) -> Index {
+    compiler_context.write_to_parser_output(&format!(
+        "<Index> -> {token_sbopen} <SimpleExpression> {token_sbclose}"
+    ));
+
+    let ast = &mut compiler_context.ast;
+    let node = ast.get_node_from_ptr(AstPtr::SimpleExpression);
+    ast.index_stack.push(node);
+
+    Index {
+        token_sbopen,
+        simple_expression: Box::new(simple_expression),
+        token_sbclose,
+    }
+}
+
 /// Parses the rule `<Factor> -> <Number>`
 pub fn factor_factor_number(
     _ctx: &Ctx,
     number: Number,
     compiler_context: &mut CompilerContext,
 ) -> Factor {
-    compiler_context.write_to_parser_file("<Factor> -> <Number>");
+    compiler_context.write_to_parser_output("<Factor> -> <Number>");
 
     let ast = &mut compiler_context.ast;
     ast.assign_node_to_ptr(AstPtr::Number.into(), AstPtr::Factor);
@@ -2023,6 +2959,98 @@ pub fn factor_factor_number(
     Factor::FactorNumber(number)
 }
 
+/// Parses the rule `<Factor> -> TokenStringLiteral`
+pub fn factor_factor_string(
+    ctx: &Ctx,
+    token_string_literal: TokenStringLiteral,
+    compiler_context: &mut CompilerContext,
+) -> Factor {
+    compiler_context
+        .push_string_literal_to_symbol_table(token_string_literal.clone(), Span::from(ctx));
+    compiler_context.write_to_parser_output(&format!("<Factor> -> {token_string_literal}"));
+
+    let ast = &mut compiler_context.ast;
+    ast.create_leaf(
+        token_string_literal.clone(),
+        AstPtr::Factor,
+        Some(ExpressionType::String),
+        Span::from(ctx),
+    );
+
+    Factor::FactorString(token_string_literal)
+}
+
+/// Parses the rule `<Factor> -> "true"`
+pub fn factor_factor_true(
+    ctx: &Ctx,
+    token_true: TokenTrue,
+    compiler_context: &mut CompilerContext,
+) -> Factor {
+    compiler_context
+        .push_to_symbol_table(SymbolTableElement::bool_literal(true).with_span(Span::from(ctx)));
+    compiler_context.write_to_parser_output(&format!("<Factor> -> {token_true}"));
+
+    let leaf = Rc::new(Node::new_leaf(
+        NodeValue::True,
+        Some(ExpressionType::Bool),
+        Span::from(ctx),
+    ));
+    compiler_context
+        .ast
+        .assign_node_to_ptr(leaf.into(), AstPtr::Factor);
+
+    Factor::FactorTrue(token_true)
+}
+
+/// Parses the rule `<Factor> -> "false"`
+pub fn factor_factor_false(
+    ctx: &Ctx,
+    token_false: TokenFalse,
+    compiler_context: &mut CompilerContext,
+) -> Factor {
+    compiler_context
+        .push_to_symbol_table(SymbolTableElement::bool_literal(false).with_span(Span::from(ctx)));
+    compiler_context.write_to_parser_output(&format!("<Factor> -> {token_false}"));
+
+    let leaf = Rc::new(Node::new_leaf(
+        NodeValue::False,
+        Some(ExpressionType::Bool),
+        Span::from(ctx),
+    ));
+    compiler_context
+        .ast
+        .assign_node_to_ptr(leaf.into(), AstPtr::Factor);
+
+    Factor::FactorFalse(token_false)
+}
+
+/// Parses the rule `<Factor> -> <FunctionCall>`
+pub fn factor_factor_call(
+    ctx: &Ctx,
+    function_call: FunctionCall,
+    compiler_context: &mut CompilerContext,
+) -> Factor {
+    compiler_context.write_to_parser_output("<Factor> -> <FunctionCall>");
+
+    let call_node = compiler_context.ast.get_node_from_ptr(AstPtr::FunctionCall);
+    if let Some(NodeValue::Value(name)) = call_node.left_child.as_ref().map(|name| &name.value)
+        && compiler_context
+            .get_function(name)
+            .is_some_and(|function| function.return_type.is_none())
+    {
+        log_type_error(
+            &format!("procedure {name} doesn't return a value"),
+            ctx,
+            compiler_context,
+        )
+    }
+
+    let ast = &mut compiler_context.ast;
+    ast.assign_node_to_ptr(call_node.into(), AstPtr::Factor);
+
+    Factor::FactorCall(function_call)
+}
+
 /// Parses the rule `<Factor> -> TokenParOpen <ArithmeticExpression> TokenParClose`
 pub fn factor_factor_paren(
     _ctx: &Ctx,
@@ -2031,7 +3059,7 @@ pub fn factor_factor_paren(
     token_par_close: TokenParClose,
     compiler_context: &mut CompilerContext,
 ) -> Factor {
-    compiler_context.write_to_parser_file(&format!(
+    compiler_context.write_to_parser_output(&format!(
         "<Factor> -> {token_par_open} <ArithmeticExpression> {token_par_close}"
     ));
 
@@ -2045,18 +3073,417 @@ pub fn factor_factor_paren(
     })
 }
 
-fn log_ast_error(error: &str, ctx: &Ctx, compiler_context: &mut CompilerContext) -> ! {
-    log_error_and_exit(
+/// Records an internal error and returns a placeholder node so parsing can continue
+fn log_ast_error(error: &str, ctx: &Ctx, compiler_context: &mut CompilerContext) -> Rc<Node> {
+    log_error(
         ctx.range(),
         CompilerError::Internal(error.into()),
         0,
         true,
         compiler_context,
+    );
+    Rc::new(Node::new_leaf(
+        NodeValue::Action(AstAction::Noop),
+        None,
+        Span::from(ctx),
+    ))
+}
+
+/// Places the functions declared before the main body in front of it
+fn prepend_functions(ctx: &Ctx, compiler_context: &mut CompilerContext) {
+    let ast = &mut compiler_context.ast;
+    let functions = mem::take(&mut ast.function_stack);
+    ast.assign_node_to_ptr(AstPtr::Body.into(), AstPtr::Program);
+    for function in functions.into_iter().rev() {
+        ast.create_node(
+            AstAction::S,
+            function.into(),
+            AstPtr::Program.into(),
+            AstPtr::Program,
+            None,
+            Span::from(ctx),
+        );
+    }
+}
+
+/// Creates the node calling `name` at `AstPtr::FunctionCall` checking the arguments against the
+/// parameters of the function
+fn create_call(
+    name: &str,
+    arguments: Vec<Rc<Node>>,
+    ctx: &Ctx,
+    compiler_context: &mut CompilerContext,
+) {
+    if let Some(span) = compiler_context.identifier_span(ctx.range().start) {
+        compiler_context.push_reference(span, name.to_string(), ReferenceKind::Function);
+    }
+    let function = compiler_context.get_function(name).cloned();
+    let parameter_types = match &function {
+        Some(function) => function
+            .parameters
+            .iter()
+            .map(|key| compiler_context.symbol_table().get_variable_type(key))
+            .collect(),
+        None => {
+            let span = compiler_context
+                .identifier_span(ctx.range().start)
+                .unwrap_or_else(|| ctx.range());
+            log_error(
+                span,
+                CompilerError::UndeclaredFunction(name.into()),
+                0,
+                true,
+                compiler_context,
+            );
+            Vec::new()
+        }
+    };
+    if function.is_some() && parameter_types.len() != arguments.len() {
+        log_error(
+            ctx.range(),
+            CompilerError::ArgumentCount(format!(
+                "{name} takes {} arguments but {} were given",
+                parameter_types.len(),
+                arguments.len()
+            )),
+            0,
+            true,
+            compiler_context,
+        );
+    }
+
+    let mut argument_list = Rc::new(Node::new_leaf(
+        NodeValue::Action(AstAction::Noop),
+        None,
+        Span::from(ctx),
+    ));
+    for (position, argument) in arguments.into_iter().enumerate().rev() {
+        let parameter_type = parameter_types.get(position).cloned().flatten();
+        // Ints are converted implicitly when passed to a float parameter
+        let argument = match (&parameter_type, &argument.r#type) {
+            (Some(ExpressionType::Float), Some(ExpressionType::Int)) => {
+                Node::int_to_float(argument)
+            }
+            _ => argument,
+        };
+        if let (Some(parameter_type), Some(argument_type)) = (&parameter_type, &argument.r#type)
+            && parameter_type != argument_type
+        {
+            log_type_error(
+                &format!(
+                    "argument {} of {name} {parameter_type} := {argument_type}",
+                    position + 1
+                ),
+                ctx,
+                compiler_context,
+            )
+        }
+        argument_list = compiler_context.ast.create_node(
+            AstAction::Argument,
+            argument.into(),
+            argument_list.into(),
+            AstPtr::FunctionCall,
+            None,
+            Span::from(ctx),
+        );
+    }
+
+    let name_leaf = Rc::new(Node::new_leaf(
+        NodeValue::Value(name.into()),
+        None,
+        Span::from(ctx),
+    ));
+    compiler_context.ast.create_node(
+        AstAction::Call,
+        name_leaf.into(),
+        argument_list.into(),
+        AstPtr::FunctionCall,
+        function.and_then(|function| function.return_type),
+        Span::from(ctx),
+    );
+}
+
+/// Creates the node at `dest_ptr` accessing the element of the array `token_id` at the index on
+/// top of the index stack
+///
+/// The index has to be an int and, when it's a literal, within the bounds of the array. Other
+/// indexes are checked when the program runs
+fn create_index(
+    token_id: &str,
+    dest_ptr: AstPtr,
+    ctx: &Ctx,
+    compiler_context: &mut CompilerContext,
+) -> Rc<Node> {
+    let key = resolve_variable(token_id, ctx, compiler_context);
+    let symbol_type = compiler_context.get_symbol_type(&key);
+    let length = match &symbol_type {
+        Some(SymbolTableElementType::Array(_, length)) => Some(*length),
+        Some(_) => {
+            log_error(
+                ctx.range(),
+                CompilerError::ArrayUse(format!("{token_id} isn't an array")),
+                0,
+                true,
+                compiler_context,
+            );
+            None
+        }
+        None => {
+            log_undeclared_variable_error(token_id, ctx, compiler_context);
+            None
+        }
+    };
+
+    let index = compiler_context.ast.index_stack.pop();
+    let index = index.unwrap_or_else(|| {
+        log_ast_error(
+            "Index stack was empty when parsing `<Index>`",
+            ctx,
+            compiler_context,
+        )
+    });
+    // An index without a type already reported its error
+    if let Some(index_type) = &index.r#type
+        && *index_type != ExpressionType::Int
+    {
+        log_type_error(
+            &format!("index of {token_id} must be INT but is {index_type}"),
+            ctx,
+            compiler_context,
+        )
+    }
+    if let (Some(length), Some(position)) = (length, constant_index(&index))
+        && (position < 0 || position as usize >= length)
+    {
+        log_error(
+            ctx.range(),
+            CompilerError::IndexOutOfBounds(format!(
+                "{token_id} has {length} elements but the index is {position}"
+            )),
+            0,
+            true,
+            compiler_context,
+        )
+    }
+
+    // Only arrays have elements, anything else already reported its error
+    let element_type = symbol_type
+        .filter(|symbol_type| matches!(symbol_type, SymbolTableElementType::Array(..)))
+        .map(ExpressionType::from);
+    let array = Rc::new(Node::new_leaf(
+        NodeValue::Value(key),
+        element_type.clone(),
+        Span::from(ctx),
+    ));
+    compiler_context.ast.create_node(
+        AstAction::Index,
+        array.into(),
+        index.into(),
+        dest_ptr,
+        element_type,
+        Span::from(ctx),
+    )
+}
+
+/// Value of an index made of an int literal, `None` if it has to be computed
+fn constant_index(node: &Node) -> Option<i64> {
+    match &node.value {
+        NodeValue::Value(value) => value.parse().ok(),
+        NodeValue::Action(AstAction::Negative) => node
+            .left_child
+            .as_ref()
+            .and_then(|child| constant_index(child))
+            .map(|value| -value),
+        _ => None,
+    }
+}
+
+/// Reports the use of `token_id` as a single value if it's undeclared or an array
+fn check_scalar(
+    token_id: &str,
+    symbol_type: &Option<SymbolTableElementType>,
+    ctx: &Ctx,
+    compiler_context: &mut CompilerContext,
+) {
+    match symbol_type {
+        None => log_undeclared_variable_error(token_id, ctx, compiler_context),
+        Some(SymbolTableElementType::Array(..)) => {
+            log_array_use_error(token_id, ctx, compiler_context)
+        }
+        Some(_) => {}
+    }
+}
+
+/// Key of the variable `token_id` visible from the current scope, recording the identifier
+/// the rule being reduced starts with as a use of it
+fn resolve_variable(token_id: &str, ctx: &Ctx, compiler_context: &mut CompilerContext) -> String {
+    let key = compiler_context.variable_key(token_id);
+    if let Some(span) = compiler_context.identifier_span(ctx.range().start) {
+        compiler_context.push_reference(span, key.clone(), ReferenceKind::Variable);
+    }
+    key
+}
+
+/// Span of `token` when it is the first thing the rule being reduced matched
+fn token_span(token: &str, ctx: &Ctx) -> Span {
+    let span = Span::from(ctx);
+    let start = span.range.start;
+    Span {
+        range: start..start + token.len(),
+        ..span
+    }
+}
+
+/// Whether there is a return anywhere in `node`
+fn contains_return(node: &Node) -> bool {
+    matches!(node.value, NodeValue::Action(AstAction::Return))
+        || [&node.left_child, &node.right_child]
+            .into_iter()
+            .flatten()
+            .any(|child| contains_return(child))
+}
+
+/// Warns that `body` never runs unless it is empty
+fn warn_unreachable(body: &Node, reason: &str, ctx: &Ctx, compiler_context: &mut CompilerContext) {
+    if !matches!(body.value, NodeValue::Action(AstAction::Noop)) {
+        log_warning(
+            ctx.range(),
+            CompilerError::UnreachableCode(reason.into()),
+            compiler_context,
+        );
+    }
+}
+
+fn always_returns(node: &Node) -> bool {
+    let returns =
+        |child: &Option<Rc<Node>>| child.as_ref().is_some_and(|child| always_returns(child));
+    match &node.value {
+        NodeValue::Action(AstAction::Return) => true,
+        NodeValue::Action(AstAction::S) => returns(&node.left_child) || returns(&node.right_child),
+        NodeValue::Action(AstAction::If) => node.right_child.as_ref().is_some_and(|branches| {
+            matches!(branches.value, NodeValue::Action(AstAction::Else))
+                && returns(&branches.left_child)
+                && returns(&branches.right_child)
+        }),
+        _ => false,
+    }
+}
+
+/// Wraps the int operand of an int and float pair in a conversion so both are floats
+fn promote_to_float(left: Rc<Node>, right: Rc<Node>) -> (Rc<Node>, Rc<Node>) {
+    match (&left.r#type, &right.r#type) {
+        (Some(ExpressionType::Int), Some(ExpressionType::Float)) => {
+            (Node::int_to_float(left), right)
+        }
+        (Some(ExpressionType::Float), Some(ExpressionType::Int)) => {
+            (left, Node::int_to_float(right))
+        }
+        _ => (left, right),
+    }
+}
+
+/// Length of a string expression made only of literals, `None` if it depends on a variable
+fn constant_string_length(node: &Node, compiler_context: &CompilerContext) -> Option<usize> {
+    match &node.value {
+        NodeValue::Value(value) => compiler_context
+            .symbol_table()
+            .get_symbol_from_name(value)
+            .filter(|symbol| matches!(symbol.data_type, SymbolTableElementType::String))
+            .and_then(|symbol| symbol.length),
+        NodeValue::Action(AstAction::Plus) => {
+            let left = constant_string_length(node.left_child.as_ref()?, compiler_context)?;
+            let right = constant_string_length(node.right_child.as_ref()?, compiler_context)?;
+            Some(left + right)
+        }
+        _ => None,
+    }
+}
+
+/// Pops a conjunction used as a value, the literals a condition is stored as are added to the
+/// symbol table so the backends can store its result
+fn pop_conjunction_value(
+    error: &str,
+    ctx: &Ctx,
+    compiler_context: &mut CompilerContext,
+) -> Rc<Node> {
+    let node = compiler_context.ast.conjunction_stack.pop();
+    let node = node.unwrap_or_else(|| log_ast_error(error, ctx, compiler_context));
+    if node.r#type == Some(ExpressionType::Bool) {
+        compiler_context.push_to_symbol_table(SymbolTableElement::bool_literal(true));
+        compiler_context.push_to_symbol_table(SymbolTableElement::bool_literal(false));
+    }
+    node
+}
+
+/// Reports reads into bools, only numbers and strings can be read
+fn check_readable(
+    token_id: &str,
+    r#type: &Option<ExpressionType>,
+    ctx: &Ctx,
+    compiler_context: &mut CompilerContext,
+) {
+    if let Some(ExpressionType::Bool) = r#type {
+        log_type_error(
+            &format!("can't read {token_id} of type BOOL"),
+            ctx,
+            compiler_context,
+        )
+    }
+}
+
+/// Turns a node used as a condition into one the backends can jump on
+///
+/// Bool values become `value != false` and anything that isn't a bool is reported, a node without
+/// a type already reported its error
+fn condition(node: Rc<Node>, ctx: &Ctx, compiler_context: &mut CompilerContext) -> Rc<Node> {
+    match &node.r#type {
+        Some(ExpressionType::Bool) => {}
+        Some(r#type) => {
+            log_type_error(
+                &format!("condition must be BOOL but is {type}"),
+                ctx,
+                compiler_context,
+            );
+            return node;
+        }
+        None => return node,
+    }
+    if matches!(
+        node.value,
+        NodeValue::True
+            | NodeValue::False
+            | NodeValue::Action(
+                AstAction::And
+                    | AstAction::Or
+                    | AstAction::GT
+                    | AstAction::GTE
+                    | AstAction::EQ
+                    | AstAction::NE
+                    | AstAction::LT
+                    | AstAction::LTE
+            )
+    ) {
+        return node;
+    }
+
+    compiler_context.push_to_symbol_table(SymbolTableElement::bool_literal(false));
+    let false_leaf = Rc::new(Node::new_leaf(
+        NodeValue::False,
+        Some(ExpressionType::Bool),
+        Span::from(ctx),
+    ));
+    compiler_context.ast.create_node(
+        AstAction::NE,
+        node.into(),
+        false_leaf.into(),
+        AstPtr::BooleanExpression,
+        Some(ExpressionType::Bool),
+        Span::from(ctx),
     )
 }
 
-fn log_type_error(error: &str, ctx: &Ctx, compiler_context: &mut CompilerContext) -> ! {
-    log_error_and_exit(
+fn log_type_error(error: &str, ctx: &Ctx, compiler_context: &mut CompilerContext) {
+    log_error(
         ctx.range(),
         CompilerError::TypeMismatch(error.into()),
         0,
@@ -2069,10 +3496,26 @@ fn log_undeclared_variable_error(
     var_name: &str,
     ctx: &Ctx,
     compiler_context: &mut CompilerContext,
-) -> ! {
-    log_error_and_exit(
-        ctx.range(),
+) {
+    // Rules start at the variable, the error points at its name alone whatever the rule covers
+    let span = compiler_context
+        .identifier_span(ctx.range().start)
+        .unwrap_or_else(|| ctx.range());
+    let diagnostic = Diagnostic::new(
+        span,
         CompilerError::UndeclaredVariable(var_name.into()),
+        true,
+    )
+    .with_help(format!(
+        "declare `{var_name}` in an `init` block before using it"
+    ));
+    compiler_context.push_diagnostic(diagnostic);
+}
+
+fn log_array_use_error(var_name: &str, ctx: &Ctx, compiler_context: &mut CompilerContext) {
+    log_error(
+        ctx.range(),
+        CompilerError::ArrayUse(format!("{var_name} is an array and has to be indexed")),
         0,
         true,
         compiler_context,
@@ -1,7 +1,7 @@
 use crate::compiler::context::Compiler;
 use crate::grammar::rules::{Context, Input, ProdKind, State};
 use crate::grammar::{TokenKind, rules_actions};
-use rustemo::{Builder, LRBuilder, Token};
+use rustemo::{Builder, Context as _, LRBuilder, Token};
 
 #[expect(clippy::large_enum_variant)]
 #[derive(Debug)]
@@ -15,6 +15,7 @@ pub enum Terminal {
     TokenInt(rules_actions::TokenInt),
     TokenFloat(rules_actions::TokenFloat),
     TokenString(rules_actions::TokenString),
+    TokenBool(rules_actions::TokenBool),
     TokenIntLiteral(rules_actions::TokenIntLiteral),
     TokenFloatLiteral(rules_actions::TokenFloatLiteral),
     TokenStringLiteral(rules_actions::TokenStringLiteral),
@@ -28,6 +29,8 @@ pub enum Terminal {
     TokenParClose(rules_actions::TokenParClose),
     TokenCBOpen(rules_actions::TokenCBOpen),
     TokenCBClose(rules_actions::TokenCBClose),
+    TokenSBOpen(rules_actions::TokenSBOpen),
+    TokenSBClose(rules_actions::TokenSBClose),
     TokenColon(rules_actions::TokenColon),
     TokenInit(rules_actions::TokenInit),
     TokenWhile(rules_actions::TokenWhile),
@@ -50,17 +53,29 @@ pub enum Terminal {
     TokenIsZero(rules_actions::TokenIsZero),
     TokenConvDate(rules_actions::TokenConvDate),
     TokenDate(rules_actions::TokenDate),
+    TokenFunction(rules_actions::TokenFunction),
+    TokenProcedure(rules_actions::TokenProcedure),
+    TokenReturn(rules_actions::TokenReturn),
 }
 
+#[expect(clippy::large_enum_variant)]
 #[derive(Debug)]
 pub enum NonTerminal {
     Program(rules_actions::Program),
+    Functions(rules_actions::Functions),
+    Function(rules_actions::Function),
+    FunctionSignature(rules_actions::FunctionSignature),
+    FunctionName(rules_actions::FunctionName),
+    Parameters(rules_actions::Parameters),
+    Parameter(rules_actions::Parameter),
     Body(rules_actions::Body),
     InitBody(rules_actions::InitBody),
     FunctionRead(rules_actions::FunctionRead),
     FunctionWrite(rules_actions::FunctionWrite),
     FunctionIsZero(rules_actions::FunctionIsZero),
     FunctionConvDate(rules_actions::FunctionConvDate),
+    FunctionCall(rules_actions::FunctionCall),
+    Arguments(rules_actions::Arguments),
     VarDeclarations(rules_actions::VarDeclarations),
     VarDeclaration(rules_actions::VarDeclaration),
     Expressions(rules_actions::Expressions),
@@ -70,6 +85,7 @@ pub enum NonTerminal {
     WhileLoop(rules_actions::WhileLoop),
     IfStatement(rules_actions::IfStatement),
     ElseStatement(rules_actions::ElseStatement),
+    BlockOpen(rules_actions::BlockOpen),
     BooleanExpression(rules_actions::BooleanExpression),
     SimpleExpression(rules_actions::SimpleExpression),
     Conjunction(rules_actions::Conjunction),
@@ -79,6 +95,7 @@ pub enum NonTerminal {
     ArithmeticExpression(rules_actions::ArithmeticExpression),
     Term(rules_actions::Term),
     Factor(rules_actions::Factor),
+    Index(rules_actions::Index),
     DummyAE(rules_actions::DummyAE),
     DummyT(rules_actions::DummyT),
     DummyElse(rules_actions::DummyElse),
@@ -89,19 +106,9 @@ impl Builder for Compiler {
     fn get_result(&mut self) -> Self::Output {
         let mut compiler_context = self.inner.borrow_mut();
 
-        if let Err(e) = compiler_context.dump_symbol_table_to_file() {
-            eprintln!("Failed to write symbol table to file {e}")
-        }
-
         match compiler_context.res_stack.pop().unwrap() {
             Symbol::NonTerminal(NonTerminal::Program(_r)) => {
-                match compiler_context.read_parser_file_to_string() {
-                    Err(e) => {
-                        eprintln!("Failed to read final list of rules {e}");
-                        std::process::exit(1)
-                    }
-                    Ok(rules) => rules,
-                }
+                compiler_context.parser_output().to_string()
             }
             _ => panic!("Invalid result on the parse stack!"),
         }
@@ -113,6 +120,7 @@ impl Builder for Compiler {
 impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> for Compiler {
     fn shift_action(&mut self, context: &Context<'i, Input>, token: Token<'i, Input, TokenKind>) {
         let mut compiler_context = self.inner.borrow_mut();
+        compiler_context.push_token(context.range(), token.kind);
         let val =
             match token.kind {
                 TokenKind::STOP => panic!("Cannot shift STOP token!"),
@@ -131,6 +139,11 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     token,
                     &mut compiler_context,
                 )),
+                TokenKind::TokenBool => Terminal::TokenBool(rules_actions::token_bool(
+                    context,
+                    token,
+                    &mut compiler_context,
+                )),
                 TokenKind::TokenIntLiteral => Terminal::TokenIntLiteral(
                     rules_actions::token_int_literal(context, token, &mut compiler_context),
                 ),
@@ -188,6 +201,16 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     token,
                     &mut compiler_context,
                 )),
+                TokenKind::TokenSBOpen => Terminal::TokenSBOpen(rules_actions::token_sbopen(
+                    context,
+                    token,
+                    &mut compiler_context,
+                )),
+                TokenKind::TokenSBClose => Terminal::TokenSBClose(rules_actions::token_sbclose(
+                    context,
+                    token,
+                    &mut compiler_context,
+                )),
                 TokenKind::TokenColon => Terminal::TokenColon(rules_actions::token_colon(
                     context,
                     token,
@@ -290,6 +313,19 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     token,
                     &mut compiler_context,
                 )),
+                TokenKind::TokenFunction => Terminal::TokenFunction(rules_actions::token_function(
+                    context,
+                    token,
+                    &mut compiler_context,
+                )),
+                TokenKind::TokenProcedure => Terminal::TokenProcedure(
+                    rules_actions::token_procedure(context, token, &mut compiler_context),
+                ),
+                TokenKind::TokenReturn => Terminal::TokenReturn(rules_actions::token_return(
+                    context,
+                    token,
+                    &mut compiler_context,
+                )),
             };
         compiler_context.res_stack.push(Symbol::Terminal(val));
     }
@@ -331,6 +367,60 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::ProgramProgramWithFunctions => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 7usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Functions(p0)),
+                        Symbol::Terminal(Terminal::TokenId(p1)),
+                        Symbol::Terminal(Terminal::TokenParOpen(p2)),
+                        Symbol::Terminal(Terminal::TokenParClose(p3)),
+                        Symbol::Terminal(Terminal::TokenCBOpen(p4)),
+                        Symbol::NonTerminal(NonTerminal::Body(p5)),
+                        Symbol::Terminal(Terminal::TokenCBClose(p6)),
+                    ) => NonTerminal::Program(rules_actions::program_program_with_functions(
+                        context,
+                        p0,
+                        p1,
+                        p2,
+                        p3,
+                        p4,
+                        p5,
+                        p6,
+                        &mut compiler_context,
+                    )),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ProgramProgramFunctionsOnlyBody => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Functions(p0)),
+                        Symbol::NonTerminal(NonTerminal::Body(p1)),
+                    ) => NonTerminal::Program(rules_actions::program_program_functions_only_body(
+                        context,
+                        p0,
+                        p1,
+                        &mut compiler_context,
+                    )),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::ProgramProgramOnlyBody => {
                 let mut i = compiler_context
                     .res_stack
@@ -347,6 +437,273 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::FunctionsFunctionsSingle => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Function(p0)) => {
+                        NonTerminal::Functions(rules_actions::functions_functions_single(
+                            context,
+                            p0,
+                            &mut compiler_context,
+                        ))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FunctionsFunctionsRecursive => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Function(p0)),
+                        Symbol::NonTerminal(NonTerminal::Functions(p1)),
+                    ) => NonTerminal::Functions(rules_actions::functions_functions_recursive(
+                        context,
+                        p0,
+                        p1,
+                        &mut compiler_context,
+                    )),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FunctionFunctionDefinition => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::FunctionSignature(p0)),
+                        Symbol::Terminal(Terminal::TokenCBOpen(p1)),
+                        Symbol::NonTerminal(NonTerminal::Body(p2)),
+                        Symbol::Terminal(Terminal::TokenCBClose(p3)),
+                    ) => NonTerminal::Function(rules_actions::function_function_definition(
+                        context,
+                        p0,
+                        p1,
+                        p2,
+                        p3,
+                        &mut compiler_context,
+                    )),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FunctionSignatureFunctionNoParameters => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 6usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::TokenFunction(p0)),
+                        Symbol::NonTerminal(NonTerminal::FunctionName(p1)),
+                        Symbol::Terminal(Terminal::TokenParOpen(p2)),
+                        Symbol::Terminal(Terminal::TokenParClose(p3)),
+                        Symbol::Terminal(Terminal::TokenColon(p4)),
+                        Symbol::NonTerminal(NonTerminal::DataType(p5)),
+                    ) => NonTerminal::FunctionSignature(
+                        rules_actions::function_signature_function_no_parameters(
+                            context,
+                            p0,
+                            p1,
+                            p2,
+                            p3,
+                            p4,
+                            p5,
+                            &mut compiler_context,
+                        ),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FunctionSignatureFunctionWithParameters => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 7usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::TokenFunction(p0)),
+                        Symbol::NonTerminal(NonTerminal::FunctionName(p1)),
+                        Symbol::Terminal(Terminal::TokenParOpen(p2)),
+                        Symbol::NonTerminal(NonTerminal::Parameters(p3)),
+                        Symbol::Terminal(Terminal::TokenParClose(p4)),
+                        Symbol::Terminal(Terminal::TokenColon(p5)),
+                        Symbol::NonTerminal(NonTerminal::DataType(p6)),
+                    ) => NonTerminal::FunctionSignature(
+                        rules_actions::function_signature_function_with_parameters(
+                            context,
+                            p0,
+                            p1,
+                            p2,
+                            p3,
+                            p4,
+                            p5,
+                            p6,
+                            &mut compiler_context,
+                        ),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FunctionSignatureProcedureNoParameters => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::TokenProcedure(p0)),
+                        Symbol::NonTerminal(NonTerminal::FunctionName(p1)),
+                        Symbol::Terminal(Terminal::TokenParOpen(p2)),
+                        Symbol::Terminal(Terminal::TokenParClose(p3)),
+                    ) => NonTerminal::FunctionSignature(
+                        rules_actions::function_signature_procedure_no_parameters(
+                            context,
+                            p0,
+                            p1,
+                            p2,
+                            p3,
+                            &mut compiler_context,
+                        ),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FunctionSignatureProcedureWithParameters => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::TokenProcedure(p0)),
+                        Symbol::NonTerminal(NonTerminal::FunctionName(p1)),
+                        Symbol::Terminal(Terminal::TokenParOpen(p2)),
+                        Symbol::NonTerminal(NonTerminal::Parameters(p3)),
+                        Symbol::Terminal(Terminal::TokenParClose(p4)),
+                    ) => NonTerminal::FunctionSignature(
+                        rules_actions::function_signature_procedure_with_parameters(
+                            context,
+                            p0,
+                            p1,
+                            p2,
+                            p3,
+                            p4,
+                            &mut compiler_context,
+                        ),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FunctionNameFunctionName => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::TokenId(p0)) => {
+                        NonTerminal::FunctionName(rules_actions::function_name_function_name(
+                            context,
+                            p0,
+                            &mut compiler_context,
+                        ))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ParametersParametersSingle => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Parameter(p0)) => {
+                        NonTerminal::Parameters(rules_actions::parameters_parameters_single(
+                            context,
+                            p0,
+                            &mut compiler_context,
+                        ))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ParametersParametersRecursive => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Parameter(p0)),
+                        Symbol::Terminal(Terminal::TokenComma(p1)),
+                        Symbol::NonTerminal(NonTerminal::Parameters(p2)),
+                    ) => NonTerminal::Parameters(rules_actions::parameters_parameters_recursive(
+                        context,
+                        p0,
+                        p1,
+                        p2,
+                        &mut compiler_context,
+                    )),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ParameterParameter => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::TokenId(p0)),
+                        Symbol::Terminal(Terminal::TokenColon(p1)),
+                        Symbol::NonTerminal(NonTerminal::DataType(p2)),
+                    ) => NonTerminal::Parameter(rules_actions::parameter_parameter(
+                        context,
+                        p0,
+                        p1,
+                        p2,
+                        &mut compiler_context,
+                    )),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::BodyBodyInitExpressions => {
                 let mut i = compiler_context
                     .res_stack
@@ -450,6 +807,38 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::FunctionReadFunctionReadIndexedCall => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::TokenRead(p0)),
+                        Symbol::Terminal(Terminal::TokenParOpen(p1)),
+                        Symbol::Terminal(Terminal::TokenId(p2)),
+                        Symbol::NonTerminal(NonTerminal::Index(p3)),
+                        Symbol::Terminal(Terminal::TokenParClose(p4)),
+                    ) => NonTerminal::FunctionRead(
+                        rules_actions::function_read_function_read_indexed_call(
+                            context,
+                            p0,
+                            p1,
+                            p2,
+                            p3,
+                            p4,
+                            &mut compiler_context,
+                        ),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::FunctionWriteFunctionWriteCall => {
                 let mut i = compiler_context
                     .res_stack
@@ -464,7 +853,7 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     (
                         Symbol::Terminal(Terminal::TokenWrite(p0)),
                         Symbol::Terminal(Terminal::TokenParOpen(p1)),
-                        Symbol::NonTerminal(NonTerminal::SimpleExpression(p2)),
+                        Symbol::NonTerminal(NonTerminal::Conjunction(p2)),
                         Symbol::Terminal(Terminal::TokenParClose(p3)),
                     ) => NonTerminal::FunctionWrite(
                         rules_actions::function_write_function_write_call(
@@ -501,14 +890,65 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                             p0,
                             p1,
                             p2,
-                            p3,
+                            p3,
+                            &mut compiler_context,
+                        ),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FunctionConvDateFunctionConvDateVariableCall => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::TokenConvDate(p0)),
+                        Symbol::Terminal(Terminal::TokenParOpen(p1)),
+                        Symbol::Terminal(Terminal::TokenDate(p2)),
+                        Symbol::Terminal(Terminal::TokenParClose(p3)),
+                    ) => NonTerminal::FunctionConvDate(
+                        rules_actions::function_conv_date_function_conv_date_variable_call(
+                            context,
+                            p0,
+                            p1,
+                            p2,
+                            p3,
+                            &mut compiler_context,
+                        ),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FunctionCallFunctionCallNoArguments => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::TokenId(p0)),
+                        Symbol::Terminal(Terminal::TokenParOpen(p1)),
+                        Symbol::Terminal(Terminal::TokenParClose(p2)),
+                    ) => NonTerminal::FunctionCall(
+                        rules_actions::function_call_function_call_no_arguments(
+                            context,
+                            p0,
+                            p1,
+                            p2,
                             &mut compiler_context,
                         ),
                     ),
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FunctionConvDateFunctionConvDateVariableCall => {
+            ProdKind::FunctionCallFunctionCallWithArguments => {
                 let mut i = compiler_context
                     .res_stack
                     .split_off(stack_len - 4usize)
@@ -520,12 +960,12 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     i.next().unwrap(),
                 ) {
                     (
-                        Symbol::Terminal(Terminal::TokenConvDate(p0)),
+                        Symbol::Terminal(Terminal::TokenId(p0)),
                         Symbol::Terminal(Terminal::TokenParOpen(p1)),
-                        Symbol::Terminal(Terminal::TokenDate(p2)),
+                        Symbol::NonTerminal(NonTerminal::Arguments(p2)),
                         Symbol::Terminal(Terminal::TokenParClose(p3)),
-                    ) => NonTerminal::FunctionConvDate(
-                        rules_actions::function_conv_date_function_conv_date_variable_call(
+                    ) => NonTerminal::FunctionCall(
+                        rules_actions::function_call_function_call_with_arguments(
                             context,
                             p0,
                             p1,
@@ -537,6 +977,42 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::ArgumentsArgumentsSingle => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Conjunction(p0)) => {
+                        NonTerminal::Arguments(rules_actions::arguments_arguments_single(
+                            context,
+                            p0,
+                            &mut compiler_context,
+                        ))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ArgumentsArgumentsRecursive => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Arguments(p0)),
+                        Symbol::Terminal(Terminal::TokenComma(p1)),
+                        Symbol::NonTerminal(NonTerminal::Conjunction(p2)),
+                    ) => NonTerminal::Arguments(rules_actions::arguments_arguments_recursive(
+                        context,
+                        p0,
+                        p1,
+                        p2,
+                        &mut compiler_context,
+                    )),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::VarDeclarationsVarDeclarationsSingle => {
                 let mut i = compiler_context
                     .res_stack
@@ -597,6 +1073,41 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::VarDeclarationVarDeclarationArray => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 6usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::TokenId(p0)),
+                        Symbol::Terminal(Terminal::TokenColon(p1)),
+                        Symbol::NonTerminal(NonTerminal::DataType(p2)),
+                        Symbol::Terminal(Terminal::TokenSBOpen(p3)),
+                        Symbol::Terminal(Terminal::TokenIntLiteral(p4)),
+                        Symbol::Terminal(Terminal::TokenSBClose(p5)),
+                    ) => NonTerminal::VarDeclaration(
+                        rules_actions::var_declaration_var_declaration_array(
+                            context,
+                            p0,
+                            p1,
+                            p2,
+                            p3,
+                            p4,
+                            p5,
+                            &mut compiler_context,
+                        ),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::VarDeclarationVarDeclarationRecursive => {
                 let mut i = compiler_context
                     .res_stack
@@ -729,6 +1240,36 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::StatementStatementCall => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::FunctionCall(p0)) => NonTerminal::Statement(
+                        rules_actions::statement_statement_call(context, p0, &mut compiler_context),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementStatementReturn => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::TokenReturn(p0)),
+                        Symbol::NonTerminal(NonTerminal::Conjunction(p1)),
+                    ) => NonTerminal::Statement(rules_actions::statement_statement_return(
+                        context,
+                        p0,
+                        p1,
+                        &mut compiler_context,
+                    )),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::AssignmentAssignmentExpression => {
                 let mut i = compiler_context
                     .res_stack
@@ -738,7 +1279,7 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     (
                         Symbol::Terminal(Terminal::TokenId(p0)),
                         Symbol::Terminal(Terminal::TokenAssign(p1)),
-                        Symbol::NonTerminal(NonTerminal::SimpleExpression(p2)),
+                        Symbol::NonTerminal(NonTerminal::Conjunction(p2)),
                     ) => NonTerminal::Assignment(rules_actions::assignment_assignment_expression(
                         context,
                         p0,
@@ -749,6 +1290,33 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::AssignmentAssignmentIndexed => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::TokenId(p0)),
+                        Symbol::NonTerminal(NonTerminal::Index(p1)),
+                        Symbol::Terminal(Terminal::TokenAssign(p2)),
+                        Symbol::NonTerminal(NonTerminal::Conjunction(p3)),
+                    ) => NonTerminal::Assignment(rules_actions::assignment_assignment_indexed(
+                        context,
+                        p0,
+                        p1,
+                        p2,
+                        p3,
+                        &mut compiler_context,
+                    )),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::AssignmentAssignmentConvDate => {
                 let mut i = compiler_context
                     .res_stack
@@ -805,6 +1373,18 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::DataTypeBoolType => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::TokenBool(p0)) => NonTerminal::DataType(
+                        rules_actions::data_type_bool_type(context, p0, &mut compiler_context),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::WhileLoopWhile => {
                 let mut i = compiler_context
                     .res_stack
@@ -824,7 +1404,7 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::Terminal(Terminal::TokenParOpen(p1)),
                         Symbol::NonTerminal(NonTerminal::Conjunction(p2)),
                         Symbol::Terminal(Terminal::TokenParClose(p3)),
-                        Symbol::Terminal(Terminal::TokenCBOpen(p4)),
+                        Symbol::NonTerminal(NonTerminal::BlockOpen(p4)),
                         Symbol::NonTerminal(NonTerminal::Body(p5)),
                         Symbol::Terminal(Terminal::TokenCBClose(p6)),
                     ) => NonTerminal::WhileLoop(rules_actions::while_loop_while(
@@ -860,7 +1440,7 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::Terminal(Terminal::TokenParOpen(p1)),
                         Symbol::NonTerminal(NonTerminal::Conjunction(p2)),
                         Symbol::Terminal(Terminal::TokenParClose(p3)),
-                        Symbol::Terminal(Terminal::TokenCBOpen(p4)),
+                        Symbol::NonTerminal(NonTerminal::BlockOpen(p4)),
                         Symbol::NonTerminal(NonTerminal::Body(p5)),
                         Symbol::Terminal(Terminal::TokenCBClose(p6)),
                     ) => NonTerminal::IfStatement(rules_actions::if_statement_if_statement(
@@ -898,7 +1478,7 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                         Symbol::Terminal(Terminal::TokenParOpen(p1)),
                         Symbol::NonTerminal(NonTerminal::Conjunction(p2)),
                         Symbol::Terminal(Terminal::TokenParClose(p3)),
-                        Symbol::Terminal(Terminal::TokenCBOpen(p4)),
+                        Symbol::NonTerminal(NonTerminal::BlockOpen(p4)),
                         Symbol::NonTerminal(NonTerminal::Body(p5)),
                         Symbol::Terminal(Terminal::TokenCBClose(p6)),
                         Symbol::NonTerminal(NonTerminal::DummyElse(_)),
@@ -937,7 +1517,7 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                 ) {
                     (
                         Symbol::Terminal(Terminal::TokenElse(p0)),
-                        Symbol::Terminal(Terminal::TokenCBOpen(p1)),
+                        Symbol::NonTerminal(NonTerminal::BlockOpen(p1)),
                         Symbol::NonTerminal(NonTerminal::Body(p2)),
                         Symbol::Terminal(Terminal::TokenCBClose(p3)),
                     ) => NonTerminal::ElseStatement(rules_actions::else_statement_else_statement(
@@ -951,6 +1531,18 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::BlockOpenBlockOpen => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::TokenCBOpen(p0)) => NonTerminal::BlockOpen(
+                        rules_actions::block_open_block_open(context, p0, &mut compiler_context),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::BooleanExpressionBooleanExpressionSimpleExpression => {
                 let mut i = compiler_context
                     .res_stack
@@ -973,51 +1565,21 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::BooleanExpressionBooleanExpressionTrue => {
-                let mut i = compiler_context
-                    .res_stack
-                    .split_off(stack_len - 1usize)
-                    .into_iter();
-                match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::TokenTrue(p0)) => NonTerminal::BooleanExpression(
-                        rules_actions::boolean_expression_boolean_expression_true(
-                            context,
-                            p0,
-                            &mut compiler_context,
-                        ),
-                    ),
-                    _ => panic!("Invalid symbol parse stack data."),
-                }
-            }
-            ProdKind::BooleanExpressionBooleanExpressionFalse => {
-                let mut i = compiler_context
-                    .res_stack
-                    .split_off(stack_len - 1usize)
-                    .into_iter();
-                match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::TokenFalse(p0)) => NonTerminal::BooleanExpression(
-                        rules_actions::boolean_expression_boolean_expression_false(
-                            context,
-                            p0,
-                            &mut compiler_context,
-                        ),
-                    ),
-                    _ => panic!("Invalid symbol parse stack data."),
-                }
-            }
-            ProdKind::BooleanExpressionBooleanExpressionVar => {
+            ProdKind::BooleanExpressionBooleanExpressionValue => {
                 let mut i = compiler_context
                     .res_stack
                     .split_off(stack_len - 1_usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::TokenId(p0)) => NonTerminal::BooleanExpression(
-                        rules_actions::boolean_expression_boolean_expression_token_id(
-                            context,
-                            p0,
-                            &mut compiler_context,
-                        ),
-                    ),
+                    Symbol::NonTerminal(NonTerminal::SimpleExpression(p0)) => {
+                        NonTerminal::BooleanExpression(
+                            rules_actions::boolean_expression_boolean_expression_value(
+                                context,
+                                p0,
+                                &mut compiler_context,
+                            ),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
@@ -1134,24 +1696,6 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::SimpleExpressionSimpleExpressionString => {
-                let mut i = compiler_context
-                    .res_stack
-                    .split_off(stack_len - 1usize)
-                    .into_iter();
-                match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::TokenStringLiteral(p0)) => {
-                        NonTerminal::SimpleExpression(
-                            rules_actions::simple_expression_simple_expression_string(
-                                context,
-                                p0,
-                                &mut compiler_context,
-                            ),
-                        )
-                    }
-                    _ => panic!("Invalid symbol parse stack data."),
-                }
-            }
 
             ProdKind::ComparisonOpComparisonOpEqual => {
                 let mut i = compiler_context
@@ -1477,6 +2021,24 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::FactorFactorIndex => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::TokenId(p0)),
+                        Symbol::NonTerminal(NonTerminal::Index(p1)),
+                    ) => NonTerminal::Factor(rules_actions::factor_factor_index(
+                        context,
+                        p0,
+                        p1,
+                        &mut compiler_context,
+                    )),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::FactorFactorNumber => {
                 let mut i = compiler_context
                     .res_stack
@@ -1489,6 +2051,54 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::FactorFactorString => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::TokenStringLiteral(p0)) => NonTerminal::Factor(
+                        rules_actions::factor_factor_string(context, p0, &mut compiler_context),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FactorFactorTrue => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::TokenTrue(p0)) => NonTerminal::Factor(
+                        rules_actions::factor_factor_true(context, p0, &mut compiler_context),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FactorFactorFalse => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::TokenFalse(p0)) => NonTerminal::Factor(
+                        rules_actions::factor_factor_false(context, p0, &mut compiler_context),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FactorFactorCall => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::FunctionCall(p0)) => NonTerminal::Factor(
+                        rules_actions::factor_factor_call(context, p0, &mut compiler_context),
+                    ),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::FactorFactorParen => {
                 let mut i = compiler_context
                     .res_stack
@@ -1509,6 +2119,26 @@ impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind> fo
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::IndexIndex => {
+                let mut i = compiler_context
+                    .res_stack
+                    .split_off(stack_len - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::TokenSBOpen(p0)),
+                        Symbol::NonTerminal(NonTerminal::SimpleExpression(p1)),
+                        Symbol::Terminal(Terminal::TokenSBClose(p2)),
+                    ) => NonTerminal::Index(rules_actions::index_index(
+                        context,
+                        p0,
+                        p1,
+                        p2,
+                        &mut compiler_context,
+                    )),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
             ProdKind::DummyAEP1 => NonTerminal::DummyAE(rules_actions::dummy_ae_empty(
                 context,
                 &mut compiler_context,
@@ -1,10 +1,14 @@
 use super::rules::{State, TokenKind};
-use crate::compiler::{
-    context::Compiler,
-    error::{CompilerError, log_error_and_exit},
+use crate::{
+    compiler::{
+        ast::Span,
+        context::{Compiler, Recovery},
+        error::{CompilerError, log_error},
+    },
+    lexer::tokens::{ends_operand, starts_statement},
 };
-use rustemo::{Context, LRContext, Lexer, Location, Position, Token};
-use std::iter;
+use rustemo::{Context, Input as _, LRContext, Lexer, LineColumn, Position, Token};
+use std::{iter, ops::Range};
 
 pub type Input = str;
 pub type Ctx<'i> = LRContext<'i, Input, State, TokenKind>;
@@ -29,71 +33,197 @@ impl<'i> Lexer<'i, Ctx<'i>, State, TokenKind> for Compiler {
             .join(", ");
         let mut pos = context.position();
 
-        let value;
-        let token;
+        let mut value = "";
+        let mut token = TokenKind::STOP;
 
-        if context.position() >= input.len() || input.is_empty() {
-            value = "";
-            token = TokenKind::STOP
-        } else {
-            let trimmed_input = input.get(context.position()..input.len()).unwrap();
-            let mut compiler_context = self.inner.borrow_mut();
+        let mut compiler_context = self.inner.borrow_mut();
+        // Panic mode recovery: on a lexer or syntax error report it and skip input until a statement
+        // starting a later line. Only the first error of a run of skipped tokens is reported so a
+        // single mistake doesn't flood the output
+        while pos < input.len() {
+            let trimmed_input = input.get(pos..input.len()).unwrap();
             let mut lexer =
                 crate::lexer::lex::Lexer::new(trimmed_input, pos, &mut compiler_context);
-            token =
-                match validate_and_get_next_token(&mut lexer, expected_tokens, expected_tokens_str)
+            let result = get_next_token(&mut lexer);
+            let range = lexer.yytextpos();
+            drop(lexer);
+
+            match result {
+                Ok(TokenKind::STOP) => {
+                    pos = input.len();
+                    break;
+                }
+                Ok(tok)
+                    if compiler_context.recovering.is_some_and(|recovery| {
+                        !recovery.resumable
+                            || !(starts_statement(tok) || tok == TokenKind::TokenCBClose)
+                            || !starts_line(&input[recovery.start..pos + range.start])
+                    }) =>
+                {
+                    pos += range.end;
+                }
+                // The parser is still in the statement broken by the error, it's completed with
+                // the tokens missing before going on with the next one
+                Ok(tok)
+                    if compiler_context.recovering.is_some()
+                        && !(between_statements(compiler_context.tokens())
+                            && expected_tokens.contains(&tok)) =>
                 {
-                    Ok(tok) => tok,
-                    Err(e) => {
-                        log_error_and_exit(lexer.yytextpos(), e, pos, true, &mut compiler_context)
+                    let tokens = compiler_context.tokens();
+                    match missing_token(tokens, &expected_tokens) {
+                        Some(missing) => {
+                            pos += range.start;
+                            compiler_context.insert_token(pos);
+                            token = missing;
+                            break;
+                        }
+                        None if between_statements(tokens) => pos += range.end,
+                        None => {
+                            if let Some(recovery) = &mut compiler_context.recovering {
+                                recovery.resumable = false;
+                            }
+                            pos += range.end;
+                        }
                     }
-                };
-            let range = lexer.yytextpos();
-            pos += range.start;
-            value = trimmed_input.get(range).unwrap();
+                }
+                Ok(tok) if expected_tokens.contains(&tok) => {
+                    compiler_context.recovering = None;
+                    token = tok;
+                    value = trimmed_input.get(range.clone()).unwrap();
+                    pos += range.start;
+                    break;
+                }
+                result => {
+                    let e = match result {
+                        Ok(tok) => CompilerError::Parser(format!(
+                            "unexpected token: {tok} expected one of: {expected_tokens_str}"
+                        )),
+                        Err(e) => e,
+                    };
+                    let skip = match e {
+                        // Unrecognized characters are skipped one at a time
+                        CompilerError::Lexer(_) => {
+                            let len = trimmed_input[range.start..]
+                                .chars()
+                                .next()
+                                .map(char::len_utf8)
+                                .unwrap_or(1);
+                            range.start..range.start + len
+                        }
+                        _ => range,
+                    };
+                    if compiler_context.recovering.is_none() {
+                        log_error(skip.clone(), e, pos, true, &mut compiler_context);
+                        compiler_context.start_recovery(pos + skip.start);
+                    }
+                    pos += skip.end;
+                }
+            }
         }
 
+        let unexpected_eof =
+            token == TokenKind::STOP && !expected_tokens.contains(&TokenKind::STOP);
+        if unexpected_eof && compiler_context.recovering.is_none() {
+            log_error(
+                0..0,
+                CompilerError::Parser(format!(
+                    "unexpected EOF: expected one of: {expected_tokens_str}"
+                )),
+                input.len(),
+                true,
+                &mut compiler_context,
+            );
+            compiler_context.recovering = Some(Recovery {
+                start: input.len(),
+                resumable: false,
+            });
+        }
+
+        // The parser only moves the location over the tokens, the layout and everything
+        // skipped while recovering has to be accounted for here
+        let skipped = &input[context.position()..pos];
+        context.set_location(skipped.location_after(context.location()));
         context.set_position(pos);
 
+        // The parser has no action for a `STOP` it doesn't expect, without any token it stops
+        // with an error instead
+        if unexpected_eof {
+            return Box::new(iter::empty());
+        }
         Box::new(iter::once(Token {
             kind: token,
             value,
-            location: Location {
-                start: Position::Position(context.position()),
-                end: Some(Position::Position(pos)),
-            },
+            location: value.location_span(context.location()),
         }))
     }
 }
 
-fn validate_and_get_next_token(
-    lexer: &mut crate::lexer::lex::Lexer,
-    expected_tokens: Vec<TokenKind>,
-    expected_tokens_str: String,
-) -> Result<TokenKind, CompilerError> {
-    match lexer.yylex() {
-        Ok(token) => {
-            if !expected_tokens.contains(&token) {
-                Err(CompilerError::Parser(format!(
-                    "unexpected token: {token} expected one of: {expected_tokens_str}"
-                )))
-            } else {
-                Ok(token)
+impl From<&Ctx<'_>> for Span {
+    fn from(ctx: &Ctx<'_>) -> Self {
+        let (line, column) = match ctx.location().start {
+            Position::LineBased(LineColumn { line, column }) => (line, column + 1),
+            Position::Position(_) => (0, 0),
+        };
+        Span::new(ctx.range(), line, column)
+    }
+}
+
+/// Whether the parser could be between two statements after reading `tokens`, only then can it
+/// continue with the next one after an error. In the middle of a statement anything it could
+/// take would be read as part of the broken one, so the rest of the input is skipped instead
+fn between_statements(tokens: &[(Range<usize>, TokenKind)]) -> bool {
+    let nesting = tokens.iter().fold(0, |nesting: i32, (_, kind)| match kind {
+        TokenKind::TokenParOpen | TokenKind::TokenSBOpen => nesting + 1,
+        TokenKind::TokenParClose | TokenKind::TokenSBClose => nesting - 1,
+        _ => nesting,
+    });
+    nesting == 0
+        && tokens.last().is_some_and(|(_, kind)| {
+            ends_operand(*kind) || matches!(kind, TokenKind::TokenCBOpen | TokenKind::TokenCBClose)
+        })
+}
+
+/// Token the parser is missing to finish the statement broken by a syntax error after reading
+/// `tokens`: an operand after an operator, else the closing bracket of the innermost open one or
+/// the `{` opening the body after a condition. `None` if the statement can't be finished that way
+fn missing_token(
+    tokens: &[(Range<usize>, TokenKind)],
+    expected: &[TokenKind],
+) -> Option<TokenKind> {
+    let mut closing = Vec::new();
+    for (_, kind) in tokens {
+        match kind {
+            TokenKind::TokenParOpen => closing.push(TokenKind::TokenParClose),
+            TokenKind::TokenSBOpen => closing.push(TokenKind::TokenSBClose),
+            TokenKind::TokenParClose | TokenKind::TokenSBClose => {
+                closing.pop();
             }
+            _ => {}
         }
-        Err(e) => {
-            if let crate::lexer::lex::Error::Unmatch = e {
-                Err(CompilerError::Lexer(format!(
-                    "unrecognized token {}",
-                    lexer.yytext()
-                )))
-            } else if !expected_tokens.contains(&TokenKind::STOP) {
-                Err(CompilerError::Parser(format!(
-                    "unexpected EOF: expected one of: {expected_tokens_str}"
-                )))
-            } else {
-                Ok(TokenKind::STOP)
-            }
+    }
+    let missing = match tokens.last() {
+        Some((_, kind)) if ends_operand(*kind) => {
+            closing.last().copied().unwrap_or(TokenKind::TokenCBOpen)
         }
+        _ => TokenKind::TokenIntLiteral,
+    };
+    expected.contains(&missing).then_some(missing)
+}
+
+/// Whether the text skipped before a token ends with a line break followed only by spaces
+fn starts_line(skipped: &str) -> bool {
+    skipped
+        .rsplit_once('\n')
+        .is_some_and(|(_, indent)| indent.trim().is_empty())
+}
+
+fn get_next_token(lexer: &mut crate::lexer::lex::Lexer) -> Result<TokenKind, CompilerError> {
+    match lexer.yylex() {
+        Ok(token) => Ok(token),
+        Err(crate::lexer::lex::Error::Unmatch) => Err(CompilerError::Lexer(format!(
+            "unrecognized token {}",
+            lexer.yycharat(0).unwrap_or_default()
+        ))),
+        Err(_) => Ok(TokenKind::STOP),
     }
 }
@@ -1,7 +1,8 @@
 use super::rules::{Context, TokenKind};
 use super::rules_lexer::Input;
-use crate::compiler::context::{CompilerContext, SymbolTableElement};
-use crate::compiler::error::{CompilerError, log_error_and_exit};
+use crate::compiler::ast::Span;
+use crate::compiler::context::CompilerContext;
+use crate::compiler::error::{CompilerError, Diagnostic};
 use rustemo::Token as RustemoToken;
 use std::fmt::Display;
 
@@ -17,6 +18,9 @@ pub type TokenFloat = String;
 /// Keyword "string"
 pub type TokenString = String;
 
+/// Keyword "bool"
+pub type TokenBool = String;
+
 /// Signed integer number
 #[derive(Debug, Clone)]
 pub struct TokenIntLiteral {
@@ -74,6 +78,12 @@ pub type TokenCBOpen = String;
 /// Keyword "}"
 pub type TokenCBClose = String;
 
+/// Keyword "["
+pub type TokenSBOpen = String;
+
+/// Keyword "]"
+pub type TokenSBClose = String;
+
 /// Keyword ":"
 pub type TokenColon = String;
 
@@ -137,6 +147,15 @@ pub type TokenIsZero = String;
 /// Keyword "convDate"
 pub type TokenConvDate = String;
 
+/// Keyword "function"
+pub type TokenFunction = String;
+
+/// Keyword "procedure"
+pub type TokenProcedure = String;
+
+/// Keyword "return"
+pub type TokenReturn = String;
+
 /// Date in "DD-MM-YYYY" format
 #[derive(Debug, Clone)]
 pub struct TokenDate {
@@ -156,6 +175,10 @@ impl Display for TokenDate {
 pub enum Program {
     /// `<Program> -> TokenId TokenParOpen TokenParClose TokenCBOpen <Body> TokenCBClose`
     ProgramWithMain(ProgramWithMain),
+    /// `<Program> -> <Functions> TokenId TokenParOpen TokenParClose TokenCBOpen <Body> TokenCBClose`
+    ProgramWithFunctions(ProgramWithFunctions),
+    /// `<Program> -> <Functions> <Body>`
+    ProgramFunctionsOnlyBody(ProgramFunctionsOnlyBody),
     /// `<Program> -> <Body>`
     ProgramOnlyBody(Body),
 }
@@ -173,7 +196,155 @@ pub struct ProgramWithMain {
     pub token_cbclose: TokenCBClose,
 }
 
+/// Struct representation of the rule
+///
+/// `<Program> -> <Functions> TokenId TokenParOpen TokenParClose TokenCBOpen <Body> TokenCBClose`
+#[derive(Debug, Clone)]
+pub struct ProgramWithFunctions {
+    pub functions: Functions,
+    pub token_id: TokenId,
+    pub token_par_open: TokenParOpen,
+    pub token_par_close: TokenParClose,
+    pub token_cbopen: TokenCBOpen,
+    pub body: Body,
+    pub token_cbclose: TokenCBClose,
+}
+
+/// Struct representation of the rule
+///
+/// `<Program> -> <Functions> <Body>`
+#[derive(Debug, Clone)]
+pub struct ProgramFunctionsOnlyBody {
+    pub functions: Functions,
+    pub body: Body,
+}
+
+/// Enum representing all the possible rules for the `<Functions>` non terminal
+#[derive(Debug, Clone)]
+pub enum Functions {
+    /// `<Functions> -> <Function>`
+    FunctionsSingle(Function),
+    /// `<Functions> -> <Function> <Functions>`
+    FunctionsRecursive(FunctionsRecursive),
+}
+
+/// Struct representation of the rule
+///
+/// `<Functions> -> <Function> <Functions>`
+#[derive(Debug, Clone)]
+pub struct FunctionsRecursive {
+    pub function: Function,
+    pub functions: Box<Functions>,
+}
+
+/// Struct representation of the rule
+///
+/// `<Function> -> <FunctionSignature> TokenCBOpen <Body> TokenCBClose`
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub function_signature: FunctionSignature,
+    pub token_cbopen: TokenCBOpen,
+    pub body: Body,
+    pub token_cbclose: TokenCBClose,
+}
+
+/// Enum representing all the possible rules for the `<FunctionSignature>` non terminal
+#[derive(Debug, Clone)]
+pub enum FunctionSignature {
+    /// `<FunctionSignature> -> TokenFunction <FunctionName> TokenParOpen TokenParClose TokenColon <DataType>`
+    FunctionNoParameters(FunctionNoParameters),
+    /// `<FunctionSignature> -> TokenFunction <FunctionName> TokenParOpen <Parameters> TokenParClose TokenColon <DataType>`
+    FunctionWithParameters(FunctionWithParameters),
+    /// `<FunctionSignature> -> TokenProcedure <FunctionName> TokenParOpen TokenParClose`
+    ProcedureNoParameters(ProcedureNoParameters),
+    /// `<FunctionSignature> -> TokenProcedure <FunctionName> TokenParOpen <Parameters> TokenParClose`
+    ProcedureWithParameters(ProcedureWithParameters),
+}
+
+/// Struct representation of the rule
+///
+/// `<FunctionSignature> -> TokenFunction <FunctionName> TokenParOpen TokenParClose TokenColon <DataType>`
+#[derive(Debug, Clone)]
+pub struct FunctionNoParameters {
+    pub token_function: TokenFunction,
+    pub function_name: FunctionName,
+    pub token_par_open: TokenParOpen,
+    pub token_par_close: TokenParClose,
+    pub token_colon: TokenColon,
+    pub data_type: DataType,
+}
+
+/// Struct representation of the rule
+///
+/// `<FunctionSignature> -> TokenFunction <FunctionName> TokenParOpen <Parameters> TokenParClose TokenColon <DataType>`
+#[derive(Debug, Clone)]
+pub struct FunctionWithParameters {
+    pub token_function: TokenFunction,
+    pub function_name: FunctionName,
+    pub token_par_open: TokenParOpen,
+    pub parameters: Parameters,
+    pub token_par_close: TokenParClose,
+    pub token_colon: TokenColon,
+    pub data_type: DataType,
+}
+
+/// Struct representation of the rule
+///
+/// `<FunctionSignature> -> TokenProcedure <FunctionName> TokenParOpen TokenParClose`
+#[derive(Debug, Clone)]
+pub struct ProcedureNoParameters {
+    pub token_procedure: TokenProcedure,
+    pub function_name: FunctionName,
+    pub token_par_open: TokenParOpen,
+    pub token_par_close: TokenParClose,
+}
+
+/// Struct representation of the rule
+///
+/// `<FunctionSignature> -> TokenProcedure <FunctionName> TokenParOpen <Parameters> TokenParClose`
+#[derive(Debug, Clone)]
+pub struct ProcedureWithParameters {
+    pub token_procedure: TokenProcedure,
+    pub function_name: FunctionName,
+    pub token_par_open: TokenParOpen,
+    pub parameters: Parameters,
+    pub token_par_close: TokenParClose,
+}
+
+/// Type declaration for the `<FunctionName>` non terminal, the identifier of the function
+pub type FunctionName = TokenId;
+
+/// Enum representing all the possible rules for the `<Parameters>` non terminal
+#[derive(Debug, Clone)]
+pub enum Parameters {
+    /// `<Parameters> -> <Parameter>`
+    ParametersSingle(Parameter),
+    /// `<Parameters> -> <Parameter> TokenComma <Parameters>`
+    ParametersRecursive(ParametersRecursive),
+}
+
+/// Struct representation of the rule
+///
+/// `<Parameters> -> <Parameter> TokenComma <Parameters>`
+#[derive(Debug, Clone)]
+pub struct ParametersRecursive {
+    pub parameter: Parameter,
+    pub token_comma: TokenComma,
+    pub parameters: Box<Parameters>,
+}
+
+/// Struct representation of the rule
+///
+/// `<Parameter> -> TokenId TokenColon <DataType>`
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    pub token_id: TokenId,
+    pub token_colon: TokenColon,
+    pub data_type: DataType,
+}
+
 /// Enum representing all the possible rules for the `<Body>` non terminal
+#[expect(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 pub enum BodyNoO {
     /// `<Body> -> TokenInit <InitBody> <Expressions>`
@@ -216,25 +387,46 @@ pub struct InitBody {
     pub token_cbclose: TokenCBClose,
 }
 
+/// Enum representing all the possible rules for the `<FunctionRead>` non terminal
+#[derive(Debug, Clone)]
+pub enum FunctionRead {
+    /// `<FunctionRead> -> TokenRead TokenParOpen TokenId TokenParClose`
+    FunctionReadCall(FunctionReadCall),
+    /// `<FunctionRead> -> TokenRead TokenParOpen TokenId <Index> TokenParClose`
+    FunctionReadIndexedCall(FunctionReadIndexedCall),
+}
+
 /// Struct representation of the rule
 ///
 /// `<FunctionRead> -> TokenRead TokenParOpen TokenId TokenParClose`
 #[derive(Debug, Clone)]
-pub struct FunctionRead {
+pub struct FunctionReadCall {
+    pub token_read: TokenRead,
+    pub token_par_open: TokenParOpen,
+    pub token_id: TokenId,
+    pub token_par_close: TokenParClose,
+}
+
+/// Struct representation of the rule
+///
+/// `<FunctionRead> -> TokenRead TokenParOpen TokenId <Index> TokenParClose`
+#[derive(Debug, Clone)]
+pub struct FunctionReadIndexedCall {
     pub token_read: TokenRead,
     pub token_par_open: TokenParOpen,
     pub token_id: TokenId,
+    pub index: Index,
     pub token_par_close: TokenParClose,
 }
 
 /// Struct representation of the rule
 ///
-/// `<FunctionWrite> -> TokenWrite TokenParOpen <SimpleExpression> TokenParClose`
+/// `<FunctionWrite> -> TokenWrite TokenParOpen <Conjunction> TokenParClose`
 #[derive(Debug, Clone)]
 pub struct FunctionWrite {
     pub token_write: TokenWrite,
     pub token_par_open: TokenParOpen,
-    pub simple_expression: SimpleExpression,
+    pub conjunction: Conjunction,
     pub token_par_close: TokenParClose,
 }
 
@@ -260,6 +452,55 @@ pub struct FunctionConvDate {
     pub token_par_close: TokenParClose,
 }
 
+/// Enum representing all the possible rules for the `<FunctionCall>` non terminal
+#[derive(Debug, Clone)]
+pub enum FunctionCall {
+    /// `<FunctionCall> -> TokenId TokenParOpen TokenParClose`
+    FunctionCallNoArguments(FunctionCallNoArguments),
+    /// `<FunctionCall> -> TokenId TokenParOpen <Arguments> TokenParClose`
+    FunctionCallWithArguments(FunctionCallWithArguments),
+}
+
+/// Struct representation of the rule
+///
+/// `<FunctionCall> -> TokenId TokenParOpen TokenParClose`
+#[derive(Debug, Clone)]
+pub struct FunctionCallNoArguments {
+    pub token_id: TokenId,
+    pub token_par_open: TokenParOpen,
+    pub token_par_close: TokenParClose,
+}
+
+/// Struct representation of the rule
+///
+/// `<FunctionCall> -> TokenId TokenParOpen <Arguments> TokenParClose`
+#[derive(Debug, Clone)]
+pub struct FunctionCallWithArguments {
+    pub token_id: TokenId,
+    pub token_par_open: TokenParOpen,
+    pub arguments: Box<Arguments>,
+    pub token_par_close: TokenParClose,
+}
+
+/// Enum representing all the possible rules for the `<Arguments>` non terminal
+#[derive(Debug, Clone)]
+pub enum Arguments {
+    /// `<Arguments> -> <Conjunction>`
+    ArgumentsSingle(Conjunction),
+    /// `<Arguments> -> <Arguments> TokenComma <Conjunction>`
+    ArgumentsRecursive(ArgumentsRecursive),
+}
+
+/// Struct representation of the rule
+///
+/// `<Arguments> -> <Arguments> TokenComma <Conjunction>`
+#[derive(Debug, Clone)]
+pub struct ArgumentsRecursive {
+    pub arguments: Box<Arguments>,
+    pub token_comma: TokenComma,
+    pub conjunction: Conjunction,
+}
+
 /// Enum representing all the possible rules for the `<VarDeclarations>` non terminal
 #[derive(Debug, Clone)]
 pub enum VarDeclarations {
@@ -283,6 +524,8 @@ pub struct VarDeclarationsRecursive {
 pub enum VarDeclaration {
     /// `<VarDeclaration> -> TokenId TokenColon <DataType>`
     VarDeclarationSingle(VarDeclarationSingle),
+    /// `<VarDeclaration> -> TokenId TokenColon <DataType> TokenSBOpen TokenIntLiteral TokenSBClose`
+    VarDeclarationArray(VarDeclarationArray),
     /// `<VarDeclaration> -> TokenId TokenComma <VarDeclaration>`
     VarDeclarationRecursive(VarDeclarationRecursive),
 }
@@ -295,6 +538,8 @@ pub struct VarDeclarationRecursive {
     pub token_id: TokenId,
     pub token_comma: TokenComma,
     pub var_declaration: Box<VarDeclaration>,
+    /// Position of `token_id` in the source
+    pub span: Span,
 }
 
 /// Struct representation of the rule
@@ -305,73 +550,121 @@ pub struct VarDeclarationSingle {
     pub token_id: TokenId,
     pub token_colon: TokenColon,
     pub data_type: DataType,
+    /// Position of `token_id` in the source
+    pub span: Span,
+}
+
+/// Struct representation of the rule
+///
+/// `<VarDeclaration> -> TokenId TokenColon <DataType> TokenSBOpen TokenIntLiteral TokenSBClose`
+#[derive(Debug, Clone)]
+pub struct VarDeclarationArray {
+    pub token_id: TokenId,
+    pub token_colon: TokenColon,
+    pub data_type: DataType,
+    pub token_sbopen: TokenSBOpen,
+    pub token_int_literal: TokenIntLiteral,
+    pub token_sbclose: TokenSBClose,
+    /// Position of `token_id` in the source
+    pub span: Span,
+}
+
+impl VarDeclarationArray {
+    /// Number of elements of the array, `None` if it doesn't have any
+    pub fn length(&self) -> Option<usize> {
+        usize::try_from(self.token_int_literal.parsed)
+            .ok()
+            .filter(|length| *length > 0)
+    }
+}
+
+impl VarDeclarations {
+    /// Adds every variable to the symbol table in the order they are written, so the ones
+    /// reported as redeclared are the ones that come later in the source
+    pub fn push_to_symbol_table(&self, compiler_context: &mut CompilerContext) {
+        let mut declarations = self;
+        loop {
+            match declarations {
+                Self::VarDeclarationsSingle(declaration) => {
+                    declaration.push_to_symbol_table(compiler_context);
+                    return;
+                }
+                Self::VarDeclarationsRecursive(recursive) => {
+                    recursive
+                        .var_declaration
+                        .push_to_symbol_table(compiler_context);
+                    declarations = &recursive.var_declarations;
+                }
+            }
+        }
+    }
 }
 
 impl VarDeclaration {
-    /// Recursively traverse `VarDeclaration` until we find the non recurive declaration with the DataType
-    /// During traversal, each variable is added to the symbol table
+    /// Traverses the `VarDeclaration` until the non recursive declaration with the DataType
+    /// and then adds each variable to the symbol table in the order they are written
     /// If a symbol already exists then we error out with a variable redeclaration error
-    pub fn push_to_symbol_table(&self, compiler_context: &mut CompilerContext) -> DataType {
-        match self {
-            Self::VarDeclarationSingle(single) => {
-                let symbol = SymbolTableElement {
-                    name: format!("_{}", single.token_id),
-                    original: single.token_id.clone(),
-                    data_type: single.data_type.clone().into(),
-                    value: None,
-                    length: Some(single.token_id.len()),
-                };
-
-                // If the symbol already exists this is a redeclaration
-                if compiler_context.symbol_exists(&symbol) {
-                    log_error_and_exit(
-                        0..0,
-                        CompilerError::Parser(format!(
-                            "Redeclaration of variable {}",
-                            single.token_id
-                        )),
-                        0,
-                        false,
-                        compiler_context,
-                    );
-                } else {
-                    compiler_context.push_to_symbol_table(symbol);
+    pub fn push_to_symbol_table(&self, compiler_context: &mut CompilerContext) {
+        let mut ids = Vec::new();
+        let mut declaration = self;
+        let (data_type, length) = loop {
+            match declaration {
+                Self::VarDeclarationSingle(single) => {
+                    ids.push((&single.token_id, &single.span));
+                    break (&single.data_type, None);
                 }
-                single.data_type.clone()
-            }
-            Self::VarDeclarationRecursive(recursive) => {
-                let data_type = recursive
-                    .var_declaration
-                    .push_to_symbol_table(compiler_context);
-                let symbol = SymbolTableElement {
-                    name: format!("_{}", recursive.token_id),
-                    original: recursive.token_id.clone(),
-                    data_type: data_type.clone().into(),
-                    value: None,
-                    length: Some(recursive.token_id.len()),
-                };
-
-                // If the symbol already exists this is a redeclaration
-                if compiler_context.symbol_exists(&symbol) {
-                    log_error_and_exit(
-                        0..0,
-                        CompilerError::Parser(format!(
-                            "Redeclaration of variable {}",
-                            recursive.token_id
-                        )),
-                        0,
-                        false,
-                        compiler_context,
-                    )
-                } else {
-                    compiler_context.push_to_symbol_table(symbol);
+                Self::VarDeclarationArray(array) => {
+                    ids.push((&array.token_id, &array.span));
+                    // Arrays without elements were already reported, they're declared with one
+                    // so their uses can still be checked
+                    break (&array.data_type, Some(array.length().unwrap_or(1)));
+                }
+                Self::VarDeclarationRecursive(recursive) => {
+                    ids.push((&recursive.token_id, &recursive.span));
+                    declaration = &recursive.var_declaration;
                 }
-                data_type
             }
+        };
+        for (token_id, span) in ids {
+            declare_variable(token_id, data_type, length, span.clone(), compiler_context);
         }
     }
 }
 
+/// Adds the variable to the symbol table erroring out if it was already declared
+fn declare_variable(
+    token_id: &TokenId,
+    data_type: &DataType,
+    length: Option<usize>,
+    span: Span,
+    compiler_context: &mut CompilerContext,
+) {
+    // Looked up first as declaring it again doesn't replace the symbol of the first one
+    let first_declaration = compiler_context.declaration_span(token_id);
+    let range = span.range.clone();
+    let declared = match length {
+        Some(length) => compiler_context.declare_array(token_id, data_type.clone(), length, span),
+        None => compiler_context.declare_variable(token_id, data_type.clone(), span),
+    };
+    // If the symbol already exists this is a redeclaration
+    if !declared {
+        let mut diagnostic = Diagnostic::new(
+            range,
+            CompilerError::Redeclaration(format!("variable {token_id}")),
+            true,
+        );
+        if let Some(first_declaration) = first_declaration {
+            diagnostic = diagnostic.with_label(
+                first_declaration.range,
+                format!("`{token_id}` first declared here"),
+            );
+        }
+        compiler_context.push_diagnostic(diagnostic.with_help(
+            "a variable can only be declared again in a nested block, where it shadows the outer one",
+        ));
+    }
+}
+
 /// Enum representing all the possible rules for the `<Expressions>` non terminal
 #[derive(Debug, Clone)]
 pub enum Expressions {
@@ -403,25 +696,51 @@ pub enum Statement {
     StatementWrite(FunctionWrite),
     /// `<Statement> -> <FunctionRead>`
     StatementRead(FunctionRead),
+    /// `<Statement> -> <FunctionCall>`
+    StatementCall(FunctionCall),
+    /// `<Statement> -> TokenReturn <Conjunction>`
+    StatementReturn(StatementReturn),
+}
+
+/// Struct representation of the rule
+///
+/// `<Statement> -> TokenReturn <Conjunction>`
+#[derive(Debug, Clone)]
+pub struct StatementReturn {
+    pub token_return: TokenReturn,
+    pub conjunction: Conjunction,
 }
 
 /// Enum representing all the possible rules for the `<Assignment>` non terminal
 #[derive(Debug, Clone)]
 pub enum Assignment {
-    /// `<Assignment> -> TokenId TokenAssign <SimpleExpression>`
+    /// `<Assignment> -> TokenId TokenAssign <Conjunction>`
     AssignmentExpression(AssignmentExpression),
+    /// `<Assignment> -> TokenId <Index> TokenAssign <Conjunction>`
+    AssignmentIndexed(AssignmentIndexed),
     /// `<Assignment> -> TokenId TokenAssign <FunctionConvDate>`
     AssignmentConvDate(ConvDate),
 }
 
 /// Struct representation of the rule
 ///
-/// `<Assignment> -> TokenId TokenAssign <SimpleExpression>`
+/// `<Assignment> -> TokenId TokenAssign <Conjunction>`
 #[derive(Debug, Clone)]
 pub struct AssignmentExpression {
     pub token_id: TokenId,
     pub token_assign: TokenAssign,
-    pub simple_expression: SimpleExpression,
+    pub conjunction: Conjunction,
+}
+
+/// Struct representation of the rule
+///
+/// `<Assignment> -> TokenId <Index> TokenAssign <Conjunction>`
+#[derive(Debug, Clone)]
+pub struct AssignmentIndexed {
+    pub token_id: TokenId,
+    pub index: Index,
+    pub token_assign: TokenAssign,
+    pub conjunction: Conjunction,
 }
 
 /// Struct representation of the rule
@@ -443,6 +762,8 @@ pub enum DataType {
     FloatType(TokenFloat),
     /// `<DataType> -> "string"`
     StringType(TokenString),
+    /// `<DataType> -> "bool"`
+    BoolType(TokenBool),
 }
 
 impl Display for DataType {
@@ -451,6 +772,7 @@ impl Display for DataType {
             DataType::IntType(_) => "VAR_INT",
             DataType::FloatType(_) => "VAR_FLOAT",
             DataType::StringType(_) => "VAR_STRING",
+            DataType::BoolType(_) => "VAR_BOOL",
         };
         write!(f, "{s}")
     }
@@ -458,14 +780,14 @@ impl Display for DataType {
 
 /// Struct representation of the rule
 ///
-/// `<WhileLoop> -> TokenWhile TokenParOpen <Conjunction> TokenParClose TokenCBOpen <Body> TokenCBClose`
+/// `<WhileLoop> -> TokenWhile TokenParOpen <Conjunction> TokenParClose <BlockOpen> <Body> TokenCBClose`
 #[derive(Debug, Clone)]
 pub struct WhileLoop {
     pub token_while: TokenWhile,
     pub token_par_open: TokenParOpen,
     pub conjunction: Conjunction,
     pub token_par_close: TokenParClose,
-    pub token_cbopen: TokenCBOpen,
+    pub block_open: BlockOpen,
     pub body: Box<Body>,
     pub token_cbclose: TokenCBClose,
 }
@@ -473,36 +795,36 @@ pub struct WhileLoop {
 /// Enum representing all the possible rules for the `<IfStatement>` non terminal
 #[derive(Debug, Clone)]
 pub enum IfStatement {
-    /// `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose TokenCBOpen <Body> TokenCBClose`
+    /// `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose <BlockOpen> <Body> TokenCBClose`
     IfStatementIfStatement(IfStatementIfStatement),
-    /// `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose TokenCBOpen <Body> TokenCBClose <ElseStatement>`
+    /// `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose <BlockOpen> <Body> TokenCBClose <ElseStatement>`
     IfStatementElseStatement(IfStatementElseStatement),
 }
 
 /// Struct representation of the rule
 ///
-/// `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose TokenCBOpen <Body> TokenCBClose`
+/// `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose <BlockOpen> <Body> TokenCBClose`
 #[derive(Debug, Clone)]
 pub struct IfStatementIfStatement {
     pub token_if: TokenIf,
     pub token_par_open: TokenParOpen,
     pub conjunction: Conjunction,
     pub token_par_close: TokenParClose,
-    pub token_cbopen: TokenCBOpen,
+    pub block_open: BlockOpen,
     pub body: Box<Body>,
     pub token_cbclose: TokenCBClose,
 }
 
 /// Struct representation of the rule
 ///
-/// `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose TokenCBOpen <Body> TokenCBClose <ElseStatement>`
+/// `<IfStatement> -> TokenIf TokenParOpen <Conjunction> TokenParClose <BlockOpen> <Body> TokenCBClose <ElseStatement>`
 #[derive(Debug, Clone)]
 pub struct IfStatementElseStatement {
     pub token_if: TokenIf,
     pub token_par_open: TokenParOpen,
     pub conjunction: Conjunction,
     pub token_par_close: TokenParClose,
-    pub token_cbopen: TokenCBOpen,
+    pub block_open: BlockOpen,
     pub body: Box<Body>,
     pub token_cbclose: TokenCBClose,
     pub else_statement: Box<ElseStatement>,
@@ -510,30 +832,29 @@ pub struct IfStatementElseStatement {
 
 /// Struct representation of the rule
 ///
-/// `<ElseStatement> -> TokenElse TokenCBOpen <Body> TokenCBClose`
+/// `<ElseStatement> -> TokenElse <BlockOpen> <Body> TokenCBClose`
 #[derive(Debug, Clone)]
 pub struct ElseStatement {
     pub token_else: TokenElse,
-    pub token_cbopen: TokenCBOpen,
+    pub block_open: BlockOpen,
     pub body: Box<Body>,
     pub token_cbclose: TokenCBClose,
 }
 
+/// Type declaration for the `<BlockOpen>` non terminal, the brace opening the scope of a block
+pub type BlockOpen = TokenCBOpen;
+
 /// Enum representing all the possible rules for the `<BooleanExpression>` non terminal
 #[derive(Debug, Clone)]
 pub enum BooleanExpression {
     /// `<BooleanExpression> -> <SimpleExpression> <Conjunction> <SimpleExpression>`
     BooleanExpressionSimpleExpression(BooleanExpressionSimpleExpression),
-    /// `<BooleanExpression> -> "true"`
-    BooleanExpressionTrue(TokenTrue),
-    /// `<BooleanExpression> -> "false"`
-    BooleanExpressionFalse(TokenFalse),
     /// `<BooleanExpression> -> <NotStatement>`
     BooleanExpressionNotStatement(NotStatement),
     /// `<BooleanExpression> -> <FunctionIsZero>`
     BooleanExpressionIsZero(FunctionIsZero),
-    /// `<BooleanExpression> -> TokenId`
-    BooleanExpressionTokenId(TokenId),
+    /// `<BooleanExpression> -> <SimpleExpression>`
+    BooleanExpressionValue(SimpleExpression),
 }
 
 /// Struct representation of the rule
@@ -575,8 +896,6 @@ pub struct BooleanExpressionChainNoO {
 pub enum SimpleExpression {
     /// `<SimpleExpression> -> <ArithmeticExpression>`
     SimpleExpressionArithmeticExpression(ArithmeticExpression),
-    /// `<SimpleExpression> -> TokenStringLiteral`
-    SimpleExpressionString(TokenStringLiteral),
 }
 
 /// Enum representing all the possible rules for the `<Conjunction>` non terminal
@@ -706,8 +1025,18 @@ pub struct TermDivFactor {
 pub enum Factor {
     /// `<Factor> -> TokenId`
     FactorId(TokenId),
+    /// `<Factor> -> TokenId <Index>`
+    FactorIndex(FactorIndex),
     /// `<Factor> -> <Number>`
     FactorNumber(Number),
+    /// `<Factor> -> TokenStringLiteral`
+    FactorString(TokenStringLiteral),
+    /// `<Factor> -> "true"`
+    FactorTrue(TokenTrue),
+    /// `<Factor> -> "false"`
+    FactorFalse(TokenFalse),
+    /// `<Factor> -> <FunctionCall>`
+    FactorCall(FunctionCall),
     /// `<Factor> -> TokenParOpen <ArithmeticExpression> TokenParClose`
     FactorParen(FactorParen),
 }
@@ -722,6 +1051,25 @@ pub struct FactorParen {
     pub token_par_close: TokenParClose,
 }
 
+/// Struct representation of the rule
+///
+/// `<Factor> -> TokenId <Index>`
+#[derive(Debug, Clone)]
+pub struct FactorIndex {
+    pub token_id: TokenId,
+    pub index: Index,
+}
+
+/// Struct representation of the rule
+///
+/// `<Index> -> TokenSBOpen <SimpleExpression> TokenSBClose`
+#[derive(Debug, Clone)]
+pub struct Index {
+    pub token_sbopen: TokenSBOpen,
+    pub simple_expression: Box<SimpleExpression>,
+    pub token_sbclose: TokenSBClose,
+}
+
 /// Enum used for all the non terminals used as markers for generating the intermediate representation
 #[derive(Debug)]
 pub enum Dummy {}
@@ -742,6 +1090,7 @@ impl Display for TokenKind {
             Self::TokenInt => "\"int\"",
             Self::TokenFloat => "\"float\"",
             Self::TokenString => "\"string\"",
+            Self::TokenBool => "\"bool\"",
             Self::TokenIntLiteral => "\"integer literal\"",
             Self::TokenFloatLiteral => "\"float literal\"",
             Self::TokenStringLiteral => "\"string literal\"",
@@ -755,6 +1104,8 @@ impl Display for TokenKind {
             Self::TokenParClose => "\")\"",
             Self::TokenCBOpen => "\"{\"",
             Self::TokenCBClose => "\"}\"",
+            Self::TokenSBOpen => "\"[\"",
+            Self::TokenSBClose => "\"]\"",
             Self::TokenColon => "\":\"",
             Self::TokenInit => "\"init\"",
             Self::TokenWhile => "\"while\"",
@@ -777,6 +1128,9 @@ impl Display for TokenKind {
             Self::TokenIsZero => "\"isZero\"",
             Self::TokenConvDate => "\"convDate\"",
             Self::TokenDate => "\"date\"",
+            Self::TokenFunction => "\"function\"",
+            Self::TokenProcedure => "\"procedure\"",
+            Self::TokenReturn => "\"return\"",
         };
         write!(f, "{text}")
     }
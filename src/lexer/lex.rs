@@ -1,5 +1,5 @@
 use crate::grammar::TokenKind;
-use crate::compiler::error::{CompilerError, log_error_and_exit};
+use crate::compiler::error::{CompilerError, log_error};
 use crate::compiler::context::CompilerContext;
 
 type CompilerCtx<'a> = &'a mut CompilerContext;
@@ -44,10 +44,10 @@ pub struct Lexer<'a> {
 }
 
 impl<'a> Lexer<'a> {
-    pub const ZZ_ROW: [usize; 110] = [0, 44, 88, 132, 176, 220, 264, 308, 352, 396, 440, 484, 528, 572, 616, 660, 704, 748, 792, 836, 792, 880, 924, 792, 792, 792, 792, 792, 792, 792, 968, 1012, 1056, 792, 44, 1100, 1144, 1188, 396, 1232, 1276, 1320, 1364, 1408, 396, 1452, 1496, 1540, 1584, 1628, 1672, 1716, 1760, 1804, 1848, 792, 792, 792, 792, 792, 1892, 1936, 1980, 396, 2024, 396, 2068, 2112, 2156, 396, 2200, 2244, 2288, 2332, 2376, 2420, 2464, 2464, 2508, 2552, 396, 2596, 396, 2640, 2684, 2728, 396, 2772, 2816, 396, 2860, 2904, 2948, 396, 396, 2992, 396, 396, 3036, 3080, 396, 396, 3124, 3168, 3212, 3256, 396, 3300, 3344, 792];
-    pub const ZZ_TRANS: [i32; 3388] = [1, 2, -1, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 9, 14, 9, 15, 9, 9, 9, 16, 9, 9, 17, 18, 19, 9, 20, 9, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 33, 34, -1, 35, 1, -1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 36, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 33, 33, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 33, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 33, -1, -1, -1, -1, -1, 9, 37, 9, 38, 9, 9, 9, 39, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 40, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 41, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 42, 9, 43, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 44, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 45, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 46, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 47, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 48, 9, 9, 49, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 50, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 51, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 52, -1, -1, -1, -1, -1, -1, 53, -1, 54, 52, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 54, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 55, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 56, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 57, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 58, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 59, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 1, 1, 1, -1, -1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 36, -1, 60, 36, -1, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 61, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, -1, -1, -1, -1, -1, 62, 9, 63, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 64, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 65, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 66, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 67, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 68, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 69, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 70, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 71, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 72, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 73, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 74, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 75, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 76, 77, -1, -1, 77, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 52, -1, -1, -1, -1, -1, -1, 78, 79, 54, 52, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 52, -1, -1, -1, -1, -1, -1, 54, -1, -1, 52, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 36, 36, 36, -1, -1, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 33, 60, 36, -1, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 61, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, -1, -1, -1, -1, -1, 9, 9, 80, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 81, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 82, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 83, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 84, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 85, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 86, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 87, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 88, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 89, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 90, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 76, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 52, -1, -1, -1, -1, -1, -1, 78, -1, 54, 52, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 91, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 92, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 93, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 94, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 95, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 96, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 97, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 98, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 99, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 100, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 101, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 102, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 103, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 104, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 105, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 106, 9, 9, 9, 9, 9, 9, 9, -1, -1, 9, -1, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 107, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 108, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 109, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1];
-    pub const ZZ_ATTR: [i32; 110] = [0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9, 0, 9, 1, 0, 9, 9, 9, 9, 9, 9, 9, 0, 1, 1, 9, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 9, 9, 9, 9, 9, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 0, 1, 0, 1, 0, 0, 9];
-    pub const ZZ_ACTION: [i32; 110] = [0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 0, 17, 18, 0, 19, 20, 21, 22, 23, 24, 25, 0, 26, 27, 28, 29, 0, 0, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 0, 45, 46, 47, 48, 49, 50, 51, 0, 0, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 0, 67, 0, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 0, 79, 80, 81, 82, 83, 84, 85, 0, 86, 87, 88, 0, 89, 0, 90, 0, 0, 91];
+    pub const ZZ_ROW: [usize; 136] = [0, 49, 98, 147, 196, 245, 294, 343, 392, 441, 490, 539, 588, 637, 686, 735, 784, 833, 882, 931, 980, 931, 1029, 1078, 1127, 931, 931, 931, 931, 931, 931, 931, 931, 931, 1176, 1225, 1274, 931, 1323, 1372, 392, 1421, 1470, 1519, 1568, 1617, 1666, 392, 1715, 1764, 1813, 1862, 1911, 1960, 2009, 2058, 2107, 2156, 2205, 2254, 2303, 931, 931, 931, 931, 931, 931, 2352, 2401, 2450, 392, 2499, 392, 2548, 2597, 2646, 2695, 392, 2744, 2793, 2842, 2891, 2940, 2989, 3038, 3087, 3136, 3185, 3185, 3234, 3283, 392, 3332, 392, 3381, 3430, 3479, 392, 3528, 3577, 392, 3626, 3675, 392, 3724, 3773, 3822, 3871, 392, 392, 3920, 3969, 4018, 392, 392, 4067, 4116, 4165, 392, 4214, 392, 392, 4263, 4312, 4361, 4410, 4459, 4508, 4557, 392, 392, 4606, 4655, 392, 4704, 931];
+    pub const ZZ_TRANS: [i32; 4753] = [-1, 1, -1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 8, 14, 8, 15, 8, 8, 8, 16, 8, 8, 17, 18, 19, 20, 8, 21, 8, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 37, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 38, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 37, 37, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 37, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 37, -1, -1, -1, -1, -1, 8, 39, 8, 40, 8, 8, 8, 8, 41, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 42, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 43, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 44, 8, 45, 8, 8, 8, 8, 8, 8, 8, 46, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 47, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 48, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 49, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 50, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 51, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 52, 8, 8, 53, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 54, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 55, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 56, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 57, -1, -1, -1, -1, -1, -1, -1, 58, -1, 59, 57, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 59, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 22, -1, 60, 22, -1, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 61, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 62, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 63, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 64, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 65, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 66, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 38, -1, 67, 38, -1, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 68, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, -1, -1, -1, -1, -1, 69, 8, 70, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 71, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 72, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 73, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 74, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 75, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 76, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 77, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 78, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 79, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 80, 8, 8, 8, 81, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 82, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 83, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 84, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 85, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 86, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 87, 88, -1, -1, 88, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 57, -1, -1, -1, -1, -1, -1, -1, 89, 90, 59, 57, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 57, -1, -1, -1, -1, -1, -1, -1, 59, -1, -1, 57, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 22, 22, 22, -1, -1, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 38, 38, 38, -1, -1, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 37, 67, 38, -1, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 68, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, -1, -1, -1, -1, -1, 8, 8, 91, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 92, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 93, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 94, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 95, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 96, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 97, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 98, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 99, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 100, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 101, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 102, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 103, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 104, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 105, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 87, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 57, -1, -1, -1, -1, -1, -1, -1, 89, -1, 59, 57, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 106, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 107, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 108, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 109, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 110, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 111, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 112, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 113, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 114, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 115, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 116, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 117, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 118, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 119, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 120, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 121, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 122, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 123, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 124, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 125, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 126, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 127, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 128, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 129, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 130, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 131, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 132, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 133, 8, 8, 8, 8, 8, 8, 8, 8, -1, -1, 8, -1, 8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 134, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 135, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1];
+    pub const ZZ_ATTR: [i32; 136] = [0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9, 0, 9, 0, 1, 0, 9, 9, 9, 9, 9, 9, 9, 9, 9, 0, 1, 1, 9, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 0, 9, 9, 9, 9, 9, 9, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1, 0, 1, 0, 9];
+    pub const ZZ_ACTION: [i32; 136] = [0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 0, 19, 0, 20, 0, 21, 22, 23, 24, 25, 26, 27, 28, 29, 0, 30, 31, 32, 0, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 0, 51, 52, 0, 53, 54, 55, 56, 57, 58, 0, 0, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 0, 78, 0, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 0, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 0, 104, 105, 106, 107, 108, 109, 0, 110, 111, 112, 0, 113, 114, 115, 0, 116, 0, 117];
     pub const ZZ_LEXSTATE: [i32; 2] = [0, 0];
     pub const YYINITIAL: usize = 0;
 
@@ -59,91 +59,94 @@ impl<'a> Lexer<'a> {
         let mut cmap: Vec<usize> = Vec::with_capacity(256);
         cmap.resize(256, 0);
         let mut cmap2: HashMap<usize, usize> = HashMap::new();
-        cmap[9] = 43;
+        cmap[9] = 48;
         cmap[10] = 4;
         cmap[11] = 4;
         cmap[12] = 4;
         cmap[13] = 3;
-        cmap[32] = 42;
-        cmap[33] = 39;
+        cmap[32] = 47;
+        cmap[33] = 44;
+        cmap[34] = 32;
         cmap[35] = 1;
-        cmap[40] = 34;
-        cmap[41] = 35;
-        cmap[42] = 32;
-        cmap[43] = 28;
-        cmap[44] = 38;
-        cmap[45] = 25;
-        cmap[46] = 26;
-        cmap[47] = 33;
-        cmap[48] = 24;
-        cmap[49] = 24;
-        cmap[50] = 24;
-        cmap[51] = 24;
-        cmap[52] = 24;
-        cmap[53] = 24;
-        cmap[54] = 24;
-        cmap[55] = 24;
-        cmap[56] = 24;
-        cmap[57] = 24;
-        cmap[58] = 30;
-        cmap[60] = 40;
-        cmap[61] = 31;
-        cmap[62] = 41;
-        cmap[65] = 29;
-        cmap[66] = 29;
-        cmap[67] = 29;
-        cmap[68] = 23;
-        cmap[69] = 27;
-        cmap[70] = 29;
-        cmap[71] = 29;
-        cmap[72] = 29;
-        cmap[73] = 29;
-        cmap[74] = 29;
-        cmap[75] = 29;
-        cmap[76] = 29;
-        cmap[77] = 29;
-        cmap[78] = 29;
-        cmap[79] = 29;
-        cmap[80] = 29;
-        cmap[81] = 29;
-        cmap[82] = 29;
-        cmap[83] = 29;
-        cmap[84] = 29;
-        cmap[85] = 29;
-        cmap[86] = 29;
-        cmap[87] = 29;
-        cmap[88] = 29;
-        cmap[89] = 29;
-        cmap[90] = 20;
+        cmap[40] = 37;
+        cmap[41] = 38;
+        cmap[42] = 35;
+        cmap[43] = 30;
+        cmap[44] = 43;
+        cmap[45] = 27;
+        cmap[46] = 28;
+        cmap[47] = 36;
+        cmap[48] = 26;
+        cmap[49] = 26;
+        cmap[50] = 26;
+        cmap[51] = 26;
+        cmap[52] = 26;
+        cmap[53] = 26;
+        cmap[54] = 26;
+        cmap[55] = 26;
+        cmap[56] = 26;
+        cmap[57] = 26;
+        cmap[58] = 33;
+        cmap[60] = 45;
+        cmap[61] = 34;
+        cmap[62] = 46;
+        cmap[65] = 31;
+        cmap[66] = 31;
+        cmap[67] = 31;
+        cmap[68] = 24;
+        cmap[69] = 29;
+        cmap[70] = 31;
+        cmap[71] = 31;
+        cmap[72] = 31;
+        cmap[73] = 31;
+        cmap[74] = 31;
+        cmap[75] = 31;
+        cmap[76] = 31;
+        cmap[77] = 31;
+        cmap[78] = 31;
+        cmap[79] = 31;
+        cmap[80] = 31;
+        cmap[81] = 31;
+        cmap[82] = 31;
+        cmap[83] = 31;
+        cmap[84] = 31;
+        cmap[85] = 31;
+        cmap[86] = 31;
+        cmap[87] = 31;
+        cmap[88] = 31;
+        cmap[89] = 31;
+        cmap[90] = 21;
+        cmap[91] = 41;
         cmap[92] = 2;
+        cmap[93] = 42;
         cmap[97] = 11;
-        cmap[98] = 29;
-        cmap[99] = 21;
-        cmap[100] = 19;
-        cmap[101] = 17;
+        cmap[98] = 12;
+        cmap[99] = 22;
+        cmap[100] = 20;
+        cmap[101] = 18;
         cmap[102] = 8;
-        cmap[103] = 14;
-        cmap[104] = 16;
+        cmap[103] = 15;
+        cmap[104] = 17;
         cmap[105] = 5;
-        cmap[106] = 29;
-        cmap[107] = 29;
+        cmap[106] = 31;
+        cmap[107] = 31;
         cmap[108] = 9;
-        cmap[109] = 29;
+        cmap[109] = 31;
         cmap[110] = 6;
         cmap[111] = 10;
-        cmap[112] = 29;
-        cmap[113] = 29;
-        cmap[114] = 13;
-        cmap[115] = 12;
+        cmap[112] = 25;
+        cmap[113] = 31;
+        cmap[114] = 14;
+        cmap[115] = 13;
         cmap[116] = 7;
-        cmap[117] = 18;
-        cmap[118] = 22;
-        cmap[119] = 15;
-        cmap[120] = 29;
-        cmap[121] = 29;
-        cmap[122] = 29;
-        cmap[123] = 36;
-        cmap[125] = 37;
+        cmap[117] = 19;
+        cmap[118] = 23;
+        cmap[119] = 16;
+        cmap[120] = 31;
+        cmap[121] = 31;
+        cmap[122] = 31;
+        cmap[123] = 39;
+        cmap[125] = 40;
         cmap[133] = 4;
         cmap2.insert(8232, 4);
         cmap2.insert(8233, 4);
@@ -338,36 +341,40 @@ impl<'a> Lexer<'a> {
                 };
                 match action {
                     1 => {  }
-                    92 => { /* nothing */ }
+                    118 => { /* nothing */ }
                     2 => {  }
-                    93 => { /* nothing */ }
+                    119 => { /* nothing */ }
                     3 => { return Ok(TokenKind::TokenId); }
-                    94 => { /* nothing */ }
+                    120 => { /* nothing */ }
                     4 => { return Ok(TokenKind::TokenId); }
-                    95 => { /* nothing */ }
+                    121 => { /* nothing */ }
                     5 => { return Ok(TokenKind::TokenId); }
-                    96 => { /* nothing */ }
+                    122 => { /* nothing */ }
                     6 => { return Ok(TokenKind::TokenId); }
-                    97 => { /* nothing */ }
+                    123 => { /* nothing */ }
                     7 => { return Ok(TokenKind::TokenId); }
-                    98 => { /* nothing */ }
+                    124 => { /* nothing */ }
                     8 => { return Ok(TokenKind::TokenId); }
-                    99 => { /* nothing */ }
+                    125 => { /* nothing */ }
                     9 => { return Ok(TokenKind::TokenId); }
-                    100 => { /* nothing */ }
+                    126 => { /* nothing */ }
                     10 => { return Ok(TokenKind::TokenId); }
-                    101 => { /* nothing */ }
+                    127 => { /* nothing */ }
                     11 => { return Ok(TokenKind::TokenId); }
-                    102 => { /* nothing */ }
+                    128 => { /* nothing */ }
                     12 => { return Ok(TokenKind::TokenId); }
-                    103 => { /* nothing */ }
+                    129 => { /* nothing */ }
                     13 => { return Ok(TokenKind::TokenId); }
-                    104 => { /* nothing */ }
+                    130 => { /* nothing */ }
                     14 => { return Ok(TokenKind::TokenId); }
-                    105 => { /* nothing */ }
-                    15 => { {
+                    131 => { /* nothing */ }
+                    15 => { return Ok(TokenKind::TokenId); }
+                    132 => { /* nothing */ }
+                    16 => { return Ok(TokenKind::TokenId); }
+                    133 => { /* nothing */ }
+                    17 => { {
                                                                     if let Err(e) = self.yytext().parse::<i64>() {
-                                                                        log_error_and_exit(
+                                                                        log_error(
                                                                             self.yytextpos(),
                                                                             CompilerError::Lexer(format!("Invalid integer literal {e}")),
                                                                             self.offset,
@@ -377,79 +384,76 @@ impl<'a> Lexer<'a> {
                                                                     }
                                                                     return Ok(TokenKind::TokenIntLiteral);
                                                                 } }
-                    106 => { /* nothing */ }
-                    16 => { return Ok(TokenKind::TokenSub); }
-                    107 => { /* nothing */ }
-                    17 => { return Ok(TokenKind::TokenSum); }
-                    108 => { /* nothing */ }
-                    18 => { return Ok(TokenKind::TokenColon); }
-                    109 => { /* nothing */ }
-                    19 => { return Ok(TokenKind::TokenMul); }
-                    110 => { /* nothing */ }
-                    20 => { return Ok(TokenKind::TokenDiv); }
-                    111 => { /* nothing */ }
-                    21 => { return Ok(TokenKind::TokenParOpen); }
-                    112 => { /* nothing */ }
-                    22 => { return Ok(TokenKind::TokenParClose); }
-                    113 => { /* nothing */ }
-                    23 => { return Ok(TokenKind::TokenCBOpen); }
-                    114 => { /* nothing */ }
-                    24 => { return Ok(TokenKind::TokenCBClose); }
-                    115 => { /* nothing */ }
-                    25 => { return Ok(TokenKind::TokenComma); }
-                    116 => { /* nothing */ }
-                    26 => { return Ok(TokenKind::TokenLess); }
-                    117 => { /* nothing */ }
-                    27 => { return Ok(TokenKind::TokenGreater); }
-                    118 => { /* nothing */ }
-                    28 => {  }
-                    119 => { /* nothing */ }
-                    29 => { {
-                                                                    if self.yytext().len() > 256 {
-                                                                        log_error_and_exit(
-                                                                            self.yytextpos(),
-                                                                            CompilerError::Lexer(format!("Invalid string length {}", self.yytext().len())),
-                                                                            self.offset,
-                                                                            true,
-                                                                            self.ctx
-                                                                        )
-                                                                    }
-                                                                    return Ok(TokenKind::TokenStringLiteral);
-                                                                } }
-                    120 => { /* nothing */ }
-                    30 => { return Ok(TokenKind::TokenId); }
-                    121 => { /* nothing */ }
-                    31 => { return Ok(TokenKind::TokenIf); }
-                    122 => { /* nothing */ }
-                    32 => { return Ok(TokenKind::TokenId); }
-                    123 => { /* nothing */ }
+                    134 => { /* nothing */ }
+                    18 => { return Ok(TokenKind::TokenSub); }
+                    135 => { /* nothing */ }
+                    19 => { return Ok(TokenKind::TokenSum); }
+                    136 => { /* nothing */ }
+                    20 => { return Ok(TokenKind::TokenColon); }
+                    137 => { /* nothing */ }
+                    21 => { return Ok(TokenKind::TokenMul); }
+                    138 => { /* nothing */ }
+                    22 => { return Ok(TokenKind::TokenDiv); }
+                    139 => { /* nothing */ }
+                    23 => { return Ok(TokenKind::TokenParOpen); }
+                    140 => { /* nothing */ }
+                    24 => { return Ok(TokenKind::TokenParClose); }
+                    141 => { /* nothing */ }
+                    25 => { return Ok(TokenKind::TokenCBOpen); }
+                    142 => { /* nothing */ }
+                    26 => { return Ok(TokenKind::TokenCBClose); }
+                    143 => { /* nothing */ }
+                    27 => { return Ok(TokenKind::TokenSBOpen); }
+                    144 => { /* nothing */ }
+                    28 => { return Ok(TokenKind::TokenSBClose); }
+                    145 => { /* nothing */ }
+                    29 => { return Ok(TokenKind::TokenComma); }
+                    146 => { /* nothing */ }
+                    30 => { return Ok(TokenKind::TokenLess); }
+                    147 => { /* nothing */ }
+                    31 => { return Ok(TokenKind::TokenGreater); }
+                    148 => { /* nothing */ }
+                    32 => {  }
+                    149 => { /* nothing */ }
                     33 => { return Ok(TokenKind::TokenId); }
-                    124 => { /* nothing */ }
-                    34 => { return Ok(TokenKind::TokenId); }
-                    125 => { /* nothing */ }
+                    150 => { /* nothing */ }
+                    34 => { return Ok(TokenKind::TokenIf); }
+                    151 => { /* nothing */ }
                     35 => { return Ok(TokenKind::TokenId); }
-                    126 => { /* nothing */ }
+                    152 => { /* nothing */ }
                     36 => { return Ok(TokenKind::TokenId); }
-                    127 => { /* nothing */ }
-                    37 => { return Ok(TokenKind::TokenOr); }
-                    128 => { /* nothing */ }
+                    153 => { /* nothing */ }
+                    37 => { return Ok(TokenKind::TokenId); }
+                    154 => { /* nothing */ }
                     38 => { return Ok(TokenKind::TokenId); }
-                    129 => { /* nothing */ }
+                    155 => { /* nothing */ }
                     39 => { return Ok(TokenKind::TokenId); }
-                    130 => { /* nothing */ }
+                    156 => { /* nothing */ }
                     40 => { return Ok(TokenKind::TokenId); }
-                    131 => { /* nothing */ }
-                    41 => { return Ok(TokenKind::TokenId); }
-                    132 => { /* nothing */ }
+                    157 => { /* nothing */ }
+                    41 => { return Ok(TokenKind::TokenOr); }
+                    158 => { /* nothing */ }
                     42 => { return Ok(TokenKind::TokenId); }
-                    133 => { /* nothing */ }
+                    159 => { /* nothing */ }
                     43 => { return Ok(TokenKind::TokenId); }
-                    134 => { /* nothing */ }
+                    160 => { /* nothing */ }
                     44 => { return Ok(TokenKind::TokenId); }
-                    135 => { /* nothing */ }
-                    45 => { {
+                    161 => { /* nothing */ }
+                    45 => { return Ok(TokenKind::TokenId); }
+                    162 => { /* nothing */ }
+                    46 => { return Ok(TokenKind::TokenId); }
+                    163 => { /* nothing */ }
+                    47 => { return Ok(TokenKind::TokenId); }
+                    164 => { /* nothing */ }
+                    48 => { return Ok(TokenKind::TokenId); }
+                    165 => { /* nothing */ }
+                    49 => { return Ok(TokenKind::TokenId); }
+                    166 => { /* nothing */ }
+                    50 => { return Ok(TokenKind::TokenId); }
+                    167 => { /* nothing */ }
+                    51 => { {
                                                                     if let Err(e) = self.yytext().parse::<i64>() {
-                                                                        log_error_and_exit(
+                                                                        log_error(
                                                                             self.yytextpos(),
                                                                             CompilerError::Lexer(format!("Invalid integer literal {e}")),
                                                                             self.offset,
@@ -459,11 +463,11 @@ impl<'a> Lexer<'a> {
                                                                     }
                                                                     return Ok(TokenKind::TokenIntLiteral);
                                                                 } }
-                    136 => { /* nothing */ }
-                    46 => { {
+                    168 => { /* nothing */ }
+                    52 => { {
                                                                     match self.yytext().parse::<f32>() {
                                                                         Err(e) => {
-                                                                            log_error_and_exit(
+                                                                            log_error(
                                                                                 self.yytextpos(),
                                                                                 CompilerError::Lexer(format!("Invalid float literal {e}")),
                                                                                 self.offset,
@@ -473,7 +477,7 @@ impl<'a> Lexer<'a> {
                                                                         }
                                                                         Ok(value) => {
                                                                             if !value.is_normal() {
-                                                                                log_error_and_exit(
+                                                                                log_error(
                                                                                     self.yytextpos(),
                                                                                     CompilerError::Lexer(format!("Invalid float literal")),
                                                                                     self.offset,
@@ -485,49 +489,73 @@ impl<'a> Lexer<'a> {
                                                                     };
                                                                     return Ok(TokenKind::TokenFloatLiteral);
                                                                 } }
-                    137 => { /* nothing */ }
-                    47 => { return Ok(TokenKind::TokenAssign); }
-                    138 => { /* nothing */ }
-                    48 => { return Ok(TokenKind::TokenEqual); }
-                    139 => { /* nothing */ }
-                    49 => { return Ok(TokenKind::TokenNotEqual); }
-                    140 => { /* nothing */ }
-                    50 => { return Ok(TokenKind::TokenLessEqual); }
-                    141 => { /* nothing */ }
-                    51 => { return Ok(TokenKind::TokenGreaterEqual); }
-                    142 => { /* nothing */ }
-                    52 => { return Ok(TokenKind::TokenId); }
-                    143 => { /* nothing */ }
-                    53 => { return Ok(TokenKind::TokenInt); }
-                    144 => { /* nothing */ }
-                    54 => { return Ok(TokenKind::TokenId); }
-                    145 => { /* nothing */ }
-                    55 => { return Ok(TokenKind::TokenNot); }
-                    146 => { /* nothing */ }
-                    56 => { return Ok(TokenKind::TokenId); }
-                    147 => { /* nothing */ }
-                    57 => { return Ok(TokenKind::TokenId); }
-                    148 => { /* nothing */ }
-                    58 => { return Ok(TokenKind::TokenId); }
-                    149 => { /* nothing */ }
-                    59 => { return Ok(TokenKind::TokenAnd); }
-                    150 => { /* nothing */ }
-                    60 => { return Ok(TokenKind::TokenId); }
-                    151 => { /* nothing */ }
+                    169 => { /* nothing */ }
+                    53 => { {
+                                                                    // rflex drops the character right before an escape inside [...], so each escape
+                                                                    // gets a throwaway # before it. Strings can't hold # anyway, so the class is still
+                                                                    // every character but ", #, \, \r and \n
+                                                                    if self.yytext().len() > 256 {
+                                                                        log_error(
+                                                                            self.yytextpos(),
+                                                                            CompilerError::Lexer(format!("Invalid string length {}", self.yytext().len())),
+                                                                            self.offset,
+                                                                            true,
+                                                                            self.ctx
+                                                                        )
+                                                                    }
+                                                                    return Ok(TokenKind::TokenStringLiteral);
+                                                                } }
+                    170 => { /* nothing */ }
+                    54 => { return Ok(TokenKind::TokenAssign); }
+                    171 => { /* nothing */ }
+                    55 => { return Ok(TokenKind::TokenEqual); }
+                    172 => { /* nothing */ }
+                    56 => { return Ok(TokenKind::TokenNotEqual); }
+                    173 => { /* nothing */ }
+                    57 => { return Ok(TokenKind::TokenLessEqual); }
+                    174 => { /* nothing */ }
+                    58 => { return Ok(TokenKind::TokenGreaterEqual); }
+                    175 => { /* nothing */ }
+                    59 => { return Ok(TokenKind::TokenId); }
+                    176 => { /* nothing */ }
+                    60 => { return Ok(TokenKind::TokenInt); }
+                    177 => { /* nothing */ }
                     61 => { return Ok(TokenKind::TokenId); }
-                    152 => { /* nothing */ }
-                    62 => { return Ok(TokenKind::TokenId); }
-                    153 => { /* nothing */ }
+                    178 => { /* nothing */ }
+                    62 => { return Ok(TokenKind::TokenNot); }
+                    179 => { /* nothing */ }
                     63 => { return Ok(TokenKind::TokenId); }
-                    154 => { /* nothing */ }
+                    180 => { /* nothing */ }
                     64 => { return Ok(TokenKind::TokenId); }
-                    155 => { /* nothing */ }
+                    181 => { /* nothing */ }
                     65 => { return Ok(TokenKind::TokenId); }
-                    156 => { /* nothing */ }
-                    66 => { {
+                    182 => { /* nothing */ }
+                    66 => { return Ok(TokenKind::TokenId); }
+                    183 => { /* nothing */ }
+                    67 => { return Ok(TokenKind::TokenAnd); }
+                    184 => { /* nothing */ }
+                    68 => { return Ok(TokenKind::TokenId); }
+                    185 => { /* nothing */ }
+                    69 => { return Ok(TokenKind::TokenId); }
+                    186 => { /* nothing */ }
+                    70 => { return Ok(TokenKind::TokenId); }
+                    187 => { /* nothing */ }
+                    71 => { return Ok(TokenKind::TokenId); }
+                    188 => { /* nothing */ }
+                    72 => { return Ok(TokenKind::TokenId); }
+                    189 => { /* nothing */ }
+                    73 => { return Ok(TokenKind::TokenId); }
+                    190 => { /* nothing */ }
+                    74 => { return Ok(TokenKind::TokenId); }
+                    191 => { /* nothing */ }
+                    75 => { return Ok(TokenKind::TokenId); }
+                    192 => { /* nothing */ }
+                    76 => { return Ok(TokenKind::TokenId); }
+                    193 => { /* nothing */ }
+                    77 => { {
                                                                     match self.yytext().parse::<f32>() {
                                                                         Err(e) => {
-                                                                            log_error_and_exit(
+                                                                            log_error(
                                                                                 self.yytextpos(),
                                                                                 CompilerError::Lexer(format!("Invalid float literal {e}")),
                                                                                 self.offset,
@@ -537,7 +565,7 @@ impl<'a> Lexer<'a> {
                                                                         }
                                                                         Ok(value) => {
                                                                             if !value.is_normal() {
-                                                                                log_error_and_exit(
+                                                                                log_error(
                                                                                     self.yytextpos(),
                                                                                     CompilerError::Lexer(format!("Invalid float literal")),
                                                                                     self.offset,
@@ -549,10 +577,10 @@ impl<'a> Lexer<'a> {
                                                                     };
                                                                     return Ok(TokenKind::TokenFloatLiteral);
                                                                 } }
-                    157 => { /* nothing */ }
-                    67 => { {
+                    194 => { /* nothing */ }
+                    78 => { {
                                                                     if let Err(e) = self.yytext().parse::<i64>() {
-                                                                        log_error_and_exit(
+                                                                        log_error(
                                                                             self.yytextpos(),
                                                                             CompilerError::Lexer(format!("Invalid integer literal {e}")),
                                                                             self.offset,
@@ -562,55 +590,85 @@ impl<'a> Lexer<'a> {
                                                                     }
                                                                     return Ok(TokenKind::TokenIntLiteral);
                                                                 } }
-                    158 => { /* nothing */ }
-                    68 => { return Ok(TokenKind::TokenInit); }
-                    159 => { /* nothing */ }
-                    69 => { return Ok(TokenKind::TokenId); }
-                    160 => { /* nothing */ }
-                    70 => { return Ok(TokenKind::TokenTrue); }
-                    161 => { /* nothing */ }
-                    71 => { return Ok(TokenKind::TokenId); }
-                    162 => { /* nothing */ }
-                    72 => { return Ok(TokenKind::TokenId); }
-                    163 => { /* nothing */ }
-                    73 => { return Ok(TokenKind::TokenId); }
-                    164 => { /* nothing */ }
-                    74 => { return Ok(TokenKind::TokenRead); }
-                    165 => { /* nothing */ }
-                    75 => { return Ok(TokenKind::TokenId); }
-                    166 => { /* nothing */ }
-                    76 => { return Ok(TokenKind::TokenId); }
-                    167 => { /* nothing */ }
-                    77 => { return Ok(TokenKind::TokenElse); }
-                    168 => { /* nothing */ }
-                    78 => { return Ok(TokenKind::TokenId); }
-                    169 => { /* nothing */ }
-                    79 => { return Ok(TokenKind::TokenId); }
-                    170 => { /* nothing */ }
-                    80 => { return Ok(TokenKind::TokenFloat); }
-                    171 => { /* nothing */ }
-                    81 => { return Ok(TokenKind::TokenFalse); }
-                    172 => { /* nothing */ }
+                    195 => { /* nothing */ }
+                    79 => { return Ok(TokenKind::TokenInit); }
+                    196 => { /* nothing */ }
+                    80 => { return Ok(TokenKind::TokenId); }
+                    197 => { /* nothing */ }
+                    81 => { return Ok(TokenKind::TokenTrue); }
+                    198 => { /* nothing */ }
                     82 => { return Ok(TokenKind::TokenId); }
-                    173 => { /* nothing */ }
-                    83 => { return Ok(TokenKind::TokenWrite); }
-                    174 => { /* nothing */ }
-                    84 => { return Ok(TokenKind::TokenWhile); }
-                    175 => { /* nothing */ }
-                    85 => { return Ok(TokenKind::TokenId); }
-                    176 => { /* nothing */ }
-                    86 => { return Ok(TokenKind::TokenIsZero); }
-                    177 => { /* nothing */ }
-                    87 => { return Ok(TokenKind::TokenString); }
-                    178 => { /* nothing */ }
-                    88 => { return Ok(TokenKind::TokenId); }
-                    179 => { /* nothing */ }
+                    199 => { /* nothing */ }
+                    83 => { return Ok(TokenKind::TokenId); }
+                    200 => { /* nothing */ }
+                    84 => { return Ok(TokenKind::TokenId); }
+                    201 => { /* nothing */ }
+                    85 => { return Ok(TokenKind::TokenBool); }
+                    202 => { /* nothing */ }
+                    86 => { return Ok(TokenKind::TokenId); }
+                    203 => { /* nothing */ }
+                    87 => { return Ok(TokenKind::TokenId); }
+                    204 => { /* nothing */ }
+                    88 => { return Ok(TokenKind::TokenRead); }
+                    205 => { /* nothing */ }
                     89 => { return Ok(TokenKind::TokenId); }
-                    180 => { /* nothing */ }
-                    90 => { return Ok(TokenKind::TokenConvDate); }
-                    181 => { /* nothing */ }
-                    91 => { return Ok(TokenKind::TokenDate); }
-                    182 => { /* nothing */ }
+                    206 => { /* nothing */ }
+                    90 => { return Ok(TokenKind::TokenId); }
+                    207 => { /* nothing */ }
+                    91 => { return Ok(TokenKind::TokenElse); }
+                    208 => { /* nothing */ }
+                    92 => { return Ok(TokenKind::TokenId); }
+                    209 => { /* nothing */ }
+                    93 => { return Ok(TokenKind::TokenId); }
+                    210 => { /* nothing */ }
+                    94 => { return Ok(TokenKind::TokenId); }
+                    211 => { /* nothing */ }
+                    95 => { return Ok(TokenKind::TokenFloat); }
+                    212 => { /* nothing */ }
+                    96 => { return Ok(TokenKind::TokenFalse); }
+                    213 => { /* nothing */ }
+                    97 => { return Ok(TokenKind::TokenId); }
+                    214 => { /* nothing */ }
+                    98 => { return Ok(TokenKind::TokenId); }
+                    215 => { /* nothing */ }
+                    99 => { return Ok(TokenKind::TokenId); }
+                    216 => { /* nothing */ }
+                    100 => { return Ok(TokenKind::TokenWrite); }
+                    217 => { /* nothing */ }
+                    101 => { return Ok(TokenKind::TokenWhile); }
+                    218 => { /* nothing */ }
+                    102 => { return Ok(TokenKind::TokenId); }
+                    219 => { /* nothing */ }
+                    103 => { return Ok(TokenKind::TokenId); }
+                    220 => { /* nothing */ }
+                    104 => { return Ok(TokenKind::TokenIsZero); }
+                    221 => { /* nothing */ }
+                    105 => { return Ok(TokenKind::TokenId); }
+                    222 => { /* nothing */ }
+                    106 => { return Ok(TokenKind::TokenString); }
+                    223 => { /* nothing */ }
+                    107 => { return Ok(TokenKind::TokenReturn); }
+                    224 => { /* nothing */ }
+                    108 => { return Ok(TokenKind::TokenId); }
+                    225 => { /* nothing */ }
+                    109 => { return Ok(TokenKind::TokenId); }
+                    226 => { /* nothing */ }
+                    110 => { return Ok(TokenKind::TokenId); }
+                    227 => { /* nothing */ }
+                    111 => { return Ok(TokenKind::TokenId); }
+                    228 => { /* nothing */ }
+                    112 => { return Ok(TokenKind::TokenId); }
+                    229 => { /* nothing */ }
+                    113 => { return Ok(TokenKind::TokenFunction); }
+                    230 => { /* nothing */ }
+                    114 => { return Ok(TokenKind::TokenConvDate); }
+                    231 => { /* nothing */ }
+                    115 => { return Ok(TokenKind::TokenId); }
+                    232 => { /* nothing */ }
+                    116 => { return Ok(TokenKind::TokenProcedure); }
+                    233 => { /* nothing */ }
+                    117 => { return Ok(TokenKind::TokenDate); }
+                    234 => { /* nothing */ }
 
                     _ => {
                         return Err(Error::Unmatch);
@@ -1,3 +1,4 @@
 #[rustfmt::skip]
 #[allow(clippy::all)]
 pub mod lex;
+pub mod tokens;
@@ -0,0 +1,154 @@
+use crate::{
+    compiler::{
+        context::CompilerContext,
+        error::{CompilerError, Diagnostic},
+    },
+    grammar::TokenKind,
+    lexer::lex::{Error, Lexer},
+};
+use std::ops::Range;
+
+/// A token along with the whitespace and comments the parser skips before it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    /// Byte range of the token in the source
+    pub span: Range<usize>,
+    /// Everything between the previous token and this one, in source order
+    pub leading_trivia: Vec<Trivia<'a>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriviaKind {
+    /// Spaces, tabs and line breaks
+    Whitespace,
+    /// A `#+ ... +#` comment
+    Comment,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trivia<'a> {
+    pub kind: TriviaKind,
+    pub text: &'a str,
+    pub span: Range<usize>,
+}
+
+impl Trivia<'_> {
+    /// Number of line breaks in the trivia, `\r\n` counts as one
+    pub fn line_breaks(&self) -> usize {
+        match self.kind {
+            TriviaKind::Whitespace => self.text.matches('\n').count(),
+            TriviaKind::Comment => 0,
+        }
+    }
+}
+
+/// Whether an expression or declaration can end with `kind`
+pub(crate) fn ends_operand(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::TokenId
+            | TokenKind::TokenIntLiteral
+            | TokenKind::TokenFloatLiteral
+            | TokenKind::TokenStringLiteral
+            | TokenKind::TokenDate
+            | TokenKind::TokenTrue
+            | TokenKind::TokenFalse
+            | TokenKind::TokenParClose
+            | TokenKind::TokenSBClose
+            | TokenKind::TokenInt
+            | TokenKind::TokenFloat
+            | TokenKind::TokenString
+            | TokenKind::TokenBool
+    )
+}
+
+/// Whether a statement, declaration or function can start with `kind`
+pub(crate) fn starts_statement(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::TokenId
+            | TokenKind::TokenIf
+            | TokenKind::TokenWhile
+            | TokenKind::TokenWrite
+            | TokenKind::TokenRead
+            | TokenKind::TokenReturn
+            | TokenKind::TokenInit
+            | TokenKind::TokenFunction
+            | TokenKind::TokenProcedure
+    )
+}
+
+/// Splits `source` into tokens keeping the whitespace and comments around them, the last token
+/// is always a `STOP` with whatever follows the last real token as its trivia
+///
+/// Fails on the first character that doesn't start any token
+pub fn tokenize(source: &str) -> Result<Vec<Token<'_>>, Vec<Diagnostic>> {
+    let mut context = CompilerContext::new(String::new(), source.to_string());
+    let mut lexer = Lexer::new(source, 0, &mut context);
+    let mut tokens = Vec::new();
+    let mut end = 0;
+    loop {
+        let (kind, span) = match lexer.yylex() {
+            Ok(kind) => (kind, lexer.yybytepos()),
+            Err(Error::EOF) => (TokenKind::STOP, source.len()..source.len()),
+            Err(Error::Unmatch) => {
+                let start = lexer.yybytepos().start;
+                let character = source[start..].chars().next().unwrap_or_default();
+                return Err(vec![Diagnostic::new(
+                    start..start + character.len_utf8(),
+                    CompilerError::Lexer(format!("unrecognized token {character}")),
+                    true,
+                )]);
+            }
+        };
+        tokens.push(Token {
+            kind,
+            text: &source[span.clone()],
+            leading_trivia: split_trivia(source, end..span.start),
+            span: span.clone(),
+        });
+        if kind == TokenKind::STOP {
+            return Ok(tokens);
+        }
+        end = span.end;
+    }
+}
+
+/// Splits the text the lexer skipped between two tokens into comments and runs of whitespace
+fn split_trivia(source: &str, range: Range<usize>) -> Vec<Trivia<'_>> {
+    let mut trivia = Vec::new();
+    let mut start = range.start;
+    while start < range.end {
+        let rest = &source[start..range.end];
+        let (kind, len) = if rest.starts_with("#+") {
+            (TriviaKind::Comment, comment_length(rest))
+        } else {
+            (TriviaKind::Whitespace, rest.find('#').unwrap_or(rest.len()))
+        };
+        trivia.push(Trivia {
+            kind,
+            text: &rest[..len],
+            span: start..start + len,
+        });
+        start += len;
+    }
+    trivia
+}
+
+/// Length of the comment `text` starts with, comments can only have a `#` when it is escaped so
+/// the first unescaped one closes it
+fn comment_length(text: &str) -> usize {
+    let mut chars = text.char_indices().skip(2);
+    while let Some((index, character)) = chars.next() {
+        match character {
+            '\\' => {
+                chars.next();
+            }
+            '#' => return index + 1,
+            _ => {}
+        }
+    }
+    text.len()
+}
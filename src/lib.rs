@@ -2,3 +2,7 @@
 pub mod compiler;
 pub mod grammar;
 pub mod lexer;
+
+pub use compiler::driver::{
+    Analysis, CompilationOutput, CompileOptions, analyze, check_str, compile_str,
+};
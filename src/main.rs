@@ -1,10 +1,25 @@
-use clap::Parser as ClapParser;
+use clap::{Args, CommandFactory, Parser as ClapParser, Subcommand, ValueEnum, error::ErrorKind};
 use lm_compiler::{
-    compiler::{ast::AstPtr, context::Compiler, error::CompilerError},
-    grammar::RulesParser,
+    CompilationOutput, CompileOptions, check_str, compile_str,
+    compiler::{
+        asm::Target,
+        error::{CompilerError, Diagnostic, ErrorFormat, emit_diagnostics},
+        formatter::format,
+        interpreter::{INTERPRETER_STACK_SIZE, Interpreter},
+    },
+    grammar::{TokenKind, cst},
+    lexer::tokens::{TriviaKind, tokenize},
+};
+use owo_colors::OwoColorize;
+use std::{
+    collections::HashMap,
+    fs::{self, create_dir_all, write},
+    io::{self, Write},
+    panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
 };
-use rustemo::Parser;
-use std::path::PathBuf;
 
 #[derive(ClapParser)]
 #[command(
@@ -12,24 +27,686 @@ use std::path::PathBuf;
     about = "Simple compiler written in Rust for the Compilers & Languages class at UNLaM"
 )]
 struct Cli {
-    #[arg(help = "Path to the source code file", value_name = "INPUT_FILE")]
-    input: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Arguments shared by every command that checks the program
+#[derive(Args)]
+struct DiagnosticArgs {
+    #[arg(
+        short = 'W',
+        long = "warnings",
+        value_enum,
+        default_value_t = Warnings::Warn,
+        help = "How to treat warnings"
+    )]
+    warnings: Warnings,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = ErrorFormat::Human,
+        help = "How to print errors and warnings to stderr"
+    )]
+    error_format: ErrorFormat,
 }
 
-fn main() -> Result<(), CompilerError> {
-    let cli = Cli::parse();
-    let compiler = Compiler::new(cli.input.clone())?;
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Warnings {
+    /// Report them and keep compiling
+    Warn,
+    /// Report them as errors making the compilation fail
+    Error,
+}
 
-    let rules = RulesParser::new(compiler.clone(), compiler.clone())
-        .parse_file(cli.input)
-        .map_err(CompilerError::ParserInternal)?;
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Emit {
+    /// Tokens read by the lexer, `.lexer`
+    Tokens,
+    /// Reductions made by the parser, `.parser`
+    Parse,
+    /// Symbol table, `.symbol_table`
+    Symbols,
+    /// Graphviz drawing of the syntax tree, `.dot`
+    Dot,
+    /// Assembly for the target, `.asm` or `.s`
+    Asm,
+    /// Three address code the backends generate the program from, `.ir`
+    Ir,
+    /// Lossless syntax tree with every token and the whitespace and comments around it, `.cst`
+    Cst,
+}
+
+impl Emit {
+    /// Extension of the file the artifact is written to, `asm` is the one of the backend
+    fn extension(self, asm: &'static str) -> &'static str {
+        match self {
+            Self::Tokens => "lexer",
+            Self::Parse => "parser",
+            Self::Symbols => "symbol_table",
+            Self::Dot => "dot",
+            Self::Asm => asm,
+            Self::Ir => "ir",
+            Self::Cst => "cst",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum AstFormat {
+    /// Indented listing with the type and position of each node
+    Text,
+    /// Graphviz drawing
+    Dot,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Report the errors and warnings in the program without generating anything
+    Check {
+        #[arg(
+            help = "Paths to the source code files, directories are searched for `.lm` files",
+            value_name = "INPUTS",
+            required = true
+        )]
+        inputs: Vec<PathBuf>,
+        #[command(flatten)]
+        diagnostics: DiagnosticArgs,
+    },
+    /// Generate the assembly and any other requested artifact
+    Build {
+        #[arg(
+            help = "Paths to the source code files, directories are searched for `.lm` files",
+            value_name = "INPUTS",
+            required = true
+        )]
+        inputs: Vec<PathBuf>,
+        #[command(flatten)]
+        diagnostics: DiagnosticArgs,
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = Target::Tasm,
+            help = "Assembly flavour to generate"
+        )]
+        target: Target,
+        #[arg(
+            long,
+            value_enum,
+            value_delimiter = ',',
+            default_values_t = [Emit::Asm],
+            help = "Artifacts to write"
+        )]
+        emit: Vec<Emit>,
+        #[arg(
+            short = 'o',
+            long,
+            value_name = "DIR",
+            help = "Directory to write the artifacts to instead of the one of the source file, `-` writes the only artifact to stdout"
+        )]
+        out_dir: Option<PathBuf>,
+        #[arg(
+            short = 'O',
+            long,
+            help = "Fold constants and drop dead branches before generating code"
+        )]
+        optimize: bool,
+    },
+    /// Execute the program directly reading from stdin and writing to stdout
+    Run {
+        #[arg(help = "Path to the source code file", value_name = "INPUT_FILE")]
+        input: PathBuf,
+        #[command(flatten)]
+        diagnostics: DiagnosticArgs,
+        #[arg(
+            short = 'O',
+            long,
+            help = "Fold constants and drop dead branches before running"
+        )]
+        optimize: bool,
+    },
+    /// Print the tokens read by the lexer, one per line with their byte range
+    Tokens {
+        #[arg(help = "Path to the source code file", value_name = "INPUT_FILE")]
+        input: PathBuf,
+        #[arg(long, help = "Also print the whitespace and comments between tokens")]
+        trivia: bool,
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = ErrorFormat::Human,
+            help = "How to print errors and warnings to stderr"
+        )]
+        error_format: ErrorFormat,
+    },
+    /// Print the syntax tree of the program
+    Ast {
+        #[arg(help = "Path to the source code file", value_name = "INPUT_FILE")]
+        input: PathBuf,
+        #[command(flatten)]
+        diagnostics: DiagnosticArgs,
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = AstFormat::Text,
+            help = "How to print the tree"
+        )]
+        format: AstFormat,
+        #[arg(
+            short = 'O',
+            long,
+            help = "Fold constants and drop dead branches before printing"
+        )]
+        optimize: bool,
+    },
+    /// Print the symbol table of the program
+    Symbols {
+        #[arg(help = "Path to the source code file", value_name = "INPUT_FILE")]
+        input: PathBuf,
+        #[command(flatten)]
+        diagnostics: DiagnosticArgs,
+        #[arg(
+            short = 'O',
+            long,
+            help = "Fold constants and drop dead branches before printing"
+        )]
+        optimize: bool,
+    },
+    /// Rewrite the files in the canonical layout
+    Fmt {
+        #[arg(
+            help = "Paths to the source code files, directories are searched for `.lm` files",
+            value_name = "INPUTS",
+            required = true
+        )]
+        inputs: Vec<PathBuf>,
+        #[arg(
+            long,
+            help = "Don't write anything, fail if any file isn't already formatted"
+        )]
+        check: bool,
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = ErrorFormat::Human,
+            help = "How to print errors and warnings to stderr"
+        )]
+        error_format: ErrorFormat,
+    },
+}
+
+/// Why a command failed, each reason exits with its own code
+enum Failure {
+    /// The source has errors, already reported, or `fmt --check` found unformatted files
+    Rejected,
+    /// The program failed while running
+    Runtime(CompilerError),
+    /// A file couldn't be read or written
+    Io(io::Error),
+    /// Some of several files failed and were already reported, with the code of the worst one
+    Reported(u8),
+}
 
-    println!("{rules}");
+impl Failure {
+    /// Clap already exits with 2 on invalid arguments
+    fn exit_code(&self) -> u8 {
+        match self {
+            Self::Rejected => 1,
+            Self::Runtime(_) => 3,
+            Self::Io(_) => 4,
+            Self::Reported(exit_code) => *exit_code,
+        }
+    }
 
-    compiler
-        .inner
-        .borrow_mut()
-        .create_ast_graph(AstPtr::Program)?;
+    /// Prints the failures that weren't reported when they happened
+    fn report(&self) {
+        match self {
+            Self::Rejected | Self::Reported(_) => {}
+            Self::Runtime(e) => eprintln!("{}: {}", "error".red().bold(), e.bold()),
+            Self::Io(e) => eprintln!("{}: {}", "error".red().bold(), e.bold()),
+        }
+    }
+
+    /// Output piped into something like `head` that stopped reading
+    fn is_broken_pipe(&self) -> bool {
+        matches!(self, Self::Io(e) if e.kind() == io::ErrorKind::BrokenPipe)
+    }
+}
+
+impl From<io::Error> for Failure {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+/// A file to work on, given as an argument or found inside a directory
+struct SourceFile {
+    path: PathBuf,
+    /// Path inside the directory it was found in, or the file name for the ones given directly.
+    /// Artifacts written to an output directory keep it, files given directly with the same name
+    /// would end up in the same place so building them into one directory is an error
+    relative: PathBuf,
+}
+
+fn main() -> ExitCode {
+    let mut cli = Cli::parse();
+    if let Command::Build {
+        inputs,
+        emit,
+        out_dir,
+        ..
+    } = &mut cli.command
+    {
+        // Asking for an artifact twice writes it once
+        let mut requested = Vec::new();
+        emit.retain(|emit| {
+            let first = !requested.contains(emit);
+            requested.push(*emit);
+            first
+        });
+        // Artifacts written one after the other to stdout couldn't be told apart
+        if out_dir.as_deref() == Some(Path::new("-"))
+            && (emit.len() > 1 || inputs.len() > 1 || inputs.iter().any(|input| input.is_dir()))
+        {
+            Cli::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    "`-o -` writes a single artifact to stdout, pass one input file and one `--emit`",
+                )
+                .exit();
+        }
+    }
+
+    match execute(cli.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) if failure.is_broken_pipe() => ExitCode::SUCCESS,
+        Err(failure) => {
+            failure.report();
+            ExitCode::from(failure.exit_code())
+        }
+    }
+}
+
+fn execute(command: Command) -> Result<(), Failure> {
+    match command {
+        Command::Check {
+            inputs,
+            diagnostics,
+        } => for_each_file(&source_files(&inputs)?, diagnostics.error_format, |file| {
+            let source = read_source(&file.path)?;
+            let result = check_str(&source, &diagnostics.options(&file.path, false));
+            report(
+                &file.path,
+                &diagnostics,
+                &source,
+                result.map(|warnings| (warnings, ())),
+            )
+        }),
+        Command::Build {
+            inputs,
+            diagnostics,
+            target,
+            emit,
+            out_dir,
+            optimize,
+        } => {
+            let files = source_files(&inputs)?;
+            if let Some(out_dir) = &out_dir
+                && out_dir != Path::new("-")
+            {
+                check_collisions(&files, out_dir)?;
+            }
+            for_each_file(&files, diagnostics.error_format, |file| {
+                let backend = target.backend();
+                let extension = backend.extension();
+                let options = CompileOptions {
+                    backend,
+                    ..diagnostics.options(&file.path, optimize)
+                };
+                let output = compile(&file.path, &diagnostics, options)?;
+
+                for &emit in &emit {
+                    let contents = match emit {
+                        Emit::Tokens => output.tokens.clone(),
+                        Emit::Parse => output.parse_trace.clone(),
+                        Emit::Symbols => output.symbol_table.to_string(),
+                        Emit::Dot => output.ast_graph.clone(),
+                        Emit::Asm => output.asm.clone(),
+                        Emit::Ir => output.ir.to_string(),
+                        Emit::Cst => syntax_tree(&file.path, diagnostics.error_format)?,
+                    };
+                    let path = match &out_dir {
+                        Some(out_dir) if out_dir == Path::new("-") => {
+                            io::stdout().lock().write_all(contents.as_bytes())?;
+                            continue;
+                        }
+                        Some(out_dir) => {
+                            let path = out_dir.join(&file.relative);
+                            if let Some(parent) = path.parent() {
+                                create_dir_all(parent)?;
+                            }
+                            path
+                        }
+                        None => file.path.clone(),
+                    };
+                    write(path.with_extension(emit.extension(extension)), contents)?;
+                }
+                Ok(())
+            })
+        }
+        Command::Run {
+            input,
+            diagnostics,
+            optimize,
+        } => {
+            // The interpreter recurses for every call of the program, which the stack of the main
+            // thread can't hold for deep recursion
+            thread::scope(|scope| {
+                let run = thread::Builder::new()
+                    .stack_size(INTERPRETER_STACK_SIZE)
+                    .spawn_scoped(scope, || {
+                        // The ints of the interpreter are as wide as the ones of the native target
+                        let options = CompileOptions {
+                            backend: Target::X86_64Linux.backend(),
+                            ..diagnostics.options(&input, optimize)
+                        };
+                        let output = compile(&input, &diagnostics, options)?;
+                        let interpreter = Interpreter::new(
+                            &output.symbol_table,
+                            io::stdin().lock(),
+                            io::stdout().lock(),
+                        );
+                        interpreter.run(&output.ast).map_err(Failure::Runtime)
+                    })?;
+                run.join()
+                    .unwrap_or_else(|panic| panic::resume_unwind(panic))
+            })
+        }
+        Command::Tokens {
+            input,
+            trivia,
+            error_format,
+        } => print_tokens(&input, trivia, error_format),
+        Command::Ast {
+            input,
+            diagnostics,
+            format,
+            optimize,
+        } => {
+            let options = diagnostics.options(&input, optimize);
+            let output = compile(&input, &diagnostics, options)?;
+            let contents = match format {
+                AstFormat::Text => output.ast.tree(),
+                AstFormat::Dot => output.ast_graph,
+            };
+            Ok(io::stdout().lock().write_all(contents.as_bytes())?)
+        }
+        Command::Symbols {
+            input,
+            diagnostics,
+            optimize,
+        } => {
+            let options = diagnostics.options(&input, optimize);
+            let output = compile(&input, &diagnostics, options)?;
+            Ok(write!(io::stdout().lock(), "{}", output.symbol_table)?)
+        }
+        Command::Fmt {
+            inputs,
+            check,
+            error_format,
+        } => for_each_file(&source_files(&inputs)?, error_format, |file| {
+            format_file(&file.path, check, error_format)
+        }),
+    }
+}
+
+impl DiagnosticArgs {
+    fn options(&self, input: &Path, optimize: bool) -> CompileOptions {
+        CompileOptions {
+            file_name: input.to_string_lossy().to_string(),
+            optimize,
+            deny_warnings: self.warnings == Warnings::Error,
+            ..Default::default()
+        }
+    }
+}
+
+/// Runs `command` on every file, one failing doesn't stop the rest
+///
+/// With more than one file a summary with how many passed and which failed is printed at the end
+fn for_each_file(
+    files: &[SourceFile],
+    error_format: ErrorFormat,
+    mut command: impl FnMut(&SourceFile) -> Result<(), Failure>,
+) -> Result<(), Failure> {
+    let mut failed = Vec::new();
+    let mut exit_code = 0;
+    for file in files {
+        if let Err(failure) = command(file) {
+            if failure.is_broken_pipe() {
+                return Err(failure);
+            }
+            failure.report();
+            exit_code = exit_code.max(failure.exit_code());
+            failed.push(file.path.to_string_lossy().to_string());
+        }
+    }
+
+    if files.len() > 1 {
+        print_summary(files.len(), &failed, error_format);
+    }
+    match exit_code {
+        0 => Ok(()),
+        exit_code => Err(Failure::Reported(exit_code)),
+    }
+}
+
+fn print_summary(total: usize, failed: &[String], error_format: ErrorFormat) {
+    match error_format {
+        ErrorFormat::Human if failed.is_empty() => {
+            eprintln!("{}: all {total} files passed", "success".green().bold())
+        }
+        ErrorFormat::Human => {
+            eprintln!(
+                "{}: {} of {total} files failed",
+                "error".red().bold(),
+                failed.len()
+            );
+            for path in failed {
+                eprintln!("  {}", path.blue());
+            }
+        }
+        ErrorFormat::Json => eprintln!(
+            "{}",
+            serde_json::json!({
+                "summary": {
+                    "files": total,
+                    "passed": total - failed.len(),
+                    "failed": failed,
+                }
+            })
+        ),
+    }
+}
+
+/// Expands the directories in `inputs` into the `.lm` files inside them and their
+/// subdirectories, sorted by path
+fn source_files(inputs: &[PathBuf]) -> Result<Vec<SourceFile>, Failure> {
+    let mut files = Vec::new();
+    for input in inputs {
+        if !input.is_dir() {
+            files.push(SourceFile {
+                path: input.clone(),
+                relative: PathBuf::from(input.file_name().unwrap_or_default()),
+            });
+            continue;
+        }
+        let mut found = Vec::new();
+        find_sources(input, &mut found).map_err(|e| {
+            io::Error::new(e.kind(), format!("couldn't read {}: {e}", input.display()))
+        })?;
+        if found.is_empty() {
+            return Err(Failure::Io(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no .lm files in {}", input.display()),
+            )));
+        }
+        found.sort();
+        files.extend(found.into_iter().map(|path| SourceFile {
+            relative: path.strip_prefix(input).unwrap_or(&path).to_path_buf(),
+            path,
+        }));
+    }
+    Ok(files)
+}
+
+/// Fails before anything is written if two different files would write their artifacts to the
+/// same place in `out_dir`
+fn check_collisions(files: &[SourceFile], out_dir: &Path) -> Result<(), Failure> {
+    let mut written = HashMap::new();
+    for file in files {
+        // Artifacts only differ from the source in their extension
+        if let Some(other) = written.insert(file.relative.with_extension(""), &file.path)
+            && *other != file.path
+        {
+            return Err(Failure::Io(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "{} and {} would overwrite each other's artifacts in {}",
+                    other.display(),
+                    file.path.display(),
+                    out_dir.display()
+                ),
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn find_sources(directory: &Path, found: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_sources(&path, found)?;
+        } else if path.extension().is_some_and(|extension| extension == "lm") {
+            found.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Compiles the file reporting every diagnostic found
+fn compile(
+    input: &Path,
+    diagnostics: &DiagnosticArgs,
+    options: CompileOptions,
+) -> Result<CompilationOutput, Failure> {
+    let source = read_source(input)?;
+    let result = compile_str(&source, &options)
+        .map(|mut output| (std::mem::take(&mut output.warnings), output));
+    report(input, diagnostics, &source, result)
+}
+
+/// Reports the warnings of a successful result or the diagnostics of a failed one
+fn report<T>(
+    input: &Path,
+    diagnostics: &DiagnosticArgs,
+    source: &str,
+    result: Result<(Vec<Diagnostic>, T), Vec<Diagnostic>>,
+) -> Result<T, Failure> {
+    let file_name = input.to_string_lossy();
+    match result {
+        Ok((warnings, value)) => {
+            emit_diagnostics(&file_name, source, &warnings, diagnostics.error_format);
+            Ok(value)
+        }
+        Err(errors) => {
+            emit_diagnostics(&file_name, source, &errors, diagnostics.error_format);
+            Err(Failure::Rejected)
+        }
+    }
+}
+
+/// Prints every token with its byte range and text, along with the trivia before it if `trivia`
+fn print_tokens(input: &Path, trivia: bool, error_format: ErrorFormat) -> Result<(), Failure> {
+    let source = read_source(input)?;
+    let tokens = match tokenize(&source) {
+        Ok(tokens) => tokens,
+        Err(diagnostics) => {
+            emit_diagnostics(
+                &input.to_string_lossy(),
+                &source,
+                &diagnostics,
+                error_format,
+            );
+            return Err(Failure::Rejected);
+        }
+    };
+
+    let mut stdout = io::stdout().lock();
+    for token in tokens {
+        if trivia {
+            for trivia in token.leading_trivia {
+                let kind = match trivia.kind {
+                    TriviaKind::Whitespace => "Whitespace",
+                    TriviaKind::Comment => "Comment",
+                };
+                writeln!(stdout, "{kind} {:?} {:?}", trivia.span, trivia.text)?;
+            }
+        }
+        if token.kind != TokenKind::STOP {
+            writeln!(stdout, "{:?} {:?} {:?}", token.kind, token.span, token.text)?;
+        }
+    }
+    Ok(())
+}
+
+/// Formats the file in place, or only checks it with `check`, failing if it can't be formatted
+/// or, when checking, it isn't already
+fn format_file(input: &Path, check: bool, error_format: ErrorFormat) -> Result<(), Failure> {
+    let source = read_source(input)?;
+    let file_name = input.to_string_lossy();
+    match format(&source) {
+        Ok(formatted) if formatted == source => Ok(()),
+        Ok(_) if check => {
+            eprintln!(
+                "{}: {} is not formatted",
+                "error".red().bold(),
+                file_name.bold()
+            );
+            Err(Failure::Rejected)
+        }
+        Ok(formatted) => Ok(write(input, formatted)?),
+        Err(diagnostics) => {
+            emit_diagnostics(&file_name, &source, &diagnostics, error_format);
+            Err(Failure::Rejected)
+        }
+    }
+}
+
+/// Dump of the lossless syntax tree of the file, reporting its errors if it can't be parsed
+fn syntax_tree(input: &Path, error_format: ErrorFormat) -> Result<String, Failure> {
+    let source = read_source(input)?;
+    match cst::parse(&source) {
+        Ok(tree) => Ok(tree.dump()),
+        Err(diagnostics) => {
+            emit_diagnostics(
+                &input.to_string_lossy(),
+                &source,
+                &diagnostics,
+                error_format,
+            );
+            Err(Failure::Rejected)
+        }
+    }
+}
 
-    compiler.inner.borrow_mut().generate_asm()
+/// Reads a source file, naming it in the error since the one from the OS doesn't
+fn read_source(path: &Path) -> Result<String, Failure> {
+    fs::read_to_string(path).map_err(|e| {
+        Failure::Io(io::Error::new(
+            e.kind(),
+            format!("couldn't read {}: {e}", path.display()),
+        ))
+    })
 }
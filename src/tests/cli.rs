@@ -0,0 +1,233 @@
+use std::{
+    env,
+    ffi::OsStr,
+    fs::{create_dir_all, read_to_string, remove_dir_all, write},
+    path::PathBuf,
+    process::{Command, Output},
+};
+
+/// Temporary directory removed when the test ends, even if it fails
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let path = env::temp_dir().join(format!("lm-compiler-{name}-{}", std::process::id()));
+        let _ = remove_dir_all(&path);
+        create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    /// Writes `source` to the file `name` inside the directory and returns its path
+    fn file(&self, name: &str, source: &str) -> PathBuf {
+        let path = self.0.join(name);
+        create_dir_all(path.parent().unwrap()).unwrap();
+        write(&path, source).unwrap();
+        path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = remove_dir_all(&self.0);
+    }
+}
+
+fn lm_compiler(args: impl IntoIterator<Item = impl AsRef<OsStr>>) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_lm-compiler"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+/// Program that recurses `depth` calls deep
+fn recursive_sum(depth: usize) -> String {
+    format!(
+        "function sum(n : int) : int {{
+    if (n == 0) {{
+        return 0
+    }}
+    return n + sum(n - 1)
+}}
+
+main(){{
+    write(sum({depth}))
+}}
+"
+    )
+}
+
+#[test]
+fn deep_recursion_runs() {
+    let dir = TempDir::new("deep-recursion");
+    let path = dir.file("sum.lm", &recursive_sum(9_000));
+    let output = lm_compiler(["run".as_ref(), path.as_os_str()]);
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stdout).ends_with("40504500\n"));
+}
+
+#[test]
+fn unbounded_recursion_is_a_runtime_error() {
+    let dir = TempDir::new("unbounded-recursion");
+    let path = dir.file("sum.lm", &recursive_sum(1_000_000));
+    let output = lm_compiler(["run".as_ref(), path.as_os_str()]);
+
+    assert_eq!(output.status.code(), Some(3), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("stack overflow"));
+}
+
+/// Building two files with the same name into one directory must fail before writing anything
+/// instead of one overwriting the artifacts of the other
+#[test]
+fn output_collision_writes_nothing() {
+    let dir = TempDir::new("collision");
+    let source = read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/hello_world.lm"
+    ))
+    .unwrap();
+    let a = dir.file("a/hello_world.lm", &source);
+    let b = dir.file("b/hello_world.lm", &source);
+    let out_dir = dir.0.join("out");
+    let output = lm_compiler([
+        "build".as_ref(),
+        "-o".as_ref(),
+        out_dir.as_os_str(),
+        a.as_os_str(),
+        b.as_os_str(),
+    ]);
+
+    assert!(!output.status.success(), "{output:?}");
+    assert!(!out_dir.exists());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("would overwrite each other"), "{stderr}");
+}
+
+#[test]
+fn stdout_takes_a_single_artifact() {
+    let dir = TempDir::new("stdout");
+    let a = dir.file("a.lm", "main(){\n    write(\"a\")\n}\n");
+    let b = dir.file("b.lm", "main(){\n    write(\"b\")\n}\n");
+    let (a, b, dir) = (
+        a.to_str().unwrap(),
+        b.to_str().unwrap(),
+        dir.0.to_str().unwrap(),
+    );
+    for args in [
+        ["build", "-o", "-", a, b].as_slice(),
+        &["build", "-o", "-", dir],
+        &["build", "-o", "-", "--emit", "ir,asm", a],
+    ] {
+        let output = lm_compiler(args);
+        assert_eq!(output.status.code(), Some(2), "{args:?}: {output:?}");
+        assert!(output.stdout.is_empty(), "{args:?}: {output:?}");
+    }
+
+    // The same artifact asked for twice is only written once
+    let twice = lm_compiler(["build", "-o", "-", "--emit", "ir,ir", a]);
+    let once = lm_compiler(["build", "-o", "-", "--emit", "ir", a]);
+    assert!(twice.status.success(), "{twice:?}");
+    assert_eq!(twice.stdout, once.stdout);
+}
+
+/// Divides the smallest int by -1, the only division whose quotient doesn't fit
+const SMALLEST_INT_BY_MINUS_ONE: &str = "main(){
+    init {
+        a, b : int
+    }
+    a := -9223372036854775807 - 1
+    b := -1
+    write(a / b)
+}
+";
+
+#[test]
+fn division_overflow_is_a_runtime_error() {
+    let dir = TempDir::new("division-overflow");
+    let path = dir.file("overflow.lm", SMALLEST_INT_BY_MINUS_ONE);
+    let output = lm_compiler(["run".as_ref(), path.as_os_str()]);
+
+    assert_eq!(output.status.code(), Some(3), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("integer overflow"));
+}
+
+#[test]
+fn native_division_overflow_is_a_runtime_error() {
+    // Without binutils there is nothing to run
+    if Command::new("as").arg("--version").output().is_err() {
+        return;
+    }
+    let dir = TempDir::new("native-division-overflow");
+    let path = dir.file("overflow.lm", SMALLEST_INT_BY_MINUS_ONE);
+    let (asm, object, program) = (
+        dir.0.join("overflow.s"),
+        dir.0.join("overflow.o"),
+        dir.0.join("overflow"),
+    );
+    let build = lm_compiler([
+        "build".as_ref(),
+        "--target".as_ref(),
+        "x86_64-linux".as_ref(),
+        path.as_os_str(),
+    ]);
+    assert!(build.status.success(), "{build:?}");
+    let assembled = Command::new("as")
+        .arg(&asm)
+        .arg("-o")
+        .arg(&object)
+        .status()
+        .unwrap();
+    assert!(assembled.success());
+    let linked = Command::new("ld")
+        .arg(&object)
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .unwrap();
+    assert!(linked.success());
+
+    let output = Command::new(&program).output().unwrap();
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: Runtime error: integer overflow\n"
+    );
+}
+
+#[test]
+fn tasm_rejects_ints_wider_than_32_bits() {
+    let dir = TempDir::new("tasm-int-range");
+    let path = dir.file("overflow.lm", SMALLEST_INT_BY_MINUS_ONE);
+    let output = lm_compiler(["build".as_ref(), path.as_os_str()]);
+
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("E0017"));
+    assert!(!dir.0.join("overflow.asm").exists());
+}
+
+#[test]
+fn recovery_checks_the_code_after_a_broken_expression() {
+    let dir = TempDir::new("recovery");
+    let path = dir.file(
+        "broken.lm",
+        "main(){
+    init {
+        a, b : int
+    }
+    a := 1 +
+    write(a)
+    b := \"one\"
+}
+",
+    );
+    let output = lm_compiler(["check".as_ref(), path.as_os_str()]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1), "{output:?}");
+    assert!(
+        stderr.contains("E0002") && stderr.contains("E0003"),
+        "{stderr}"
+    );
+    // `a` is read by the skipped `write`, it must not be reported as never read
+    assert!(!stderr.contains("W0001"), "{stderr}");
+}
@@ -1,18 +1,359 @@
-use lm_compiler::{compiler::context::Compiler, grammar::RulesParser};
-use rustemo::Parser;
-use std::path::Path;
+use lm_compiler::{
+    CompilationOutput, CompileOptions, compile_str,
+    compiler::{asm::Target, context::SymbolTable, formatter::format, interpreter::Interpreter},
+    grammar::cst,
+};
+use similar::TextDiff;
+use std::{
+    env,
+    error::Error,
+    fmt,
+    fs::{read_to_string, write},
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
 
 fn integration_test(path: &Path) -> datatest_stable::Result<()> {
-    let compiler = Compiler::new(path.into())?;
-    RulesParser::new(compiler.clone(), compiler.clone())
-        .parse_file(path)
-        .map_err(|e| e.to_string())
-        .map(|_| ())?;
-    Ok(compiler.inner.borrow_mut().generate_asm()?)
+    compile(path, Target::Tasm, false).map(|_| ())
+}
+
+/// Every stage must give the same output as the snapshots committed next to the example,
+/// running with `BLESS=1` writes the current output to them instead
+fn snapshot_test(path: &Path) -> datatest_stable::Result<()> {
+    let source = read_to_string(path)?;
+    let compile = |target: Target| {
+        // Only the name goes in the snapshots so they don't depend on where the tests run from
+        let options = CompileOptions {
+            file_name: path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string(),
+            backend: target.backend(),
+            ..Default::default()
+        };
+        compile_str(&source, &options).map_err(|diagnostics| {
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.error.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    };
+    let output = compile(Target::Tasm)?;
+    let native = compile(Target::X86_64Linux)?;
+
+    let snapshots = [
+        ("tokens", output.tokens),
+        ("parser", output.parse_trace),
+        ("symbol_table", output.symbol_table.to_string()),
+        ("dot", output.ast_graph),
+        ("ir", output.ir.to_string()),
+        ("asm", output.asm),
+        ("s", native.asm),
+    ];
+    let bless = env::var_os("BLESS").is_some();
+    let mut mismatches = Vec::new();
+    for (extension, actual) in snapshots {
+        let snapshot = path.with_extension(extension);
+        let expected = read_to_string(&snapshot).ok();
+        if expected.as_deref() == Some(actual.as_str()) {
+            continue;
+        }
+        if bless {
+            write(&snapshot, actual)?;
+            continue;
+        }
+        match expected {
+            Some(expected) => mismatches.push(format!(
+                "{} changed\n{}",
+                snapshot.display(),
+                TextDiff::from_lines(&expected, &actual)
+                    .unified_diff()
+                    .header("expected", "actual")
+            )),
+            None => mismatches.push(format!("{} is missing", snapshot.display())),
+        }
+    }
+    if !mismatches.is_empty() {
+        return Err(Box::new(Report(format!(
+            "{}\nrun `BLESS=1 cargo test` to accept the new output",
+            mismatches.join("\n")
+        ))));
+    }
+    Ok(())
+}
+
+/// Error printed as is instead of as a quoted string, so diffs stay readable in the test output
+struct Report(String);
+
+impl fmt::Debug for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for Report {}
+
+/// The program must fail with exactly the errors its first lines expect in order, each one written
+/// as `#+ expect <CODE> at <LINE>:<COLUMN>-<LINE>:<COLUMN> +#` with where its span starts and ends
+fn error_test(path: &Path) -> datatest_stable::Result<()> {
+    let source = read_to_string(path)?;
+    let expected = source
+        .lines()
+        .map_while(|line| line.strip_prefix("#+ expect ")?.strip_suffix(" +#"))
+        .collect::<Vec<_>>();
+    if expected.is_empty() {
+        return Err(
+            "the file must start with `#+ expect <CODE> at <LINE>:<COLUMN>-<LINE>:<COLUMN> +#`"
+                .into(),
+        );
+    }
+    let options = CompileOptions {
+        file_name: path.to_string_lossy().to_string(),
+        ..Default::default()
+    };
+    let diagnostics = match compile_str(&source, &options) {
+        Ok(_) => {
+            return Err(
+                format!("expected {} but the program compiled", expected.join(", ")).into(),
+            );
+        }
+        Err(diagnostics) => diagnostics,
+    };
+
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.is_error())
+        .map(|diagnostic| {
+            let (line, column) = line_column(&source, diagnostic.span.start);
+            let (end_line, end_column) = line_column(&source, diagnostic.span.end);
+            (
+                format!(
+                    "{} at {line}:{column}-{end_line}:{end_column}",
+                    diagnostic.error.code()
+                ),
+                diagnostic.error.to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    if !errors.iter().map(|(error, _)| error).eq(&expected) {
+        let found = errors
+            .iter()
+            .map(|(error, message)| format!("  {error}: {message}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        return Err(Box::new(Report(format!(
+            "expected\n  {}\nfound\n{found}",
+            expected.join("\n  ")
+        ))));
+    }
+    Ok(())
+}
+
+/// Line and column of the byte `pos`, both counting from 1 like diagnostics do
+fn line_column(source: &str, pos: usize) -> (usize, usize) {
+    let before = &source[..pos.min(source.len())];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    (before.matches('\n').count() + 1, pos - line_start + 1)
+}
+
+/// An error from the backend must point at the code it was generating, generating without the
+/// symbol table makes it fail on the first instruction or function that uses a symbol
+fn backend_error_test(path: &Path) -> datatest_stable::Result<()> {
+    let source = read_to_string(path)?;
+    let output = compile(path, Target::Tasm, false)?;
+    let Err(diagnostic) =
+        Target::Tasm
+            .backend()
+            .generate(&output.ir, &SymbolTable::default(), &mut Vec::new())
+    else {
+        // Nothing in the program needs a symbol
+        return Ok(());
+    };
+
+    let message = diagnostic.error.to_string();
+    let symbol = message
+        .split_once("Symbol ")
+        .and_then(|(_, rest)| rest.split_once(" is not in the symbol table"))
+        .map(|(symbol, _)| symbol)
+        .ok_or_else(|| format!("unexpected error: {message}"))?;
+    let (line, column) = line_column(&source, diagnostic.span.start);
+    let (end_line, end_column) = line_column(&source, diagnostic.span.end);
+    let code = source.get(diagnostic.span.clone()).unwrap_or_default();
+    // Variables declared in a function are stored as `function@variable`
+    let name = symbol
+        .rsplit('@')
+        .next()
+        .unwrap_or(symbol)
+        .trim_matches('"');
+    if !code.contains(name) {
+        return Err(Box::new(Report(format!(
+            "the error about {symbol} points at {line}:{column}-{end_line}:{end_column}: {code:?}"
+        ))));
+    }
+    Ok(())
+}
+
+/// Formatting must be stable and keep the program compiling
+fn formatting_test(path: &Path) -> datatest_stable::Result<()> {
+    let formatted = format(&read_to_string(path)?).map_err(|diagnostics| {
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.error.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    })?;
+    if format(&formatted).ok().as_ref() != Some(&formatted) {
+        return Err(format!("formatting again changed the output:\n{formatted}").into());
+    }
+    let options = CompileOptions {
+        file_name: path.to_string_lossy().to_string(),
+        ..Default::default()
+    };
+    if compile_str(&formatted, &options).is_err() {
+        return Err(format!("the formatted program doesn't compile:\n{formatted}").into());
+    }
+    Ok(())
+}
+
+/// The lossless tree must give back the exact source it was parsed from
+fn lossless_test(path: &Path) -> datatest_stable::Result<()> {
+    let source = read_to_string(path)?;
+    let tree = cst::parse(&source).map_err(|diagnostics| {
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.error.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    })?;
+    if tree.to_string() != source {
+        return Err(format!("the tree gave back different source:\n{tree}").into());
+    }
+    Ok(())
+}
+
+/// Runs the program next to the expected `.out` file feeding it the `.in` file if there is one
+fn execution_test(path: &Path) -> datatest_stable::Result<()> {
+    interpret(path, false)
+}
+
+/// Same as `execution_test` with the optimizations enabled, they must not change the output
+fn optimized_execution_test(path: &Path) -> datatest_stable::Result<()> {
+    interpret(path, true)
+}
+
+fn interpret(path: &Path, optimize: bool) -> datatest_stable::Result<()> {
+    let output = compile(&path.with_extension("lm"), Target::Tasm, optimize)?;
+    let input = read_to_string(path.with_extension("in")).unwrap_or_default();
+
+    let mut stdout = Vec::new();
+    Interpreter::new(&output.symbol_table, input.as_bytes(), &mut stdout).run(&output.ast)?;
+
+    compare_output(path, &String::from_utf8(stdout)?)
+}
+
+/// Same as `execution_test` but assembling, linking and running the x86-64 Linux output
+fn native_execution_test(path: &Path) -> datatest_stable::Result<()> {
+    run_native(path, false)
+}
+
+/// Same as `native_execution_test` with the optimizations enabled
+fn optimized_native_execution_test(path: &Path) -> datatest_stable::Result<()> {
+    run_native(path, true)
+}
+
+fn run_native(path: &Path, optimize: bool) -> datatest_stable::Result<()> {
+    // Without binutils there is nothing to run
+    if Command::new("as").arg("--version").output().is_err() {
+        return Ok(());
+    }
+
+    let output = compile(&path.with_extension("lm"), Target::X86_64Linux, optimize)?;
+    let input = read_to_string(path.with_extension("in")).unwrap_or_default();
+
+    let name = path.file_stem().unwrap_or_default().to_string_lossy();
+    let suffix = if optimize { "-O" } else { "" };
+    let base = env::temp_dir().join(format!("lm-compiler-{}-{name}{suffix}", std::process::id()));
+    let (asm, object) = (base.with_extension("s"), base.with_extension("o"));
+    write(&asm, output.asm)?;
+    run_tool(Command::new("as").arg(&asm).arg("-o").arg(&object))?;
+    run_tool(Command::new("ld").arg(&object).arg("-o").arg(&base))?;
+
+    let mut child = Command::new(&base)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    child
+        .stdin
+        .take()
+        .ok_or("Failed to open stdin")?
+        .write_all(input.as_bytes())?;
+    let result = child.wait_with_output()?;
+
+    for file in [&asm, &object, &base] {
+        let _ = std::fs::remove_file(file);
+    }
+    compare_output(path, &String::from_utf8(result.stdout)?)
+}
+
+fn run_tool(command: &mut Command) -> datatest_stable::Result<()> {
+    let output = command.output()?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned().into());
+    }
+    Ok(())
+}
+
+fn compare_output(path: &Path, stdout: &str) -> datatest_stable::Result<()> {
+    let expected = read_to_string(path)?;
+    if stdout != expected {
+        return Err(format!("expected output:\n{expected}\nactual output:\n{stdout}").into());
+    }
+    Ok(())
+}
+
+fn compile(
+    path: &Path,
+    target: Target,
+    optimize: bool,
+) -> datatest_stable::Result<CompilationOutput> {
+    let source = read_to_string(path)?;
+    let options = CompileOptions {
+        file_name: path.to_string_lossy().to_string(),
+        backend: target.backend(),
+        optimize,
+        ..Default::default()
+    };
+    compile_str(&source, &options).map_err(|diagnostics| {
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.error.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+            .into()
+    })
 }
 
 #[cfg(test)]
 datatest_stable::harness!(
-    { test = integration_test, root = "./examples", pattern = r".*\.lm" },
-    { test = integration_test, root = "./inputs", pattern = r"test.txt" }
+    { test = integration_test, root = "./examples", pattern = r"^[^/]*\.lm$" },
+    { test = integration_test, root = "./inputs", pattern = r"test.txt" },
+    { test = snapshot_test, root = "./examples", pattern = r"^[^/]*\.lm$" },
+    { test = formatting_test, root = "./examples", pattern = r"^[^/]*\.lm$" },
+    { test = lossless_test, root = "./examples", pattern = r"^[^/]*\.lm$" },
+    { test = lossless_test, root = "./inputs", pattern = r"test.txt" },
+    { test = error_test, root = "./examples/errors", pattern = r".*\.lm" },
+    { test = backend_error_test, root = "./examples", pattern = r"^[^/]*\.lm$" },
+    { test = execution_test, root = "./examples", pattern = r".*\.out" },
+    { test = native_execution_test, root = "./examples", pattern = r".*\.out" },
+    { test = optimized_execution_test, root = "./examples", pattern = r".*\.out" },
+    { test = optimized_native_execution_test, root = "./examples", pattern = r".*\.out" }
 );